@@ -1,10 +1,12 @@
 fn main() {
     println!("cargo:rerun-if-changed=src/protos/database_meta.proto");
+    println!("cargo:rerun-if-changed=src/protos/http_api.proto");
 
     protobuf_codegen::Codegen::new()
         .out_dir("src/protos")
         .include("src/protos")
         .input("src/protos/database_meta.proto")
+        .input("src/protos/http_api.proto")
         .run()
         .expect("Protobuf codegen fail");
 }
@@ -0,0 +1,141 @@
+use crate::data::{DiffResponseJsonData, QueryResponseJsonData};
+use crate::errors::ClientError;
+use crate::DiffbeltClient;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use hyper::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub trait CursorPage: DeserializeOwned + Send + 'static {
+    fn cursor_id(&self) -> Option<&str>;
+}
+
+impl CursorPage for QueryResponseJsonData {
+    fn cursor_id(&self) -> Option<&str> {
+        self.cursor_id.as_deref()
+    }
+}
+
+impl CursorPage for DiffResponseJsonData {
+    fn cursor_id(&self) -> Option<&str> {
+        self.cursor_id.as_deref()
+    }
+}
+
+pub struct CursorStream<T> {
+    inner: BoxStream<'static, Result<T, ClientError>>,
+}
+
+enum CursorStep<Req> {
+    Start(Req),
+    Next(Box<str>),
+    Done,
+}
+
+struct CursorState<Req> {
+    client: DiffbeltClient,
+    // Like `/collections/name/query/`, cursor id is appended to it
+    path: String,
+    step: CursorStep<Req>,
+}
+
+impl<T: CursorPage> CursorStream<T> {
+    pub(crate) fn new<Req: Serialize + Send + Sync + 'static>(
+        client: DiffbeltClient,
+        path: String,
+        request: Req,
+    ) -> Self {
+        let state = CursorState {
+            client,
+            path,
+            step: CursorStep::Start(request),
+        };
+
+        let inner = futures::stream::unfold(state, |mut state| async move {
+            let result = match std::mem::replace(&mut state.step, CursorStep::Done) {
+                CursorStep::Start(request) => {
+                    state
+                        .client
+                        .request::<Req, T>(Method::POST, &state.path, Some(&request))
+                        .await
+                }
+                CursorStep::Next(cursor_id) => {
+                    let path = state.cursor_path(&cursor_id);
+
+                    // Kept while the request is in flight, so the cursor
+                    // will be aborted if the stream is dropped before the response
+                    state.step = CursorStep::Next(cursor_id);
+
+                    let result = state
+                        .client
+                        .request::<(), T>(Method::GET, &path, None)
+                        .await;
+
+                    state.step = CursorStep::Done;
+
+                    result
+                }
+                CursorStep::Done => {
+                    return None;
+                }
+            };
+
+            if let Ok(page) = &result {
+                if let Some(cursor_id) = page.cursor_id() {
+                    state.step = CursorStep::Next(Box::from(cursor_id));
+                }
+            }
+
+            Some((result, state))
+        });
+
+        Self {
+            inner: inner.boxed(),
+        }
+    }
+}
+
+impl<T> Stream for CursorStream<T> {
+    type Item = Result<T, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+impl<Req> CursorState<Req> {
+    fn cursor_path(&self, cursor_id: &str) -> String {
+        let mut path = self.path.clone();
+        path.push_str(&urlencoding::encode(cursor_id));
+
+        path
+    }
+}
+
+impl<Req> Drop for CursorState<Req> {
+    fn drop(&mut self) {
+        let CursorStep::Next(cursor_id) = std::mem::replace(&mut self.step, CursorStep::Done)
+        else {
+            return;
+        };
+
+        // Without runtime there is no way to send the request, the cursor will be
+        // evicted by the server when the collection has too many of them
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let client = self.client.clone();
+        let path = self.cursor_path(&cursor_id);
+
+        runtime.spawn(async move {
+            client
+                .request_no_response(Method::DELETE, &path, None::<&()>)
+                .await
+                .unwrap_or(());
+        });
+    }
+}
@@ -0,0 +1,35 @@
+pub use diffbelt::http::data::collection_settings::{
+    CollectionSettingsJsonData, CollectionSettingsUpdateJsonData,
+};
+pub use diffbelt::http::data::diff_response::DiffResponseJsonData;
+pub use diffbelt::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+pub use diffbelt::http::data::encoded_key::EncodedKeyJsonData;
+pub use diffbelt::http::data::encoded_phantom_id::EncodedPhantomIdJsonData;
+pub use diffbelt::http::data::encoded_value::EncodedValueJsonData;
+pub use diffbelt::http::data::key_value::KeyValueJsonData;
+pub use diffbelt::http::data::key_value_diff::{IntermediateValueJsonData, KeyValueDiffJsonData};
+pub use diffbelt::http::data::key_value_update::KeyValueUpdateJsonData;
+pub use diffbelt::http::data::query_response::QueryResponseJsonData;
+pub use diffbelt::http::data::reader_record::{
+    ReaderDiffFromDefJsonData, ReaderRecordJsonData, UpdateReaderJsonData,
+};
+pub use diffbelt::http::routing::routes::collection::create::{
+    CreateCollectionRequestJsonData, CreateCollectionResponseJsonData,
+};
+pub use diffbelt::http::routing::routes::collection::get::GetCollectionResponseJsonData;
+pub use diffbelt::http::routing::routes::collection::list::{
+    ListCollectionsItemJsonData, ListCollectionsResponseJsonData,
+};
+pub use diffbelt::http::routing::routes::diff::start::StartDiffRequestJsonData;
+pub use diffbelt::http::routing::routes::generation::abort::AbortGenerationRequestJsonData;
+pub use diffbelt::http::routing::routes::generation::commit::CommitGenerationRequestJsonData;
+pub use diffbelt::http::routing::routes::generation::start::StartGenerationRequestJsonData;
+pub use diffbelt::http::routing::routes::get::{GetRequestJsonData, GetResponseJsonData};
+pub use diffbelt::http::routing::routes::put::{PutRequestJsonData, PutResponseJsonData};
+pub use diffbelt::http::routing::routes::put_many::{
+    PutManyRequestJsonData, PutManyResponseJsonData,
+};
+pub use diffbelt::http::routing::routes::query::start::StartQueryRequestJsonData;
+pub use diffbelt::http::routing::routes::reader::create::CreateReaderRequestJsonData;
+pub use diffbelt::http::routing::routes::reader::list::ListReadersResponseJsonData;
+pub use diffbelt::http::routing::routes::reader::update::UpdateReaderRequestJsonData;
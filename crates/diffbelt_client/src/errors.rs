@@ -0,0 +1,26 @@
+#[derive(Debug)]
+pub enum ClientError {
+    Hyper(hyper::Error),
+    Http(hyper::http::Error),
+    Json(serde_json::Error),
+    // Non-200 response, body is usually a JSON like `{"error":"400","reason":"..."}`
+    Status { status: u16, body: String },
+}
+
+impl From<hyper::Error> for ClientError {
+    fn from(err: hyper::Error) -> Self {
+        ClientError::Hyper(err)
+    }
+}
+
+impl From<hyper::http::Error> for ClientError {
+    fn from(err: hyper::http::Error) -> Self {
+        ClientError::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for ClientError {
+    fn from(err: serde_json::Error) -> Self {
+        ClientError::Json(err)
+    }
+}
@@ -0,0 +1,353 @@
+//! HTTP client of the diffbelt server.
+//!
+//! Requests and responses are the same structs that the server uses, they are
+//! re-exported in [`data`]. Keys, values and generation ids are passed as
+//! `{value, encoding}` objects, use `encode`/`into_*` methods of them to convert
+//! from/to bytes.
+//!
+//! Query and diff cursors can be read page by page with [`DiffbeltClient::query_stream`]
+//! and [`DiffbeltClient::diff_stream`].
+
+use crate::data::*;
+use crate::errors::ClientError;
+use hyper::client::HttpConnector;
+use hyper::{Body, Client, Method, Request, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+mod cursor;
+pub mod data;
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+pub use crate::cursor::CursorStream;
+
+#[derive(Clone)]
+pub struct DiffbeltClient {
+    // Without trailing slash, like `http://127.0.0.1:3030`
+    base_url: String,
+    // Value of the `Authorization` header
+    authorization: Option<String>,
+    client: Client<HttpConnector>,
+}
+
+#[derive(Default)]
+pub struct DiffbeltClientOptions {
+    // Sent as `Authorization: Bearer <token>` with every request,
+    // needed if the server has `http.tokens`
+    pub token: Option<String>,
+}
+
+impl DiffbeltClient {
+    pub fn new(base_url: &str) -> Self {
+        Self::new_with_options(base_url, Default::default())
+    }
+
+    pub fn new_with_options(base_url: &str, options: DiffbeltClientOptions) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            authorization: options.token.map(|token| format!("Bearer {}", token)),
+            client: Client::new(),
+        }
+    }
+
+    pub async fn list_collections(&self) -> Result<ListCollectionsResponseJsonData, ClientError> {
+        self.request(Method::GET, "/collections/", None::<&()>)
+            .await
+    }
+
+    pub async fn create_collection(
+        &self,
+        request: &CreateCollectionRequestJsonData,
+    ) -> Result<CreateCollectionResponseJsonData, ClientError> {
+        self.request(Method::POST, "/collections/", Some(request))
+            .await
+    }
+
+    pub async fn get_collection(
+        &self,
+        collection_name: &str,
+    ) -> Result<GetCollectionResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "");
+
+        self.request(Method::GET, &path, None::<&()>).await
+    }
+
+    pub async fn delete_collection(&self, collection_name: &str) -> Result<(), ClientError> {
+        let path = collection_path(collection_name, "");
+
+        self.request_no_response(Method::DELETE, &path, None::<&()>)
+            .await
+    }
+
+    pub async fn get(
+        &self,
+        collection_name: &str,
+        request: &GetRequestJsonData,
+    ) -> Result<GetResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "/get");
+
+        self.request(Method::POST, &path, Some(request)).await
+    }
+
+    pub async fn put(
+        &self,
+        collection_name: &str,
+        request: &PutRequestJsonData,
+    ) -> Result<PutResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "/put");
+
+        self.request(Method::POST, &path, Some(request)).await
+    }
+
+    pub async fn put_many(
+        &self,
+        collection_name: &str,
+        request: &PutManyRequestJsonData,
+    ) -> Result<PutManyResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "/putMany");
+
+        self.request(Method::POST, &path, Some(request)).await
+    }
+
+    pub async fn start_generation(
+        &self,
+        collection_name: &str,
+        request: &StartGenerationRequestJsonData,
+    ) -> Result<(), ClientError> {
+        let path = collection_path(collection_name, "/generation/start");
+
+        self.request_no_response(Method::POST, &path, Some(request))
+            .await
+    }
+
+    pub async fn commit_generation(
+        &self,
+        collection_name: &str,
+        request: &CommitGenerationRequestJsonData,
+    ) -> Result<(), ClientError> {
+        let path = collection_path(collection_name, "/generation/commit");
+
+        self.request_no_response(Method::POST, &path, Some(request))
+            .await
+    }
+
+    pub async fn abort_generation(
+        &self,
+        collection_name: &str,
+        request: &AbortGenerationRequestJsonData,
+    ) -> Result<(), ClientError> {
+        let path = collection_path(collection_name, "/generation/abort");
+
+        self.request_no_response(Method::POST, &path, Some(request))
+            .await
+    }
+
+    pub async fn list_readers(
+        &self,
+        collection_name: &str,
+    ) -> Result<ListReadersResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "/readers/");
+
+        self.request(Method::GET, &path, None::<&()>).await
+    }
+
+    pub async fn create_reader(
+        &self,
+        collection_name: &str,
+        request: &CreateReaderRequestJsonData,
+    ) -> Result<(), ClientError> {
+        let path = collection_path(collection_name, "/readers/");
+
+        self.request_no_response(Method::POST, &path, Some(request))
+            .await
+    }
+
+    pub async fn update_reader(
+        &self,
+        collection_name: &str,
+        reader_name: &str,
+        request: &UpdateReaderRequestJsonData,
+    ) -> Result<(), ClientError> {
+        let path = collection_item_path(collection_name, "/readers/", reader_name);
+
+        self.request_no_response(Method::PUT, &path, Some(request))
+            .await
+    }
+
+    pub async fn delete_reader(
+        &self,
+        collection_name: &str,
+        reader_name: &str,
+    ) -> Result<(), ClientError> {
+        let path = collection_item_path(collection_name, "/readers/", reader_name);
+
+        self.request_no_response(Method::DELETE, &path, None::<&()>)
+            .await
+    }
+
+    pub async fn start_query(
+        &self,
+        collection_name: &str,
+        request: &StartQueryRequestJsonData,
+    ) -> Result<QueryResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "/query/");
+
+        self.request(Method::POST, &path, Some(request)).await
+    }
+
+    pub async fn next_query(
+        &self,
+        collection_name: &str,
+        cursor_id: &str,
+    ) -> Result<QueryResponseJsonData, ClientError> {
+        let path = collection_item_path(collection_name, "/query/", cursor_id);
+
+        self.request(Method::GET, &path, None::<&()>).await
+    }
+
+    pub async fn abort_query(
+        &self,
+        collection_name: &str,
+        cursor_id: &str,
+    ) -> Result<(), ClientError> {
+        let path = collection_item_path(collection_name, "/query/", cursor_id);
+
+        self.request_no_response(Method::DELETE, &path, None::<&()>)
+            .await
+    }
+
+    pub async fn start_diff(
+        &self,
+        collection_name: &str,
+        request: &StartDiffRequestJsonData,
+    ) -> Result<DiffResponseJsonData, ClientError> {
+        let path = collection_path(collection_name, "/diff/");
+
+        self.request(Method::POST, &path, Some(request)).await
+    }
+
+    pub async fn next_diff(
+        &self,
+        collection_name: &str,
+        cursor_id: &str,
+    ) -> Result<DiffResponseJsonData, ClientError> {
+        let path = collection_item_path(collection_name, "/diff/", cursor_id);
+
+        self.request(Method::GET, &path, None::<&()>).await
+    }
+
+    pub async fn abort_diff(
+        &self,
+        collection_name: &str,
+        cursor_id: &str,
+    ) -> Result<(), ClientError> {
+        let path = collection_item_path(collection_name, "/diff/", cursor_id);
+
+        self.request_no_response(Method::DELETE, &path, None::<&()>)
+            .await
+    }
+
+    // Yields pages until `cursorId` is absent, the cursor is aborted
+    // if the stream is dropped before that
+    pub fn query_stream(
+        &self,
+        collection_name: &str,
+        request: StartQueryRequestJsonData,
+    ) -> CursorStream<QueryResponseJsonData> {
+        CursorStream::new(
+            self.clone(),
+            collection_path(collection_name, "/query/"),
+            request,
+        )
+    }
+
+    pub fn diff_stream(
+        &self,
+        collection_name: &str,
+        request: StartDiffRequestJsonData,
+    ) -> CursorStream<DiffResponseJsonData> {
+        CursorStream::new(
+            self.clone(),
+            collection_path(collection_name, "/diff/"),
+            request,
+        )
+    }
+
+    async fn request<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Req>,
+    ) -> Result<Res, ClientError> {
+        let bytes = self.request_bytes(method, path, body).await?;
+
+        let response = serde_json::from_slice(&bytes)?;
+
+        Ok(response)
+    }
+
+    async fn request_no_response<Req: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Req>,
+    ) -> Result<(), ClientError> {
+        self.request_bytes(method, path, body).await?;
+
+        Ok(())
+    }
+
+    async fn request_bytes<Req: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Req>,
+    ) -> Result<hyper::body::Bytes, ClientError> {
+        let mut url = String::with_capacity(self.base_url.len() + path.len());
+        url.push_str(&self.base_url);
+        url.push_str(path);
+
+        let mut builder = Request::builder().method(method).uri(url);
+
+        if let Some(authorization) = &self.authorization {
+            builder = builder.header("Authorization", authorization);
+        }
+
+        let request = match body {
+            Some(body) => builder
+                .header("Content-Type", "application/json; charset=utf-8")
+                .body(Body::from(serde_json::to_vec(body)?))?,
+            None => builder.body(Body::empty())?,
+        };
+
+        let response = self.client.request(request).await?;
+        let status = response.status();
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+        if status != StatusCode::OK {
+            return Err(ClientError::Status {
+                status: status.as_u16(),
+                body: String::from_utf8_lossy(&bytes).into_owned(),
+            });
+        }
+
+        Ok(bytes)
+    }
+}
+
+fn collection_path(collection_name: &str, suffix: &str) -> String {
+    let mut path = String::from("/collections/");
+    path.push_str(&urlencoding::encode(collection_name));
+    path.push_str(suffix);
+
+    path
+}
+
+fn collection_item_path(collection_name: &str, suffix: &str, item: &str) -> String {
+    let mut path = collection_path(collection_name, suffix);
+    path.push_str(&urlencoding::encode(item));
+
+    path
+}
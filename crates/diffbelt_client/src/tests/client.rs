@@ -0,0 +1,281 @@
+use crate::data::*;
+use crate::errors::ClientError;
+use crate::tests::test_server::TestServer;
+use crate::{DiffbeltClient, DiffbeltClientOptions};
+use diffbelt::http::auth::{AccessGrant, AccessRole, ApiToken};
+use diffbelt::util::str_serialization::StrSerializationType;
+use diffbelt::{
+    create_main_tokio_runtime, DatabaseConfig, IsByteArray, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use futures::StreamExt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[test]
+fn client_test() {
+    let server = TestServer::new();
+    let runtime = create_main_tokio_runtime().unwrap();
+
+    runtime.block_on(client_test_inner(&server));
+
+    // Database should be closed before the directory removal
+    drop(runtime);
+}
+
+async fn client_test_inner(server: &TestServer) {
+    // Small packs to have multiple pages in cursors
+    let client = server
+        .start(DatabaseConfig {
+            query_pack_limit: 2,
+            diff_pack_limit: 2,
+            ..Default::default()
+        })
+        .await;
+
+    let initial_generation_id = OwnedGenerationId::zero_64bits();
+    let generation_id = initial_generation_id.incremented();
+    let encoded_generation_id =
+        || EncodedGenerationIdJsonData::encode(generation_id.as_ref(), StrSerializationType::Utf8);
+
+    client
+        .create_collection(&CreateCollectionRequestJsonData {
+            collection_name: "test".to_string(),
+            is_manual: true,
+            if_value_changed: None,
+            initial_generation_id: Some(EncodedGenerationIdJsonData::encode(
+                initial_generation_id.as_ref(),
+                StrSerializationType::Utf8,
+            )),
+            settings: None,
+        })
+        .await
+        .unwrap();
+
+    let collections = client.list_collections().await.unwrap();
+    assert_eq!(collections.items.len(), 1);
+    assert_eq!(collections.items[0].name, "test");
+    assert!(collections.items[0].is_manual);
+
+    client
+        .start_generation(
+            "test",
+            &StartGenerationRequestJsonData {
+                generation_id: encoded_generation_id(),
+                abort_outdated: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    client
+        .put_many(
+            "test",
+            &PutManyRequestJsonData {
+                items: (0..5)
+                    .map(|index| KeyValueUpdateJsonData {
+                        key: encoded_key(&format!("key{}", index)),
+                        if_not_present: None,
+                        if_value_changed: None,
+                        value: Some(EncodedValueJsonData::encode(OwnedCollectionValue::new(
+                            // Not UTF-8, will be passed in base64
+                            &[0xff, index as u8],
+                        ))),
+                    })
+                    .collect(),
+                generation_id: Some(encoded_generation_id()),
+                phantom_id: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    client
+        .commit_generation(
+            "test",
+            &CommitGenerationRequestJsonData {
+                generation_id: encoded_generation_id(),
+                update_readers: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let response = client
+        .get(
+            "test",
+            &GetRequestJsonData {
+                key: encoded_key("key3"),
+                generation_id: None,
+                phantom_id: None,
+            },
+        )
+        .await
+        .unwrap();
+    let value = response
+        .item
+        .unwrap()
+        .value
+        .into_collection_value()
+        .unwrap();
+    assert_eq!(value.get_value(), &[0xff, 3]);
+
+    // Query cursor is followed until the end
+    let pages: Vec<QueryResponseJsonData> = client
+        .query_stream("test", StartQueryRequestJsonData::default())
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert!(pages.len() > 2);
+
+    let keys: Vec<Vec<u8>> = pages
+        .into_iter()
+        .flat_map(|page| page.items)
+        .map(|item| {
+            item.key
+                .into_collection_key()
+                .unwrap()
+                .get_byte_array()
+                .to_vec()
+        })
+        .collect();
+    let expected: Vec<Vec<u8>> = (0..5)
+        .map(|index| format!("key{}", index).into_bytes())
+        .collect();
+    assert_eq!(keys, expected);
+
+    let items_count: usize = client
+        .diff_stream(
+            "test",
+            StartDiffRequestJsonData {
+                from_generation_id: Some(EncodedGenerationIdJsonData::encode(
+                    initial_generation_id.as_ref(),
+                    StrSerializationType::Utf8,
+                )),
+                ..Default::default()
+            },
+        )
+        .map(|page| page.unwrap().items.len())
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .sum();
+    assert_eq!(items_count, 5);
+
+    // Cursor is aborted when the stream is dropped before the end
+    let mut stream = client.query_stream("test", StartQueryRequestJsonData::default());
+    let page = stream.next().await.unwrap().unwrap();
+    let cursor_id = page.cursor_id.unwrap();
+    drop(stream);
+
+    wait_for_cursor_abort(&client, &cursor_id).await;
+
+    client
+        .create_reader(
+            "test",
+            &CreateReaderRequestJsonData {
+                reader_name: "reader".to_string(),
+                generation_id: Some(encoded_generation_id()),
+                collection_name: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let readers = client.list_readers("test").await.unwrap();
+    assert_eq!(readers.items.len(), 1);
+    assert_eq!(readers.items[0].reader_name, "reader");
+
+    client.delete_reader("test", "reader").await.unwrap();
+    assert!(client.list_readers("test").await.unwrap().items.is_empty());
+
+    let collection = client.get_collection("test").await.unwrap();
+    let collection_generation_id = collection.generation_id.unwrap();
+    assert_eq!(
+        collection_generation_id.into_generation_id().unwrap(),
+        generation_id
+    );
+
+    client.delete_collection("test").await.unwrap();
+
+    let result = client.get_collection("test").await;
+    assert!(matches!(
+        result,
+        Err(ClientError::Status { status: 400, .. })
+    ));
+}
+
+#[test]
+fn client_token_test() {
+    let server = TestServer::new();
+    let runtime = create_main_tokio_runtime().unwrap();
+
+    runtime.block_on(client_token_test_inner(&server));
+
+    drop(runtime);
+}
+
+async fn client_token_test_inner(server: &TestServer) {
+    let base_url = server
+        .start_with_tokens(
+            Default::default(),
+            vec![Arc::new(ApiToken {
+                name: "reader".to_string(),
+                token: "secret".to_string(),
+                grants: vec![AccessGrant {
+                    pattern: "*".to_string(),
+                    role: AccessRole::Read,
+                }],
+            })],
+        )
+        .await;
+
+    let client = DiffbeltClient::new(&base_url);
+
+    let result = client.list_collections().await;
+    assert!(matches!(
+        result,
+        Err(ClientError::Status { status: 401, .. })
+    ));
+
+    let client = DiffbeltClient::new_with_options(
+        &base_url,
+        DiffbeltClientOptions {
+            token: Some("secret".to_string()),
+        },
+    );
+
+    let collections = client.list_collections().await.unwrap();
+    assert!(collections.items.is_empty());
+
+    let result = client
+        .create_collection(&CreateCollectionRequestJsonData {
+            collection_name: "test".to_string(),
+            is_manual: true,
+            if_value_changed: None,
+            initial_generation_id: None,
+            settings: None,
+        })
+        .await;
+    assert!(matches!(
+        result,
+        Err(ClientError::Status { status: 403, .. })
+    ));
+}
+
+fn encoded_key(key: &str) -> EncodedKeyJsonData {
+    EncodedKeyJsonData::encode(OwnedCollectionKey::from_boxed_slice(key.as_bytes().into()).unwrap())
+}
+
+async fn wait_for_cursor_abort(client: &DiffbeltClient, cursor_id: &str) {
+    for _ in 0..100 {
+        if client.next_query("test", cursor_id).await.is_err() {
+            return;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    panic!("cursor was not aborted");
+}
@@ -0,0 +1,119 @@
+use crate::{DiffbeltClient, DiffbeltClientOptions};
+use diffbelt::config::Config;
+use diffbelt::context::Context;
+use diffbelt::http::auth::ApiToken;
+use diffbelt::http::config::{HttpConfig, ListenAddress};
+use diffbelt::http::routing::register_routes::register_routes;
+use diffbelt::http::routing::Routing;
+use diffbelt::http::server::start_http_server;
+use diffbelt::{Database, DatabaseConfig, DatabaseOpenOptions};
+use std::fs::remove_dir_all;
+use std::net::{SocketAddr, TcpListener};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::sleep;
+
+pub struct TestServer {
+    data_path: PathBuf,
+}
+
+impl TestServer {
+    pub fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos();
+
+        let data_path = std::env::temp_dir().join(format!(
+            "diffbelt_client_test_{}_{}",
+            std::process::id(),
+            nanos
+        ));
+
+        Self { data_path }
+    }
+
+    // Server task lives until the runtime is dropped
+    pub async fn start(&self, database_config: DatabaseConfig) -> DiffbeltClient {
+        let base_url = self.start_with_tokens(database_config, Vec::new()).await;
+
+        DiffbeltClient::new(&base_url)
+    }
+
+    // Returns the base url, the first token is used to wait for the server start
+    pub async fn start_with_tokens(
+        &self,
+        database_config: DatabaseConfig,
+        tokens: Vec<Arc<ApiToken>>,
+    ) -> String {
+        // Port is released right away, so there is a small chance that it will be taken
+        let listen_address = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let config = Arc::new(Config {
+            data_path: self.data_path.clone(),
+            is_clear: false,
+            backup_path: None,
+            http: HttpConfig {
+                listen_addresses: vec![ListenAddress::Tcp(listen_address)],
+                tokens,
+                ..Default::default()
+            },
+            database: Arc::new(database_config),
+            log: Default::default(),
+            warnings: Vec::new(),
+        });
+
+        let database = Database::open(DatabaseOpenOptions {
+            data_path: &config.data_path,
+            config: config.database.clone(),
+        })
+        .await
+        .expect("Cannot open database");
+
+        let mut context = Context {
+            config,
+            routing: Routing::new(),
+            database: Arc::new(database),
+            http_metrics: Default::default(),
+            logging: Default::default(),
+        };
+
+        register_routes(&mut context);
+
+        let token = context
+            .config
+            .http
+            .tokens
+            .first()
+            .map(|token| token.token.clone());
+
+        tokio::spawn(start_http_server(Arc::new(context)));
+
+        let base_url = base_url(listen_address);
+        let client = DiffbeltClient::new_with_options(&base_url, DiffbeltClientOptions { token });
+
+        for _ in 0..100 {
+            if client.list_collections().await.is_ok() {
+                return base_url;
+            }
+
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        panic!("server has not started");
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        remove_dir_all(&self.data_path).unwrap_or(());
+    }
+}
+
+fn base_url(address: SocketAddr) -> String {
+    format!("http://{}", address)
+}
@@ -21,11 +21,6 @@ impl DiffCursor {
         &self,
         options: GetPackOptions,
     ) -> Result<DiffCursorPack, CollectionMethodError> {
-        if !self.omit_intermediate_values {
-            // TODO: implement intermediate values in the diff
-            return Err(CollectionMethodError::NotImplementedYet);
-        }
-
         let GetPackOptions {
             db,
             db_inner,
@@ -65,6 +60,7 @@ impl DiffCursor {
             from_generation_id: from_generation_id.as_ref().map(|id| id.as_ref()),
             to_generation_id_loose: self.to_generation_id.as_ref(),
             prev_diff_state: self.raw_db_cursor_state.as_ref(),
+            omit_intermediate_values: self.omit_intermediate_values,
             limit: config.diff_pack_limit,
             records_to_view_limit: config.diff_pack_records_limit,
             total_count_in_generations_limit: config.diff_changes_limit,
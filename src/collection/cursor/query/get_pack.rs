@@ -34,6 +34,8 @@ impl QueryCursor {
         let result = db.query_collection_records_sync(QueryCollectionRecordsOptions {
             generation_id: self.generation_id.as_ref(),
             phantom_id: phantom_id.clone(),
+            key_range: self.key_range.as_ref(),
+            reverse: self.reverse,
             last_record_key,
             from_record_key,
             limit: config.query_pack_limit,
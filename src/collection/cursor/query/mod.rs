@@ -1,3 +1,4 @@
+use crate::common::key_range::OwnedKeyRange;
 use crate::common::{GenerationId, KeyValue, OwnedGenerationId, OwnedPhantomId};
 use crate::database::cursors::query::QueryCursor;
 
@@ -9,6 +10,8 @@ pub mod get_pack;
 pub struct QueryCursorNewOptions {
     pub generation_id: OwnedGenerationId,
     pub phantom_id: Option<OwnedPhantomId>,
+    pub key_range: OwnedKeyRange,
+    pub reverse: bool,
 }
 
 pub struct QueryCursorPack {
@@ -22,6 +25,8 @@ impl QueryCursor {
             public_id: CursorPublicId(0),
             generation_id: options.generation_id,
             phantom_id: options.phantom_id,
+            key_range: options.key_range,
+            reverse: options.reverse,
             last_and_next_record_key: None,
         }
     }
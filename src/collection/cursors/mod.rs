@@ -1,35 +1,42 @@
-#[cfg(test)]
 use crate::collection::Collection;
-#[cfg(test)]
+use crate::database::cursors::storage::CursorType;
 use crate::messages::cursors::DatabaseCollectionSpecificCursorsTask;
-#[cfg(test)]
 use crate::messages::cursors::{DatabaseCollectionCursorsTask, GetCollectionCursorsCountTask};
-#[cfg(test)]
 use crate::util::async_sync_call::async_sync_call;
-#[cfg(test)]
 use std::marker::PhantomData;
 
-#[cfg(test)]
 impl Collection {
     pub async fn query_cursors_count(&self) -> usize {
-        let count = async_sync_call(|sender| {
-            self.database_inner
-                .add_cursors_task(DatabaseCollectionCursorsTask::Query(
-                    DatabaseCollectionSpecificCursorsTask::GetCollectionQueryCursorsCount(
-                        GetCollectionCursorsCountTask {
-                            cursor_type: PhantomData::default(),
-                            collection_id: self.cursors_id,
-                            sender,
-                        },
-                    ),
-                ))
+        self.cursors_count(DatabaseCollectionCursorsTask::Query)
+            .await
+    }
+
+    pub async fn diff_cursors_count(&self) -> usize {
+        self.cursors_count(DatabaseCollectionCursorsTask::Diff)
+            .await
+    }
+
+    // Collection that is dropped concurrently has no cursors
+    async fn cursors_count<T: CursorType>(
+        &self,
+        make_task: fn(DatabaseCollectionSpecificCursorsTask<T>) -> DatabaseCollectionCursorsTask,
+    ) -> usize {
+        let result = async_sync_call(|sender| {
+            self.database_inner.add_cursors_task(make_task(
+                DatabaseCollectionSpecificCursorsTask::GetCollectionQueryCursorsCount(
+                    GetCollectionCursorsCountTask {
+                        cursor_type: PhantomData,
+                        collection_id: self.cursors_id,
+                        sender,
+                    },
+                ),
+            ))
         })
-        .await
-        .map_err(|_| ())
-        .unwrap()
-        .map_err(|_| ())
-        .unwrap();
+        .await;
 
-        count
+        match result {
+            Ok(Ok(count)) => count,
+            _ => 0,
+        }
     }
 }
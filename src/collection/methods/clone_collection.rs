@@ -0,0 +1,124 @@
+use crate::collection::constants::COLLECTION_CF_META;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::open::open_collection_raw_db;
+use crate::collection::util::reader_value::{OwnedReaderValue, ReaderValue};
+use crate::collection::Collection;
+use crate::common::{GenerationId, IsByteArray, OwnedGenerationId};
+use crate::raw_db::remove_all_records_after_generation::RemoveAllRecordsAfterGenerationSyncOptions;
+use crate::raw_db::{RawDbConfig, RawDbError, RawDbOpenError};
+use crate::util::tokio::spawn_blocking;
+
+pub struct CreateCheckpointOptions {
+    pub path: String,
+    // Current generation if not specified
+    pub generation_id: Option<OwnedGenerationId>,
+}
+
+impl Collection {
+    // Makes a copy of the collection raw_db at `path`, returns generation id to which
+    // the copy should be cut by `prepare_cloned_collection_sync`
+    pub async fn create_checkpoint(
+        &self,
+        options: CreateCheckpointOptions,
+    ) -> Result<OwnedGenerationId, CollectionMethodError> {
+        let CreateCheckpointOptions {
+            path,
+            generation_id,
+        } = options;
+
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        // GC should not remove records of the generation until checkpoint is created
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        let current_generation_id = self.generation_pair().generation_id;
+
+        let generation_id = match generation_id {
+            Some(generation_id) => {
+                if generation_id > current_generation_id {
+                    return Err(CollectionMethodError::GenerationIdNotCommitted(
+                        current_generation_id,
+                    ));
+                }
+
+                self.check_generation_is_not_less_than_minimum(generation_id.as_ref())?;
+
+                generation_id
+            }
+            None => current_generation_id,
+        };
+
+        let raw_db = self.raw_db.clone();
+
+        spawn_blocking(move || raw_db.create_checkpoint_sync(&path))
+            .await
+            .map_err(|_| CollectionMethodError::TaskJoin)??;
+
+        drop(minimum_generation_id_lock);
+        drop(deletion_lock);
+
+        Ok(generation_id)
+    }
+}
+
+// Removes everything that was written to the copy of collection after `generation_id`,
+// copy should not be opened as a collection yet
+pub fn prepare_cloned_collection_sync(
+    path: &str,
+    generation_id: GenerationId<'_>,
+    is_manual: bool,
+    raw_db_config: &RawDbConfig,
+) -> Result<(), CollectionMethodError> {
+    let raw_db = open_collection_raw_db(path, raw_db_config).map_err(|err| match err {
+        RawDbOpenError::RocksDbError(err) => RawDbError::RocksDb(err),
+    })?;
+
+    raw_db.remove_all_records_after_generation_sync(
+        RemoveAllRecordsAfterGenerationSyncOptions { generation_id },
+    )?;
+
+    raw_db.put_cf_sync(
+        COLLECTION_CF_META,
+        b"generation_id",
+        generation_id.get_byte_array(),
+    )?;
+
+    if is_manual {
+        // Started generation is not copied
+        raw_db.delete_cf_sync(COLLECTION_CF_META, b"next_generation_id")?;
+    } else {
+        let next_generation_id = generation_id.incremented();
+
+        raw_db.put_cf_sync(
+            COLLECTION_CF_META,
+            b"next_generation_id",
+            next_generation_id.get_byte_array(),
+        )?;
+    }
+
+    // Readers of the collection itself can't be ahead of its generation
+    let readers = raw_db.get_range_sync_cf(COLLECTION_CF_META, b"reader:", b"reader;")?;
+
+    for (key, value) in readers {
+        let reader_value =
+            ReaderValue::from_slice(&value).or(Err(CollectionMethodError::InvalidReaderValue))?;
+
+        if !reader_value.get_collection_name().is_empty() {
+            continue;
+        }
+
+        if reader_value.get_generation_id() <= generation_id {
+            continue;
+        }
+
+        let new_value = OwnedReaderValue::new(None, generation_id)
+            .or(Err(CollectionMethodError::InvalidReaderValue))?;
+
+        raw_db.put_cf_sync(COLLECTION_CF_META, &key, new_value.get_byte_array())?;
+    }
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::{Collection, CommitGenerationUpdateReader};
 use crate::common::OwnedGenerationId;
-use crate::messages::generations::{
-    CommitManualGenerationError, CommitManualGenerationTask, DatabaseCollectionGenerationsTask,
+use crate::messages::generations::{CommitManualGenerationTask, DatabaseCollectionGenerationsTask};
+use crate::messages::readers::{
+    DatabaseCollectionReadersTask, GetMinimumGenerationIdLocksTask,
+    GetMinimumGenerationIdLocksTaskResponse,
 };
-use crate::messages::readers::{DatabaseCollectionReadersTask, GetMinimumGenerationIdLocksTask};
 use crate::util::async_sync_call::async_sync_call;
 
 pub struct CommitGenerationOptions {
@@ -22,43 +23,10 @@ impl Collection {
             update_readers,
         } = options;
 
-        let minimum_generation_id_locks = if let Some(update_readers) = &update_readers {
-            let collection_name = self.name.clone();
-            let mut reader_names = Vec::new();
-
-            for update in update_readers {
-                reader_names.push(update.reader_name.clone());
-            }
-
-            let locks = async_sync_call(|sender| {
-                self.database_inner.add_readers_task(
-                    DatabaseCollectionReadersTask::GetMinimumGenerationIdLocks(
-                        GetMinimumGenerationIdLocksTask {
-                            collection_name,
-                            reader_names,
-                            sender,
-                        },
-                    ),
-                )
-            })
+        let minimum_generation_id_locks = self
+            .lock_readers_minimum_generation_ids(update_readers.as_deref())
             .await?;
 
-            for update in update_readers {
-                if let Some((minimum_generation_id, _)) = locks
-                    .minimum_generation_ids_with_locks
-                    .get(&update.reader_name)
-                {
-                    if &update.generation_id < minimum_generation_id {
-                        return Err(CollectionMethodError::GenerationIdLessThanMinimum);
-                    }
-                }
-            }
-
-            Some(locks)
-        } else {
-            None
-        };
-
         let _: () = async_sync_call(|sender| {
             self.database_inner.add_generations_task(
                 DatabaseCollectionGenerationsTask::CommitManualGeneration(
@@ -73,18 +41,56 @@ impl Collection {
         })
         .await
         .map_err(CollectionMethodError::OneshotRecv)?
-        .map_err(|err| match err {
-            CommitManualGenerationError::RawDb(err) => CollectionMethodError::RawDb(err),
-            CommitManualGenerationError::OutdatedGeneration => {
-                CollectionMethodError::OutdatedGeneration
-            }
-            CommitManualGenerationError::NoSuchCollection => {
-                CollectionMethodError::NoSuchCollection
-            }
-        })?;
+        .map_err(CollectionMethodError::from)?;
 
         drop(minimum_generation_id_locks);
 
         Ok(())
     }
+
+    // Checks that readers will not be moved below their minimum generation ids,
+    // minimum generation ids are not changing until returned locks are dropped
+    pub async fn lock_readers_minimum_generation_ids(
+        &self,
+        update_readers: Option<&[CommitGenerationUpdateReader]>,
+    ) -> Result<Option<GetMinimumGenerationIdLocksTaskResponse>, CollectionMethodError> {
+        let Some(update_readers) = update_readers else {
+            return Ok(None);
+        };
+
+        let collection_name = self.name.clone();
+        let mut reader_names = Vec::new();
+
+        for update in update_readers {
+            reader_names.push(update.reader_name.clone());
+        }
+
+        let locks = async_sync_call(|sender| {
+            self.database_inner.add_readers_task(
+                DatabaseCollectionReadersTask::GetMinimumGenerationIdLocks(
+                    GetMinimumGenerationIdLocksTask {
+                        collection_name,
+                        reader_names,
+                        sender,
+                    },
+                ),
+            )
+        })
+        .await?;
+
+        for update in update_readers {
+            if let Some((minimum_generation_id, _)) = locks
+                .minimum_generation_ids_with_locks
+                .get(&update.reader_name)
+            {
+                if &update.generation_id < minimum_generation_id {
+                    return Err(CollectionMethodError::GenerationIdLessThanMinimum(
+                        minimum_generation_id.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(Some(locks))
+    }
 }
@@ -2,8 +2,8 @@ use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::Collection;
 use crate::common::{GenerationId, OwnedGenerationId};
 use crate::messages::readers::{DatabaseCollectionReadersTask, UpdateReaderTask};
+use crate::util::tokio::spawn_blocking;
 use std::sync::Arc;
-use tokio::task::spawn_blocking;
 
 use crate::raw_db::update_reader::{RawDbCreateReaderOptions, RawDbCreateReaderResult};
 use crate::util::async_sync_call::async_sync_call;
@@ -35,14 +35,12 @@ impl Collection {
 
         let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
 
-        if self.generation_is_less_than_minimum(
+        self.check_generation_is_not_less_than_minimum(
             generation_id
                 .as_ref()
                 .map(|id| id.as_ref())
                 .unwrap_or_else(|| GenerationId::empty()),
-        ) {
-            return Err(CollectionMethodError::GenerationIdLessThanMinimum);
-        }
+        )?;
 
         let result = spawn_blocking(move || {
             raw_db.create_reader_sync(RawDbCreateReaderOptions {
@@ -1,20 +1,12 @@
 use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::Collection;
 
-use crate::messages::generations::{
-    DatabaseCollectionGenerationsTask, DropCollectionGenerationsTask,
-};
-use crate::raw_db::RawDb;
-use crate::util::async_sync_call::async_sync_call;
-
-use crate::messages::garbage_collector::{
-    DatabaseGarbageCollectorTask, GarbageCollectorDropCollectionTask,
+use crate::collection::util::close::{
+    detach_collection_from_threads, wait_for_raw_db_close, DetachCollectionOptions,
 };
 use crate::util::tokio::spawn_blocking_async;
 use std::future::Future;
 use std::ops::DerefMut;
-use std::path::PathBuf;
-use std::sync::Arc;
 
 impl Collection {
     pub fn delete_collection(&self) -> impl Future<Output = Result<(), CollectionMethodError>> {
@@ -27,6 +19,8 @@ impl Collection {
         let raw_db = self.raw_db.clone();
 
         let join = spawn_blocking_async(async move {
+            let _backup_guard = database_inner.backup_lock.read().await;
+
             // Make all methods return `NoSuchCollection` after this write
             // if they have ref to this Collection
             let mut deletion_lock = is_deleted.write().await;
@@ -37,82 +31,36 @@ impl Collection {
             let is_deleted = deletion_lock.deref_mut();
             *is_deleted = true;
 
+            // Methods that are waiting for the lock will see the flag and release
+            // their refs to raw_db, otherwise we can wait for raw_db drop forever
+            drop(deletion_lock);
+
             // Preparation to delete
-            database_inner
+            let trash_id = database_inner
                 .start_delete_collection(&collection_name)
                 .await?;
 
-            let _: () = async_sync_call(|sender| {
-                database_inner.add_generations_task(
-                    DatabaseCollectionGenerationsTask::DropCollection(
-                        DropCollectionGenerationsTask {
-                            collection_id: collection_generations_id,
-                            sender: Some(sender),
-                        },
-                    ),
-                )
-            })
-            .await?;
-
-            let _: () = async_sync_call(|sender| {
-                database_inner.add_gc_task(DatabaseGarbageCollectorTask::DropCollection(
-                    GarbageCollectorDropCollectionTask {
-                        collection_name: Arc::<str>::clone(&collection_name),
-                        id: gc_id,
-                        sender: Some(sender),
-                    },
-                ))
+            detach_collection_from_threads(DetachCollectionOptions {
+                database_inner: &database_inner,
+                collection_name: &collection_name,
+                generations_id: collection_generations_id,
+                gc_id,
             })
             .await?;
 
             database_inner
-                .remove_readers_pointing_to_collection(collection_name.clone())
+                .detach_readers_of_deleted_collection(collection_name.clone())
                 .await?;
 
-            // Destroy raw_db, remove files
-            let path = raw_db.get_path().to_string();
-            let mut is_alive_receiver = raw_db.get_is_alive_receiver();
-            drop(raw_db);
-
-            loop {
-                let result = is_alive_receiver.changed().await;
-                match result {
-                    Ok(_) => {}
-                    Err(_) => {
-                        // error is possible only if it was droppped, so db should be dropped
-                        break;
-                    }
-                }
+            // Close raw_db and move its files to the trash
+            wait_for_raw_db_close(raw_db).await;
 
-                let is_alive = *is_alive_receiver.borrow();
-
-                if !is_alive {
-                    break;
-                }
-            }
-
-            RawDb::destroy(&path)?;
-
-            // TODO: do not delete, move it and then delete after a few hours/days
-            //       as config says and give ability to restore it
-            let path = PathBuf::from(path);
-            let path = path.as_path();
-
-            std::fs::remove_dir_all(path).or_else(|err| {
-                match err.kind() {
-                    std::io::ErrorKind::NotFound => {
-                        return Ok(());
-                    }
-                    _ => {}
-                }
-
-                Err(CollectionMethodError::CannotDeleteRawDbPath(err))
-            })?;
+            database_inner
+                .move_collection_to_trash_sync(&collection_name, &trash_id)
+                .map_err(CollectionMethodError::CannotDeleteRawDbPath)?;
 
             // Finalization of deletion
-            database_inner.finish_delete_collection_sync(&collection_name)?;
-
-            drop(deletion_lock);
+            database_inner.finish_delete_collection_sync(&collection_name, Some(&trash_id))?;
 
             Ok::<(), CollectionMethodError>(())
         });
@@ -19,6 +19,7 @@ use crate::messages::cursors::{
 };
 
 use crate::util::async_sync_call::async_sync_call;
+use crate::util::tokio::spawn_blocking;
 
 type CursorId = Box<str>;
 type NextCursorId = Box<str>;
@@ -26,6 +27,7 @@ type NextCursorId = Box<str>;
 pub struct DiffOptions {
     pub from_generation_id: GenerationIdSource,
     pub to_generation_id_loose: Option<OwnedGenerationId>,
+    pub omit_intermediate_values: bool,
 }
 
 pub struct ReadDiffCursorOptions {
@@ -48,26 +50,31 @@ impl Collection {
         let DiffOptions {
             from_generation_id,
             to_generation_id_loose,
+            omit_intermediate_values,
         } = options;
 
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        self.check_diff_from_generation_id(&from_generation_id)?;
+
         let to_generation_id_loose = self.generation_id_or_current(to_generation_id_loose).await;
 
         let initial_cursor = DiffCursor::new(DiffCursorNewOptions {
             from_generation_id,
             to_generation_id_loose,
-            omit_intermediate_values: true,
+            omit_intermediate_values,
         });
 
-        let deletion_lock = self.is_deleted.read().await;
-        if deletion_lock.to_owned() {
-            return Err(CollectionMethodError::NoSuchCollection);
-        }
-
         let result = {
             let db = self.raw_db.clone();
             let db_inner = self.database_inner.clone();
             let config = self.config.clone();
-            tokio::task::spawn_blocking(move || {
+            spawn_blocking(move || {
                 initial_cursor.get_pack_sync(GetPackOptions {
                     db,
                     db_inner,
@@ -98,7 +105,7 @@ impl Collection {
                                 data: AddDiffCursorData {
                                     from_generation_id,
                                     to_generation_id,
-                                    omit_intermediate_values: true,
+                                    omit_intermediate_values,
                                     raw_db_cursor_state: next_diff_state,
                                 },
                                 sender,
@@ -114,6 +121,7 @@ impl Collection {
             None => None,
         };
 
+        drop(minimum_generation_id_lock);
         drop(deletion_lock);
 
         Ok(DiffOk {
@@ -185,12 +193,17 @@ impl Collection {
             return Err(CollectionMethodError::NoSuchCollection);
         }
 
+        // Readers could move forward since the cursor was created
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        self.check_diff_from_generation_id(&cursor.from_generation_id)?;
+
         let result = {
             let cursor = cursor.clone();
             let db = self.raw_db.clone();
             let db_inner = self.database_inner.clone();
             let config = self.config.clone();
-            tokio::task::spawn_blocking(move || {
+            spawn_blocking(move || {
                 cursor.get_pack_sync(GetPackOptions {
                     db,
                     db_inner,
@@ -252,6 +265,7 @@ impl Collection {
             }
         };
 
+        drop(minimum_generation_id_lock);
         drop(deletion_lock);
 
         Ok(DiffOk {
@@ -293,4 +307,18 @@ impl Collection {
 
         Ok(())
     }
+
+    // Diff from no generation or from a reader is always possible, readers
+    // are never behind the minimum generation id
+    fn check_diff_from_generation_id(
+        &self,
+        from_generation_id: &GenerationIdSource,
+    ) -> Result<(), CollectionMethodError> {
+        match from_generation_id {
+            GenerationIdSource::Value(Some(generation_id)) => {
+                self.check_generation_is_not_less_than_minimum(generation_id.as_ref())
+            }
+            GenerationIdSource::Value(None) | GenerationIdSource::Reader(_) => Ok(()),
+        }
+    }
 }
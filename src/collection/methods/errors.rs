@@ -1,5 +1,7 @@
 use crate::collection::util::reader_value::OwnedReaderValue;
+use crate::common::OwnedGenerationId;
 use crate::database::cursors::storage::CursorError;
+use crate::database::trash::TrashError;
 use crate::messages::generations::{
     CommitManualGenerationError, LockManualGenerationIdError, StartManualGenerationIdError,
 };
@@ -14,14 +16,18 @@ pub enum CollectionMethodError {
     CannotPutInManualCollection,
     UnsupportedOperationForThisCollectionType,
     InvalidKey,
+    InvalidGenerationId,
     ReaderAlreadyExists(OwnedReaderValue),
     InvalidUtf8,
     InvalidReaderValue,
     NoSuchCursor,
-    NotImplementedYet,
     NoSuchReader,
+    OrphanedReader,
     NoSuchCollection,
-    GenerationIdLessThanMinimum,
+    // Contains current minimum generation id of the collection
+    GenerationIdLessThanMinimum(OwnedGenerationId),
+    // Generation is not yet committed, contains current generation id of the collection
+    GenerationIdNotCommitted(OwnedGenerationId),
 
     RawDb(RawDbError),
     Channels,
@@ -29,11 +35,38 @@ pub enum CollectionMethodError {
     CannotDeleteRawDbPath(std::io::Error),
     OneshotRecv(oneshot::error::RecvError),
     QueryCursor(CursorError),
+    Trash(TrashError),
+    Protobuf(protobuf::Error),
+    Io(std::io::Error),
 }
 
 impl From<RawDbError> for CollectionMethodError {
     fn from(err: RawDbError) -> Self {
-        CollectionMethodError::RawDb(err)
+        match err {
+            RawDbError::OrphanedReader => CollectionMethodError::OrphanedReader,
+            err => CollectionMethodError::RawDb(err),
+        }
+    }
+}
+
+impl From<protobuf::Error> for CollectionMethodError {
+    fn from(err: protobuf::Error) -> Self {
+        CollectionMethodError::Protobuf(err)
+    }
+}
+
+impl From<std::io::Error> for CollectionMethodError {
+    fn from(err: std::io::Error) -> Self {
+        CollectionMethodError::Io(err)
+    }
+}
+
+impl From<TrashError> for CollectionMethodError {
+    fn from(err: TrashError) -> Self {
+        match err {
+            TrashError::RawDb(err) => err.into(),
+            err => CollectionMethodError::Trash(err),
+        }
     }
 }
 
@@ -79,7 +112,10 @@ impl From<CommitManualGenerationError> for CollectionMethodError {
             CommitManualGenerationError::OutdatedGeneration => {
                 CollectionMethodError::OutdatedGeneration
             }
-            CommitManualGenerationError::RawDb(err) => CollectionMethodError::RawDb(err),
+            CommitManualGenerationError::RawDb(err)
+            | CommitManualGenerationError::TransactionInterrupted(err) => {
+                CollectionMethodError::RawDb(err)
+            }
             CommitManualGenerationError::NoSuchCollection => {
                 CollectionMethodError::NoSuchCollection
             }
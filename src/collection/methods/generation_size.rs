@@ -0,0 +1,55 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::OwnedGenerationId;
+use crate::util::tokio::spawn_blocking_async;
+
+impl Collection {
+    // Number of puts to the generation, phantom puts are not counted
+    pub async fn generation_size(
+        &self,
+        generation_id: OwnedGenerationId,
+    ) -> Result<u32, CollectionMethodError> {
+        let raw_db = self.raw_db.clone();
+
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let size =
+            spawn_blocking_async(
+                async move { raw_db.get_generation_size_sync(generation_id.as_ref()) },
+            )
+            .await
+            .or(Err(CollectionMethodError::TaskJoin))??;
+
+        drop(deletion_lock);
+
+        Ok(size)
+    }
+
+    // Sizes of generations in range `(from_generation_id, to_generation_id]`,
+    // generations without puts are not present
+    pub async fn generation_sizes(
+        &self,
+        from_generation_id: OwnedGenerationId,
+        to_generation_id: OwnedGenerationId,
+    ) -> Result<Vec<(OwnedGenerationId, u32)>, CollectionMethodError> {
+        let raw_db = self.raw_db.clone();
+
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let sizes = spawn_blocking_async(async move {
+            raw_db.get_generation_sizes_sync(from_generation_id.as_ref(), to_generation_id.as_ref())
+        })
+        .await
+        .or(Err(CollectionMethodError::TaskJoin))??;
+
+        drop(deletion_lock);
+
+        Ok(sizes)
+    }
+}
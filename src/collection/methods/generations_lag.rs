@@ -0,0 +1,42 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::{GenerationId, OwnedGenerationId};
+use crate::raw_db::get_generations_lag::GenerationsLag;
+use crate::util::tokio::spawn_blocking_async;
+
+pub struct GenerationsLagOk {
+    // Current generation of the collection, lag is counted up to it
+    pub generation_id: OwnedGenerationId,
+    pub lag: GenerationsLag,
+}
+
+impl Collection {
+    // How far behind is the reader with `from_generation_id`,
+    // the generation in progress is not counted
+    pub async fn generations_lag(
+        &self,
+        from_generation_id: Option<OwnedGenerationId>,
+    ) -> Result<GenerationsLagOk, CollectionMethodError> {
+        let generation_id = self.generation_pair().generation_id;
+        let raw_db = self.raw_db.clone();
+
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let to_generation_id = generation_id.clone();
+        let lag = spawn_blocking_async(async move {
+            raw_db.get_generations_lag_sync(
+                GenerationId::from_opt_owned(&from_generation_id),
+                to_generation_id.as_ref(),
+            )
+        })
+        .await
+        .or(Err(CollectionMethodError::TaskJoin))??;
+
+        drop(deletion_lock);
+
+        Ok(GenerationsLagOk { generation_id, lag })
+    }
+}
@@ -26,6 +26,17 @@ impl Collection {
         &self,
         options: CollectionGetOptions,
     ) -> Result<CollectionGetOk, CollectionMethodError> {
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        if let Some(generation_id) = &options.generation_id {
+            self.check_generation_is_not_less_than_minimum(generation_id.as_ref())?;
+        }
+
         let generation_id = self.generation_id_or_current(options.generation_id).await;
 
         let record_key = OwnedRecordKey::new(
@@ -35,18 +46,15 @@ impl Collection {
         )
         .or(Err(CollectionMethodError::InvalidKey))?;
 
-        let deletion_lock = self.is_deleted.read().await;
-        if deletion_lock.to_owned() {
-            return Err(CollectionMethodError::NoSuchCollection);
-        }
-
         let result = self
             .raw_db
             .get_collection_record(GetCollectionRecordOptions {
                 record_key: record_key.as_ref(),
+                include_real_records: false,
             })
             .await?;
 
+        drop(minimum_generation_id_lock);
         drop(deletion_lock);
 
         let mut generation_id = generation_id;
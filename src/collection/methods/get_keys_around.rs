@@ -6,6 +6,7 @@ use crate::collection::Collection;
 
 use crate::common::{OwnedCollectionKey, OwnedGenerationId, OwnedPhantomId};
 use crate::raw_db::get_keys_around::{RawDbGetKeysAroundOptions, RawDbGetKeysAroundResult};
+use crate::util::tokio::spawn_blocking;
 
 pub struct CollectionGetKeysAroundOptions {
     pub key: OwnedCollectionKey,
@@ -22,6 +23,7 @@ pub struct CollectionGetKeysAroundOk {
     pub right: Vec<OwnedCollectionKey>,
     pub has_more_on_the_left: bool,
     pub has_more_on_the_right: bool,
+    pub found_key: bool,
 }
 
 impl Collection {
@@ -29,13 +31,21 @@ impl Collection {
         &self,
         options: CollectionGetKeysAroundOptions,
     ) -> Result<CollectionGetKeysAroundOk, CollectionMethodError> {
-        if !options.require_key_existance {
-            return Err(CollectionMethodError::NotImplementedYet);
-        }
-
+        let require_key_existance = options.require_key_existance;
         let limit = options.limit.min(COLLECTION_GET_KEYS_AROUND_MAX_LIMIT);
         let records_to_view_limit = self.config.query_pack_records_limit;
 
+        let deletion_lock = self.is_deleted.read().await;
+        if *deletion_lock {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        if let Some(generation_id) = &options.generation_id {
+            self.check_generation_is_not_less_than_minimum(generation_id.as_ref())?;
+        }
+
         let generation_id = self.generation_id_or_current(options.generation_id).await;
 
         let record_key = OwnedRecordKey::new(
@@ -45,16 +55,12 @@ impl Collection {
         )
         .or(Err(CollectionMethodError::InvalidKey))?;
 
-        let deletion_lock = self.is_deleted.read().await;
-        if *deletion_lock {
-            return Err(CollectionMethodError::NoSuchCollection);
-        }
-
         let result = {
             let db = self.raw_db.clone();
-            tokio::task::spawn_blocking(move || {
+            spawn_blocking(move || {
                 db.keys_around_sync(RawDbGetKeysAroundOptions {
                     record_key: record_key.as_ref(),
+                    require_key_existance,
                     limit,
                     records_to_view_limit,
                 })
@@ -63,6 +69,7 @@ impl Collection {
             .or(Err(CollectionMethodError::TaskJoin))??
         };
 
+        drop(minimum_generation_id_lock);
         drop(deletion_lock);
 
         let RawDbGetKeysAroundResult {
@@ -70,6 +77,7 @@ impl Collection {
             right,
             has_more_on_the_left,
             has_more_on_the_right,
+            found_key,
         } = result;
 
         Ok(CollectionGetKeysAroundOk {
@@ -78,6 +86,7 @@ impl Collection {
             right,
             has_more_on_the_left,
             has_more_on_the_right,
+            found_key,
         })
     }
 }
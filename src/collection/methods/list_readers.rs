@@ -49,6 +49,7 @@ impl Collection {
                 reader_name: reader_name.to_string(),
                 generation_id: generation_id.to_opt_owned_if_empty(),
                 collection_name,
+                is_orphaned: reader_value.is_orphaned(),
             });
         }
 
@@ -0,0 +1,74 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::reader::ReaderRecord;
+use crate::common::OwnedGenerationId;
+use crate::raw_db::get_generations_lag::GenerationsLag;
+use crate::raw_db::properties::RawDbProperties;
+use crate::util::tokio::spawn_blocking_async;
+use std::sync::atomic::Ordering;
+
+pub struct CollectionMetrics {
+    pub generation_id: OwnedGenerationId,
+    pub commits_count: u64,
+    // Number of puts to the generation in progress, if there is one
+    pub next_generation_size: Option<u32>,
+    pub query_cursors_count: usize,
+    pub diff_cursors_count: usize,
+    // Generations below the minimum generation id of readers that are not collected yet
+    pub gc_lag: GenerationsLag,
+    pub gc_removed_records: u64,
+    pub gc_batches: u64,
+    pub readers: Vec<ReaderRecord>,
+    pub raw_db: RawDbProperties,
+}
+
+impl Collection {
+    pub async fn metrics(&self) -> Result<CollectionMetrics, CollectionMethodError> {
+        let pair = self.generation_pair();
+
+        let next_generation_size = match pair.next_generation_id {
+            Some(next_generation_id) => Some(self.generation_size(next_generation_id).await?),
+            None => None,
+        };
+
+        let readers = self.list_readers().await?.items;
+
+        let gc_stats = &self.gc.stats;
+
+        let minimum_generation_id = self.minimum_generation_id.borrow().clone();
+        let gc_collected_generation_id = gc_stats.collected_generation_id.lock().unwrap().clone();
+
+        let raw_db = self.raw_db.clone();
+
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let (gc_lag, raw_db_properties) = spawn_blocking_async(async move {
+            let gc_lag = raw_db.get_generations_lag_sync(
+                Some(gc_collected_generation_id.as_ref()),
+                minimum_generation_id.as_ref(),
+            )?;
+
+            Ok::<_, CollectionMethodError>((gc_lag, raw_db.get_properties_sync()?))
+        })
+        .await
+        .or(Err(CollectionMethodError::TaskJoin))??;
+
+        drop(deletion_lock);
+
+        Ok(CollectionMetrics {
+            generation_id: pair.generation_id,
+            commits_count: self.commits_count.load(Ordering::Relaxed),
+            next_generation_size,
+            query_cursors_count: self.query_cursors_count().await,
+            diff_cursors_count: self.diff_cursors_count().await,
+            gc_lag,
+            gc_removed_records: gc_stats.removed_records.load(Ordering::Relaxed),
+            gc_batches: gc_stats.batches.load(Ordering::Relaxed),
+            readers,
+            raw_db: raw_db_properties,
+        })
+    }
+}
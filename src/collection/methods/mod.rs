@@ -1,15 +1,22 @@
 pub mod abort_generation;
+pub mod clone_collection;
 pub mod commit_generation;
 pub mod create_reader;
 pub mod delete_collection;
 pub mod delete_reader;
 pub mod diff;
 pub mod errors;
+pub mod generation_size;
+pub mod generations_lag;
 pub mod get;
 pub mod get_keys_around;
 pub mod list_readers;
+pub mod metrics;
 pub mod phantom;
 pub mod put;
 pub mod query;
+pub mod rename_collection;
 pub mod start_generation;
 pub mod update_reader;
+pub mod update_settings;
+pub mod watch;
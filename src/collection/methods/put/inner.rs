@@ -9,6 +9,7 @@ use crate::collection::util::record_key::OwnedRecordKey;
 use crate::collection::Collection;
 use crate::common::{GenerationId, KeyValueUpdate, PhantomId};
 use crate::raw_db::contains_existing_collection_record::ContainsExistingCollectionRecordOptions;
+use crate::raw_db::get_collection_record::GetCollectionRecordOptions;
 use crate::util::bytes::is_byte_array_equal_both_opt;
 use crate::util::tokio::spawn;
 
@@ -54,6 +55,8 @@ pub fn validate_put(options: ValidatePutOptions<'_>) -> Option<CollectionMethodE
 pub struct CollectionPutInnerOptions<'a, 'b> {
     pub update: &'b KeyValueUpdate,
     pub record_generation_id: GenerationId<'a>,
+    // Returned if value is not changed and there is no record of the key
+    pub current_generation_id: GenerationId<'a>,
     pub phantom_id: Option<PhantomId<'a>>,
 }
 
@@ -76,6 +79,7 @@ impl Collection {
         let key = update.key.as_ref().as_ref();
         let phantom_id = options.phantom_id;
         let record_generation_id = options.record_generation_id;
+        let current_generation_id = options.current_generation_id;
 
         let phantom_id_or_empty = PhantomId::or_empty(&phantom_id);
 
@@ -132,6 +136,30 @@ impl Collection {
                     }
                 }
             }
+        } else if update.if_value_changed.unwrap_or(self.if_value_changed) {
+            // Compare with the value that is visible for the phantom
+            let existing = self
+                .raw_db
+                .get_collection_record(GetCollectionRecordOptions {
+                    record_key: record_key.as_ref(),
+                    include_real_records: true,
+                })
+                .await?;
+
+            let (generation_id, existing_value) = match existing {
+                Some((record_key, value)) => (
+                    record_key.as_ref().get_generation_id().to_owned(),
+                    Some(value),
+                ),
+                None => (current_generation_id.to_owned(), None),
+            };
+
+            if existing_value == update.value {
+                return Ok(CollectionPutInnerResult::Done(Ok(CollectionPutOk {
+                    generation_id,
+                    was_put: false,
+                })));
+            }
         }
 
         Ok(CollectionPutInnerResult::Continue(
@@ -7,6 +7,7 @@ use std::collections::BTreeMap;
 
 use crate::collection::Collection;
 
+use crate::collection::methods::put::CollectionPutOk;
 use crate::common::{KeyValueUpdate, OwnedGenerationId, OwnedPhantomId};
 use crate::messages::generations::{
     DatabaseCollectionGenerationsTask, LockNextGenerationIdTask, LockNextGenerationIdTaskResponse,
@@ -26,6 +27,8 @@ pub struct CollectionPutManyOptions {
 #[derive(Debug)]
 pub struct CollectionPutManyOk {
     pub generation_id: OwnedGenerationId,
+    // Same order as `options.items`, see `CollectionPutOk.was_put`
+    pub was_put: Vec<bool>,
 }
 
 pub type CollectionPutManyResult = Result<CollectionPutManyOk, CollectionMethodError>;
@@ -70,6 +73,10 @@ impl Collection {
             None => {}
         }
 
+        let current_generation_id = generation_id
+            .clone()
+            .unwrap_or_else(|| next_generation_id_lock.generation_id().to_owned());
+        let current_generation_id = current_generation_id.as_ref();
         let record_generation_id = generation_id.clone().unwrap_or(next_generation_id);
         let record_generation_id = record_generation_id.as_ref();
 
@@ -79,71 +86,82 @@ impl Collection {
             return Err(CollectionMethodError::NoSuchCollection);
         }
 
+        // If same key is present multiple times, only last one will be put
+        let mut was_put = vec![false; items.len()];
         let mut items_ordered = BTreeMap::new();
 
-        for item in items {
-            items_ordered.insert(item.key.clone(), item);
+        for (index, item) in items.into_iter().enumerate() {
+            items_ordered.insert(item.key.clone(), (index, item));
         }
 
-        let items_inner = items_ordered.into_iter().map(|(_, update)| async move {
-            let result = self
-                .put_inner(CollectionPutInnerOptions {
-                    update: &update,
-                    record_generation_id,
-                    phantom_id,
-                })
-                .await;
-
-            (result, update)
-        });
+        let items_inner = items_ordered
+            .into_iter()
+            .map(|(_, (index, update))| async move {
+                let result = self
+                    .put_inner(CollectionPutInnerOptions {
+                        update: &update,
+                        record_generation_id,
+                        current_generation_id,
+                        phantom_id,
+                    })
+                    .await;
+
+                (result, index, update)
+            });
 
         let items_inner: Vec<(
             Result<CollectionPutInnerResult, CollectionMethodError>,
+            usize,
             KeyValueUpdate,
         )> = futures::future::join_all(items_inner).await;
 
+        // Indices of items that will be written
+        let mut put_indices = Vec::with_capacity(items_inner.len());
+
         type AccumulatorVec<'a> = (Vec<PutManyCollectionRecordsItem>, Vec<ResolvePutFn<'a>>);
 
         type Accumulator<'a> =
-            Result<AccumulatorVec<'a>, (CollectionMethodError, AccumulatorVec<'a>)>;
+            Result<AccumulatorVec<'a>, (CollectionMethodError, Vec<ResolvePutFn<'a>>)>;
 
         let initial = (
             Vec::with_capacity(items_inner.len()),
             Vec::with_capacity(items_inner.len()),
         );
-        let items_inner: Accumulator<'_> =
-            items_inner
-                .into_iter()
-                .fold(Ok(initial), |acc, (item, update)| match item {
-                    Ok(inner_result) => match inner_result {
-                        CollectionPutInnerResult::Continue(cont) => match acc {
-                            Ok((mut items_vec, mut resolve_vec)) => {
-                                items_vec.push(PutManyCollectionRecordsItem {
-                                    record_key: cont.record_key,
-                                    value: update.value,
-                                });
-                                push_if(&mut resolve_vec, cont.resolve);
-                                Ok((items_vec, resolve_vec))
-                            }
-                            Err((first_error, (items_vec, mut resolve_vec))) => {
-                                push_if(&mut resolve_vec, cont.resolve);
-                                Err((first_error, (items_vec, resolve_vec)))
-                            }
-                        },
-                        CollectionPutInnerResult::Done(_done) => acc,
-                    },
-                    Err(err) => match acc {
-                        Ok(acc_vec) => Err((err, acc_vec)),
-                        err => err,
-                    },
-                });
+        let mut items_inner = items_inner.into_iter();
+        let accumulated: Accumulator<'_> = items_inner.try_fold(
+            initial,
+            |(mut items_vec, mut resolve_vec), (item, index, update)| match item {
+                Ok(CollectionPutInnerResult::Continue(cont)) => {
+                    put_indices.push(index);
+                    items_vec.push(PutManyCollectionRecordsItem {
+                        record_key: cont.record_key,
+                        value: update.value,
+                    });
+                    push_if(&mut resolve_vec, cont.resolve);
+                    Ok((items_vec, resolve_vec))
+                }
+                Ok(CollectionPutInnerResult::Done(done)) => {
+                    if let Ok(CollectionPutOk { was_put: true, .. }) = done {
+                        was_put[index] = true;
+                    }
+                    Ok((items_vec, resolve_vec))
+                }
+                Err(err) => Err((err, resolve_vec)),
+            },
+        );
 
         // Contains keys which needs to be put
-        let (items, resolves) = match items_inner {
+        let (items, resolves) = match accumulated {
             Ok(inner_results) => inner_results,
-            // In case of error locking some of records, cancel all locks
-            Err((first_error, (_, resolve_vec))) => {
-                for resolve in resolve_vec {
+            // In case of error locking some of records, cancel all locks,
+            // including the ones of items after the failed one
+            Err((first_error, resolve_vec)) => {
+                let rest_resolves = items_inner.filter_map(|(item, _, _)| match item {
+                    Ok(CollectionPutInnerResult::Continue(cont)) => cont.resolve,
+                    _ => None,
+                });
+
+                for resolve in resolve_vec.into_iter().chain(rest_resolves) {
                     resolve(HandleIfNotPresentResolve::Err);
                 }
 
@@ -163,7 +181,7 @@ impl Collection {
         }
 
         let result_generation_id = if is_empty {
-            generation_id.unwrap_or_else(|| next_generation_id_lock.generation_id().to_owned())
+            current_generation_id.to_owned()
         } else {
             record_generation_id.to_owned()
         };
@@ -171,12 +189,19 @@ impl Collection {
         drop(next_generation_id_lock);
 
         let (result, if_not_present_result) = match result {
-            Ok(_) => (
-                Ok(CollectionPutManyOk {
-                    generation_id: result_generation_id,
-                }),
-                HandleIfNotPresentResolve::WasPut,
-            ),
+            Ok(_) => {
+                for index in put_indices {
+                    was_put[index] = true;
+                }
+
+                (
+                    Ok(CollectionPutManyOk {
+                        generation_id: result_generation_id,
+                        was_put,
+                    }),
+                    HandleIfNotPresentResolve::WasPut,
+                )
+            }
             Err(err) => (
                 Err(CollectionMethodError::RawDb(err)),
                 HandleIfNotPresentResolve::Err,
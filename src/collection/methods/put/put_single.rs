@@ -21,7 +21,8 @@ pub struct CollectionPutOptions {
 #[derive(Debug)]
 pub struct CollectionPutOk {
     pub generation_id: OwnedGenerationId,
-    // if `update.if_not_present == true`, it can be false when nothing was changed
+    // if `update.if_not_present` or `update.if_value_changed` is true,
+    // it can be false when nothing was changed
     pub was_put: bool,
 }
 
@@ -68,6 +69,9 @@ impl Collection {
         }
 
         //// Insert
+        let current_generation_id = generation_id
+            .clone()
+            .unwrap_or_else(|| next_generation_id_lock.generation_id().to_owned());
         let record_generation_id = generation_id.unwrap_or(next_generation_id);
         let record_generation_id = record_generation_id.as_ref();
 
@@ -80,6 +84,7 @@ impl Collection {
             .put_inner(CollectionPutInnerOptions {
                 update: &update,
                 record_generation_id,
+                current_generation_id: current_generation_id.as_ref(),
                 phantom_id,
             })
             .await?;
@@ -4,7 +4,8 @@ use std::marker::PhantomData;
 
 use crate::collection::cursor::query::get_pack::GetPackOptions;
 use crate::collection::cursor::query::{QueryCursorNewOptions, QueryCursorPack};
-use crate::common::{KeyValue, OwnedGenerationId, OwnedPhantomId};
+use crate::common::key_range::OwnedKeyRange;
+use crate::common::{KeyValue, OwnedCollectionKey, OwnedGenerationId, OwnedPhantomId};
 use crate::database::cursors::query::{
     AddQueryCursorContinuationData, AddQueryCursorData, QueryCursor,
 };
@@ -18,6 +19,8 @@ use crate::messages::cursors::{
     GetCursorByPublicIdTask,
 };
 use crate::util::async_sync_call::async_sync_call;
+use crate::util::tokio::spawn_blocking;
+use std::ops::Bound;
 
 type CursorId = Box<str>;
 type NextCursorId = Box<str>;
@@ -25,6 +28,12 @@ type NextCursorId = Box<str>;
 pub struct QueryOptions {
     pub generation_id: Option<OwnedGenerationId>,
     pub phantom_id: Option<OwnedPhantomId>,
+    pub from_key: Bound<OwnedCollectionKey>,
+    pub to_key: Bound<OwnedCollectionKey>,
+    // Intersected with `from_key`/`to_key` range
+    pub prefix: Option<OwnedCollectionKey>,
+    // Iterate in descending keys order
+    pub reverse: bool,
 }
 
 pub struct ReadQueryCursorOptions {
@@ -46,28 +55,40 @@ impl Collection {
         let QueryOptions {
             generation_id,
             phantom_id,
+            from_key,
+            to_key,
+            prefix,
+            reverse,
         } = options;
 
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        if let Some(generation_id) = &generation_id {
+            self.check_generation_is_not_less_than_minimum(generation_id.as_ref())?;
+        }
+
         let generation_id = self.generation_id_or_current(generation_id).await;
 
+        let key_range = OwnedKeyRange::new(from_key, to_key, prefix);
+
         let initial_cursor = QueryCursor::new(QueryCursorNewOptions {
             generation_id: generation_id.clone(),
             phantom_id: phantom_id.clone(),
+            key_range: key_range.clone(),
+            reverse,
         });
 
-        let deletion_lock = self.is_deleted.read().await;
-        if deletion_lock.to_owned() {
-            return Err(CollectionMethodError::NoSuchCollection);
-        }
-
         let result = {
             let db = self.raw_db.clone();
             let config = self.config.clone();
-            tokio::task::spawn_blocking(move || {
-                initial_cursor.get_pack_sync(GetPackOptions { db, config })
-            })
-            .await
-            .or(Err(CollectionMethodError::TaskJoin))??
+            spawn_blocking(move || initial_cursor.get_pack_sync(GetPackOptions { db, config }))
+                .await
+                .or(Err(CollectionMethodError::TaskJoin))??
         };
 
         let QueryCursorPack {
@@ -87,6 +108,8 @@ impl Collection {
                                 data: AddQueryCursorData {
                                     generation_id,
                                     phantom_id,
+                                    key_range,
+                                    reverse,
                                     last_and_next_record_key,
                                 },
                                 sender,
@@ -102,6 +125,7 @@ impl Collection {
             None => None,
         };
 
+        drop(minimum_generation_id_lock);
         drop(deletion_lock);
 
         Ok(QueryOk {
@@ -171,11 +195,16 @@ impl Collection {
             return Err(CollectionMethodError::NoSuchCollection);
         }
 
+        // Readers could move forward since the cursor was created
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        self.check_generation_is_not_less_than_minimum(cursor.generation_id.as_ref())?;
+
         let result = {
             let cursor = cursor.clone();
             let db = self.raw_db.clone();
             let config = self.config.clone();
-            tokio::task::spawn_blocking(move || cursor.get_pack_sync(GetPackOptions { db, config }))
+            spawn_blocking(move || cursor.get_pack_sync(GetPackOptions { db, config }))
                 .await
                 .or(Err(CollectionMethodError::TaskJoin))??
         };
@@ -231,6 +260,7 @@ impl Collection {
             }
         };
 
+        drop(minimum_generation_id_lock);
         drop(deletion_lock);
 
         Ok(QueryOk {
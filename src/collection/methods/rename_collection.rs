@@ -0,0 +1,67 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::util::close::{
+    detach_collection_from_threads, wait_for_raw_db_close, DetachCollectionOptions,
+};
+use crate::collection::Collection;
+use crate::util::tokio::spawn_blocking_async;
+use std::future::Future;
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+impl Collection {
+    // Closes the collection and moves it to the new name, after that
+    // collection should be opened again with the new name
+    pub fn rename_collection(
+        &self,
+        new_collection_name: &str,
+    ) -> impl Future<Output = Result<(), CollectionMethodError>> {
+        let is_deleted = self.is_deleted.clone();
+        let collection_name = self.name.clone();
+        let new_collection_name = Arc::<str>::from(new_collection_name);
+        let collection_generations_id = self.generations_id;
+        let database_inner = self.database_inner.clone();
+        let gc_id = self.gc.id;
+
+        let raw_db = self.raw_db.clone();
+
+        let join = spawn_blocking_async(async move {
+            // For the holders of this Collection it looks like it was deleted
+            let mut deletion_lock = is_deleted.write().await;
+            if deletion_lock.to_owned() {
+                return Err(CollectionMethodError::NoSuchCollection);
+            }
+
+            let is_deleted = deletion_lock.deref_mut();
+            *is_deleted = true;
+
+            drop(deletion_lock);
+
+            database_inner
+                .start_rename_collection(&collection_name, &new_collection_name)
+                .await?;
+
+            detach_collection_from_threads(DetachCollectionOptions {
+                database_inner: &database_inner,
+                collection_name: &collection_name,
+                generations_id: collection_generations_id,
+                gc_id,
+            })
+            .await?;
+
+            wait_for_raw_db_close(raw_db).await;
+
+            database_inner
+                .retarget_readers_of_renamed_collection(
+                    collection_name.clone(),
+                    new_collection_name.clone(),
+                )
+                .await?;
+
+            database_inner.move_renamed_collection_sync(&collection_name, &new_collection_name)?;
+
+            Ok::<(), CollectionMethodError>(())
+        });
+
+        async move { join.await.map_err(|_| CollectionMethodError::TaskJoin)? }
+    }
+}
@@ -4,8 +4,8 @@ use crate::common::OwnedGenerationId;
 use crate::messages::readers::{DatabaseCollectionReadersTask, UpdateReaderTask};
 use crate::raw_db::update_reader::RawDbUpdateReaderOptions;
 use crate::util::async_sync_call::async_sync_call;
+use crate::util::tokio::spawn_blocking;
 use std::sync::Arc;
-use tokio::task::spawn_blocking;
 
 pub struct UpdateReaderOptions {
     pub reader_name: String,
@@ -21,6 +21,8 @@ impl Collection {
         let generation_id = options.generation_id.unwrap_or(OwnedGenerationId::empty());
         let raw_db = self.raw_db.clone();
 
+        let backup_guard = self.database_inner.backup_lock.read().await;
+
         let deletion_lock = self.is_deleted.read().await;
         if deletion_lock.to_owned() {
             return Err(CollectionMethodError::NoSuchCollection);
@@ -31,9 +33,7 @@ impl Collection {
 
         let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
 
-        if self.generation_is_less_than_minimum(generation_id.as_ref()) {
-            return Err(CollectionMethodError::GenerationIdLessThanMinimum);
-        }
+        self.check_generation_is_not_less_than_minimum(generation_id.as_ref())?;
 
         let _: () = spawn_blocking(move || {
             raw_db.update_reader_sync(RawDbUpdateReaderOptions {
@@ -60,6 +60,7 @@ impl Collection {
 
         drop(minimum_generation_id_lock);
         drop(deletion_lock);
+        drop(backup_guard);
 
         Ok(())
     }
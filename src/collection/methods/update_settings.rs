@@ -0,0 +1,35 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::settings::CollectionSettings;
+use crate::collection::Collection;
+use crate::messages::generations::{
+    DatabaseCollectionGenerationsTask, UpdateCollectionSettingsTask,
+};
+use crate::util::async_sync_call::async_sync_call;
+
+impl Collection {
+    // Only applies settings to the running collection, they are persisted by the database
+    pub async fn update_settings(
+        &self,
+        settings: CollectionSettings,
+    ) -> Result<(), CollectionMethodError> {
+        let mut current_settings = self.settings.write().await;
+
+        let _: () = async_sync_call(|sender| {
+            self.database_inner.add_generations_task(
+                DatabaseCollectionGenerationsTask::UpdateCollectionSettings(
+                    UpdateCollectionSettingsTask {
+                        collection_id: self.generations_id,
+                        settings,
+                        sender,
+                    },
+                ),
+            )
+        })
+        .await
+        .map_err(CollectionMethodError::OneshotRecv)?;
+
+        *current_settings = settings;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::key_range::OwnedKeyRange;
+use crate::common::{OwnedCollectionKey, OwnedGenerationId};
+use crate::raw_db::get_changed_keys::{GetChangedKeysOk, GetChangedKeysOptions};
+use crate::util::tokio::spawn_blocking_async;
+use std::ops::Bound;
+use std::time::Duration;
+use tokio::select;
+use tokio::time::{sleep_until, Instant};
+
+pub struct WatchOptions {
+    // Current generation if not specified
+    pub from_generation_id: Option<OwnedGenerationId>,
+    pub from_key: Bound<OwnedCollectionKey>,
+    pub to_key: Bound<OwnedCollectionKey>,
+    // Intersected with `from_key`/`to_key` range
+    pub prefix: Option<OwnedCollectionKey>,
+    pub timeout: Duration,
+}
+
+pub struct WatchOk {
+    // Changes are checked up to this generation, it should be passed
+    // as `from_generation_id` to the next call
+    pub generation_id: OwnedGenerationId,
+    // Empty on timeout
+    pub keys: Vec<OwnedCollectionKey>,
+    // There are more than `diff_changes_limit` changed keys, use diff to get all of them
+    pub has_more: bool,
+}
+
+impl Collection {
+    // Waits until a committed generation after `from_generation_id` changes keys of the range
+    pub async fn watch(&self, options: WatchOptions) -> Result<WatchOk, CollectionMethodError> {
+        let WatchOptions {
+            from_generation_id,
+            from_key,
+            to_key,
+            prefix,
+            timeout,
+        } = options;
+
+        let key_range = OwnedKeyRange::new(from_key, to_key, prefix);
+        let deadline = Instant::now() + timeout;
+
+        let mut receiver = self.generation_pair_receiver.clone();
+
+        let mut generation_id = match from_generation_id {
+            Some(generation_id) => generation_id,
+            None => receiver.borrow_and_update().generation_id.clone(),
+        };
+
+        loop {
+            let current_generation_id = receiver.borrow_and_update().generation_id.clone();
+
+            if current_generation_id > generation_id {
+                let GetChangedKeysOk { keys, has_more } = self
+                    .get_changed_keys(
+                        generation_id,
+                        current_generation_id.clone(),
+                        key_range.clone(),
+                    )
+                    .await?;
+
+                generation_id = current_generation_id;
+
+                if !keys.is_empty() {
+                    return Ok(WatchOk {
+                        generation_id,
+                        keys,
+                        has_more,
+                    });
+                }
+            }
+
+            select! {
+                result = receiver.changed() => {
+                    result.or(Err(CollectionMethodError::NoSuchCollection))?;
+                },
+                _ = sleep_until(deadline) => {
+                    return Ok(WatchOk {
+                        generation_id,
+                        keys: Vec::new(),
+                        has_more: false,
+                    });
+                },
+            }
+        }
+    }
+
+    async fn get_changed_keys(
+        &self,
+        from_generation_id: OwnedGenerationId,
+        to_generation_id: OwnedGenerationId,
+        key_range: OwnedKeyRange,
+    ) -> Result<GetChangedKeysOk, CollectionMethodError> {
+        let raw_db = self.raw_db.clone();
+        let limit = self.config.diff_changes_limit;
+
+        let deletion_lock = self.is_deleted.read().await;
+        if deletion_lock.to_owned() {
+            return Err(CollectionMethodError::NoSuchCollection);
+        }
+
+        // Changes of collected generations are removed
+        let minimum_generation_id_lock = self.minimum_generation_id_lock.read().await;
+
+        self.check_generation_is_not_less_than_minimum(from_generation_id.as_ref())?;
+
+        let result = spawn_blocking_async(async move {
+            raw_db.get_changed_keys_sync(GetChangedKeysOptions {
+                from_generation_id: from_generation_id.as_ref(),
+                to_generation_id: to_generation_id.as_ref(),
+                key_range: key_range.as_ref(),
+                limit,
+            })
+        })
+        .await
+        .or(Err(CollectionMethodError::TaskJoin))??;
+
+        drop(minimum_generation_id_lock);
+        drop(deletion_lock);
+
+        Ok(result)
+    }
+}
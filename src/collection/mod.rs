@@ -1,3 +1,4 @@
+use crate::collection::settings::CollectionSettings;
 use crate::collection::util::collection_raw_db::CollectionRawDb;
 use crate::collection::util::record_key::OwnedRecordKey;
 use crate::common::reader::ReaderName;
@@ -12,6 +13,7 @@ use crate::messages::garbage_collector::NewCollectionTaskResponse;
 use crate::raw_db::RawDbError;
 use if_not_present::ConcurrentPutStatus;
 use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::sync::{oneshot, watch, RwLock};
 
@@ -23,6 +25,7 @@ mod if_not_present;
 pub mod methods;
 pub mod open;
 pub mod readers;
+pub mod settings;
 pub mod util;
 
 pub struct Collection {
@@ -30,6 +33,9 @@ pub struct Collection {
     name: Arc<str>,
     raw_db: CollectionRawDb,
     is_manual: bool,
+    // default for `KeyValueUpdate.if_value_changed`
+    if_value_changed: bool,
+    settings: RwLock<CollectionSettings>,
     // you need to lock it for reading before any operations with raw_db
     is_deleted: Arc<RwLock<bool>>,
     pub generation_pair_receiver: watch::Receiver<GenerationIdNextGenerationIdPair>,
@@ -40,6 +46,7 @@ pub struct Collection {
     prev_phantom_id: RwLock<OwnedPhantomId>,
     cursors_id: InnerCursorsCollectionId,
     generations_id: InnerGenerationsCollectionId,
+    commits_count: Arc<AtomicU64>,
     gc: NewCollectionTaskResponse,
     drop_sender: Option<oneshot::Sender<()>>,
 }
@@ -58,6 +65,18 @@ impl Collection {
         self.is_manual
     }
 
+    pub fn is_if_value_changed(&self) -> bool {
+        self.if_value_changed
+    }
+
+    pub async fn get_settings(&self) -> CollectionSettings {
+        *self.settings.read().await
+    }
+
+    pub fn generations_id(&self) -> InnerGenerationsCollectionId {
+        self.generations_id
+    }
+
     pub fn generation_pair(&self) -> GenerationIdNextGenerationIdPair {
         self.generation_pair_receiver.borrow().clone()
     }
@@ -31,6 +31,11 @@ pub async fn init_readers(
             let reader_value =
                 ReaderValue::from_slice(&value).or(Err(CollectionOpenError::InvalidReaderValue))?;
 
+            // Its collection was deleted, it should not prevent GC of the new one with the same name
+            if reader_value.is_orphaned() {
+                continue;
+            }
+
             let generation_id = reader_value.get_generation_id();
             let to_collection_name = reader_value.get_collection_name();
             let to_collection_name = if to_collection_name.is_empty() {
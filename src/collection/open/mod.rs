@@ -12,6 +12,7 @@ use crate::collection::constants::{
     COLLECTION_CF_PHANTOMS,
 };
 use crate::collection::open::init_readers::init_readers;
+use crate::collection::settings::CollectionSettings;
 use crate::collection::util::generation_size_merge::{
     generation_size_full_merge, generation_size_partial_merge,
 };
@@ -25,24 +26,23 @@ use crate::messages::generations::{
     NewCollectionGenerationsTaskResponse,
 };
 use crate::raw_db::{
-    RawDb, RawDbColumnFamily, RawDbComparator, RawDbError, RawDbMerge, RawDbOpenError, RawDbOptions,
+    RawDb, RawDbColumnFamily, RawDbComparator, RawDbConfig, RawDbError, RawDbMerge, RawDbOpenError,
+    RawDbOptions,
 };
 use crate::util::async_spawns::watch_is_true_or_end;
 use crate::util::async_sync_call::async_sync_call;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::pin;
 
-use crate::collection::util::collection_raw_db::wrap_collection_raw_db;
 use crate::messages::garbage_collector::{
     DatabaseGarbageCollectorTask, GarbageCollectorCommonError, GarbageCollectorNewCollectionTask,
 };
 use crate::messages::readers::{
     DatabaseCollectionReadersTask, ReaderNewCollectionTask, ReaderNewCollectionTaskResponse,
 };
-#[cfg(feature = "debug_prints")]
-use crate::util::debug_print::debug_print;
 use tokio::sync::{oneshot, RwLock};
 
 pub struct CollectionOpenOptions<'a> {
@@ -50,6 +50,8 @@ pub struct CollectionOpenOptions<'a> {
     pub name: String,
     pub data_path: &'a PathBuf,
     pub is_manual: bool,
+    pub if_value_changed: bool,
+    pub settings: CollectionSettings,
     pub database_inner: Arc<DatabaseInner>,
 }
 
@@ -92,49 +94,7 @@ impl Collection {
 
         let collection_name = Arc::from(collection_name);
 
-        let raw_db = RawDb::open_raw_db(RawDbOptions {
-            path,
-            comparator: Some(RawDbComparator {
-                name: "v1".to_string(),
-                compare_fn: record_key_compare_fn,
-            }),
-            column_families: vec![
-                RawDbColumnFamily {
-                    name: COLLECTION_CF_GENERATIONS.to_string(),
-                    comparator: Some(RawDbComparator {
-                        name: "v1".to_string(),
-                        compare_fn: generation_key_compare_fn,
-                    }),
-                    merge: None,
-                },
-                RawDbColumnFamily {
-                    name: COLLECTION_CF_GENERATIONS_SIZE.to_string(),
-                    comparator: None,
-                    merge: Some(RawDbMerge {
-                        name: "v1".to_string(),
-                        full_merge: Box::new(generation_size_full_merge),
-                        partial_merge: Box::new(generation_size_partial_merge),
-                    }),
-                },
-                RawDbColumnFamily {
-                    name: COLLECTION_CF_PHANTOMS.to_string(),
-                    comparator: Some(RawDbComparator {
-                        name: "v1".to_string(),
-                        compare_fn: phantom_key_compare_fn,
-                    }),
-                    merge: None,
-                },
-                RawDbColumnFamily {
-                    name: COLLECTION_CF_META.to_string(),
-                    comparator: None,
-                    merge: Some(RawDbMerge {
-                        name: "v1".to_string(),
-                        full_merge: Box::new(meta_full_merge),
-                        partial_merge: Box::new(meta_partial_merge),
-                    }),
-                },
-            ],
-        })?;
+        let raw_db = open_collection_raw_db(path, &options.config.raw_db)?;
 
         let is_manual_stored = raw_db.get_cf(COLLECTION_CF_META, b"is_manual").await?;
         let is_manual = match is_manual_stored {
@@ -238,12 +198,9 @@ impl Collection {
         .await
         .map_err(CollectionOpenError::OneshotRecv)?;
 
-        let raw_db = wrap_collection_raw_db(
-            Arc::new(raw_db),
-            #[cfg(feature = "debug_prints")]
-            collection_name.to_string(),
-        );
+        let raw_db = Arc::new(raw_db);
         let is_deleted = Arc::new(RwLock::new(false));
+        let commits_count = Arc::new(AtomicU64::new(0));
 
         let NewCollectionGenerationsTaskResponse {
             collection_id: generations_id,
@@ -255,11 +212,13 @@ impl Collection {
                 NewCollectionGenerationsTask {
                     name: Arc::<str>::clone(&collection_name),
                     is_manual,
+                    settings: options.settings,
                     generation_id: generation_id.clone(),
                     next_generation_id: next_generation_id.clone(),
                     sender,
                     db,
                     is_deleted: is_deleted.clone(),
+                    commits_count: commits_count.clone(),
                 },
             ))
         })
@@ -348,6 +307,8 @@ impl Collection {
             name: collection_name,
             raw_db,
             is_manual,
+            if_value_changed: options.if_value_changed,
+            settings: RwLock::new(options.settings),
             is_deleted,
             generation_pair_receiver,
             if_not_present_writes: Arc::new(RwLock::new(HashMap::new())),
@@ -357,6 +318,7 @@ impl Collection {
             prev_phantom_id: RwLock::new(prev_phantom_id),
             cursors_id,
             generations_id,
+            commits_count,
             gc: gc_response,
             drop_sender: Some(drop_sender),
         };
@@ -370,3 +332,51 @@ impl Collection {
         data_path.join(collection_name)
     }
 }
+
+// Raw db of the collection with all its column families, comparators and merge operators
+pub fn open_collection_raw_db(path: &str, config: &RawDbConfig) -> Result<RawDb, RawDbOpenError> {
+    RawDb::open_raw_db(RawDbOptions {
+        path,
+        config,
+        comparator: Some(RawDbComparator {
+            name: "v1".to_string(),
+            compare_fn: record_key_compare_fn,
+        }),
+        column_families: vec![
+            RawDbColumnFamily {
+                name: COLLECTION_CF_GENERATIONS.to_string(),
+                comparator: Some(RawDbComparator {
+                    name: "v1".to_string(),
+                    compare_fn: generation_key_compare_fn,
+                }),
+                merge: None,
+            },
+            RawDbColumnFamily {
+                name: COLLECTION_CF_GENERATIONS_SIZE.to_string(),
+                comparator: None,
+                merge: Some(RawDbMerge {
+                    name: "v1".to_string(),
+                    full_merge: Box::new(generation_size_full_merge),
+                    partial_merge: Box::new(generation_size_partial_merge),
+                }),
+            },
+            RawDbColumnFamily {
+                name: COLLECTION_CF_PHANTOMS.to_string(),
+                comparator: Some(RawDbComparator {
+                    name: "v1".to_string(),
+                    compare_fn: phantom_key_compare_fn,
+                }),
+                merge: None,
+            },
+            RawDbColumnFamily {
+                name: COLLECTION_CF_META.to_string(),
+                comparator: None,
+                merge: Some(RawDbMerge {
+                    name: "v1".to_string(),
+                    full_merge: Box::new(meta_full_merge),
+                    partial_merge: Box::new(meta_partial_merge),
+                }),
+            },
+        ],
+    })
+}
@@ -1 +1,3 @@
+pub mod orphan;
 pub mod remove;
+pub mod retarget;
@@ -0,0 +1,38 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::messages::readers::{DatabaseCollectionReadersTask, DeleteReaderTask};
+use crate::raw_db::update_reader::RawDbOrphanReaderOptions;
+use crate::util::tokio::spawn_blocking;
+use std::sync::Arc;
+
+impl Collection {
+    pub async fn inner_orphan_reader(
+        &self,
+        reader_name: Arc<str>,
+    ) -> Result<(), CollectionMethodError> {
+        let raw_db = self.raw_db.clone();
+
+        let reader_name_for_blocking = reader_name.clone();
+
+        spawn_blocking(move || {
+            raw_db.orphan_reader_sync(RawDbOrphanReaderOptions {
+                reader_name: reader_name_for_blocking.as_ref(),
+            })
+        })
+        .await
+        .map_err(|_| CollectionMethodError::TaskJoin)?
+        .map_err(CollectionMethodError::RawDb)?;
+
+        // Orphaned reader is kept in the raw_db, but not tracked anymore
+        self.database_inner
+            .add_readers_task(DatabaseCollectionReadersTask::DeleteReader(
+                DeleteReaderTask {
+                    owner_collection_name: self.name.clone(),
+                    reader_name,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
@@ -2,8 +2,8 @@ use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::Collection;
 use crate::messages::readers::{DatabaseCollectionReadersTask, DeleteReaderTask};
 use crate::raw_db::update_reader::RawDbDeleteReaderOptions;
+use crate::util::tokio::spawn_blocking;
 use std::sync::Arc;
-use tokio::task::spawn_blocking;
 
 impl Collection {
     pub async fn inner_remove_reader(
@@ -0,0 +1,29 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::raw_db::update_reader::RawDbRetargetReaderOptions;
+use crate::util::tokio::spawn_blocking;
+use std::sync::Arc;
+
+impl Collection {
+    // Only persists the new target of the reader, readers thread should be
+    // updated separately (it is done for all readers of the renamed collection at once)
+    pub async fn inner_retarget_reader(
+        &self,
+        reader_name: Arc<str>,
+        collection_name: Arc<str>,
+    ) -> Result<(), CollectionMethodError> {
+        let raw_db = self.raw_db.clone();
+
+        spawn_blocking(move || {
+            raw_db.retarget_reader_sync(RawDbRetargetReaderOptions {
+                reader_name: reader_name.as_ref(),
+                collection_name: collection_name.as_ref(),
+            })
+        })
+        .await
+        .map_err(|_| CollectionMethodError::TaskJoin)?
+        .map_err(CollectionMethodError::RawDb)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::protos::database_meta::CollectionSettings as CollectionSettingsRecord;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+// Settings of the automatic generations commit, used only by non-manual collections
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CollectionSettings {
+    // If `max_generation_age` is not specified, next generation is committed after this delay
+    // since the first put to it, otherwise after this delay without puts
+    pub commit_delay: Duration,
+    // Next generation is committed not later than this time since the first put to it
+    pub max_generation_age: Option<Duration>,
+    // Next generation is committed immediately when it has so many puts
+    pub max_generation_puts: Option<NonZeroU32>,
+}
+
+#[derive(Default)]
+pub struct CollectionSettingsUpdate {
+    pub commit_delay: Option<Duration>,
+    // `Some(None)` resets the value
+    pub max_generation_age: Option<Option<Duration>>,
+    pub max_generation_puts: Option<Option<NonZeroU32>>,
+}
+
+impl Default for CollectionSettings {
+    fn default() -> Self {
+        Self {
+            commit_delay: Duration::from_millis(50),
+            max_generation_age: None,
+            max_generation_puts: None,
+        }
+    }
+}
+
+impl CollectionSettings {
+    pub fn from_record(record: &CollectionSettingsRecord) -> Self {
+        let default = Self::default();
+
+        Self {
+            commit_delay: record
+                .commit_delay_ms
+                .map(Duration::from_millis)
+                .unwrap_or(default.commit_delay),
+            max_generation_age: record.max_generation_age_ms.map(Duration::from_millis),
+            max_generation_puts: record.max_generation_puts.and_then(NonZeroU32::new),
+        }
+    }
+
+    pub fn to_record(self) -> CollectionSettingsRecord {
+        let mut record = CollectionSettingsRecord::new();
+        record.commit_delay_ms = Some(self.commit_delay.as_millis() as u64);
+        record.max_generation_age_ms = self
+            .max_generation_age
+            .map(|duration| duration.as_millis() as u64);
+        record.max_generation_puts = self.max_generation_puts.map(NonZeroU32::get);
+
+        record
+    }
+
+    pub fn apply(&mut self, update: CollectionSettingsUpdate) {
+        if let Some(commit_delay) = update.commit_delay {
+            self.commit_delay = commit_delay;
+        }
+        if let Some(max_generation_age) = update.max_generation_age {
+            self.max_generation_age = max_generation_age;
+        }
+        if let Some(max_generation_puts) = update.max_generation_puts {
+            self.max_generation_puts = max_generation_puts;
+        }
+    }
+}
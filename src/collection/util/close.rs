@@ -0,0 +1,77 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::util::collection_raw_db::CollectionRawDb;
+use crate::database::generations::collection::InnerGenerationsCollectionId;
+use crate::database::DatabaseInner;
+use crate::messages::garbage_collector::{
+    DatabaseGarbageCollectorTask, GarbageCollectorDropCollectionTask,
+};
+use crate::messages::generations::{
+    DatabaseCollectionGenerationsTask, DropCollectionGenerationsTask,
+};
+use crate::util::async_sync_call::async_sync_call;
+use std::sync::Arc;
+
+pub struct DetachCollectionOptions<'a> {
+    pub database_inner: &'a DatabaseInner,
+    pub collection_name: &'a Arc<str>,
+    pub generations_id: InnerGenerationsCollectionId,
+    pub gc_id: usize,
+}
+
+// Stops generations and garbage collector handling of the collection
+pub async fn detach_collection_from_threads(
+    options: DetachCollectionOptions<'_>,
+) -> Result<(), CollectionMethodError> {
+    let DetachCollectionOptions {
+        database_inner,
+        collection_name,
+        generations_id,
+        gc_id,
+    } = options;
+
+    let _: () = async_sync_call(|sender| {
+        database_inner.add_generations_task(DatabaseCollectionGenerationsTask::DropCollection(
+            DropCollectionGenerationsTask {
+                collection_id: generations_id,
+                sender: Some(sender),
+            },
+        ))
+    })
+    .await?;
+
+    let _: () = async_sync_call(|sender| {
+        database_inner.add_gc_task(DatabaseGarbageCollectorTask::DropCollection(
+            GarbageCollectorDropCollectionTask {
+                collection_name: Arc::<str>::clone(collection_name),
+                id: gc_id,
+                sender: Some(sender),
+            },
+        ))
+    })
+    .await?;
+
+    Ok(())
+}
+
+// Waits until all refs to raw_db are dropped and its files are closed
+pub async fn wait_for_raw_db_close(raw_db: CollectionRawDb) {
+    let mut is_alive_receiver = raw_db.get_is_alive_receiver();
+    drop(raw_db);
+
+    loop {
+        let result = is_alive_receiver.changed().await;
+        match result {
+            Ok(_) => {}
+            Err(_) => {
+                // error is possible only if it was droppped, so db should be dropped
+                break;
+            }
+        }
+
+        let is_alive = *is_alive_receiver.borrow();
+
+        if !is_alive {
+            break;
+        }
+    }
+}
@@ -1,11 +1,21 @@
+use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::Collection;
 use crate::common::GenerationId;
 
 impl Collection {
-    pub fn generation_is_less_than_minimum(&self, generation_id: GenerationId<'_>) -> bool {
+    // Caller should hold `minimum_generation_id_lock` while reading at `generation_id`
+    pub fn check_generation_is_not_less_than_minimum(
+        &self,
+        generation_id: GenerationId<'_>,
+    ) -> Result<(), CollectionMethodError> {
         let minimum_generation_id = self.minimum_generation_id.borrow();
-        let minimum_generation_id = minimum_generation_id.as_ref();
 
-        generation_id < minimum_generation_id
+        if generation_id < minimum_generation_id.as_ref() {
+            return Err(CollectionMethodError::GenerationIdLessThanMinimum(
+                minimum_generation_id.clone(),
+            ));
+        }
+
+        Ok(())
     }
 }
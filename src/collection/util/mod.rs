@@ -1,3 +1,4 @@
+pub mod close;
 pub mod collection_raw_db;
 pub mod existing_value_flags;
 pub mod generation_id_or_current;
@@ -1,6 +1,10 @@
 use crate::common::{GenerationId, IsByteArray};
+use crate::raw_db::RawDbError;
 use std::str::from_utf8;
 
+// Optional last byte of the value, absent in values without flags
+const FLAG_ORPHANED: u8 = 1;
+
 #[derive(Debug)]
 pub struct OwnedReaderValue(Box<[u8]>);
 pub struct ReaderValue<'a>(&'a [u8]);
@@ -45,12 +49,60 @@ impl<'a> ReaderValue<'a> {
 
     pub fn get_generation_id(&self) -> GenerationId<'a> {
         let collection_name_len = self.0[0] as usize;
+        let generation_id_len = self.0[1 + collection_name_len] as usize;
 
-        let bytes = &self.0[(2 + collection_name_len)..];
+        let bytes =
+            &self.0[(2 + collection_name_len)..(2 + collection_name_len + generation_id_len)];
 
         GenerationId::new_unchecked(bytes)
     }
 
+    // Orphaned reader points to the deleted collection
+    pub fn is_orphaned(&self) -> bool {
+        self.get_flags() & FLAG_ORPHANED != 0
+    }
+
+    pub fn to_orphaned(&self) -> OwnedReaderValue {
+        let collection_name_len = self.0[0] as usize;
+        let generation_id_len = self.0[1 + collection_name_len] as usize;
+        let flags_offset = 2 + collection_name_len + generation_id_len;
+
+        let mut value = Vec::with_capacity(flags_offset + 1);
+        value.extend_from_slice(&self.0[..flags_offset]);
+        value.push(self.get_flags() | FLAG_ORPHANED);
+
+        OwnedReaderValue(value.into_boxed_slice())
+    }
+
+    // Same reader pointing to another collection, flags are kept
+    pub fn with_collection_name(
+        &self,
+        collection_name: &str,
+    ) -> Result<OwnedReaderValue, RawDbError> {
+        let value = OwnedReaderValue::new(Some(collection_name), self.get_generation_id())
+            .or(Err(RawDbError::InvalidReaderValue))?;
+
+        let flags = self.get_flags();
+        if flags == 0 {
+            return Ok(value);
+        }
+
+        let mut value = value.0.into_vec();
+        value.push(flags);
+
+        Ok(OwnedReaderValue(value.into_boxed_slice()))
+    }
+
+    fn get_flags(&self) -> u8 {
+        let collection_name_len = self.0[0] as usize;
+        let generation_id_len = self.0[1 + collection_name_len] as usize;
+
+        self.0
+            .get(2 + collection_name_len + generation_id_len)
+            .copied()
+            .unwrap_or(0)
+    }
+
     pub fn from_slice(bytes: &'a [u8]) -> Result<Self, ()> {
         if bytes.len() < 2 {
             return Err(());
@@ -72,7 +124,9 @@ impl<'a> ReaderValue<'a> {
 
         let generation_id_len = bytes[1 + collection_name_len] as usize;
 
-        if bytes.len() != 2 + collection_name_len + generation_id_len {
+        let len_without_flags = 2 + collection_name_len + generation_id_len;
+
+        if bytes.len() != len_without_flags && bytes.len() != len_without_flags + 1 {
             return Err(());
         }
 
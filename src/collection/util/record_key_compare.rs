@@ -68,9 +68,10 @@ pub fn record_key_compare_fn(left: &[u8], right: &[u8]) -> Ordering {
     }
 
     if left[0] != 0 || right[0] != 0 {
-        eprintln!("left: {:?}", left);
-        eprintln!("right: {:?}", right);
-        panic!("record key reserved flag byte is not zero");
+        panic!(
+            "record key reserved flag byte is not zero, left: {:?}, right: {:?}",
+            left, right
+        );
     }
 
     let (ord, left_to, right_to) = record_key_compare_u24_sized(left, right, 1, 1);
@@ -0,0 +1,215 @@
+use crate::common::{CollectionKey, IsByteArray, OwnedCollectionKey};
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+#[derive(Clone, Debug)]
+pub struct OwnedKeyRange {
+    pub from: Bound<OwnedCollectionKey>,
+    pub to: Bound<OwnedCollectionKey>,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct KeyRange<'a> {
+    pub from: Bound<CollectionKey<'a>>,
+    pub to: Bound<CollectionKey<'a>>,
+}
+
+impl OwnedKeyRange {
+    pub fn full() -> Self {
+        Self {
+            from: Bound::Unbounded,
+            to: Bound::Unbounded,
+        }
+    }
+
+    pub fn new(
+        from: Bound<OwnedCollectionKey>,
+        to: Bound<OwnedCollectionKey>,
+        prefix: Option<OwnedCollectionKey>,
+    ) -> Self {
+        let Some(prefix) = prefix else {
+            return Self { from, to };
+        };
+
+        // All keys with prefix are in the `[prefix, prefix_successor)` range,
+        // so prefix can be expressed as an intersection with it
+        let prefix_to = prefix_successor(prefix.get_byte_array())
+            .map(Bound::Excluded)
+            .unwrap_or(Bound::Unbounded);
+        let prefix_from = Bound::Included(prefix);
+
+        Self {
+            from: max_lower_bound(from, prefix_from),
+            to: min_upper_bound(to, prefix_to),
+        }
+    }
+
+    pub fn as_ref(&self) -> KeyRange<'_> {
+        KeyRange {
+            from: bound_as_ref(&self.from),
+            to: bound_as_ref(&self.to),
+        }
+    }
+}
+
+impl<'a> KeyRange<'a> {
+    pub fn full() -> Self {
+        Self {
+            from: Bound::Unbounded,
+            to: Bound::Unbounded,
+        }
+    }
+
+    pub fn to_owned(self) -> OwnedKeyRange {
+        OwnedKeyRange {
+            from: self.from.map(|key| key.to_owned()),
+            to: self.to.map(|key| key.to_owned()),
+        }
+    }
+
+    pub fn is_below_from(&self, key: CollectionKey<'_>) -> bool {
+        match self.from {
+            Bound::Included(from) => key.get_byte_array() < from.get_byte_array(),
+            Bound::Excluded(from) => key.get_byte_array() <= from.get_byte_array(),
+            Bound::Unbounded => false,
+        }
+    }
+
+    pub fn is_above_to(&self, key: CollectionKey<'_>) -> bool {
+        match self.to {
+            Bound::Included(to) => key.get_byte_array() > to.get_byte_array(),
+            Bound::Excluded(to) => key.get_byte_array() >= to.get_byte_array(),
+            Bound::Unbounded => false,
+        }
+    }
+
+    pub fn contains(&self, key: CollectionKey<'_>) -> bool {
+        !self.is_below_from(key) && !self.is_above_to(key)
+    }
+}
+
+fn bound_as_ref(bound: &Bound<OwnedCollectionKey>) -> Bound<CollectionKey<'_>> {
+    match bound {
+        Bound::Included(key) => Bound::Included(key.as_ref()),
+        Bound::Excluded(key) => Bound::Excluded(key.as_ref()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+fn prefix_successor(prefix: &[u8]) -> Option<OwnedCollectionKey> {
+    let mut bytes = prefix.to_vec();
+
+    while let Some(last) = bytes.last_mut() {
+        if *last == 0xff {
+            bytes.pop();
+            continue;
+        }
+
+        *last += 1;
+
+        // Successor is never longer than the prefix, so it is always a valid key
+        return Some(OwnedCollectionKey::from_boxed_slice(bytes.into_boxed_slice()).unwrap());
+    }
+
+    None
+}
+
+fn max_lower_bound(
+    a: Bound<OwnedCollectionKey>,
+    b: Bound<OwnedCollectionKey>,
+) -> Bound<OwnedCollectionKey> {
+    let (a_key, b_key) = match (&a, &b) {
+        (Bound::Unbounded, _) => return b,
+        (_, Bound::Unbounded) => return a,
+        (
+            Bound::Included(a_key) | Bound::Excluded(a_key),
+            Bound::Included(b_key) | Bound::Excluded(b_key),
+        ) => (a_key, b_key),
+    };
+
+    match a_key.cmp(b_key) {
+        Ordering::Greater => a,
+        Ordering::Less => b,
+        Ordering::Equal => match a {
+            Bound::Excluded(_) => a,
+            _ => b,
+        },
+    }
+}
+
+fn min_upper_bound(
+    a: Bound<OwnedCollectionKey>,
+    b: Bound<OwnedCollectionKey>,
+) -> Bound<OwnedCollectionKey> {
+    let (a_key, b_key) = match (&a, &b) {
+        (Bound::Unbounded, _) => return b,
+        (_, Bound::Unbounded) => return a,
+        (
+            Bound::Included(a_key) | Bound::Excluded(a_key),
+            Bound::Included(b_key) | Bound::Excluded(b_key),
+        ) => (a_key, b_key),
+    };
+
+    match a_key.cmp(b_key) {
+        Ordering::Less => a,
+        Ordering::Greater => b,
+        Ordering::Equal => match a {
+            Bound::Excluded(_) => a,
+            _ => b,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::key_range::OwnedKeyRange;
+    use crate::common::{CollectionKey, OwnedCollectionKey};
+    use std::ops::Bound;
+
+    fn key(bytes: &[u8]) -> OwnedCollectionKey {
+        OwnedCollectionKey::from_boxed_slice(bytes.into()).unwrap()
+    }
+
+    #[test]
+    fn test_prefix_range() {
+        let range = OwnedKeyRange::new(Bound::Unbounded, Bound::Unbounded, Some(key(b"ab")));
+        let range = range.as_ref();
+
+        assert!(!range.contains(CollectionKey::new_unchecked(b"a")));
+        assert!(range.contains(CollectionKey::new_unchecked(b"ab")));
+        assert!(range.contains(CollectionKey::new_unchecked(b"ab\xff\xff")));
+        assert!(!range.contains(CollectionKey::new_unchecked(b"ac")));
+    }
+
+    #[test]
+    fn test_prefix_of_ff_bytes() {
+        let range = OwnedKeyRange::new(Bound::Unbounded, Bound::Unbounded, Some(key(b"a\xff")));
+
+        assert_eq!(range.to, Bound::Excluded(key(b"b")));
+
+        let range = OwnedKeyRange::new(Bound::Unbounded, Bound::Unbounded, Some(key(b"\xff")));
+
+        assert_eq!(range.to, Bound::Unbounded);
+    }
+
+    #[test]
+    fn test_prefix_intersection() {
+        let range = OwnedKeyRange::new(
+            Bound::Excluded(key(b"ab")),
+            Bound::Included(key(b"z")),
+            Some(key(b"ab")),
+        );
+
+        assert_eq!(range.from, Bound::Excluded(key(b"ab")));
+        assert_eq!(range.to, Bound::Excluded(key(b"ac")));
+
+        let range = OwnedKeyRange::new(
+            Bound::Included(key(b"a")),
+            Bound::Excluded(key(b"abc")),
+            Some(key(b"ab")),
+        );
+
+        assert_eq!(range.from, Bound::Included(key(b"ab")));
+        assert_eq!(range.to, Bound::Excluded(key(b"abc")));
+    }
+}
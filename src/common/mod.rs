@@ -12,6 +12,7 @@ use std::sync::Arc;
 pub mod collection;
 pub mod constants;
 pub mod generation_id;
+pub mod key_range;
 pub mod reader;
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -43,15 +44,23 @@ pub struct KeyValue {
 pub struct KeyValueDiff {
     pub key: OwnedCollectionKey,
     pub from_value: Option<OwnedCollectionValue>,
-    pub intermediate_values: Vec<Option<OwnedCollectionValue>>,
+    pub intermediate_values: Vec<IntermediateValue>,
     pub to_value: Option<OwnedCollectionValue>,
 }
 
+#[derive(PartialEq, Eq, Debug)]
+pub struct IntermediateValue {
+    pub generation_id: OwnedGenerationId,
+    pub value: Option<OwnedCollectionValue>,
+}
+
 #[derive(Clone)]
 pub struct KeyValueUpdate {
     pub key: Cow<'static, OwnedCollectionKey>,
     pub value: Option<OwnedCollectionValue>,
     pub if_not_present: bool,
+    // `None` means that collection default will be used
+    pub if_value_changed: Option<bool>,
     no_manual_creation: PhantomData<()>,
 }
 
@@ -59,6 +68,7 @@ pub struct KeyValueUpdateNewOptions {
     pub key: OwnedCollectionKey,
     pub value: Option<OwnedCollectionValue>,
     pub if_not_present: bool,
+    pub if_value_changed: Option<bool>,
 }
 
 impl KeyValueUpdate {
@@ -67,12 +77,14 @@ impl KeyValueUpdate {
             key,
             value,
             if_not_present,
+            if_value_changed,
         } = options;
 
         Self {
             key: Cow::Owned(key),
             value,
             if_not_present,
+            if_value_changed,
             no_manual_creation: PhantomData::default(),
         }
     }
@@ -384,11 +396,14 @@ pub trait IsByteArrayMut<'a> {
     fn get_byte_array_mut(&'a mut self) -> &'a mut [u8];
 }
 
+#[cfg(test)]
 #[derive(Debug)]
 pub struct NeverEq;
 
+#[cfg(test)]
 unsafe impl Send for NeverEq {}
 
+#[cfg(test)]
 impl PartialEq for NeverEq {
     fn eq(&self, _: &Self) -> bool {
         false
@@ -398,6 +413,7 @@ impl PartialEq for NeverEq {
         true
     }
 }
+#[cfg(test)]
 impl Eq for NeverEq {}
 
 #[cfg(test)]
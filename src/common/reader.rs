@@ -8,6 +8,8 @@ pub struct ReaderRecord {
     pub reader_name: String,
     pub collection_name: Option<String>,
     pub generation_id: Option<OwnedGenerationId>,
+    // Collection that reader is pointing to was deleted
+    pub is_orphaned: bool,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,594 @@
+use crate::config::ReadConfigError;
+use crate::database::config::{DatabaseConfig, DeletedCollectionReadersMode};
+use crate::http::auth::{AccessGrant, AccessRole, ApiToken};
+use crate::http::config::{HttpConfig, ListenAddress, TlsConfig};
+use crate::logging::config::{LogConfig, LogFormat};
+use crate::logging::{parse_filter, LoggingError};
+use crate::raw_db::RawDbConfig;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+// Sections of the config file, env variables `DIFFBELT_<SECTION>_<KEY>` are overriding
+// values from these sections, for example `DIFFBELT_DATABASE_QUERY_PACK_LIMIT=100`
+const SECTIONS: [&str; 4] = ["http", "database", "rocksdb", "log"];
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub data_path: Option<PathBuf>,
+    pub clear: Option<bool>,
+    pub backup_path: Option<PathBuf>,
+    pub http: HttpConfigFile,
+    pub database: DatabaseConfigFile,
+    pub rocksdb: RawDbConfigFile,
+    pub log: LogConfigFile,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpConfigFile {
+    pub listen_address: Option<ListenAddressFile>,
+    pub tls_cert_path: Option<PathBuf>,
+    pub tls_key_path: Option<PathBuf>,
+    pub tokens: Vec<ApiTokenFile>,
+    pub create_collection_request_max_bytes: Option<usize>,
+    pub get_request_max_bytes: Option<usize>,
+    pub get_keys_around_request_max_bytes: Option<usize>,
+    pub put_request_max_bytes: Option<usize>,
+    pub put_many_request_max_bytes: Option<usize>,
+    pub query_start_request_max_bytes: Option<usize>,
+    pub diff_start_request_max_bytes: Option<usize>,
+    pub reader_request_max_bytes: Option<usize>,
+}
+
+// One address or a list of them, env variable can have a comma-separated list
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum ListenAddressFile {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiTokenFile {
+    pub name: String,
+    pub token: String,
+    // Collection name pattern to "read", "write" or "admin"
+    pub collections: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfigFile {
+    pub query_pack_limit: Option<usize>,
+    pub query_pack_records_limit: Option<usize>,
+    pub diff_changes_limit: Option<usize>,
+    pub diff_pack_limit: Option<usize>,
+    pub diff_pack_records_limit: Option<usize>,
+    pub max_cursors_per_collection: Option<usize>,
+    pub gc_records_limit: Option<usize>,
+    pub gc_lookups_limit: Option<usize>,
+    // "remove" or "orphan"
+    pub deleted_collection_readers: Option<String>,
+    pub trash_retention_secs: Option<u64>,
+    pub trash_purge_interval_secs: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct RawDbConfigFile {
+    pub max_open_files: Option<i32>,
+    pub max_background_jobs: Option<i32>,
+    pub increase_parallelism: Option<i32>,
+    pub write_buffer_size: Option<usize>,
+    pub max_write_buffer_number: Option<i32>,
+    pub target_file_size_base: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfigFile {
+    pub filter: Option<String>,
+    // "text" or "json"
+    pub format: Option<String>,
+}
+
+// Format is selected by the extension, `.json` files are parsed as JSON, everything else as TOML
+pub fn read_config_file(path: &Path) -> Result<toml::Value, ReadConfigError> {
+    let content = std::fs::read_to_string(path)?;
+
+    let is_json = path
+        .extension()
+        .map(|extension| extension == "json")
+        .unwrap_or(false);
+
+    let value = if is_json {
+        serde_json::from_str(&content)?
+    } else {
+        toml::from_str(&content)?
+    };
+
+    Ok(value)
+}
+
+// Returns warnings about ignored env variables, logging is not initialized yet to report them
+pub fn apply_env_overrides(
+    value: &mut toml::Value,
+    vars: impl Iterator<Item = (String, String)>,
+) -> Result<Vec<String>, ReadConfigError> {
+    let root = value
+        .as_table_mut()
+        .ok_or_else(|| ReadConfigError::Invalid(vec!["config should be an object".to_string()]))?;
+
+    let mut warnings = Vec::new();
+
+    for (name, var_value) in vars {
+        let Some(name) = name.strip_prefix("DIFFBELT_") else {
+            continue;
+        };
+
+        match name {
+            // Path of the config file itself
+            "CONFIG" => {}
+            "DATA_PATH" => {
+                root.insert("data_path".to_string(), toml::Value::String(var_value));
+            }
+            "CLEAR" => {
+                let is_clear = var_value == "1" || var_value == "true";
+                root.insert("clear".to_string(), toml::Value::Boolean(is_clear));
+            }
+            "BACKUP_PATH" => {
+                root.insert("backup_path".to_string(), toml::Value::String(var_value));
+            }
+            name => {
+                let name = name.to_lowercase();
+
+                let section_key_and_kind = SECTIONS.iter().find_map(|section| {
+                    let key = name.strip_prefix(section)?.strip_prefix('_')?;
+                    let kind = env_value_kind(section, key)?;
+
+                    Some((*section, key, kind))
+                });
+
+                let Some((section, key, kind)) = section_key_and_kind else {
+                    warnings.push(format!(
+                        "unknown env variable DIFFBELT_{} is ignored",
+                        name.to_uppercase()
+                    ));
+                    continue;
+                };
+
+                let section_value = root
+                    .entry(section)
+                    .or_insert_with(|| toml::Value::Table(toml::map::Map::new()));
+
+                let section_table = section_value.as_table_mut().ok_or_else(|| {
+                    ReadConfigError::Invalid(vec![format!("{} should be an object", section)])
+                })?;
+
+                section_table.insert(key.to_string(), parse_env_value(var_value, kind));
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+#[derive(Copy, Clone)]
+enum EnvValueKind {
+    String,
+    Integer,
+}
+
+// Keys of `SECTIONS` that can be set by env variables, `http.tokens` is only in the file
+fn env_value_kind(section: &str, key: &str) -> Option<EnvValueKind> {
+    let kind = match (section, key) {
+        ("http", "listen_address" | "tls_cert_path" | "tls_key_path") => EnvValueKind::String,
+        (
+            "http",
+            "create_collection_request_max_bytes"
+            | "get_request_max_bytes"
+            | "get_keys_around_request_max_bytes"
+            | "put_request_max_bytes"
+            | "put_many_request_max_bytes"
+            | "query_start_request_max_bytes"
+            | "diff_start_request_max_bytes"
+            | "reader_request_max_bytes",
+        ) => EnvValueKind::Integer,
+        ("database", "deleted_collection_readers") => EnvValueKind::String,
+        (
+            "database",
+            "query_pack_limit"
+            | "query_pack_records_limit"
+            | "diff_changes_limit"
+            | "diff_pack_limit"
+            | "diff_pack_records_limit"
+            | "max_cursors_per_collection"
+            | "gc_records_limit"
+            | "gc_lookups_limit"
+            | "trash_retention_secs"
+            | "trash_purge_interval_secs",
+        ) => EnvValueKind::Integer,
+        (
+            "rocksdb",
+            "max_open_files"
+            | "max_background_jobs"
+            | "increase_parallelism"
+            | "write_buffer_size"
+            | "max_write_buffer_number"
+            | "target_file_size_base",
+        ) => EnvValueKind::Integer,
+        ("log", "filter" | "format") => EnvValueKind::String,
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+// Not a number is left as a string, so the error says which field has the invalid type
+fn parse_env_value(value: String, kind: EnvValueKind) -> toml::Value {
+    match kind {
+        EnvValueKind::String => toml::Value::String(value),
+        EnvValueKind::Integer => match value.parse::<i64>() {
+            Ok(number) => toml::Value::Integer(number),
+            Err(_) => toml::Value::String(value),
+        },
+    }
+}
+
+struct Validator {
+    errors: Vec<String>,
+}
+
+impl Validator {
+    fn positive(&mut self, name: &str, value: Option<usize>, default: usize) -> usize {
+        match value {
+            Some(0) => {
+                self.errors.push(format!("{} should be > 0", name));
+                default
+            }
+            Some(value) => value,
+            None => default,
+        }
+    }
+
+    fn non_zero(
+        &mut self,
+        name: &str,
+        value: Option<usize>,
+        default: NonZeroUsize,
+    ) -> NonZeroUsize {
+        match value {
+            Some(value) => NonZeroUsize::new(value).unwrap_or_else(|| {
+                self.errors.push(format!("{} should be > 0", name));
+                default
+            }),
+            None => default,
+        }
+    }
+}
+
+impl HttpConfigFile {
+    fn into_config(self, validator: &mut Validator) -> HttpConfig {
+        let default = HttpConfig::default();
+
+        let listen_addresses = match self.listen_address {
+            Some(addresses) => {
+                let addresses = match addresses {
+                    ListenAddressFile::One(addresses) => addresses
+                        .split(',')
+                        .map(|address| address.trim().to_string())
+                        .collect(),
+                    ListenAddressFile::Many(addresses) => addresses,
+                };
+
+                if addresses.iter().all(|address| address.is_empty()) {
+                    validator
+                        .errors
+                        .push("http.listen_address should not be empty".to_string());
+                }
+
+                let mut listen_addresses = Vec::with_capacity(addresses.len());
+
+                for address in addresses.into_iter().filter(|address| !address.is_empty()) {
+                    match address.parse::<ListenAddress>() {
+                        Ok(address) => listen_addresses.push(address),
+                        Err(_) => validator
+                            .errors
+                            .push(format!("http.listen_address \"{}\" is invalid", address)),
+                    }
+                }
+
+                listen_addresses
+            }
+            None => default.listen_addresses,
+        };
+
+        let tls = match (self.tls_cert_path, self.tls_key_path) {
+            (Some(cert_path), Some(key_path)) => Some(TlsConfig {
+                cert_path,
+                key_path,
+            }),
+            (None, None) => None,
+            _ => {
+                validator.errors.push(
+                    "http.tls_cert_path and http.tls_key_path should be specified together"
+                        .to_string(),
+                );
+                None
+            }
+        };
+
+        let mut tokens: Vec<Arc<ApiToken>> = Vec::with_capacity(self.tokens.len());
+
+        for token in self.tokens {
+            if token.token.is_empty() {
+                validator.errors.push(format!(
+                    "http.tokens \"{}\" token should not be empty",
+                    token.name
+                ));
+            } else if tokens.iter().any(|other| other.token == token.token) {
+                validator.errors.push(format!(
+                    "http.tokens \"{}\" token is not unique",
+                    token.name
+                ));
+            }
+
+            let mut grants = Vec::with_capacity(token.collections.len());
+
+            for (pattern, role) in token.collections {
+                let Some(role) = AccessRole::parse(&role) else {
+                    validator.errors.push(format!(
+                        "http.tokens \"{}\" role of \"{}\" should be \"read\", \"write\" or \"admin\"",
+                        token.name, pattern
+                    ));
+                    continue;
+                };
+
+                grants.push(AccessGrant { pattern, role });
+            }
+
+            tokens.push(Arc::new(ApiToken {
+                name: token.name,
+                token: token.token,
+                grants,
+            }));
+        }
+
+        HttpConfig {
+            listen_addresses,
+            tls,
+            tokens,
+            create_collection_request_max_bytes: validator.positive(
+                "http.create_collection_request_max_bytes",
+                self.create_collection_request_max_bytes,
+                default.create_collection_request_max_bytes,
+            ),
+            get_request_max_bytes: validator.positive(
+                "http.get_request_max_bytes",
+                self.get_request_max_bytes,
+                default.get_request_max_bytes,
+            ),
+            get_keys_around_request_max_bytes: validator.positive(
+                "http.get_keys_around_request_max_bytes",
+                self.get_keys_around_request_max_bytes,
+                default.get_keys_around_request_max_bytes,
+            ),
+            put_request_max_bytes: validator.positive(
+                "http.put_request_max_bytes",
+                self.put_request_max_bytes,
+                default.put_request_max_bytes,
+            ),
+            put_many_request_max_bytes: validator.positive(
+                "http.put_many_request_max_bytes",
+                self.put_many_request_max_bytes,
+                default.put_many_request_max_bytes,
+            ),
+            query_start_request_max_bytes: validator.positive(
+                "http.query_start_request_max_bytes",
+                self.query_start_request_max_bytes,
+                default.query_start_request_max_bytes,
+            ),
+            diff_start_request_max_bytes: validator.positive(
+                "http.diff_start_request_max_bytes",
+                self.diff_start_request_max_bytes,
+                default.diff_start_request_max_bytes,
+            ),
+            reader_request_max_bytes: validator.positive(
+                "http.reader_request_max_bytes",
+                self.reader_request_max_bytes,
+                default.reader_request_max_bytes,
+            ),
+        }
+    }
+}
+
+impl DatabaseConfigFile {
+    fn into_config(self, raw_db: RawDbConfig, validator: &mut Validator) -> DatabaseConfig {
+        let default = DatabaseConfig::default();
+
+        let deleted_collection_readers = match self.deleted_collection_readers.as_deref() {
+            None => default.deleted_collection_readers,
+            Some("remove") => DeletedCollectionReadersMode::Remove,
+            Some("orphan") => DeletedCollectionReadersMode::Orphan,
+            Some(_) => {
+                validator.errors.push(
+                    "database.deleted_collection_readers should be \"remove\" or \"orphan\""
+                        .to_string(),
+                );
+                default.deleted_collection_readers
+            }
+        };
+
+        let trash_purge_interval = match self.trash_purge_interval_secs {
+            Some(0) => {
+                validator
+                    .errors
+                    .push("database.trash_purge_interval_secs should be > 0".to_string());
+                default.trash_purge_interval
+            }
+            Some(secs) => Duration::from_secs(secs),
+            None => default.trash_purge_interval,
+        };
+
+        DatabaseConfig {
+            query_pack_limit: validator.positive(
+                "database.query_pack_limit",
+                self.query_pack_limit,
+                default.query_pack_limit,
+            ),
+            query_pack_records_limit: validator.positive(
+                "database.query_pack_records_limit",
+                self.query_pack_records_limit,
+                default.query_pack_records_limit,
+            ),
+            diff_changes_limit: validator.positive(
+                "database.diff_changes_limit",
+                self.diff_changes_limit,
+                default.diff_changes_limit,
+            ),
+            diff_pack_limit: validator.positive(
+                "database.diff_pack_limit",
+                self.diff_pack_limit,
+                default.diff_pack_limit,
+            ),
+            diff_pack_records_limit: validator.positive(
+                "database.diff_pack_records_limit",
+                self.diff_pack_records_limit,
+                default.diff_pack_records_limit,
+            ),
+            max_cursors_per_collection: validator.non_zero(
+                "database.max_cursors_per_collection",
+                self.max_cursors_per_collection,
+                default.max_cursors_per_collection,
+            ),
+            gc_records_limit: validator.non_zero(
+                "database.gc_records_limit",
+                self.gc_records_limit,
+                default.gc_records_limit,
+            ),
+            gc_lookups_limit: validator.non_zero(
+                "database.gc_lookups_limit",
+                self.gc_lookups_limit,
+                default.gc_lookups_limit,
+            ),
+            deleted_collection_readers,
+            trash_retention: self
+                .trash_retention_secs
+                .map(Duration::from_secs)
+                .unwrap_or(default.trash_retention),
+            trash_purge_interval,
+            raw_db,
+        }
+    }
+}
+
+impl RawDbConfigFile {
+    fn into_config(self, validator: &mut Validator) -> RawDbConfig {
+        let mut check_positive = |name: &str, value: Option<i32>| {
+            if let Some(value) = value {
+                if value <= 0 {
+                    validator
+                        .errors
+                        .push(format!("rocksdb.{} should be > 0", name));
+                }
+            }
+
+            value
+        };
+
+        let max_background_jobs = check_positive("max_background_jobs", self.max_background_jobs);
+        let increase_parallelism =
+            check_positive("increase_parallelism", self.increase_parallelism);
+        let max_write_buffer_number =
+            check_positive("max_write_buffer_number", self.max_write_buffer_number);
+
+        RawDbConfig {
+            // -1 means that files are always kept open
+            max_open_files: self.max_open_files,
+            max_background_jobs,
+            increase_parallelism,
+            write_buffer_size: self.write_buffer_size,
+            max_write_buffer_number,
+            target_file_size_base: self.target_file_size_base,
+        }
+    }
+}
+
+impl LogConfigFile {
+    fn into_config(self, validator: &mut Validator) -> LogConfig {
+        let default = LogConfig::default();
+
+        let filter = match self.filter {
+            Some(filter) => match parse_filter(&filter) {
+                Ok(_) => filter,
+                Err(LoggingError::InvalidFilter(reason)) => {
+                    validator
+                        .errors
+                        .push(format!("log.filter is invalid: {}", reason));
+                    default.filter
+                }
+                Err(_) => default.filter,
+            },
+            None => default.filter,
+        };
+
+        let format = match self.format.as_deref() {
+            None => default.format,
+            Some("text") => LogFormat::Text,
+            Some("json") => LogFormat::Json,
+            Some(_) => {
+                validator
+                    .errors
+                    .push("log.format should be \"text\" or \"json\"".to_string());
+                default.format
+            }
+        };
+
+        LogConfig { filter, format }
+    }
+}
+
+pub struct ParsedConfigFile {
+    pub data_path: Option<PathBuf>,
+    pub is_clear: bool,
+    pub backup_path: Option<PathBuf>,
+    pub http: HttpConfig,
+    pub database: DatabaseConfig,
+    pub log: LogConfig,
+}
+
+impl ConfigFile {
+    pub fn from_value(value: toml::Value) -> Result<Self, ReadConfigError> {
+        let file = value.try_into()?;
+
+        Ok(file)
+    }
+
+    pub fn validate(self) -> Result<ParsedConfigFile, ReadConfigError> {
+        let mut validator = Validator { errors: Vec::new() };
+
+        let http = self.http.into_config(&mut validator);
+        let raw_db = self.rocksdb.into_config(&mut validator);
+        let database = self.database.into_config(raw_db, &mut validator);
+        let log = self.log.into_config(&mut validator);
+
+        if !validator.errors.is_empty() {
+            return Err(ReadConfigError::Invalid(validator.errors));
+        }
+
+        Ok(ParsedConfigFile {
+            data_path: self.data_path,
+            is_clear: self.clear.unwrap_or(false),
+            backup_path: self.backup_path,
+            http,
+            database,
+            log,
+        })
+    }
+}
@@ -1,56 +1,113 @@
+use crate::config::file::{apply_env_overrides, read_config_file, ConfigFile};
+use crate::database::config::DatabaseConfig;
+use crate::http::config::HttpConfig;
+use crate::logging::config::LogConfig;
 use std::env;
 use std::env::VarError;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub mod file;
 
 pub struct Config {
     pub data_path: PathBuf,
     pub is_clear: bool,
+    // Directory for `POST /admin/backup`, backups are disabled if not specified
+    pub backup_path: Option<PathBuf>,
+    pub http: HttpConfig,
+    pub database: Arc<DatabaseConfig>,
+    pub log: LogConfig,
+    // Ignored env variables, to be reported by the caller
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug)]
-pub enum ReadConfigFromEnvError {
+pub enum ReadConfigError {
     EnvVarError(env::VarError),
     VarNotPresent(String),
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+    // All problems that were found, one per item
+    Invalid(Vec<String>),
 }
 
-impl From<env::VarError> for ReadConfigFromEnvError {
+impl From<env::VarError> for ReadConfigError {
     fn from(err: VarError) -> Self {
-        ReadConfigFromEnvError::EnvVarError(err)
+        ReadConfigError::EnvVarError(err)
     }
 }
 
-fn get_var(name: &str) -> Result<String, ReadConfigFromEnvError> {
-    let result = env::var(name);
-    match result {
-        Err(err) => match err {
-            env::VarError::NotPresent => {
-                Err(ReadConfigFromEnvError::VarNotPresent(name.to_string()))
-            }
-            err => Err(ReadConfigFromEnvError::EnvVarError(err)),
-        },
-        Ok(value) => Ok(value),
+impl From<std::io::Error> for ReadConfigError {
+    fn from(err: std::io::Error) -> Self {
+        ReadConfigError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for ReadConfigError {
+    fn from(err: toml::de::Error) -> Self {
+        ReadConfigError::Toml(err)
+    }
+}
+
+impl From<serde_json::Error> for ReadConfigError {
+    fn from(err: serde_json::Error) -> Self {
+        ReadConfigError::Json(err)
     }
 }
 
-fn get_opt_var(name: &str) -> Result<Option<String>, ReadConfigFromEnvError> {
+fn get_opt_var(name: &str) -> Result<Option<String>, ReadConfigError> {
     let result = env::var(name);
     match result {
         Err(err) => match err {
             VarError::NotPresent => Ok(None),
-            err => Err(ReadConfigFromEnvError::EnvVarError(err)),
+            err => Err(ReadConfigError::EnvVarError(err)),
         },
         Ok(value) => Ok(Some(value)),
     }
 }
 
 impl Config {
-    pub fn read_from_env() -> Result<Self, ReadConfigFromEnvError> {
-        let data_path = get_var("DIFFBELT_DATA_PATH")?;
-        let data_path = PathBuf::from(data_path);
+    // Reads config file from `DIFFBELT_CONFIG` path (if specified) and applies
+    // `DIFFBELT_*` env variables over it
+    pub fn read() -> Result<Self, ReadConfigError> {
+        let value = match get_opt_var("DIFFBELT_CONFIG")? {
+            Some(path) => read_config_file(Path::new(&path))?,
+            None => toml::Value::Table(toml::map::Map::new()),
+        };
+
+        let vars = env::vars_os().filter_map(|(name, value)| {
+            let name = name.into_string().ok()?;
+            if !name.starts_with("DIFFBELT_") {
+                return None;
+            }
+
+            Some((name, value.into_string().ok()?))
+        });
+
+        Self::from_value(value, vars)
+    }
+
+    pub fn from_value(
+        mut value: toml::Value,
+        vars: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ReadConfigError> {
+        let warnings = apply_env_overrides(&mut value, vars)?;
+
+        let file = ConfigFile::from_value(value)?.validate()?;
+
+        let data_path = file
+            .data_path
+            .ok_or_else(|| ReadConfigError::VarNotPresent("DIFFBELT_DATA_PATH".to_string()))?;
 
         Ok(Config {
             data_path,
-            is_clear: get_opt_var("DIFFBELT_CLEAR")?.unwrap_or("0".to_string()) == "1",
+            is_clear: file.is_clear,
+            backup_path: file.backup_path,
+            http: file.http,
+            database: Arc::new(file.database),
+            log: file.log,
+            warnings,
         })
     }
 }
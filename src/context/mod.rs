@@ -1,11 +1,15 @@
 use crate::config::Config;
 use crate::database::Database;
 
+use crate::http::metrics::HttpMetrics;
 use crate::http::routing::Routing;
+use crate::logging::Logging;
 use std::sync::Arc;
 
 pub struct Context {
     pub config: Arc<Config>,
     pub routing: Routing,
     pub database: Arc<Database>,
+    pub http_metrics: HttpMetrics,
+    pub logging: Logging,
 }
@@ -0,0 +1,360 @@
+use crate::collection::methods::clone_collection::CreateCheckpointOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::OwnedGenerationId;
+use crate::database::clone_collection::get_tmp_path;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::trash::{now_ms, remove_dir_if_exists};
+use crate::database::Database;
+use crate::raw_db::{RawDb, RawDbConfig, RawDbError, RawDbOpenError, RawDbOptions};
+use crate::util::base62;
+use crate::util::tokio::spawn_blocking;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// Layout of the backup path:
+//   <backupId>/          — backup, it has the same layout as `data_path`
+//   _shared/<identity>/  — table files of incremental backups, they are hard-linked to backups
+//   _tmp/                — not finished backups
+pub const SHARED_DIR: &str = "_shared";
+const META_DIR: &str = "_meta";
+
+pub struct BackupOptions {
+    pub path: PathBuf,
+    // Table files that are already in the backup path are not copied again
+    pub incremental: bool,
+}
+
+pub struct BackupCollection {
+    pub name: String,
+    pub generation_id: OwnedGenerationId,
+}
+
+pub struct BackupOk {
+    pub backup_id: String,
+    pub collections: Vec<BackupCollection>,
+}
+
+pub struct RestoreBackupOptions<'a> {
+    pub path: &'a Path,
+    pub backup_id: &'a str,
+    pub data_path: &'a Path,
+    pub raw_db_config: &'a RawDbConfig,
+}
+
+#[derive(Debug)]
+pub enum BackupError {
+    NoSuchBackup,
+    DataPathNotEmpty,
+    RawDb(RawDbError),
+    Io(std::io::Error),
+    TaskJoin,
+    CollectionMethod(CollectionMethodError),
+}
+
+impl From<RawDbError> for BackupError {
+    fn from(err: RawDbError) -> Self {
+        BackupError::RawDb(err)
+    }
+}
+
+impl From<std::io::Error> for BackupError {
+    fn from(err: std::io::Error) -> Self {
+        BackupError::Io(err)
+    }
+}
+
+impl From<CollectionMethodError> for BackupError {
+    fn from(err: CollectionMethodError) -> Self {
+        BackupError::CollectionMethod(err)
+    }
+}
+
+// Ids are ordered by the creation time
+fn generate_backup_id() -> String {
+    let mut id = String::with_capacity(22);
+    id.push_str(&base62::from_u64(now_ms()));
+    id.push_str(&base62::from_u64(rand::random()));
+
+    id
+}
+
+impl Database {
+    // Backup of `_meta` and all collections, generations and readers of all collections
+    // are taken at the same moment
+    pub async fn backup(&self, options: BackupOptions) -> Result<BackupOk, BackupError> {
+        let BackupOptions { path, incremental } = options;
+
+        // Collections can't be created, renamed, cloned or restored during the backup
+        let _alter_guard = self.collections_alter_lock.lock().await;
+        // Waits for running commits, reader updates and deletions, new ones will wait for us
+        let _backup_guard = self.inner.backup_lock.write().await;
+
+        let collections_lock = self.collections.read().await;
+        let mut collections: Vec<(String, Arc<Collection>)> = collections_lock
+            .iter()
+            .map(|(name, collection)| (name.clone(), collection.clone()))
+            .collect();
+        drop(collections_lock);
+
+        collections.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let backup_id = generate_backup_id();
+
+        // Leftovers of not finished backups are removed here
+        let tmp_path = get_tmp_path(&path);
+        remove_dir_if_exists(&tmp_path)?;
+
+        let backup_tmp_path = tmp_path.join(&backup_id);
+        std::fs::create_dir_all(&backup_tmp_path)?;
+
+        let result = if incremental {
+            self.create_incremental_backup(&backup_id, &backup_tmp_path, &path, collections)
+                .await
+        } else {
+            self.create_checkpoint_backup(&backup_tmp_path, collections)
+                .await
+        }?;
+
+        std::fs::rename(&backup_tmp_path, path.join(&backup_id))?;
+
+        Ok(BackupOk {
+            backup_id,
+            collections: result,
+        })
+    }
+
+    async fn create_checkpoint_backup(
+        &self,
+        backup_path: &PathBuf,
+        collections: Vec<(String, Arc<Collection>)>,
+    ) -> Result<Vec<BackupCollection>, BackupError> {
+        let mut result = Vec::with_capacity(collections.len());
+
+        let database_raw_db = self.database_raw_db.clone();
+        let meta_backup_path = backup_path.join(META_DIR);
+
+        spawn_blocking(move || {
+            database_raw_db.create_checkpoint_sync(meta_backup_path.to_str().unwrap())
+        })
+        .await
+        .map_err(|_| BackupError::TaskJoin)??;
+
+        for (name, collection) in collections {
+            let generation_id = collection
+                .create_checkpoint(CreateCheckpointOptions {
+                    path: Collection::get_path(backup_path, &name)
+                        .to_str()
+                        .unwrap()
+                        .to_string(),
+                    generation_id: None,
+                })
+                .await?;
+
+            result.push(BackupCollection {
+                name,
+                generation_id,
+            });
+        }
+
+        Ok(result)
+    }
+
+    // `rocksdb::backup::BackupEngine` is not used: RocksDB 7.4.4 reads table properties of
+    // every table file with the comparator from the file, comparators of collections are not
+    // registered in RocksDB, so it gets a null comparator and crashes. Option to name shared
+    // files without reading them (`share_files_with_checksum_naming`) is not in the Rust API
+    async fn create_incremental_backup(
+        &self,
+        backup_id: &str,
+        backup_path: &PathBuf,
+        path: &Path,
+        collections: Vec<(String, Arc<Collection>)>,
+    ) -> Result<Vec<BackupCollection>, BackupError> {
+        let mut result = Vec::with_capacity(collections.len());
+
+        let shared_path = path.join(SHARED_DIR);
+
+        // Checkpoints are created near the data to be hard-linked instead of copied
+        let checkpoint_path = get_tmp_path(&self.data_path).join(backup_id);
+        std::fs::create_dir_all(&checkpoint_path)?;
+
+        let database_raw_db = self.database_raw_db.clone();
+        let meta_checkpoint_path = checkpoint_path.join(META_DIR);
+        let meta_path = self.data_path.join(META_DIR);
+        let meta_backup_path = backup_path.join(META_DIR);
+        let meta_shared_path = shared_path.clone();
+
+        spawn_blocking(move || {
+            database_raw_db.create_checkpoint_sync(meta_checkpoint_path.to_str().unwrap())?;
+
+            share_checkpoint_sync(
+                &meta_path,
+                &meta_checkpoint_path,
+                &meta_backup_path,
+                &meta_shared_path,
+            )
+        })
+        .await
+        .map_err(|_| BackupError::TaskJoin)??;
+
+        for (name, collection) in collections {
+            let collection_checkpoint_path = Collection::get_path(&checkpoint_path, &name);
+
+            let generation_id = collection
+                .create_checkpoint(CreateCheckpointOptions {
+                    path: collection_checkpoint_path.to_str().unwrap().to_string(),
+                    generation_id: None,
+                })
+                .await?;
+
+            let collection_path = Collection::get_path(&self.data_path, &name);
+            let collection_backup_path = Collection::get_path(backup_path, &name);
+            let shared_path = shared_path.clone();
+
+            spawn_blocking(move || {
+                share_checkpoint_sync(
+                    &collection_path,
+                    &collection_checkpoint_path,
+                    &collection_backup_path,
+                    &shared_path,
+                )
+            })
+            .await
+            .map_err(|_| BackupError::TaskJoin)??;
+
+            result.push(BackupCollection {
+                name,
+                generation_id,
+            });
+        }
+
+        remove_dir_if_exists(&checkpoint_path)?;
+
+        Ok(result)
+    }
+}
+
+// Moves checkpoint of the raw_db at `db_path` to the backup, table files are immutable and
+// have unique names in the raw_db, so they are copied to the shared dir only once
+fn share_checkpoint_sync(
+    db_path: &Path,
+    checkpoint_path: &Path,
+    backup_path: &Path,
+    shared_path: &Path,
+) -> Result<(), BackupError> {
+    // Checkpoints have no identity, so restored raw_db will get a new one on open
+    let identity = std::fs::read_to_string(db_path.join("IDENTITY"))?;
+    let shared_path = shared_path.join(identity.trim());
+
+    std::fs::create_dir_all(&shared_path)?;
+    std::fs::create_dir_all(backup_path)?;
+
+    for entry in std::fs::read_dir(checkpoint_path)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let target = backup_path.join(&file_name);
+
+        let is_table_file = file_name
+            .to_str()
+            .map(|name| name.ends_with(".sst"))
+            .unwrap_or(false);
+
+        if !is_table_file {
+            std::fs::copy(entry.path(), target)?;
+            continue;
+        }
+
+        let shared_file = shared_path.join(&file_name);
+
+        if !shared_file.exists() {
+            let mut shared_tmp_file = shared_file.clone().into_os_string();
+            shared_tmp_file.push(".tmp");
+
+            // Shared file is used by next backups, so it should be complete before the rename
+            std::fs::copy(entry.path(), &shared_tmp_file)?;
+            std::fs::File::open(&shared_tmp_file)?.sync_all()?;
+            std::fs::rename(&shared_tmp_file, &shared_file)?;
+        }
+
+        std::fs::hard_link(shared_file, target)?;
+    }
+
+    Ok(())
+}
+
+// Copies backup `backup_id` from the backup path to an empty `data_path`,
+// database should not be opened at this moment
+pub fn restore_database_backup_sync(options: RestoreBackupOptions<'_>) -> Result<(), BackupError> {
+    let RestoreBackupOptions {
+        path,
+        backup_id,
+        data_path,
+        raw_db_config,
+    } = options;
+
+    let is_valid_id = !backup_id.is_empty() && backup_id.chars().all(char::is_alphanumeric);
+
+    let backup_path = path.join(backup_id);
+    if !is_valid_id || !backup_path.is_dir() {
+        return Err(BackupError::NoSuchBackup);
+    }
+
+    if is_non_empty_dir(data_path)? {
+        return Err(BackupError::DataPathNotEmpty);
+    }
+
+    copy_dir_sync(&backup_path, data_path)?;
+
+    remove_trash_records_sync(data_path, raw_db_config)
+}
+
+// Deleted collections are not backed up, so they can't be restored from the trash
+fn remove_trash_records_sync(
+    data_path: &Path,
+    raw_db_config: &RawDbConfig,
+) -> Result<(), BackupError> {
+    let meta_raw_db_path = data_path.join(META_DIR);
+
+    let meta_raw_db = RawDb::open_raw_db(RawDbOptions {
+        path: meta_raw_db_path.to_str().unwrap(),
+        comparator: None,
+        column_families: vec![],
+        config: raw_db_config,
+    })
+    .map_err(|err| match err {
+        RawDbOpenError::RocksDbError(err) => RawDbError::RocksDb(err),
+    })?;
+
+    let records = meta_raw_db.get_range_sync_cf(DATABASE_RAW_DB_CF, b"trash:", b"trash;")?;
+    let keys: Vec<&[u8]> = records.iter().map(|(key, _)| key.as_ref()).collect();
+
+    meta_raw_db.delete_many_cf_sync(DATABASE_RAW_DB_CF, &keys)?;
+
+    Ok(())
+}
+
+fn is_non_empty_dir(path: &Path) -> Result<bool, std::io::Error> {
+    match std::fs::read_dir(path) {
+        Ok(mut entries) => Ok(entries.next().is_some()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+fn copy_dir_sync(from: &Path, to: &Path) -> Result<(), std::io::Error> {
+    std::fs::create_dir_all(to)?;
+
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_dir_sync(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
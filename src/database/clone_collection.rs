@@ -0,0 +1,155 @@
+use crate::collection::methods::clone_collection::{
+    prepare_cloned_collection_sync, CreateCheckpointOptions,
+};
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::open::{CollectionOpenError, CollectionOpenOptions};
+use crate::collection::settings::CollectionSettings;
+use crate::collection::Collection;
+use crate::common::OwnedGenerationId;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::Database;
+use crate::protos::database_meta::CollectionRecord;
+use crate::raw_db::RawDbError;
+use crate::util::base62;
+use crate::util::tokio::spawn_blocking;
+use protobuf::Message;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// Clones are prepared here and moved to the collection path when ready,
+// leftovers of not finished clones are removed on `Database::open`
+pub const TMP_DIR: &str = "_tmp";
+
+pub struct CloneCollectionOptions {
+    // Current generation of the source collection if not specified
+    pub generation_id: Option<OwnedGenerationId>,
+}
+
+#[derive(Debug)]
+pub enum CloneCollectionError {
+    NoSuchCollection,
+    AlreadyExist,
+    RawDb(RawDbError),
+    Protobuf(protobuf::Error),
+    Io(std::io::Error),
+    TaskJoin,
+    CollectionMethod(CollectionMethodError),
+    CollectionOpen(CollectionOpenError),
+}
+
+pub fn get_tmp_path(data_path: &Path) -> PathBuf {
+    data_path.join(TMP_DIR)
+}
+
+pub fn collection_record_key(id: &str) -> String {
+    let mut key = String::with_capacity("collection:".len() + id.len());
+    key.push_str("collection:");
+    key.push_str(id);
+
+    key
+}
+
+impl Database {
+    pub async fn clone_collection(
+        &self,
+        id: &str,
+        new_id: &str,
+        options: CloneCollectionOptions,
+    ) -> Result<Arc<Collection>, CloneCollectionError> {
+        let _guard = self.collections_alter_lock.lock().await;
+
+        let collections = self.collections.read().await;
+        if collections.contains_key(new_id) {
+            return Err(CloneCollectionError::AlreadyExist);
+        }
+        let collection = collections
+            .get(id)
+            .cloned()
+            .ok_or(CloneCollectionError::NoSuchCollection)?;
+        drop(collections);
+
+        let is_manual = collection.is_manual();
+        let if_value_changed = collection.is_if_value_changed();
+
+        let collection_record = self
+            .database_raw_db
+            .get_cf(DATABASE_RAW_DB_CF, collection_record_key(id).as_bytes())
+            .await
+            .map_err(CloneCollectionError::RawDb)?
+            .ok_or(CloneCollectionError::NoSuchCollection)?;
+
+        // Clone has the same settings as the source collection
+        let mut collection_record = CollectionRecord::parse_from_bytes(&collection_record)
+            .map_err(CloneCollectionError::Protobuf)?;
+        collection_record.id = new_id.to_string();
+
+        let settings = CollectionSettings::from_record(&collection_record.settings);
+
+        let collection_record = collection_record
+            .write_to_bytes()
+            .map_err(CloneCollectionError::Protobuf)?;
+
+        let tmp_path = get_tmp_path(&self.data_path);
+        std::fs::create_dir_all(&tmp_path).map_err(CloneCollectionError::Io)?;
+
+        let tmp_path = tmp_path.join(base62::from_u64(rand::random()).as_ref());
+        let tmp_path_str = tmp_path.to_str().unwrap().to_string();
+
+        let generation_id = collection
+            .create_checkpoint(CreateCheckpointOptions {
+                path: tmp_path_str.clone(),
+                generation_id: options.generation_id,
+            })
+            .await
+            .map_err(|err| match err {
+                CollectionMethodError::NoSuchCollection => CloneCollectionError::NoSuchCollection,
+                err => CloneCollectionError::CollectionMethod(err),
+            })?;
+
+        drop(collection);
+
+        let collection_path = Collection::get_path(&self.data_path, new_id);
+        let config = self.config.clone();
+
+        spawn_blocking(move || {
+            prepare_cloned_collection_sync(
+                &tmp_path_str,
+                generation_id.as_ref(),
+                is_manual,
+                &config.raw_db,
+            )
+            .map_err(CloneCollectionError::CollectionMethod)?;
+
+            std::fs::rename(tmp_path, collection_path).map_err(CloneCollectionError::Io)
+        })
+        .await
+        .map_err(|_| CloneCollectionError::TaskJoin)??;
+
+        self.database_raw_db
+            .put_cf(
+                DATABASE_RAW_DB_CF,
+                collection_record_key(new_id).as_bytes(),
+                &collection_record,
+            )
+            .await
+            .map_err(CloneCollectionError::RawDb)?;
+
+        let collection = Collection::open(CollectionOpenOptions {
+            config: self.config.clone(),
+            name: new_id.to_string(),
+            is_manual,
+            if_value_changed,
+            settings,
+            data_path: &self.data_path,
+            database_inner: self.inner.clone(),
+        })
+        .await
+        .map_err(CloneCollectionError::CollectionOpen)?;
+
+        let mut collections = self.collections.write().await;
+        collections.insert(new_id.to_string(), collection.clone());
+        drop(collections);
+
+        Ok(collection)
+    }
+}
@@ -1,4 +1,14 @@
+use crate::raw_db::RawDbConfig;
 use std::num::NonZeroUsize;
+use std::time::Duration;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DeletedCollectionReadersMode {
+    // Readers of other collections that are pointing to the deleted collection are removed
+    Remove,
+    // Such readers are kept, but marked as orphaned and not used anymore for GC
+    Orphan,
+}
 
 pub struct DatabaseConfig {
     pub query_pack_limit: usize,
@@ -31,6 +41,14 @@ pub struct DatabaseConfig {
 
     pub gc_records_limit: NonZeroUsize,
     pub gc_lookups_limit: NonZeroUsize,
+
+    pub deleted_collection_readers: DeletedCollectionReadersMode,
+
+    // Deleted collections are kept in the trash for this time and can be restored
+    pub trash_retention: Duration,
+    pub trash_purge_interval: Duration,
+
+    pub raw_db: RawDbConfig,
 }
 
 impl Default for DatabaseConfig {
@@ -44,6 +62,10 @@ impl Default for DatabaseConfig {
             max_cursors_per_collection: NonZeroUsize::new(100).unwrap(),
             gc_records_limit: NonZeroUsize::new(1000).unwrap(),
             gc_lookups_limit: NonZeroUsize::new(8000).unwrap(),
+            deleted_collection_readers: DeletedCollectionReadersMode::Remove,
+            trash_retention: Duration::from_secs(7 * 24 * 60 * 60),
+            trash_purge_interval: Duration::from_secs(60 * 60),
+            raw_db: RawDbConfig::default(),
         }
     }
 }
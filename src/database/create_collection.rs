@@ -1,14 +1,19 @@
 use crate::collection::open::{CollectionOpenError, CollectionOpenOptions};
+use crate::collection::settings::CollectionSettings;
 use crate::collection::Collection;
 use crate::database::constants::DATABASE_RAW_DB_CF;
 use crate::database::Database;
 use crate::protos::database_meta::CollectionRecord;
 use crate::raw_db::RawDbError;
-use protobuf::Message;
+use protobuf::{Message, MessageField};
 use std::sync::Arc;
 
+#[derive(Default)]
 pub struct CreateCollectionOptions {
     pub is_manual: bool,
+    // Puts will be skipped if value is not changed, can be overridden per update
+    pub if_value_changed: bool,
+    pub settings: CollectionSettings,
 }
 
 #[derive(Debug)]
@@ -63,6 +68,8 @@ impl Database {
         let mut collection_record = CollectionRecord::new();
         collection_record.id = id.to_string();
         collection_record.is_manual = options.is_manual;
+        collection_record.if_value_changed = options.if_value_changed;
+        collection_record.settings = MessageField::some(options.settings.to_record());
 
         let collection_record = collection_record
             .write_to_bytes()
@@ -81,6 +88,8 @@ impl Database {
             config: self.config.clone(),
             name: id.to_string(),
             is_manual: options.is_manual,
+            if_value_changed: options.if_value_changed,
+            settings: options.settings,
             data_path: &self.data_path,
             database_inner: self.inner.clone(),
         })
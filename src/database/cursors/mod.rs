@@ -13,11 +13,5 @@ mod thread;
 pub async fn start_cursors_task_thread(
     config: Arc<DatabaseConfig>,
 ) -> AsyncTaskThread<DatabaseCollectionCursorsTask> {
-    AsyncTaskThread::new(
-        run,
-        config,
-        #[cfg(feature = "debug_prints")]
-        "cursors",
-    )
-    .await
+    AsyncTaskThread::new(run, config, "cursors").await
 }
@@ -1,3 +1,4 @@
+use crate::common::key_range::OwnedKeyRange;
 use crate::common::{GenerationId, OwnedGenerationId, OwnedPhantomId, PhantomId};
 use crate::database::cursors::storage::{CursorPublicId, CursorType};
 use crate::raw_db::query_collection_records::LastAndNextRecordKey;
@@ -6,12 +7,16 @@ pub struct QueryCursor {
     pub public_id: CursorPublicId,
     pub generation_id: OwnedGenerationId,
     pub phantom_id: Option<OwnedPhantomId>,
+    pub key_range: OwnedKeyRange,
+    pub reverse: bool,
     pub last_and_next_record_key: Option<LastAndNextRecordKey>,
 }
 
 pub struct AddQueryCursorData {
     pub generation_id: OwnedGenerationId,
     pub phantom_id: Option<OwnedPhantomId>,
+    pub key_range: OwnedKeyRange,
+    pub reverse: bool,
     pub last_and_next_record_key: Option<LastAndNextRecordKey>,
 }
 
@@ -48,6 +53,8 @@ impl CursorType for QueryCursorType {
             public_id,
             generation_id: data.generation_id,
             phantom_id: data.phantom_id,
+            key_range: data.key_range,
+            reverse: data.reverse,
             last_and_next_record_key: data.last_and_next_record_key,
         }
     }
@@ -64,6 +71,8 @@ impl CursorType for QueryCursorType {
             public_id,
             generation_id,
             phantom_id,
+            key_range,
+            reverse,
             last_and_next_record_key: _,
         } = data;
 
@@ -71,6 +80,8 @@ impl CursorType for QueryCursorType {
             public_id: public_id.clone(),
             generation_id: generation_id.clone(),
             phantom_id: phantom_id.clone(),
+            key_range: key_range.clone(),
+            reverse: *reverse,
             last_and_next_record_key,
         }
     }
@@ -88,6 +99,8 @@ impl CursorType for QueryCursorType {
             public_id: _,
             generation_id,
             phantom_id,
+            key_range,
+            reverse,
             last_and_next_record_key: _,
         } = data;
 
@@ -95,6 +108,8 @@ impl CursorType for QueryCursorType {
             public_id,
             generation_id: generation_id.clone(),
             phantom_id: phantom_id.clone(),
+            key_range: key_range.clone(),
+            reverse: *reverse,
             last_and_next_record_key,
         }
     }
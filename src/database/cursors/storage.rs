@@ -360,7 +360,6 @@ impl<T: CursorType> InnerCursors<T> {
         Ok(())
     }
 
-    #[cfg(test)]
     pub fn query_cursors_count(&self) -> usize {
         self.public_ids.len()
     }
@@ -3,12 +3,11 @@ use crate::database::cursors::collection::InnerCursorsCollection;
 use crate::database::cursors::storage::{CursorError, CursorType, InnerCursors};
 use std::sync::Arc;
 
-#[cfg(test)]
-use crate::messages::cursors::GetCollectionCursorsCountTask;
 use crate::messages::cursors::{
     AbortCursorTask, AddCursorContinuationTask, AddCursorTask, DatabaseCollectionCursorsTask,
     DatabaseCollectionSpecificCursorsTask, DropCollectionCursorsTask, FinishCursorTask,
-    FullyFinishCursorTask, GetCursorByPublicIdTask, NewCollectionCursorsTask,
+    FullyFinishCursorTask, GetCollectionCursorsCountTask, GetCursorByPublicIdTask,
+    NewCollectionCursorsTask,
 };
 use crate::util::async_task_thread::TaskPoller;
 use crate::util::indexed_container::IndexedContainer;
@@ -27,7 +26,9 @@ pub async fn run(
         collections: IndexedContainer::new(),
     };
 
-    while let Some(task) = poller.poll().await {
+    while let Some((task, span)) = poller.poll().await {
+        let _entered = span.enter();
+
         match task {
             DatabaseCollectionCursorsTask::NewCollection(task) => state.new_collection(task),
             DatabaseCollectionCursorsTask::DropCollection(task) => state.drop_collection(task),
@@ -69,7 +70,6 @@ impl CursorsThreadState {
             DatabaseCollectionSpecificCursorsTask::AbortQueryCursor(task) => {
                 self.abort_query_cursor(get_cursors, task)
             }
-            #[cfg(test)]
             DatabaseCollectionSpecificCursorsTask::GetCollectionQueryCursorsCount(task) => {
                 self.collection_query_cursors_count(get_cursors, task)
             }
@@ -252,7 +252,6 @@ impl CursorsThreadState {
         sender.send(result).unwrap_or(());
     }
 
-    #[cfg(test)]
     fn collection_query_cursors_count<
         T: CursorType,
         F: Fn(&mut InnerCursorsCollection) -> &mut InnerCursors<T>,
@@ -1,4 +1,5 @@
-use crate::collection::{Collection, GetReaderGenerationIdError};
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::{Collection, CommitGenerationUpdateReader, GetReaderGenerationIdError};
 use crate::common::OwnedGenerationId;
 use crate::raw_db::{RawDb, RawDbError};
 use std::collections::{HashMap, HashSet};
@@ -7,16 +8,28 @@ use crate::collection::methods::errors::CollectionMethodError;
 use crate::database::constants::DATABASE_RAW_DB_CF;
 use crate::messages::cursors::DatabaseCollectionCursorsTask;
 use crate::messages::generations::DatabaseCollectionGenerationsTask;
-use crate::messages::readers::{DatabaseCollectionReadersTask, GetReadersPointingToCollectionTask};
+use crate::messages::readers::{
+    DatabaseCollectionReadersTask, DropCollectionReadersTask, GetReadersPointingToCollectionTask,
+    RenameCollectionReadersTask,
+};
 use crate::util::async_task_thread::AsyncTaskThread;
 
-use crate::database::config::DatabaseConfig;
+use crate::database::config::{DatabaseConfig, DeletedCollectionReadersMode};
+use crate::database::trash::{
+    generate_trash_id, get_trash_path, now_ms, parse_trash_records, remove_dir_if_exists,
+    take_collection_record, trash_record_key, TrashError, TRASH_DIR,
+};
 use crate::messages::garbage_collector::DatabaseGarbageCollectorTask;
+use crate::protos::database_meta::{CollectionRecord, TransactionRecord, TrashRecord};
+use crate::util::tokio::spawn_blocking;
+use protobuf::Message;
+use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::{oneshot, watch, RwLock};
+use tokio::sync::{oneshot, watch, Mutex, RwLock};
 
 pub struct DatabaseInner {
     pub config: Arc<DatabaseConfig>,
+    data_path: PathBuf,
     collections_for_deletion: Arc<RwLock<HashSet<String>>>,
     database_raw_db: Arc<RawDb>,
     collections: Arc<RwLock<HashMap<String, Arc<Collection>>>>,
@@ -25,6 +38,24 @@ pub struct DatabaseInner {
     generations: AsyncTaskThread<DatabaseCollectionGenerationsTask>,
     garbage_collector: AsyncTaskThread<DatabaseGarbageCollectorTask>,
     stop_receiver: watch::Receiver<bool>,
+    // Restore and purge of trash entries should not run concurrently
+    pub trash_lock: Mutex<()>,
+    // Commits, reader updates and deletions of collections are holding it for reading,
+    // backup takes it for writing to see the same state of all collections
+    pub backup_lock: RwLock<()>,
+}
+
+pub struct DatabaseInnerNewOptions {
+    pub config: Arc<DatabaseConfig>,
+    pub data_path: PathBuf,
+    pub collections_for_deletion: Arc<RwLock<HashSet<String>>>,
+    pub database_raw_db: Arc<RawDb>,
+    pub collections: Arc<RwLock<HashMap<String, Arc<Collection>>>>,
+    pub readers: AsyncTaskThread<DatabaseCollectionReadersTask>,
+    pub cursors: AsyncTaskThread<DatabaseCollectionCursorsTask>,
+    pub generations: AsyncTaskThread<DatabaseCollectionGenerationsTask>,
+    pub garbage_collector: AsyncTaskThread<DatabaseGarbageCollectorTask>,
+    pub stop_receiver: watch::Receiver<bool>,
 }
 
 pub enum GetReaderGenerationIdFnError {
@@ -34,19 +65,23 @@ pub enum GetReaderGenerationIdFnError {
 }
 
 impl DatabaseInner {
-    pub fn new(
-        config: Arc<DatabaseConfig>,
-        collections_for_deletion: Arc<RwLock<HashSet<String>>>,
-        database_raw_db: Arc<RawDb>,
-        collections: Arc<RwLock<HashMap<String, Arc<Collection>>>>,
-        readers: AsyncTaskThread<DatabaseCollectionReadersTask>,
-        cursors: AsyncTaskThread<DatabaseCollectionCursorsTask>,
-        generations: AsyncTaskThread<DatabaseCollectionGenerationsTask>,
-        garbage_collector: AsyncTaskThread<DatabaseGarbageCollectorTask>,
-        stop_receiver: watch::Receiver<bool>,
-    ) -> Self {
+    pub fn new(options: DatabaseInnerNewOptions) -> Self {
+        let DatabaseInnerNewOptions {
+            config,
+            data_path,
+            collections_for_deletion,
+            database_raw_db,
+            collections,
+            readers,
+            cursors,
+            generations,
+            garbage_collector,
+            stop_receiver,
+        } = options;
+
         Self {
             config,
+            data_path,
             collections_for_deletion,
             database_raw_db,
             collections,
@@ -55,6 +90,8 @@ impl DatabaseInner {
             generations,
             garbage_collector,
             stop_receiver,
+            trash_lock: Mutex::new(()),
+            backup_lock: RwLock::new(()),
         }
     }
 
@@ -83,52 +120,58 @@ impl DatabaseInner {
             })
     }
 
-    fn mark_collection_for_deletion_sync(&self, collection_name: &str) -> Result<(), RawDbError> {
+    fn mark_collection_for_deletion_sync(
+        &self,
+        collection_name: &str,
+        trash_id: &str,
+    ) -> Result<(), RawDbError> {
         let mut key = String::with_capacity("deleteCollection:".len() + collection_name.len());
         key.push_str("deleteCollection:");
         key.push_str(collection_name);
 
-        self.database_raw_db
-            .put_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes(), b"")?;
+        self.database_raw_db.put_cf_sync(
+            DATABASE_RAW_DB_CF,
+            key.as_bytes(),
+            trash_id.as_bytes(),
+        )?;
 
         Ok(())
     }
 
-    fn unmark_collection_for_deletion_sync(&self, collection_name: &str) -> Result<(), RawDbError> {
-        let mut key = String::with_capacity("deleteCollection:".len() + collection_name.len());
-        key.push_str("deleteCollection:");
-        key.push_str(collection_name);
-
-        self.database_raw_db
-            .delete_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes())?;
-
-        Ok(())
-    }
-
-    pub fn is_marked_for_deletion_sync(&self, collection_name: &str) -> Result<bool, RawDbError> {
+    // Returns trash id of the collection if it is marked for deletion,
+    // it can be empty if collection was marked by an older version
+    pub fn get_deletion_mark_sync(
+        &self,
+        collection_name: &str,
+    ) -> Result<Option<String>, RawDbError> {
         let mut key = String::with_capacity("deleteCollection:".len() + collection_name.len());
         key.push_str("deleteCollection:");
         key.push_str(collection_name);
 
-        let is_marked = self
+        let mark = self
             .database_raw_db
-            .get_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes())?
-            .is_some();
+            .get_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes())?;
 
-        Ok(is_marked)
+        Ok(mark.map(|value| String::from_utf8_lossy(&value).into_owned()))
     }
 
-    pub async fn start_delete_collection(&self, collection_name: &str) -> Result<(), RawDbError> {
-        // Now we need remove this collection from `Database.collections` and remove its raw_db,
-        // cleanup collection data from meta_raw_db of `Database`
+    // Returns id of the trash entry where collection files should be moved
+    pub async fn start_delete_collection(
+        &self,
+        collection_name: &str,
+    ) -> Result<String, RawDbError> {
+        // Now we need remove this collection from `Database.collections` and move its raw_db
+        // to the trash, cleanup collection data from meta_raw_db of `Database`
         // Order here matters, we need expect that process can crash in any moment,
         // after restart it should work and collection should be in one of states:
         //   - didn't deleted
-        //   - deleted
-        //   - marked as deleted (and then deleted on `Database::open`)
+        //   - deleted (and placed in the trash)
+        //   - marked as deleted (and then moved to the trash on `Database::open`)
+
+        let trash_id = generate_trash_id();
 
         // Mark for deletion, this will delete this collection on database open
-        self.mark_collection_for_deletion_sync(collection_name)?;
+        self.mark_collection_for_deletion_sync(collection_name, &trash_id)?;
 
         // Block creation of this collection
         let mut collections_for_deletion = self.collections_for_deletion.write().await;
@@ -140,16 +183,190 @@ impl DatabaseInner {
         collections_lock.remove(collection_name);
         drop(collections_lock);
 
+        Ok(trash_id)
+    }
+
+    // Collection raw_db should be closed at this moment
+    pub fn move_collection_to_trash_sync(
+        &self,
+        collection_name: &str,
+        trash_id: &str,
+    ) -> Result<(), std::io::Error> {
+        std::fs::create_dir_all(self.data_path.join(TRASH_DIR))?;
+
+        let from = Collection::get_path(&self.data_path, collection_name);
+        let to = get_trash_path(&self.data_path, trash_id);
+
+        match std::fs::rename(from, to) {
+            Ok(()) => Ok(()),
+            // Already moved before the crash
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    // `trash_id` is `None` if collection files were destroyed instead of moving to the trash
+    pub fn finish_delete_collection_sync(
+        &self,
+        collection_name: &str,
+        trash_id: Option<&str>,
+    ) -> Result<(), TrashError> {
+        let mut collection_key = String::with_capacity("collection:".len() + collection_name.len());
+        collection_key.push_str("collection:");
+        collection_key.push_str(collection_name);
+
+        let mut deletion_key =
+            String::with_capacity("deleteCollection:".len() + collection_name.len());
+        deletion_key.push_str("deleteCollection:");
+        deletion_key.push_str(collection_name);
+
+        let deletes = [collection_key.as_bytes(), deletion_key.as_bytes()];
+
+        let Some(trash_id) = trash_id else {
+            // Collection record and deletion mark are removed together, if process will crash
+            // before it, deletion will be finished on the next `Database::open`
+            self.database_raw_db
+                .delete_many_cf_sync(DATABASE_RAW_DB_CF, &deletes)?;
+
+            return Ok(());
+        };
+
+        let collection_record = self
+            .database_raw_db
+            .get_cf_sync(DATABASE_RAW_DB_CF, collection_key.as_bytes())?;
+
+        let collection_record = match collection_record {
+            Some(bytes) => CollectionRecord::parse_from_bytes(&bytes)?,
+            None => {
+                let mut record = CollectionRecord::new();
+                record.id = collection_name.to_string();
+                record
+            }
+        };
+
+        let mut trash_record = TrashRecord::new();
+        trash_record.collection = Some(collection_record).into();
+        trash_record.deleted_at = now_ms();
+
+        let trash_record = trash_record.write_to_bytes()?;
+        let trash_key = trash_record_key(trash_id);
+
+        // Trash record is written together with removal of the collection record
+        self.database_raw_db.put_and_delete_many_cf_sync(
+            DATABASE_RAW_DB_CF,
+            &[(trash_key.as_bytes(), &trash_record)],
+            &deletes,
+        )?;
+
         Ok(())
     }
 
-    pub fn finish_delete_collection_sync(&self, collection_name: &str) -> Result<(), RawDbError> {
-        self.unmark_collection_for_deletion_sync(collection_name)?;
+    pub fn finish_restore_collection_sync(
+        &self,
+        trash_id: &str,
+        collection_record: &CollectionRecord,
+    ) -> Result<(), TrashError> {
+        let mut collection_key =
+            String::with_capacity("collection:".len() + collection_record.id.len());
+        collection_key.push_str("collection:");
+        collection_key.push_str(&collection_record.id);
+
+        let trash_key = trash_record_key(trash_id);
+        let collection_record = collection_record.write_to_bytes()?;
+
+        self.database_raw_db.put_and_delete_many_cf_sync(
+            DATABASE_RAW_DB_CF,
+            &[(collection_key.as_bytes(), &collection_record)],
+            &[trash_key.as_bytes()],
+        )?;
 
         Ok(())
     }
 
-    pub async fn remove_readers_pointing_to_collection(
+    // If process was crashed in the middle of restoration, collection files are already
+    // moved from the trash, but records are not updated, finish such restorations
+    pub fn recover_interrupted_restores_sync(&self) -> Result<(), TrashError> {
+        let records =
+            self.database_raw_db
+                .get_range_sync_cf(DATABASE_RAW_DB_CF, b"trash:", b"trash;")?;
+        let records = parse_trash_records(records)?;
+
+        for (trash_id, record) in records {
+            if get_trash_path(&self.data_path, &trash_id).exists() {
+                continue;
+            }
+
+            let collection_record = take_collection_record(&trash_id, record)?;
+            let collection_name = collection_record.id.as_str();
+
+            if !Collection::get_path(&self.data_path, collection_name).exists() {
+                continue;
+            }
+
+            let mut collection_key =
+                String::with_capacity("collection:".len() + collection_name.len());
+            collection_key.push_str("collection:");
+            collection_key.push_str(collection_name);
+
+            let is_exists = self
+                .database_raw_db
+                .get_cf_sync(DATABASE_RAW_DB_CF, collection_key.as_bytes())?
+                .is_some();
+
+            if is_exists {
+                continue;
+            }
+
+            self.finish_restore_collection_sync(&trash_id, &collection_record)?;
+        }
+
+        Ok(())
+    }
+
+    // Destroys trash entries that are older than `config.trash_retention`
+    pub async fn purge_trash(&self) -> Result<(), TrashError> {
+        let _trash_guard = self.trash_lock.lock().await;
+
+        let records = self
+            .database_raw_db
+            .get_range_cf(DATABASE_RAW_DB_CF, b"trash:", b"trash;")
+            .await?;
+        let records = parse_trash_records(records)?;
+
+        let retention = self.config.trash_retention.as_millis() as u64;
+        let now = now_ms();
+
+        for (trash_id, record) in records {
+            if record.deleted_at.saturating_add(retention) > now {
+                continue;
+            }
+
+            let path = get_trash_path(&self.data_path, &trash_id);
+            let database_raw_db = self.database_raw_db.clone();
+
+            spawn_blocking(move || {
+                // Files are removed first, if process will crash, record will be purged again
+                if path.exists() {
+                    RawDb::destroy(path.to_str().unwrap())?;
+                }
+
+                remove_dir_if_exists(&path)?;
+
+                database_raw_db
+                    .delete_cf_sync(DATABASE_RAW_DB_CF, trash_record_key(&trash_id).as_bytes())?;
+
+                Ok::<(), TrashError>(())
+            })
+            .await
+            .map_err(|err| TrashError::RawDb(RawDbError::Join(err)))??;
+        }
+
+        Ok(())
+    }
+
+    // Removes or orphans (depending on the config) readers of other collections
+    // that are pointing to this one, and stops tracking of readers of this collection
+    pub async fn detach_readers_of_deleted_collection(
         &self,
         collection_name: Arc<str>,
     ) -> Result<(), CollectionMethodError> {
@@ -158,7 +375,7 @@ impl DatabaseInner {
         self.add_readers_task(
             DatabaseCollectionReadersTask::GetReadersPointingToCollectionExceptThisOne(
                 GetReadersPointingToCollectionTask {
-                    collection_name,
+                    collection_name: collection_name.clone(),
                     sender,
                 },
             ),
@@ -192,10 +409,279 @@ impl DatabaseInner {
             };
 
             for reader_name in reader_names {
-                collection.inner_remove_reader(reader_name).await?;
+                match self.config.deleted_collection_readers {
+                    DeletedCollectionReadersMode::Remove => {
+                        collection.inner_remove_reader(reader_name).await?;
+                    }
+                    DeletedCollectionReadersMode::Orphan => {
+                        collection.inner_orphan_reader(reader_name).await?;
+                    }
+                }
             }
         }
 
+        let (sender, receiver) = oneshot::channel();
+
+        self.add_readers_task(DatabaseCollectionReadersTask::DropCollection(
+            DropCollectionReadersTask {
+                collection_name,
+                sender,
+            },
+        ))
+        .await;
+
+        receiver.await.map_err(CollectionMethodError::OneshotRecv)?;
+
+        Ok(())
+    }
+
+    pub async fn start_rename_collection(
+        &self,
+        collection_name: &str,
+        new_collection_name: &str,
+    ) -> Result<(), RawDbError> {
+        // Collection can be in one of states after the crash:
+        //   - didn't renamed
+        //   - renamed
+        //   - marked as renamed (and then renaming is finished on `Database::open`)
+        let key = rename_mark_key(collection_name);
+
+        self.database_raw_db.put_cf_sync(
+            DATABASE_RAW_DB_CF,
+            key.as_bytes(),
+            new_collection_name.as_bytes(),
+        )?;
+
+        let mut collections_lock = self.collections.write().await;
+        collections_lock.remove(collection_name);
+        drop(collections_lock);
+
+        Ok(())
+    }
+
+    // (collection_name, new_collection_name) of collections that are marked as renamed
+    pub fn get_rename_marks_sync(&self) -> Result<Vec<(String, String)>, RawDbError> {
+        let marks = self.database_raw_db.get_range_sync_cf(
+            DATABASE_RAW_DB_CF,
+            b"renameCollection:",
+            b"renameCollection;",
+        )?;
+
+        let marks = marks
+            .into_iter()
+            .map(|(key, value)| {
+                let collection_name =
+                    String::from_utf8_lossy(&key["renameCollection:".len()..]).into_owned();
+                let new_collection_name = String::from_utf8_lossy(&value).into_owned();
+
+                (collection_name, new_collection_name)
+            })
+            .collect();
+
+        Ok(marks)
+    }
+
+    // Collection raw_db should be closed at this moment, can be called multiple times
+    pub fn move_renamed_collection_sync(
+        &self,
+        collection_name: &str,
+        new_collection_name: &str,
+    ) -> Result<(), CollectionMethodError> {
+        let from = Collection::get_path(&self.data_path, collection_name);
+        let to = Collection::get_path(&self.data_path, new_collection_name);
+
+        match std::fs::rename(from, to) {
+            Ok(()) => {}
+            // Already moved before the crash
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(CollectionMethodError::Io(err));
+            }
+        }
+
+        let mut collection_key = String::with_capacity("collection:".len() + collection_name.len());
+        collection_key.push_str("collection:");
+        collection_key.push_str(collection_name);
+
+        let collection_record = self
+            .database_raw_db
+            .get_cf_sync(DATABASE_RAW_DB_CF, collection_key.as_bytes())?;
+
+        let Some(collection_record) = collection_record else {
+            // Records are already updated
+            return Ok(());
+        };
+
+        let mut collection_record = CollectionRecord::parse_from_bytes(&collection_record)?;
+        collection_record.id = new_collection_name.to_string();
+        let collection_record = collection_record.write_to_bytes()?;
+
+        let mut new_collection_key =
+            String::with_capacity("collection:".len() + new_collection_name.len());
+        new_collection_key.push_str("collection:");
+        new_collection_key.push_str(new_collection_name);
+
+        self.database_raw_db.put_and_delete_many_cf_sync(
+            DATABASE_RAW_DB_CF,
+            &[(new_collection_key.as_bytes(), &collection_record)],
+            &[collection_key.as_bytes()],
+        )?;
+
+        Ok(())
+    }
+
+    // Readers of other collections that are pointing to the renamed collection are updated
+    // to point to the new name, then readers thread moves all its readers to the new name
+    pub async fn retarget_readers_of_renamed_collection(
+        &self,
+        collection_name: Arc<str>,
+        new_collection_name: Arc<str>,
+    ) -> Result<(), CollectionMethodError> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.add_readers_task(
+            DatabaseCollectionReadersTask::GetReadersPointingToCollectionExceptThisOne(
+                GetReadersPointingToCollectionTask {
+                    collection_name: collection_name.clone(),
+                    sender,
+                },
+            ),
+        )
+        .await;
+
+        let readers = receiver.await.map_err(CollectionMethodError::OneshotRecv)?;
+
+        for reader in readers {
+            let collection = {
+                let collections = self.collections.read().await;
+                collections
+                    .get(reader.owner_collection_name.as_ref())
+                    .cloned()
+            };
+
+            let Some(collection) = collection else {
+                continue;
+            };
+
+            collection
+                .inner_retarget_reader(reader.reader_name, new_collection_name.clone())
+                .await?;
+        }
+
+        let (sender, receiver) = oneshot::channel();
+
+        self.add_readers_task(DatabaseCollectionReadersTask::RenameCollection(
+            RenameCollectionReadersTask {
+                collection_name,
+                new_collection_name,
+                sender,
+            },
+        ))
+        .await;
+
+        receiver.await.map_err(CollectionMethodError::OneshotRecv)?;
+
+        Ok(())
+    }
+
+    pub fn finish_rename_collection_sync(&self, collection_name: &str) -> Result<(), RawDbError> {
+        let key = rename_mark_key(collection_name);
+
+        self.database_raw_db
+            .delete_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub async fn put_transaction_record(
+        &self,
+        transaction_id: &str,
+        record: Vec<u8>,
+    ) -> Result<(), RawDbError> {
+        let key = transaction_record_key(transaction_id);
+
+        self.database_raw_db
+            .put_cf(DATABASE_RAW_DB_CF, key.as_bytes(), &record)
+            .await
+    }
+
+    pub async fn finish_transaction(&self, transaction_id: &str) -> Result<(), RawDbError> {
+        let key = transaction_record_key(transaction_id);
+        let database_raw_db = self.database_raw_db.clone();
+
+        spawn_blocking(move || database_raw_db.delete_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes()))
+            .await?
+    }
+
+    // Commits generations of transactions that were interrupted by a crash,
+    // should be called when all collections are opened
+    pub async fn finish_interrupted_transactions(&self) -> Result<(), CollectionMethodError> {
+        let records = self
+            .database_raw_db
+            .get_range_cf(DATABASE_RAW_DB_CF, b"transaction:", b"transaction;")
+            .await?;
+
+        for (key, value) in records {
+            let record = TransactionRecord::parse_from_bytes(&value)?;
+
+            for item in record.collections {
+                let collection = {
+                    let collections = self.collections.read().await;
+                    collections.get(&item.collection_name).cloned()
+                };
+
+                // Collection was deleted after the crash
+                let Some(collection) = collection else {
+                    continue;
+                };
+
+                let generation_id =
+                    OwnedGenerationId::from_boxed_slice(item.generation_id.into_boxed_slice())
+                        .or(Err(CollectionMethodError::InvalidGenerationId))?;
+
+                let is_committed = collection.generation_pair().next_generation_id.as_ref()
+                    != Some(&generation_id);
+
+                if is_committed {
+                    continue;
+                }
+
+                let update_readers = if item.update_readers.is_empty() {
+                    None
+                } else {
+                    Some(
+                        item.update_readers
+                            .into_iter()
+                            .map(|update| {
+                                let generation_id = OwnedGenerationId::from_boxed_slice(
+                                    update.generation_id.into_boxed_slice(),
+                                )
+                                .or(Err(CollectionMethodError::InvalidGenerationId))?;
+
+                                Ok(CommitGenerationUpdateReader {
+                                    reader_name: Arc::from(update.reader_name),
+                                    generation_id,
+                                })
+                            })
+                            .collect::<Result<_, CollectionMethodError>>()?,
+                    )
+                };
+
+                collection
+                    .commit_generation(CommitGenerationOptions {
+                        generation_id,
+                        update_readers,
+                    })
+                    .await?;
+            }
+
+            let database_raw_db = self.database_raw_db.clone();
+
+            spawn_blocking(move || database_raw_db.delete_cf_sync(DATABASE_RAW_DB_CF, &key))
+                .await
+                .map_err(|_| CollectionMethodError::TaskJoin)??;
+        }
+
         Ok(())
     }
 
@@ -226,3 +712,19 @@ impl DatabaseInner {
         self.stop_receiver.clone()
     }
 }
+
+fn rename_mark_key(collection_name: &str) -> String {
+    let mut key = String::with_capacity("renameCollection:".len() + collection_name.len());
+    key.push_str("renameCollection:");
+    key.push_str(collection_name);
+
+    key
+}
+
+fn transaction_record_key(transaction_id: &str) -> String {
+    let mut key = String::with_capacity("transaction:".len() + transaction_id.len());
+    key.push_str("transaction:");
+    key.push_str(transaction_id);
+
+    key
+}
@@ -0,0 +1,321 @@
+use crate::collection::constants::COLLECTION_CF_META;
+use crate::collection::methods::clone_collection::CreateCheckpointOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::open::open_collection_raw_db;
+use crate::collection::util::generation_key::OwnedGenerationKey;
+use crate::collection::util::reader_value::ReaderValue;
+use crate::common::key_range::OwnedKeyRange;
+use crate::common::{IsByteArray, OwnedGenerationId};
+use crate::database::clone_collection::get_tmp_path;
+use crate::database::dump::{
+    encode_bytes, DumpEnd, DumpError, DumpGeneration, DumpHeader, DumpLine, DumpReader, DumpRecord,
+    DumpSettings, DUMP_FORMAT, DUMP_VERSION,
+};
+use crate::database::trash::remove_dir_if_exists;
+use crate::database::Database;
+use crate::raw_db::get_generation_records::{GetGenerationRecordsOk, GetGenerationRecordsOptions};
+use crate::raw_db::query_collection_records::{
+    LastAndNextRecordKey, QueryCollectionRecordsOptions, QueryCollectionRecordsResult,
+};
+use crate::raw_db::{RawDb, RawDbError, RawDbOpenError};
+use crate::util::base62;
+use crate::util::tokio::spawn_blocking;
+use std::path::PathBuf;
+use std::str::from_utf8;
+use std::sync::Arc;
+
+pub struct ExportCollectionOptions {
+    // Current generation if not specified
+    pub generation_id: Option<OwnedGenerationId>,
+    // All generations that are not collected by GC yet, not only the values at `generation_id`
+    pub history: bool,
+}
+
+// Lines are read by packs from a checkpoint of the collection, so the collection
+// can be changed and even deleted while the dump is written
+pub struct CollectionExport {
+    raw_db: Option<Arc<RawDb>>,
+    path: PathBuf,
+    generation_id: OwnedGenerationId,
+    pack_limit: usize,
+    records_to_view_limit: usize,
+    records: u64,
+    state: ExportState,
+}
+
+enum ExportState {
+    Header(DumpHeader),
+    Records(Option<LastAndNextRecordKey>),
+    History(Option<OwnedGenerationKey>, Option<OwnedGenerationId>),
+    Readers,
+    Finished,
+}
+
+impl Database {
+    pub async fn export_collection(
+        &self,
+        name: &str,
+        options: ExportCollectionOptions,
+    ) -> Result<CollectionExport, DumpError> {
+        let ExportCollectionOptions {
+            generation_id,
+            history,
+        } = options;
+
+        let collection = self
+            .get_collection(name)
+            .await
+            .ok_or(DumpError::NoSuchCollection)?;
+
+        let tmp_path = get_tmp_path(&self.data_path);
+        std::fs::create_dir_all(&tmp_path)?;
+
+        let path = tmp_path.join(base62::from_u64(rand::random()).as_ref());
+
+        let generation_id = collection
+            .create_checkpoint(CreateCheckpointOptions {
+                path: path.to_str().unwrap().to_string(),
+                generation_id,
+            })
+            .await
+            .map_err(|err| match err {
+                CollectionMethodError::NoSuchCollection => DumpError::NoSuchCollection,
+                err => DumpError::CollectionMethod(err),
+            })?;
+
+        let header = DumpHeader {
+            format: DUMP_FORMAT.to_string(),
+            version: DUMP_VERSION,
+            collection_name: name.to_string(),
+            is_manual: collection.is_manual(),
+            if_value_changed: collection.is_if_value_changed(),
+            settings: DumpSettings::from_settings(collection.get_settings().await),
+            generation_id: encode_bytes(generation_id.get_byte_array()),
+            history,
+        };
+
+        drop(collection);
+
+        let raw_db = match open_collection_raw_db(path.to_str().unwrap(), &self.config.raw_db) {
+            Ok(raw_db) => raw_db,
+            Err(RawDbOpenError::RocksDbError(err)) => {
+                remove_dir_if_exists(&path)?;
+                return Err(DumpError::RawDb(RawDbError::RocksDb(err)));
+            }
+        };
+
+        Ok(CollectionExport {
+            raw_db: Some(Arc::new(raw_db)),
+            path,
+            generation_id,
+            pack_limit: self.config.query_pack_limit,
+            records_to_view_limit: self.config.query_pack_records_limit,
+            records: 0,
+            state: ExportState::Header(header),
+        })
+    }
+}
+
+impl CollectionExport {
+    pub fn generation_id(&self) -> &OwnedGenerationId {
+        &self.generation_id
+    }
+
+    // Next lines of the dump, `None` after the `end` line
+    pub async fn next_pack(&mut self) -> Result<Option<Vec<DumpLine>>, DumpError> {
+        let state = std::mem::replace(&mut self.state, ExportState::Finished);
+
+        let lines = match state {
+            ExportState::Header(header) => {
+                if header.history {
+                    self.state = ExportState::History(None, None);
+
+                    return Ok(Some(vec![DumpLine::Header(header)]));
+                }
+
+                self.state = ExportState::Records(None);
+
+                let generation = DumpGeneration {
+                    generation_id: header.generation_id.clone(),
+                };
+
+                vec![DumpLine::Header(header), DumpLine::Generation(generation)]
+            }
+            ExportState::Records(last_and_next_record_key) => {
+                self.read_records(last_and_next_record_key).await?
+            }
+            ExportState::History(last_generation_key, last_generation_id) => {
+                self.read_history(last_generation_key, last_generation_id)
+                    .await?
+            }
+            ExportState::Readers => {
+                let mut lines = self.read_readers().await?;
+
+                lines.push(DumpLine::End(DumpEnd {
+                    records: self.records,
+                }));
+
+                lines
+            }
+            ExportState::Finished => {
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(lines))
+    }
+
+    async fn read_records(
+        &mut self,
+        last_and_next_record_key: Option<LastAndNextRecordKey>,
+    ) -> Result<Vec<DumpLine>, DumpError> {
+        let raw_db = self.raw_db.clone().unwrap();
+        let generation_id = self.generation_id.clone();
+        let limit = self.pack_limit;
+        let records_to_view_limit = self.records_to_view_limit;
+
+        let result = spawn_blocking(move || {
+            let (last_record_key, from_record_key) = match &last_and_next_record_key {
+                Some(last_and_next) => (
+                    Some(last_and_next.last.as_ref()),
+                    Some(last_and_next.next.as_ref()),
+                ),
+                None => (None, None),
+            };
+
+            raw_db.query_collection_records_sync(QueryCollectionRecordsOptions {
+                generation_id: generation_id.as_ref(),
+                phantom_id: None,
+                key_range: OwnedKeyRange::full().as_ref(),
+                reverse: false,
+                from_record_key,
+                last_record_key,
+                limit,
+                records_to_view_limit,
+            })
+        })
+        .await
+        .map_err(|_| DumpError::TaskJoin)??;
+
+        let QueryCollectionRecordsResult {
+            items,
+            last_and_next_record_key,
+        } = result;
+
+        self.records += items.len() as u64;
+        self.state = match last_and_next_record_key {
+            Some(last_and_next_record_key) => ExportState::Records(Some(last_and_next_record_key)),
+            None => ExportState::Readers,
+        };
+
+        let lines = items
+            .into_iter()
+            .map(|item| {
+                DumpLine::Record(DumpRecord {
+                    key: encode_bytes(item.key.get_byte_array()),
+                    value: Some(encode_bytes(item.value.get_value())),
+                })
+            })
+            .collect();
+
+        Ok(lines)
+    }
+
+    async fn read_history(
+        &mut self,
+        last_generation_key: Option<OwnedGenerationKey>,
+        mut last_generation_id: Option<OwnedGenerationId>,
+    ) -> Result<Vec<DumpLine>, DumpError> {
+        let raw_db = self.raw_db.clone().unwrap();
+        let generation_id = self.generation_id.clone();
+        let limit = self.pack_limit;
+
+        let result = spawn_blocking(move || {
+            raw_db.get_generation_records_sync(GetGenerationRecordsOptions {
+                after: last_generation_key.as_ref().map(|key| key.as_ref()),
+                to_generation_id: generation_id.as_ref(),
+                limit,
+            })
+        })
+        .await
+        .map_err(|_| DumpError::TaskJoin)??;
+
+        let GetGenerationRecordsOk {
+            items,
+            last_generation_key,
+        } = result;
+
+        let mut lines = Vec::with_capacity(items.len() + 1);
+
+        self.records += items.len() as u64;
+
+        for item in items {
+            if last_generation_id.as_ref() != Some(&item.generation_id) {
+                lines.push(DumpLine::Generation(DumpGeneration {
+                    generation_id: encode_bytes(item.generation_id.get_byte_array()),
+                }));
+
+                last_generation_id = Some(item.generation_id);
+            }
+
+            lines.push(DumpLine::Record(DumpRecord {
+                key: encode_bytes(item.key.get_byte_array()),
+                value: item.value.map(|value| encode_bytes(value.get_value())),
+            }));
+        }
+
+        self.state = match last_generation_key {
+            Some(last_generation_key) => {
+                ExportState::History(Some(last_generation_key), last_generation_id)
+            }
+            None => ExportState::Readers,
+        };
+
+        Ok(lines)
+    }
+
+    async fn read_readers(&mut self) -> Result<Vec<DumpLine>, DumpError> {
+        let raw_db = self.raw_db.clone().unwrap();
+
+        let readers = raw_db
+            .get_range_cf(COLLECTION_CF_META, b"reader:", b"reader;")
+            .await?;
+
+        let mut lines = Vec::with_capacity(readers.len() + 1);
+
+        for (key, value) in readers {
+            let reader_name =
+                from_utf8(&key[(b"reader:".len())..]).or(Err(RawDbError::InvalidReaderValue))?;
+            let reader_value =
+                ReaderValue::from_slice(&value).or(Err(RawDbError::InvalidReaderValue))?;
+
+            // Its collection was deleted, it cannot be used anymore
+            if reader_value.is_orphaned() {
+                continue;
+            }
+
+            let collection_name = reader_value.get_collection_name();
+
+            lines.push(DumpLine::Reader(DumpReader {
+                reader_name: reader_name.to_string(),
+                collection_name: if collection_name.is_empty() {
+                    None
+                } else {
+                    Some(collection_name.to_string())
+                },
+                generation_id: encode_bytes(reader_value.get_generation_id().get_byte_array()),
+            }));
+        }
+
+        Ok(lines)
+    }
+}
+
+impl Drop for CollectionExport {
+    fn drop(&mut self) {
+        // Checkpoint should be closed before removal
+        self.raw_db.take();
+
+        let _ = remove_dir_if_exists(&self.path);
+    }
+}
@@ -0,0 +1,478 @@
+use crate::collection::constants::COLLECTION_CF_META;
+use crate::collection::methods::clone_collection::prepare_cloned_collection_sync;
+use crate::collection::open::{open_collection_raw_db, CollectionOpenOptions};
+use crate::collection::util::record_key::OwnedRecordKey;
+use crate::collection::Collection;
+use crate::common::constants::MAX_COLLECTION_NAME_LENGTH;
+use crate::common::{OwnedCollectionKey, OwnedCollectionValue, OwnedGenerationId, PhantomId};
+use crate::database::clone_collection::{collection_record_key, get_tmp_path};
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::dump::{
+    decode_bytes, DumpError, DumpHeader, DumpLine, DumpReader, DumpRecord, DUMP_FORMAT,
+    DUMP_VERSION,
+};
+use crate::database::trash::remove_dir_if_exists;
+use crate::database::Database;
+use crate::protos::database_meta::CollectionRecord;
+use crate::raw_db::put_many_collection_records::{
+    PutManyCollectionRecordsItem, PutManyCollectionRecordsOptions,
+};
+use crate::raw_db::update_reader::{RawDbCreateReaderOptions, RawDbCreateReaderResult};
+use crate::raw_db::{RawDb, RawDbError, RawDbOpenError};
+use crate::util::base62;
+use crate::util::tokio::spawn_blocking;
+use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+use protobuf::{Message, MessageField};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub struct ImportCollectionOptions {
+    // Lines longer than this are rejected, it limits memory used by one record
+    pub max_line_bytes: usize,
+}
+
+pub struct ImportCollectionOk {
+    pub collection: Arc<Collection>,
+    pub records: u64,
+}
+
+// Records are written to the new collection in packs of this size
+const RECORDS_PACK_SIZE: usize = 1000;
+
+struct ImportedCollection {
+    header: DumpHeader,
+    generation_id: OwnedGenerationId,
+    records: u64,
+}
+
+impl Database {
+    // Collection is prepared in the temporary directory and is added to the database
+    // only if the whole dump is valid
+    pub async fn import_collection<R: AsyncBufRead + Unpin>(
+        &self,
+        mut reader: R,
+        options: ImportCollectionOptions,
+    ) -> Result<ImportCollectionOk, DumpError> {
+        let mut lines = LineReader {
+            buffer: Vec::new(),
+            max_line_bytes: options.max_line_bytes,
+        };
+
+        let header = match lines.next(&mut reader).await? {
+            Some(DumpLine::Header(header)) => header,
+            Some(_) => {
+                return Err(DumpError::InvalidDump(
+                    "first line should be a header".to_string(),
+                ));
+            }
+            None => {
+                return Err(DumpError::InvalidDump("dump is empty".to_string()));
+            }
+        };
+
+        let generation_id = validate_header(&header)?;
+
+        if self.get_collection(&header.collection_name).await.is_some() {
+            return Err(DumpError::AlreadyExist);
+        }
+
+        let tmp_path = get_tmp_path(&self.data_path);
+        std::fs::create_dir_all(&tmp_path)?;
+
+        let path = tmp_path.join(base62::from_u64(rand::random()).as_ref());
+
+        let result = self
+            .prepare_imported_collection(
+                &path,
+                ImportedCollection {
+                    header,
+                    generation_id,
+                    records: 0,
+                },
+                &mut reader,
+                &mut lines,
+            )
+            .await;
+
+        let result = match result {
+            Ok(imported) => self.add_imported_collection(&path, imported).await,
+            Err(err) => Err(err),
+        };
+
+        remove_dir_if_exists(&path)?;
+
+        result
+    }
+
+    async fn prepare_imported_collection<R: AsyncBufRead + Unpin>(
+        &self,
+        path: &Path,
+        mut imported: ImportedCollection,
+        reader: &mut R,
+        lines: &mut LineReader,
+    ) -> Result<ImportedCollection, DumpError> {
+        let path_str = path.to_str().unwrap();
+
+        let raw_db =
+            open_collection_raw_db(path_str, &self.config.raw_db).map_err(|err| match err {
+                RawDbOpenError::RocksDbError(err) => RawDbError::RocksDb(err),
+            })?;
+        let raw_db = Arc::new(raw_db);
+
+        let mut current_generation_id: Option<OwnedGenerationId> = None;
+        let mut items = Vec::with_capacity(RECORDS_PACK_SIZE);
+        let mut readers = Vec::new();
+
+        let end = loop {
+            let line = lines
+                .next(reader)
+                .await?
+                .ok_or_else(|| DumpError::InvalidDump("dump is truncated".to_string()))?;
+
+            match line {
+                DumpLine::Header(_) => {
+                    return Err(DumpError::InvalidDump(
+                        "header should be the first line".to_string(),
+                    ));
+                }
+                DumpLine::Generation(generation) => {
+                    if !readers.is_empty() {
+                        return Err(DumpError::InvalidDump(
+                            "generations should be before readers".to_string(),
+                        ));
+                    }
+
+                    let generation_id = decode_generation_id(&generation.generation_id)?;
+
+                    validate_generation_order(
+                        &imported,
+                        current_generation_id.as_ref(),
+                        &generation_id,
+                    )?;
+
+                    current_generation_id = Some(generation_id);
+                }
+                DumpLine::Record(record) => {
+                    let Some(generation_id) = &current_generation_id else {
+                        return Err(DumpError::InvalidDump(
+                            "record should be after a generation".to_string(),
+                        ));
+                    };
+                    if !readers.is_empty() {
+                        return Err(DumpError::InvalidDump(
+                            "records should be before readers".to_string(),
+                        ));
+                    }
+
+                    items.push(decode_record(
+                        record,
+                        generation_id,
+                        imported.header.history,
+                    )?);
+                    imported.records += 1;
+
+                    if items.len() >= RECORDS_PACK_SIZE {
+                        raw_db
+                            .put_many_collection_records(PutManyCollectionRecordsOptions {
+                                items: std::mem::take(&mut items),
+                            })
+                            .await?;
+                    }
+                }
+                DumpLine::Reader(reader) => {
+                    readers.push(reader);
+                }
+                DumpLine::End(end) => {
+                    break end;
+                }
+            }
+        };
+
+        if end.records != imported.records {
+            return Err(DumpError::InvalidDump(format!(
+                "end has {} records, but there were {}",
+                end.records, imported.records
+            )));
+        }
+
+        if lines.next(reader).await?.is_some() {
+            return Err(DumpError::InvalidDump(
+                "end should be the last line".to_string(),
+            ));
+        }
+
+        if !items.is_empty() {
+            raw_db
+                .put_many_collection_records(PutManyCollectionRecordsOptions { items })
+                .await?;
+        }
+
+        let is_manual = imported.header.is_manual;
+        let generation_id = imported.generation_id.clone();
+        let raw_db_config = self.config.raw_db.clone();
+        let path_string = path_str.to_string();
+
+        spawn_blocking(move || {
+            write_readers_sync(&raw_db, readers)?;
+
+            raw_db.put_cf_sync(
+                COLLECTION_CF_META,
+                b"is_manual",
+                &[if is_manual { 1 } else { 0 }],
+            )?;
+
+            // Raw_db should be closed before it will be opened again
+            drop(raw_db);
+
+            prepare_cloned_collection_sync(
+                &path_string,
+                generation_id.as_ref(),
+                is_manual,
+                &raw_db_config,
+            )
+            .map_err(DumpError::CollectionMethod)
+        })
+        .await
+        .map_err(|_| DumpError::TaskJoin)??;
+
+        Ok(imported)
+    }
+
+    async fn add_imported_collection(
+        &self,
+        path: &PathBuf,
+        imported: ImportedCollection,
+    ) -> Result<ImportCollectionOk, DumpError> {
+        let ImportedCollection {
+            header, records, ..
+        } = imported;
+
+        let name = header.collection_name.as_str();
+        let settings = header.settings.to_settings();
+
+        let _guard = self.collections_alter_lock.lock().await;
+
+        let collections = self.collections.read().await;
+        if collections.contains_key(name) {
+            return Err(DumpError::AlreadyExist);
+        }
+        drop(collections);
+
+        let mut collection_record = CollectionRecord::new();
+        collection_record.id = name.to_string();
+        collection_record.is_manual = header.is_manual;
+        collection_record.if_value_changed = header.if_value_changed;
+        collection_record.settings = MessageField::some(settings.to_record());
+
+        let collection_record = collection_record
+            .write_to_bytes()
+            .map_err(DumpError::Protobuf)?;
+
+        // Directory can be left if the collection was not removed completely
+        let collection_path = Collection::get_path(&self.data_path, name);
+        if collection_path.exists() {
+            return Err(DumpError::AlreadyExist);
+        }
+
+        std::fs::rename(path, collection_path)?;
+
+        self.database_raw_db
+            .put_cf(
+                DATABASE_RAW_DB_CF,
+                collection_record_key(name).as_bytes(),
+                &collection_record,
+            )
+            .await?;
+
+        let collection = Collection::open(CollectionOpenOptions {
+            config: self.config.clone(),
+            name: name.to_string(),
+            is_manual: header.is_manual,
+            if_value_changed: header.if_value_changed,
+            settings,
+            data_path: &self.data_path,
+            database_inner: self.inner.clone(),
+        })
+        .await?;
+
+        let mut collections = self.collections.write().await;
+        collections.insert(name.to_string(), collection.clone());
+        drop(collections);
+
+        Ok(ImportCollectionOk {
+            collection,
+            records,
+        })
+    }
+}
+
+struct LineReader {
+    buffer: Vec<u8>,
+    max_line_bytes: usize,
+}
+
+impl LineReader {
+    // Empty lines are skipped, `None` on the end of the input
+    async fn next<R: AsyncBufRead + Unpin>(
+        &mut self,
+        reader: &mut R,
+    ) -> Result<Option<DumpLine>, DumpError> {
+        loop {
+            self.buffer.clear();
+
+            let read = (&mut *reader)
+                .take(self.max_line_bytes as u64 + 1)
+                .read_until(b'\n', &mut self.buffer)
+                .await
+                .map_err(DumpError::Read)?;
+
+            if read == 0 {
+                return Ok(None);
+            }
+
+            if self.buffer.last() == Some(&b'\n') {
+                self.buffer.pop();
+            } else if self.buffer.len() > self.max_line_bytes {
+                return Err(DumpError::InvalidDump(format!(
+                    "line cannot be > {} bytes",
+                    self.max_line_bytes
+                )));
+            }
+
+            if self.buffer.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            let line = serde_json::from_slice(&self.buffer)
+                .map_err(|err| DumpError::InvalidDump(format!("invalid line: {}", err)))?;
+
+            return Ok(Some(line));
+        }
+    }
+}
+
+fn validate_header(header: &DumpHeader) -> Result<OwnedGenerationId, DumpError> {
+    if header.format != DUMP_FORMAT {
+        return Err(DumpError::InvalidDump(format!(
+            "format should be \"{}\"",
+            DUMP_FORMAT
+        )));
+    }
+
+    if header.version != DUMP_VERSION {
+        return Err(DumpError::UnsupportedVersion(header.version));
+    }
+
+    let name = &header.collection_name;
+    if name.is_empty() || name.len() > MAX_COLLECTION_NAME_LENGTH {
+        return Err(DumpError::InvalidDump(format!(
+            "collectionName should be from 1 to {} bytes",
+            MAX_COLLECTION_NAME_LENGTH
+        )));
+    }
+
+    decode_generation_id(&header.generation_id)
+}
+
+fn validate_generation_order(
+    imported: &ImportedCollection,
+    prev_generation_id: Option<&OwnedGenerationId>,
+    generation_id: &OwnedGenerationId,
+) -> Result<(), DumpError> {
+    if generation_id > &imported.generation_id {
+        return Err(DumpError::InvalidDump(
+            "generationId cannot be greater than generationId of the header".to_string(),
+        ));
+    }
+
+    if !imported.header.history {
+        if prev_generation_id.is_some() || generation_id != &imported.generation_id {
+            return Err(DumpError::InvalidDump(
+                "dump without history should have one generation of the header".to_string(),
+            ));
+        }
+
+        return Ok(());
+    }
+
+    if let Some(prev_generation_id) = prev_generation_id {
+        if generation_id <= prev_generation_id {
+            return Err(DumpError::InvalidDump(
+                "generations should be in increasing order".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_generation_id(value: &str) -> Result<OwnedGenerationId, DumpError> {
+    let bytes = decode_bytes(value, "generationId")?;
+
+    OwnedGenerationId::from_boxed_slice(bytes)
+        .map_err(|_| DumpError::InvalidDump("generationId is too long".to_string()))
+}
+
+fn decode_record(
+    record: DumpRecord,
+    generation_id: &OwnedGenerationId,
+    is_history: bool,
+) -> Result<PutManyCollectionRecordsItem, DumpError> {
+    let key = OwnedCollectionKey::from_boxed_slice(decode_bytes(&record.key, "key")?)
+        .map_err(|_| DumpError::InvalidDump("key is too long".to_string()))?;
+
+    let value = match record.value {
+        Some(value) => Some(OwnedCollectionValue::new(&decode_bytes(&value, "value")?)),
+        None if is_history => None,
+        None => {
+            return Err(DumpError::InvalidDump(
+                "deleted records are allowed only in dumps with history".to_string(),
+            ));
+        }
+    };
+
+    let record_key = OwnedRecordKey::new(key.as_ref(), generation_id.as_ref(), PhantomId::empty())
+        .map_err(|_| DumpError::InvalidDump("invalid record key".to_string()))?;
+
+    Ok(PutManyCollectionRecordsItem { record_key, value })
+}
+
+fn write_readers_sync(raw_db: &RawDb, readers: Vec<DumpReader>) -> Result<(), DumpError> {
+    for reader in readers {
+        let DumpReader {
+            reader_name,
+            collection_name,
+            generation_id,
+        } = reader;
+
+        let generation_id = decode_generation_id(&generation_id)?;
+
+        if reader_name.is_empty() {
+            return Err(DumpError::InvalidDump(
+                "readerName cannot be empty".to_string(),
+            ));
+        }
+
+        let result = raw_db
+            .create_reader_sync(RawDbCreateReaderOptions {
+                reader_name: &reader_name,
+                collection_name: collection_name.as_deref(),
+                generation_id: generation_id.as_ref(),
+            })
+            .map_err(|err| match err {
+                RawDbError::InvalidReaderValue => {
+                    DumpError::InvalidDump("collectionName of reader is too long".to_string())
+                }
+                err => DumpError::RawDb(err),
+            })?;
+
+        if let RawDbCreateReaderResult::AlreadyExists(_) = result {
+            return Err(DumpError::InvalidDump(format!(
+                "reader \"{}\" is duplicated",
+                reader_name
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::open::CollectionOpenError;
+use crate::collection::settings::CollectionSettings;
+use crate::raw_db::RawDbError;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+pub mod export;
+pub mod import;
+
+// Dump is a newline-delimited JSON, all binary values are in base64:
+//   header                      — always the first line
+//   generation, record...       — records of each generation in the increasing order,
+//                                 only one generation if there is no history
+//   reader...
+//   end                         — always the last line, protects from truncated dumps
+pub const DUMP_FORMAT: &str = "diffbelt-collection";
+pub const DUMP_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DumpLine {
+    Header(DumpHeader),
+    Generation(DumpGeneration),
+    Record(DumpRecord),
+    Reader(DumpReader),
+    End(DumpEnd),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpHeader {
+    pub format: String,
+    pub version: u32,
+    pub collection_name: String,
+    pub is_manual: bool,
+    pub if_value_changed: bool,
+    pub settings: DumpSettings,
+    // Collection is imported at this generation
+    pub generation_id: String,
+    // If false, there is only one generation with all values at `generation_id`
+    pub history: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpSettings {
+    pub commit_delay_ms: u64,
+    pub max_generation_age_ms: Option<u64>,
+    pub max_generation_puts: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpGeneration {
+    pub generation_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpRecord {
+    pub key: String,
+    // `null` if the key was deleted in this generation, only in dumps with history
+    pub value: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpReader {
+    pub reader_name: String,
+    // Absent if the reader points to the collection itself
+    pub collection_name: Option<String>,
+    pub generation_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpEnd {
+    pub records: u64,
+}
+
+#[derive(Debug)]
+pub enum DumpError {
+    NoSuchCollection,
+    AlreadyExist,
+    // Dump is not valid, reason is for the user
+    InvalidDump(String),
+    UnsupportedVersion(u32),
+    // Dump cannot be read, for example the request body was interrupted
+    Read(std::io::Error),
+    RawDb(RawDbError),
+    // Filesystem errors
+    Io(std::io::Error),
+    Protobuf(protobuf::Error),
+    TaskJoin,
+    CollectionMethod(CollectionMethodError),
+    CollectionOpen(CollectionOpenError),
+}
+
+impl From<RawDbError> for DumpError {
+    fn from(err: RawDbError) -> Self {
+        DumpError::RawDb(err)
+    }
+}
+
+impl From<std::io::Error> for DumpError {
+    fn from(err: std::io::Error) -> Self {
+        DumpError::Io(err)
+    }
+}
+
+impl From<CollectionMethodError> for DumpError {
+    fn from(err: CollectionMethodError) -> Self {
+        DumpError::CollectionMethod(err)
+    }
+}
+
+impl From<CollectionOpenError> for DumpError {
+    fn from(err: CollectionOpenError) -> Self {
+        DumpError::CollectionOpen(err)
+    }
+}
+
+impl DumpSettings {
+    pub fn from_settings(settings: CollectionSettings) -> Self {
+        Self {
+            commit_delay_ms: settings.commit_delay.as_millis() as u64,
+            max_generation_age_ms: settings
+                .max_generation_age
+                .map(|duration| duration.as_millis() as u64),
+            max_generation_puts: settings.max_generation_puts.map(NonZeroU32::get),
+        }
+    }
+
+    pub fn to_settings(&self) -> CollectionSettings {
+        CollectionSettings {
+            commit_delay: Duration::from_millis(self.commit_delay_ms),
+            max_generation_age: self.max_generation_age_ms.map(Duration::from_millis),
+            max_generation_puts: self.max_generation_puts.and_then(NonZeroU32::new),
+        }
+    }
+}
+
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+pub fn decode_bytes(value: &str, field: &str) -> Result<Box<[u8]>, DumpError> {
+    base64::decode(value)
+        .map(Vec::into_boxed_slice)
+        .map_err(|_| DumpError::InvalidDump(format!("{} is not a valid base64", field)))
+}
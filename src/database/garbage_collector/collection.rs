@@ -2,25 +2,36 @@ use crate::collection::util::collection_raw_db::CollectionRawDb;
 use crate::collection::util::record_key::OwnedRecordKey;
 use crate::common::OwnedGenerationId;
 use crate::database::config::DatabaseConfig;
+use crate::messages::garbage_collector::GarbageCollectorStats;
 use crate::raw_db::garbage_collector::{CleanupGenerationsLessThanOptions, CleanupResult};
+use crate::util::tokio::spawn_blocking;
 use std::ops::Deref;
 use std::rc::Rc;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tokio::sync::{oneshot, watch, RwLock};
-use tokio::task::{spawn_blocking, spawn_local, yield_now};
+use tokio::task::{spawn_local, yield_now};
+use tracing::{Instrument, Span};
 
 pub struct GarbageCollectorCollection {
     pub id: usize,
     raw_db: CollectionRawDb,
     is_deleted: Arc<RwLock<bool>>,
+    stats: Arc<GarbageCollectorStats>,
 }
 
 impl GarbageCollectorCollection {
-    pub fn new(id: usize, raw_db: CollectionRawDb, is_deleted: Arc<RwLock<bool>>) -> Self {
+    pub fn new(
+        id: usize,
+        raw_db: CollectionRawDb,
+        is_deleted: Arc<RwLock<bool>>,
+        stats: Arc<GarbageCollectorStats>,
+    ) -> Self {
         Self {
             id,
             raw_db,
             is_deleted,
+            stats,
         }
     }
 
@@ -29,47 +40,50 @@ impl GarbageCollectorCollection {
         config: &DatabaseConfig,
         mut minimum_generation_id: watch::Receiver<OwnedGenerationId>,
         mut stop_receiver: oneshot::Receiver<()>,
+        span: Span,
     ) {
         let records_limit = config.gc_records_limit;
         let lookups_limit = config.gc_lookups_limit;
 
         let raw_db = self.raw_db.clone();
 
-        spawn_local(async move {
-            let mut local_generation_less_than = OwnedGenerationId::empty();
-            let mut continue_from_record_key = None;
+        spawn_local(
+            async move {
+                let mut local_generation_less_than = OwnedGenerationId::empty();
+                let mut continue_from_record_key = None;
 
-            let check_generation =
-                |minimum_generation_id: &mut watch::Receiver<OwnedGenerationId>,
-                 local_generation_less_than: &mut OwnedGenerationId,
-                 continue_from_record_key: &mut Option<OwnedRecordKey>| {
-                    let current = minimum_generation_id.borrow_and_update();
-                    let current = current.deref();
+                let check_generation =
+                    |minimum_generation_id: &mut watch::Receiver<OwnedGenerationId>,
+                     local_generation_less_than: &mut OwnedGenerationId,
+                     continue_from_record_key: &mut Option<OwnedRecordKey>| {
+                        let current = minimum_generation_id.borrow_and_update();
+                        let current = current.deref();
 
-                    if current > local_generation_less_than {
-                        *local_generation_less_than = current.clone();
-                        *continue_from_record_key = None;
-                    }
-                };
-
-            loop {
-                check_generation(
-                    &mut minimum_generation_id,
-                    &mut local_generation_less_than,
-                    &mut continue_from_record_key,
-                );
-
-                let result = {
-                    let raw_db = raw_db.clone();
-                    let local_generation_less_than = local_generation_less_than.clone();
-
-                    let is_deleted = self.is_deleted.read().await;
-                    if *is_deleted {
-                        return;
-                    }
+                        if current > local_generation_less_than {
+                            *local_generation_less_than = current.clone();
+                            *continue_from_record_key = None;
+                        }
+                    };
+
+                loop {
+                    check_generation(
+                        &mut minimum_generation_id,
+                        &mut local_generation_less_than,
+                        &mut continue_from_record_key,
+                    );
 
-                    spawn_blocking(move || {
-                        raw_db
+                    let result =
+                        {
+                            let raw_db = raw_db.clone();
+                            let local_generation_less_than = local_generation_less_than.clone();
+
+                            let is_deleted = self.is_deleted.read().await;
+                            if *is_deleted {
+                                return;
+                            }
+
+                            spawn_blocking(move || {
+                                raw_db
                             .cleanup_generations_less_than_sync(CleanupGenerationsLessThanOptions {
                                 generation_less_than: local_generation_less_than.as_ref(),
                                 continue_from_record_key,
@@ -77,36 +91,50 @@ impl GarbageCollectorCollection {
                                 lookups_limit,
                             })
                             .expect("garbage_collector:raw_db:cleanup_generations_less_than_sync")
-                    })
-                    .await
-                    .expect("garbage_collector:join")
-                };
+                            })
+                            .await
+                            .expect("garbage_collector:join")
+                        };
 
-                match result {
-                    CleanupResult::NeedToContinue(continuation) => {
-                        continue_from_record_key = continuation;
+                    let (result, removed_records) = result;
 
-                        yield_now().await;
-                    }
-                    CleanupResult::Finished => {
-                        continue_from_record_key = None;
-
-                        tokio::select! {
-                            result = minimum_generation_id.changed() => {
-                                match result {
-                                    Ok(_) => {},
-                                    Err(_) => {
-                                        return;
-                                    },
+                    tracing::debug!(removed_records, "cleanup batch is finished");
+
+                    self.stats.batches.fetch_add(1, Ordering::Relaxed);
+                    self.stats
+                        .removed_records
+                        .fetch_add(removed_records as u64, Ordering::Relaxed);
+
+                    match result {
+                        CleanupResult::NeedToContinue(continuation) => {
+                            continue_from_record_key = continuation;
+
+                            yield_now().await;
+                        }
+                        CleanupResult::Finished => {
+                            continue_from_record_key = None;
+
+                            *self.stats.collected_generation_id.lock().unwrap() =
+                                local_generation_less_than.clone();
+
+                            tokio::select! {
+                                result = minimum_generation_id.changed() => {
+                                    match result {
+                                        Ok(_) => {},
+                                        Err(_) => {
+                                            return;
+                                        },
+                                    }
+                                },
+                                _ = &mut stop_receiver => {
+                                    return;
                                 }
-                            },
-                            _ = &mut stop_receiver => {
-                                return;
-                            }
-                        };
+                            };
+                        }
                     }
                 }
             }
-        });
+            .instrument(span),
+        );
     }
 }
@@ -7,11 +7,5 @@ use crate::util::async_task_thread::AsyncTaskThread;
 
 pub async fn start_garbage_collector_task_thread() -> AsyncTaskThread<DatabaseGarbageCollectorTask>
 {
-    AsyncTaskThread::new(
-        run,
-        (),
-        #[cfg(feature = "debug_prints")]
-        "garbage_collector",
-    )
-    .await
+    AsyncTaskThread::new(run, (), "garbage_collector").await
 }
@@ -3,7 +3,7 @@ use crate::database::config::DatabaseConfig;
 use crate::database::garbage_collector::collection::GarbageCollectorCollection;
 use crate::messages::garbage_collector::{
     DatabaseGarbageCollectorTask, GarbageCollectorCommonError, GarbageCollectorDropCollectionTask,
-    GarbageCollectorNewCollectionTask, NewCollectionTaskResponse,
+    GarbageCollectorNewCollectionTask, GarbageCollectorStats, NewCollectionTaskResponse,
 };
 use crate::util::async_task_thread::TaskPoller;
 use crate::util::auto_sender_on_drop::AutoSenderOnDrop;
@@ -22,7 +22,7 @@ struct GarbageCollectorState {
 
 pub async fn run(_: (), mut poller: TaskPoller<DatabaseGarbageCollectorTask>) {
     let task = poller.poll().await;
-    let Some(task) = task else {
+    let Some((task, _)) = task else {
         return;
     };
 
@@ -40,7 +40,9 @@ pub async fn run(_: (), mut poller: TaskPoller<DatabaseGarbageCollectorTask>) {
         collections: RefCell::new(HashMap::new()),
     });
 
-    while let Some(task) = poller.poll().await {
+    while let Some((task, span)) = poller.poll().await {
+        let _entered = span.enter();
+
         match task {
             DatabaseGarbageCollectorTask::NewCollection(task) => {
                 state.clone().new_collection(task);
@@ -76,7 +78,14 @@ impl GarbageCollectorState {
 
         let id = self.counter.replace(self.counter.get() + 1);
 
-        let collection = Rc::new(GarbageCollectorCollection::new(id, raw_db, is_deleted));
+        let stats = Arc::new(GarbageCollectorStats::default());
+
+        let collection = Rc::new(GarbageCollectorCollection::new(
+            id,
+            raw_db,
+            is_deleted,
+            stats.clone(),
+        ));
 
         {
             self.collections
@@ -87,15 +96,27 @@ impl GarbageCollectorState {
         let (drop_handle, drop_receiver) = AutoSenderOnDrop::new(());
 
         sender
-            .send(Ok(NewCollectionTaskResponse { id, drop_handle }))
+            .send(Ok(NewCollectionTaskResponse {
+                id,
+                drop_handle,
+                stats,
+            }))
             .unwrap_or(());
 
         let (drop_sender, drop_receiver2) = oneshot::channel();
 
+        // Cleanup lives as long as the collection, not the request that has opened it
+        let span = tracing::debug_span!(
+            parent: None,
+            "garbage_collector",
+            collection = &*collection_name
+        );
+
         collection.cleanup_generations_less_than(
             &self.config,
             minimum_generation_id,
             drop_receiver2,
+            span,
         );
 
         spawn_local(async move {
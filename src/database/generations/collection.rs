@@ -2,13 +2,16 @@ use crate::common::{IsByteArray, OwnedGenerationId};
 use crate::database::generations::next_generation_lock::{
     GenerationIdLock, NextGenerationIdLockData,
 };
-use crate::util::async_lock::AsyncLock;
+use crate::util::async_lock::{AsyncLock, AsyncLockExclusiveInstance};
 use crate::util::indexed_container::{IndexedContainerItem, IndexedContainerPointer};
+use crate::util::tokio::{spawn, spawn_blocking};
 
 use std::future::Future;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use crate::collection::settings::CollectionSettings;
 use crate::collection::CommitGenerationUpdateReader;
 use crate::messages::generations::{
     CommitManualGenerationError, LockManualGenerationIdError, StartManualGenerationIdError,
@@ -27,8 +30,9 @@ use crate::messages::readers::{
 };
 use crate::raw_db::has_generation_changes::HasGenerationChangesOptions;
 use crate::util::async_sync_call::async_sync_call;
-use tokio::sync::{oneshot, watch, RwLock};
-use tokio::task::spawn_blocking;
+use std::time::Duration;
+use tokio::sync::{oneshot, watch, OwnedRwLockReadGuard, RwLock};
+use tokio::time::Instant;
 
 #[derive(Copy, Clone)]
 pub struct InnerGenerationsCollectionId {
@@ -42,18 +46,26 @@ pub struct GenerationIdNextGenerationIdPair {
     pub next_generation_id: Option<OwnedGenerationId>,
 }
 
+struct ScheduledGeneration {
+    generation_id: OwnedGenerationId,
+    first_put_at: Instant,
+    last_put_at: Instant,
+}
+
 pub struct InnerGenerationsCollection {
     pub inner_id: InnerGenerationsCollectionId,
     name: Arc<str>,
     pub is_manual: bool,
+    pub settings: CollectionSettings,
     db: CollectionRawDb,
-    scheduled_for_generation_id: Option<OwnedGenerationId>,
+    scheduled: Option<ScheduledGeneration>,
     pub generation_pair_sender: Arc<watch::Sender<GenerationIdNextGenerationIdPair>>,
     pub generation_pair_receiver: watch::Receiver<GenerationIdNextGenerationIdPair>,
     pub next_generation_locks:
         AsyncLock<GenerationIdNextGenerationIdPair, NextGenerationIdLockData>,
     pub is_next_generation_scheduled: bool,
     pub is_deleted: Arc<RwLock<bool>>,
+    pub commits_count: Arc<AtomicU64>,
 }
 
 pub enum NextGenerationScheduleAction {
@@ -98,10 +110,11 @@ impl InnerGenerationsCollection {
             inner_id,
             name,
             is_manual,
+            settings: CollectionSettings::default(),
             db,
             // generation_id: generation_id.clone(),
             // next_generation_id: next_generation_id.clone(),
-            scheduled_for_generation_id: None,
+            scheduled: None,
             generation_pair_sender: Arc::new(generation_pair_sender),
             generation_pair_receiver,
             // TODO: move to config
@@ -109,6 +122,7 @@ impl InnerGenerationsCollection {
             // TODO: check after restart
             is_next_generation_scheduled: false,
             is_deleted,
+            commits_count: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -280,17 +294,81 @@ impl InnerGenerationsCollection {
         &mut self,
         expected_generation_id: OwnedGenerationId,
     ) -> NextGenerationScheduleAction {
-        if self.is_manual || self.scheduled_for_generation_id.is_some() {
+        if self.is_manual {
             return NextGenerationScheduleAction::NoNeedSchedule;
         }
 
-        self.scheduled_for_generation_id = Some(expected_generation_id);
+        let now = Instant::now();
+
+        if let Some(scheduled) = self.scheduled.as_mut() {
+            if scheduled.generation_id == expected_generation_id {
+                scheduled.last_put_at = now;
+            }
+
+            return NextGenerationScheduleAction::NoNeedSchedule;
+        }
+
+        self.scheduled = Some(ScheduledGeneration {
+            generation_id: expected_generation_id,
+            first_put_at: now,
+            last_put_at: now,
+        });
 
         NextGenerationScheduleAction::NeedSchedule
     }
 
-    pub fn commit_next_generation(&mut self) {
-        let Some(expected_generation_id) = self.scheduled_for_generation_id.take() else {
+    pub fn is_scheduled_for(&self, generation_id: &OwnedGenerationId) -> bool {
+        self.scheduled
+            .as_ref()
+            .map(|scheduled| &scheduled.generation_id == generation_id)
+            .unwrap_or(false)
+    }
+
+    // Returns `None` if scheduled generation should be committed now
+    pub fn time_until_commit(&self, now: Instant) -> Option<Duration> {
+        let scheduled = self.scheduled.as_ref()?;
+
+        let commit_delay = self.settings.commit_delay;
+
+        let commit_at = match self.settings.max_generation_age {
+            Some(max_generation_age) => (scheduled.last_put_at + commit_delay)
+                .min(scheduled.first_put_at + max_generation_age),
+            None => scheduled.first_put_at + commit_delay,
+        };
+
+        if commit_at <= now {
+            return None;
+        }
+
+        Some(commit_at - now)
+    }
+
+    // Resolves to `true` if next generation has reached `max_generation_puts`,
+    // `None` if there is no such limit
+    pub fn is_next_generation_full(
+        &self,
+        next_generation_id: OwnedGenerationId,
+    ) -> Option<impl Future<Output = Result<bool, RawDbError>>> {
+        let max_generation_puts = self.settings.max_generation_puts?;
+        let raw_db = self.db.clone();
+
+        Some(async move {
+            spawn_blocking(move || {
+                let size = raw_db.get_generation_size_sync(next_generation_id.as_ref())?;
+
+                Ok(size >= max_generation_puts.get())
+            })
+            .await
+            .map_err(RawDbError::Join)?
+        })
+    }
+
+    pub fn commit_next_generation(&mut self, database: Arc<DatabaseInner>) {
+        let Some(ScheduledGeneration {
+            generation_id: expected_generation_id,
+            ..
+        }) = self.scheduled.take()
+        else {
             return;
         };
 
@@ -298,8 +376,11 @@ impl InnerGenerationsCollection {
         let generation_pair_sender = self.generation_pair_sender.clone();
         let raw_db = self.db.clone();
         let is_deleted = self.is_deleted.clone();
+        let commits_count = self.commits_count.clone();
+
+        spawn(async move {
+            let _backup_guard = database.backup_lock.read().await;
 
-        tokio::spawn(async move {
             let mut lock = next_generation_locks.lock_exclusive_without_data().await;
 
             let pair = lock.value_mut();
@@ -342,6 +423,8 @@ impl InnerGenerationsCollection {
             .expect("commit_next_generation:join_error")
             .expect("commit_next_generation:raw_db_error");
 
+            commits_count.fetch_add(1, Ordering::Relaxed);
+
             generation_pair_sender
                 .send(lock.value().clone())
                 .unwrap_or(());
@@ -413,18 +496,45 @@ impl InnerGenerationsCollection {
         next_generation_id: OwnedGenerationId,
         update_readers: Option<Vec<CommitGenerationUpdateReader>>,
     ) -> impl Future<Output = Result<(), CommitManualGenerationError>> {
+        let locking = self.lock_manual_generation_commit(
+            database.clone(),
+            next_generation_id,
+            update_readers,
+        );
+
+        async move {
+            let _backup_guard = database.backup_lock.read().await;
+
+            locking.await?.commit().await
+        }
+    }
+
+    // Next generation can't be changed or committed by others until the commit is dropped,
+    // collection can't be deleted until then too, so only the write of the commit can fail
+    pub fn lock_manual_generation_commit(
+        &mut self,
+        database: Arc<DatabaseInner>,
+        next_generation_id: OwnedGenerationId,
+        update_readers: Option<Vec<CommitGenerationUpdateReader>>,
+    ) -> impl Future<Output = Result<ManualGenerationCommit, CommitManualGenerationError>> {
         let next_generation_locks = self.next_generation_locks.mirror();
         let generation_pair_sender = self.generation_pair_sender.clone();
         let raw_db = self.db.clone();
         let is_deleted = self.is_deleted.clone();
         let name = self.name.clone();
+        let commits_count = self.commits_count.clone();
 
         async move {
-            let mut lock = next_generation_locks.lock_exclusive_without_data().await;
+            // Taken before the generation lock, in the same order as puts do
+            let deletion_lock = is_deleted.read_owned().await;
+            if *deletion_lock {
+                return Err(CommitManualGenerationError::NoSuchCollection);
+            }
 
-            let pair = lock.value_mut();
+            let lock = next_generation_locks.lock_exclusive_without_data().await;
 
-            let is_equal = pair
+            let is_equal = lock
+                .value()
                 .next_generation_id
                 .as_ref()
                 .map(|id| id.as_ref() == next_generation_id.as_ref())
@@ -434,75 +544,116 @@ impl InnerGenerationsCollection {
                 return Err(CommitManualGenerationError::OutdatedGeneration);
             }
 
-            let update_readers_for_readers_thread = update_readers.as_ref().map(|update_readers| {
-                update_readers
-                    .iter()
-                    .map(
-                        |CommitGenerationUpdateReader {
-                             reader_name,
-                             generation_id,
-                         }| UpdateReaderTask {
-                            owner_collection_name: name.clone(),
-                            to_collection_name: None,
-                            reader_name: reader_name.clone(),
-                            generation_id: generation_id.clone(),
-                            sender: None,
-                        },
-                    )
-                    .collect()
-            });
+            Ok(ManualGenerationCommit {
+                lock,
+                database,
+                name,
+                raw_db,
+                deletion_lock,
+                generation_pair_sender,
+                commits_count,
+                next_generation_id,
+                update_readers,
+            })
+        }
+    }
+}
 
-            let generation_id_for_db = next_generation_id.clone();
-            let _: () = spawn_blocking(move || {
-                let is_deleted = is_deleted.blocking_read();
-                if *is_deleted {
-                    return Err(CommitManualGenerationError::NoSuchCollection);
-                }
+pub struct ManualGenerationCommit {
+    lock: AsyncLockExclusiveInstance<GenerationIdNextGenerationIdPair, NextGenerationIdLockData>,
+    database: Arc<DatabaseInner>,
+    name: Arc<str>,
+    raw_db: CollectionRawDb,
+    deletion_lock: OwnedRwLockReadGuard<bool>,
+    generation_pair_sender: Arc<watch::Sender<GenerationIdNextGenerationIdPair>>,
+    commits_count: Arc<AtomicU64>,
+    next_generation_id: OwnedGenerationId,
+    update_readers: Option<Vec<CommitGenerationUpdateReader>>,
+}
 
-                raw_db
-                    .commit_generation_sync(RawDbCommitGenerationOptions {
-                        generation_id: generation_id_for_db.as_ref(),
-                        next_generation_id: OwnedGenerationId::empty().as_ref(),
-                        update_readers: update_readers.as_ref().map(|update_readers| {
-                            update_readers
-                                .iter()
-                                .map(
-                                    |CommitGenerationUpdateReader {
-                                         reader_name,
-                                         generation_id,
-                                     }| RawDbUpdateReader {
-                                        reader_name: reader_name.deref(),
-                                        generation_id: generation_id.as_ref(),
-                                    },
-                                )
-                                .collect()
-                        }),
-                    })
-                    .map_err(CommitManualGenerationError::RawDb)
+impl ManualGenerationCommit {
+    pub async fn commit(self) -> Result<(), CommitManualGenerationError> {
+        let ManualGenerationCommit {
+            mut lock,
+            database,
+            name,
+            raw_db,
+            deletion_lock,
+            generation_pair_sender,
+            commits_count,
+            next_generation_id,
+            update_readers,
+        } = self;
+
+        let pair = lock.value_mut();
+
+        let update_readers_for_readers_thread = update_readers.as_ref().map(|update_readers| {
+            update_readers
+                .iter()
+                .map(
+                    |CommitGenerationUpdateReader {
+                         reader_name,
+                         generation_id,
+                     }| UpdateReaderTask {
+                        owner_collection_name: name.clone(),
+                        to_collection_name: None,
+                        reader_name: reader_name.clone(),
+                        generation_id: generation_id.clone(),
+                        sender: None,
+                    },
+                )
+                .collect()
+        });
+
+        let generation_id_for_db = next_generation_id.clone();
+        let _: () = spawn_blocking(move || {
+            let _deletion_lock = deletion_lock;
+
+            raw_db
+                .commit_generation_sync(RawDbCommitGenerationOptions {
+                    generation_id: generation_id_for_db.as_ref(),
+                    next_generation_id: OwnedGenerationId::empty().as_ref(),
+                    update_readers: update_readers.as_ref().map(|update_readers| {
+                        update_readers
+                            .iter()
+                            .map(
+                                |CommitGenerationUpdateReader {
+                                     reader_name,
+                                     generation_id,
+                                 }| RawDbUpdateReader {
+                                    reader_name: reader_name.deref(),
+                                    generation_id: generation_id.as_ref(),
+                                },
+                            )
+                            .collect()
+                    }),
+                })
+                .map_err(CommitManualGenerationError::RawDb)
+        })
+        .await
+        .map_err(|error| CommitManualGenerationError::RawDb(RawDbError::Join(error)))??;
+
+        if let Some(update_readers_for_readers_thread) = update_readers_for_readers_thread {
+            let _: () = async_sync_call(|sender| {
+                database.add_readers_task(DatabaseCollectionReadersTask::UpdateReaders(
+                    UpdateReadersTask {
+                        updates: update_readers_for_readers_thread,
+                        sender,
+                    },
+                ))
             })
             .await
-            .map_err(|error| CommitManualGenerationError::RawDb(RawDbError::Join(error)))??;
+            .unwrap_or(());
+        }
 
-            if let Some(update_readers_for_readers_thread) = update_readers_for_readers_thread {
-                let _: () = async_sync_call(|sender| {
-                    database.add_readers_task(DatabaseCollectionReadersTask::UpdateReaders(
-                        UpdateReadersTask {
-                            updates: update_readers_for_readers_thread,
-                            sender,
-                        },
-                    ))
-                })
-                .await
-                .unwrap_or(());
-            }
+        pair.generation_id = next_generation_id.clone();
+        pair.next_generation_id.take();
 
-            pair.generation_id = next_generation_id.clone();
-            pair.next_generation_id.take();
+        commits_count.fetch_add(1, Ordering::Relaxed);
 
-            generation_pair_sender.send(pair.clone()).unwrap_or(());
+        generation_pair_sender.send(pair.clone()).unwrap_or(());
 
-            Ok(())
-        }
+        Ok(())
     }
 }
 
@@ -1,23 +1,27 @@
 use crate::common::OwnedGenerationId;
 use crate::database::generations::collection::{
-    InnerGenerationsCollection, InnerGenerationsCollectionId, NextGenerationLocked,
-    NextGenerationScheduleAction,
+    InnerGenerationsCollection, InnerGenerationsCollectionId, ManualGenerationCommit,
+    NextGenerationLocked, NextGenerationScheduleAction,
 };
+use std::future::Future;
 use std::sync::Arc;
 
 use crate::database::DatabaseInner;
 use crate::messages::generations::{
     AbortManualGenerationTask, CommitManualGenerationError, CommitManualGenerationTask,
-    DatabaseCollectionGenerationsTask, DropCollectionGenerationsTask, LockManualGenerationIdError,
-    LockNextGenerationIdTask, LockNextGenerationIdTaskResponse, NewCollectionGenerationsTask,
-    NewCollectionGenerationsTaskResponse, StartManualGenerationIdError,
-    StartManualGenerationIdTask,
+    CommitTransactionTask, DatabaseCollectionGenerationsTask, DropCollectionGenerationsTask,
+    LockManualGenerationIdError, LockNextGenerationIdTask, LockNextGenerationIdTaskResponse,
+    NewCollectionGenerationsTask, NewCollectionGenerationsTaskResponse,
+    StartManualGenerationIdError, StartManualGenerationIdTask, TransactionCollectionCommit,
+    UpdateCollectionSettingsTask,
 };
 use crate::util::async_task_thread::TaskPoller;
 use crate::util::indexed_container::IndexedContainer;
+use crate::util::tokio::spawn;
 use std::time::Duration;
 use tokio::sync::mpsc;
-use tokio::time::sleep;
+use tokio::time::{sleep, Instant};
+use tracing::Span;
 
 struct GenerationsThreadState {
     database: Arc<DatabaseInner>,
@@ -34,11 +38,16 @@ enum ThreadTask {
     CommitNonManualCollectionGeneration {
         collection_id: InnerGenerationsCollectionId,
     },
+    // Next generation has reached `max_generation_puts`
+    CommitFullNonManualCollectionGeneration {
+        collection_id: InnerGenerationsCollectionId,
+        expected_generation_id: OwnedGenerationId,
+    },
 }
 
 pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionGenerationsTask>) {
     let task = poller.poll().await;
-    let Some(task) = task else {
+    let Some((task, _)) = task else {
         return;
     };
 
@@ -54,7 +63,9 @@ pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionGenerationsTask
         collections: IndexedContainer::new(),
     };
 
-    while let Some(task) = poll_task(&mut poller, &mut receiver).await {
+    while let Some((task, span)) = poll_task(&mut poller, &mut receiver).await {
+        let _entered = span.enter();
+
         match task {
             ThreadTask::External(task) => match task {
                 DatabaseCollectionGenerationsTask::NewCollection(task) => {
@@ -75,6 +86,12 @@ pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionGenerationsTask
                 DatabaseCollectionGenerationsTask::CommitManualGeneration(task) => {
                     state.commit_manual_generation(task);
                 }
+                DatabaseCollectionGenerationsTask::CommitTransaction(task) => {
+                    state.commit_transaction(task);
+                }
+                DatabaseCollectionGenerationsTask::UpdateCollectionSettings(task) => {
+                    state.update_collection_settings(task);
+                }
                 DatabaseCollectionGenerationsTask::Init(_) => {}
             },
             ThreadTask::ScheduleNextGeneration {
@@ -86,20 +103,30 @@ pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionGenerationsTask
             ThreadTask::CommitNonManualCollectionGeneration { collection_id } => {
                 state.commit_non_manual_collection_generation(collection_id);
             }
+            ThreadTask::CommitFullNonManualCollectionGeneration {
+                collection_id,
+                expected_generation_id,
+            } => {
+                state.commit_full_non_manual_collection_generation(
+                    collection_id,
+                    expected_generation_id,
+                );
+            }
         }
     }
 }
 
+// Tasks of the thread itself are not related to requests, they have no span
 async fn poll_task(
     poller: &mut TaskPoller<DatabaseCollectionGenerationsTask>,
     receiver: &mut mpsc::Receiver<ThreadTask>,
-) -> Option<ThreadTask> {
+) -> Option<(ThreadTask, Span)> {
     tokio::select! {
         maybe_task = poller.poll() => {
-            return maybe_task.map(|task| ThreadTask::External(task));
+            maybe_task.map(|(task, span)| (ThreadTask::External(task), span))
         },
         maybe_task = receiver.recv() => {
-            return maybe_task;
+            maybe_task.map(|task| (task, Span::none()))
         },
     }
 }
@@ -109,15 +136,17 @@ impl GenerationsThreadState {
         let NewCollectionGenerationsTask {
             name,
             is_manual,
+            settings,
             generation_id,
             next_generation_id,
             db,
             is_deleted,
+            commits_count,
             sender,
         } = task;
 
         let id = self.collections.insert(|inner_id| {
-            InnerGenerationsCollection::new(
+            let mut collection = InnerGenerationsCollection::new(
                 inner_id,
                 name,
                 is_manual,
@@ -125,7 +154,11 @@ impl GenerationsThreadState {
                 generation_id.clone(),
                 next_generation_id,
                 is_deleted,
-            )
+            );
+            collection.settings = settings;
+            collection.commits_count = commits_count;
+
+            collection
         });
 
         let item = self.collections.get(&id).unwrap();
@@ -147,7 +180,7 @@ impl GenerationsThreadState {
         let is_need_to_schedule_generation =
             item.is_need_to_schedule_generation(generation_id.incremented());
 
-        tokio::spawn(async move {
+        spawn(async move {
             let need_to_schedule = match is_need_to_schedule_generation.await {
                 Ok(need_to_schedule) => need_to_schedule,
                 Err(err) => {
@@ -236,7 +269,7 @@ impl GenerationsThreadState {
 
         let fut = item.start_manual_generation(next_generation_id, abort_outdated);
 
-        tokio::spawn(async move {
+        spawn(async move {
             let result = fut.await;
 
             match result {
@@ -268,7 +301,7 @@ impl GenerationsThreadState {
 
         let thread_task_sender = self.sender.clone();
 
-        tokio::spawn(async move {
+        spawn(async move {
             let locked = match locked.await {
                 Ok(locked) => locked,
                 Err(err) => {
@@ -323,15 +356,44 @@ impl GenerationsThreadState {
             return;
         };
 
-        let NextGenerationScheduleAction::NeedSchedule = item.schedule_next_generation(expected_generation_id) else {
+        let action = item.schedule_next_generation(expected_generation_id.clone());
+
+        if item.is_scheduled_for(&expected_generation_id) {
+            let is_full = item.is_next_generation_full(expected_generation_id.incremented());
+
+            if let Some(is_full) = is_full {
+                let thread_task_sender = self.sender.clone();
+
+                spawn(async move {
+                    let Ok(true) = is_full.await else {
+                        return;
+                    };
+
+                    thread_task_sender
+                        .send(ThreadTask::CommitFullNonManualCollectionGeneration {
+                            collection_id,
+                            expected_generation_id,
+                        })
+                        .await
+                        .unwrap_or(());
+                });
+            }
+        }
+
+        let NextGenerationScheduleAction::NeedSchedule = action else {
             return;
         };
 
+        let commit_delay = item.settings.commit_delay;
+
+        self.commit_after(collection_id, commit_delay);
+    }
+
+    fn commit_after(&self, collection_id: InnerGenerationsCollectionId, delay: Duration) {
         let thread_task_sender = self.sender.clone();
 
-        tokio::spawn(async move {
-            // TODO: move to the config, or better to collection settings
-            sleep(Duration::from_millis(50)).await;
+        spawn(async move {
+            sleep(delay).await;
 
             thread_task_sender
                 .send(ThreadTask::CommitNonManualCollectionGeneration { collection_id })
@@ -348,7 +410,49 @@ impl GenerationsThreadState {
             return;
         };
 
-        item.commit_next_generation();
+        // There were puts after scheduling or settings were changed
+        if let Some(delay) = item.time_until_commit(Instant::now()) {
+            self.commit_after(collection_id, delay);
+            return;
+        }
+
+        item.commit_next_generation(self.database.clone());
+    }
+
+    fn commit_full_non_manual_collection_generation(
+        &mut self,
+        collection_id: InnerGenerationsCollectionId,
+        expected_generation_id: OwnedGenerationId,
+    ) {
+        let Some(item) = self.collections.get_mut(&collection_id) else {
+            return;
+        };
+
+        if !item.is_scheduled_for(&expected_generation_id) {
+            return;
+        }
+
+        item.commit_next_generation(self.database.clone());
+    }
+
+    fn update_collection_settings(&mut self, task: UpdateCollectionSettingsTask) {
+        let UpdateCollectionSettingsTask {
+            collection_id,
+            settings,
+            sender,
+        } = task;
+
+        if let Some(item) = self.collections.get_mut(&collection_id) {
+            item.settings = settings;
+
+            // Scheduled commit time could become earlier
+            match item.time_until_commit(Instant::now()) {
+                Some(delay) => self.commit_after(collection_id, delay),
+                None => item.commit_next_generation(self.database.clone()),
+            }
+        }
+
+        sender.send(()).unwrap_or(());
     }
 
     fn abort_manual_generation(&mut self, task: AbortManualGenerationTask) {
@@ -365,7 +469,7 @@ impl GenerationsThreadState {
 
         let aborting = item.abort_manual_generation(generation_id);
 
-        tokio::spawn(async move {
+        spawn(async move {
             let result = aborting.await;
 
             sender.send(result).unwrap_or(());
@@ -388,10 +492,91 @@ impl GenerationsThreadState {
         let committing =
             item.commit_manual_generation(self.database.clone(), generation_id, update_readers);
 
-        tokio::spawn(async move {
+        spawn(async move {
             let result = committing.await;
 
             sender.send(result).unwrap_or(());
         });
     }
+
+    fn commit_transaction(&mut self, task: CommitTransactionTask) {
+        let CommitTransactionTask {
+            collections,
+            transaction_id,
+            record,
+            sender,
+        } = task;
+
+        let mut lockings = Vec::with_capacity(collections.len());
+
+        for TransactionCollectionCommit {
+            collection_id,
+            generation_id,
+            update_readers,
+        } in collections
+        {
+            let Some(item) = self.collections.get_mut(&collection_id) else {
+                sender.send(Err(CommitManualGenerationError::NoSuchCollection)).unwrap_or(());
+                return;
+            };
+
+            lockings.push(item.lock_manual_generation_commit(
+                self.database.clone(),
+                generation_id,
+                update_readers,
+            ));
+        }
+
+        let database = self.database.clone();
+
+        spawn(async move {
+            let result = commit_transaction(database, lockings, transaction_id, record).await;
+
+            sender.send(result).unwrap_or(());
+        });
+    }
+}
+
+async fn commit_transaction(
+    database: Arc<DatabaseInner>,
+    lockings: Vec<
+        impl Future<Output = Result<ManualGenerationCommit, CommitManualGenerationError>>,
+    >,
+    transaction_id: String,
+    record: Vec<u8>,
+) -> Result<(), CommitManualGenerationError> {
+    let _backup_guard = database.backup_lock.read().await;
+
+    // All generations are locked and checked before anything is committed
+    let mut commits = Vec::with_capacity(lockings.len());
+    for locking in lockings {
+        commits.push(locking.await?);
+    }
+
+    // If process will crash after this, commit will be finished on `Database::open`
+    database
+        .put_transaction_record(&transaction_id, record)
+        .await
+        .map_err(CommitManualGenerationError::RawDb)?;
+
+    // Only writes can fail here, other generations are still committed,
+    // failed ones are left to `Database::open` together with the record
+    let mut error = None;
+    for commit in commits {
+        if let Err(err) = commit.commit().await {
+            tracing::error!(?err, transaction_id, "transaction commit error");
+            error.get_or_insert(err);
+        }
+    }
+
+    match error {
+        None => database
+            .finish_transaction(&transaction_id)
+            .await
+            .map_err(CommitManualGenerationError::RawDb),
+        Some(CommitManualGenerationError::RawDb(err)) => {
+            Err(CommitManualGenerationError::TransactionInterrupted(err))
+        }
+        Some(err) => Err(err),
+    }
 }
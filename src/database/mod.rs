@@ -2,6 +2,7 @@ use crate::collection::Collection;
 
 use crate::database::config::DatabaseConfig;
 pub use crate::database::database_inner::{DatabaseInner, GetReaderGenerationIdFnError};
+use crate::database::transforms::RunningTransform;
 use crate::raw_db::RawDb;
 use crate::util::atomic_cleanup::AtomicCleanup;
 use std::collections::HashMap;
@@ -9,17 +10,26 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::{watch, Mutex, RwLock};
 
+pub mod backup;
+pub mod clone_collection;
 pub mod config;
 pub mod constants;
 pub mod create_collection;
 pub mod cursors;
 mod database_inner;
 mod drop;
+pub mod dump;
 mod garbage_collector;
 pub mod generations;
 pub mod list_collections;
 pub mod open;
 mod readers;
+pub mod readers_status;
+pub mod rename_collection;
+pub mod transaction;
+pub mod transforms;
+pub mod trash;
+pub mod update_collection_settings;
 
 pub struct Database {
     config: Arc<DatabaseConfig>,
@@ -28,5 +38,6 @@ pub struct Database {
     collections_alter_lock: Mutex<()>,
     collections: Arc<RwLock<HashMap<String, Arc<Collection>>>>,
     inner: Arc<DatabaseInner>,
+    transforms: Mutex<HashMap<String, RunningTransform>>,
     stop_sender: AtomicCleanup<watch::Sender<bool>>,
 }
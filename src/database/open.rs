@@ -1,26 +1,33 @@
 use crate::collection::open::{CollectionOpenError, CollectionOpenOptions};
+use crate::collection::settings::CollectionSettings;
 use crate::collection::Collection;
 
 use crate::collection::methods::errors::CollectionMethodError;
+use crate::database::clone_collection::get_tmp_path;
 use crate::database::config::DatabaseConfig;
 use crate::database::constants::DATABASE_RAW_DB_CF;
 use crate::database::cursors::start_cursors_task_thread;
+use crate::database::database_inner::DatabaseInnerNewOptions;
 use crate::database::garbage_collector::start_garbage_collector_task_thread;
 use crate::database::generations::start_generations_task_thread;
 use crate::database::readers::start_readers_task_thread;
+use crate::database::transforms::TransformError;
+use crate::database::trash::{remove_dir_if_exists, TrashError};
 use crate::database::{Database, DatabaseInner};
 use crate::messages::garbage_collector::DatabaseGarbageCollectorTask;
 use crate::messages::generations::DatabaseCollectionGenerationsTask;
 use crate::messages::readers::DatabaseCollectionReadersTask;
 use crate::protos::database_meta::CollectionRecord;
 use crate::raw_db::{RawDb, RawDbError, RawDbOptions};
-use crate::util::async_spawns::run_when_watch_is_true_or_end;
+use crate::util::async_spawns::{run_when_watch_is_true_or_end, watch_is_true_or_end};
 use crate::util::atomic_cleanup::AtomicCleanup;
+use crate::util::tokio::spawn;
 use protobuf::Message;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::{watch, Mutex, RwLock};
+use tokio::time::sleep;
 
 pub struct DatabaseOpenOptions<'a> {
     pub data_path: &'a PathBuf,
@@ -34,6 +41,8 @@ pub enum DatabaseOpenError {
     CollectionsReading,
     CollectionRawDbDeletion(std::io::Error),
     CollectionMethod(CollectionMethodError),
+    Trash(TrashError),
+    Transform(TransformError),
 }
 
 impl Database {
@@ -47,16 +56,12 @@ impl Database {
             path: meta_raw_db_path,
             comparator: None,
             column_families: vec![],
+            config: &options.config.raw_db,
         })
         .expect("Cannot open meta raw_db");
 
         let database_raw_db = Arc::new(meta_raw_db);
 
-        let collection_records = database_raw_db
-            .get_range_cf(DATABASE_RAW_DB_CF, b"collection:", b"collection;")
-            .await
-            .map_err(|err| DatabaseOpenError::RawDb(err))?;
-
         let collections_arc = Arc::new(RwLock::new(HashMap::new()));
         let mut collections_lock = collections_arc.write().await;
 
@@ -69,17 +74,31 @@ impl Database {
 
         let (stop_sender, stop_receiver) = watch::channel(false);
 
-        let database_inner = Arc::new(DatabaseInner::new(
-            options.config.clone(),
-            collections_for_deletion.clone(),
-            database_raw_db.clone(),
-            collections_arc.clone(),
+        let database_inner = Arc::new(DatabaseInner::new(DatabaseInnerNewOptions {
+            config: options.config.clone(),
+            data_path: data_path.clone(),
+            collections_for_deletion: collections_for_deletion.clone(),
+            database_raw_db: database_raw_db.clone(),
+            collections: collections_arc.clone(),
             readers,
             cursors,
             generations,
             garbage_collector,
-            stop_receiver.clone(),
-        ));
+            stop_receiver: stop_receiver.clone(),
+        }));
+
+        // Task threads are stopped on drop, so they are not left running
+        // if opening fails below
+        let database = Database {
+            config: options.config.clone(),
+            data_path: data_path.clone(),
+            database_raw_db: database_raw_db.clone(),
+            collections_alter_lock: Mutex::new(()),
+            collections: collections_arc.clone(),
+            inner: database_inner.clone(),
+            transforms: Mutex::new(HashMap::new()),
+            stop_sender: AtomicCleanup::some(stop_sender),
+        };
 
         database_inner
             .add_readers_task(DatabaseCollectionReadersTask::Init(database_inner.clone()))
@@ -95,6 +114,31 @@ impl Database {
             .add_gc_task(DatabaseGarbageCollectorTask::Init(database_inner.clone()))
             .await;
 
+        database_inner
+            .recover_interrupted_restores_sync()
+            .map_err(DatabaseOpenError::Trash)?;
+
+        // Not finished clones
+        remove_dir_if_exists(&get_tmp_path(data_path))
+            .map_err(DatabaseOpenError::CollectionRawDbDeletion)?;
+
+        // Collection files and records of not finished renames are moved before
+        // opening of collections, readers are updated when all collections are opened
+        let renamed_collections = database_inner
+            .get_rename_marks_sync()
+            .map_err(DatabaseOpenError::RawDb)?;
+
+        for (collection_name, new_collection_name) in &renamed_collections {
+            let _: () = database_inner
+                .move_renamed_collection_sync(collection_name, new_collection_name)
+                .map_err(DatabaseOpenError::CollectionMethod)?;
+        }
+
+        let collection_records = database_raw_db
+            .get_range_cf(DATABASE_RAW_DB_CF, b"collection:", b"collection;")
+            .await
+            .map_err(DatabaseOpenError::RawDb)?;
+
         let mut deleted_collections = Vec::new();
 
         for (_, value) in collection_records {
@@ -103,24 +147,27 @@ impl Database {
 
             let id = record.id;
 
-            let is_deleted = database_inner
-                .is_marked_for_deletion_sync(id.as_str())
-                .map_err(|err| DatabaseOpenError::RawDb(err))?;
+            let deletion_mark = database_inner
+                .get_deletion_mark_sync(id.as_str())
+                .map_err(DatabaseOpenError::RawDb)?;
 
-            if is_deleted {
-                let path = Collection::get_path(data_path, &id);
-                std::fs::remove_dir_all(path).or_else(|err| {
-                    match err.kind() {
-                        std::io::ErrorKind::NotFound => {
-                            return Ok(());
-                        }
-                        _ => {}
-                    }
+            if let Some(trash_id) = deletion_mark {
+                // Collections marked by older versions have no trash id, destroy them
+                let trash_id = if trash_id.is_empty() {
+                    let path = Collection::get_path(data_path, &id);
+                    remove_dir_if_exists(&path)
+                        .map_err(DatabaseOpenError::CollectionRawDbDeletion)?;
+
+                    None
+                } else {
+                    database_inner
+                        .move_collection_to_trash_sync(&id, &trash_id)
+                        .map_err(DatabaseOpenError::CollectionRawDbDeletion)?;
 
-                    Err(DatabaseOpenError::CollectionRawDbDeletion(err))
-                })?;
+                    Some(trash_id)
+                };
 
-                deleted_collections.push(Arc::from(id));
+                deleted_collections.push((Arc::from(id), trash_id));
 
                 continue;
             }
@@ -130,6 +177,8 @@ impl Database {
                 name: id.clone(),
                 data_path,
                 is_manual: record.is_manual,
+                if_value_changed: record.if_value_changed,
+                settings: CollectionSettings::from_record(&record.settings),
                 database_inner: database_inner.clone(),
             })
             .await
@@ -140,21 +189,60 @@ impl Database {
 
         drop(collections_lock);
 
-        for collection_name in deleted_collections {
+        for (collection_name, trash_id) in deleted_collections {
             let _: () = database_inner
-                .remove_readers_pointing_to_collection(Arc::clone(&collection_name))
+                .detach_readers_of_deleted_collection(Arc::clone(&collection_name))
                 .await
                 .map_err(DatabaseOpenError::CollectionMethod)?;
 
             let _: () = database_inner
-                .finish_delete_collection_sync(&collection_name)
-                .map_err(|err| DatabaseOpenError::RawDb(err))?;
+                .finish_delete_collection_sync(&collection_name, trash_id.as_deref())
+                .map_err(DatabaseOpenError::Trash)?;
+        }
+
+        for (collection_name, new_collection_name) in renamed_collections {
+            let _: () = database_inner
+                .retarget_readers_of_renamed_collection(
+                    Arc::from(collection_name.as_str()),
+                    Arc::from(new_collection_name),
+                )
+                .await
+                .map_err(DatabaseOpenError::CollectionMethod)?;
+
+            let _: () = database_inner
+                .finish_rename_collection_sync(&collection_name)
+                .map_err(DatabaseOpenError::RawDb)?;
         }
 
+        database_inner
+            .finish_interrupted_transactions()
+            .await
+            .map_err(DatabaseOpenError::CollectionMethod)?;
+
         database_inner
             .add_readers_task(DatabaseCollectionReadersTask::InitFinish)
             .await;
 
+        {
+            let database_inner = database_inner.clone();
+            let mut stop_receiver = stop_receiver.clone();
+
+            spawn(async move {
+                loop {
+                    if let Err(err) = database_inner.purge_trash().await {
+                        tracing::error!(?err, "trash purge error");
+                    }
+
+                    tokio::select! {
+                        _ = sleep(database_inner.config.trash_purge_interval) => {}
+                        _ = watch_is_true_or_end(&mut stop_receiver) => {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+
         {
             let collections_arc = collections_arc.clone();
             run_when_watch_is_true_or_end(stop_receiver, async move {
@@ -163,14 +251,11 @@ impl Database {
             });
         }
 
-        Ok(Database {
-            config: options.config,
-            data_path: data_path.clone(),
-            database_raw_db,
-            collections_alter_lock: Mutex::new(()),
-            collections: collections_arc,
-            inner: database_inner,
-            stop_sender: AtomicCleanup::some(stop_sender),
-        })
+        database
+            .start_persisted_transforms()
+            .await
+            .map_err(DatabaseOpenError::Transform)?;
+
+        Ok(database)
     }
 }
@@ -5,11 +5,5 @@ use crate::util::async_task_thread::AsyncTaskThread;
 mod thread;
 
 pub async fn start_readers_task_thread() -> AsyncTaskThread<DatabaseCollectionReadersTask> {
-    AsyncTaskThread::new(
-        run,
-        (),
-        #[cfg(feature = "debug_prints")]
-        "readers",
-    )
-    .await
+    AsyncTaskThread::new(run, (), "readers").await
 }
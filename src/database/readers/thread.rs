@@ -1,8 +1,9 @@
 use crate::common::OwnedGenerationId;
 use crate::messages::readers::{
     CollectionNameReaderName, DatabaseCollectionReadersTask, DeleteReaderTask,
-    GetMinimumGenerationIdLocksTask, GetMinimumGenerationIdLocksTaskResponse,
-    GetReadersPointingToCollectionTask, ReaderNewCollectionTask, ReaderNewCollectionTaskResponse,
+    DropCollectionReadersTask, GetMinimumGenerationIdLocksTask,
+    GetMinimumGenerationIdLocksTaskResponse, GetReadersPointingToCollectionTask,
+    ReaderNewCollectionTask, ReaderNewCollectionTaskResponse, RenameCollectionReadersTask,
     UpdateReaderTask, UpdateReadersTask,
 };
 use crate::util::async_task_thread::TaskPoller;
@@ -43,7 +44,7 @@ struct ReadersState {
 
 pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionReadersTask>) {
     let task = poller.poll().await;
-    let Some(task) = task else {
+    let Some((task, _)) = task else {
         return;
     };
 
@@ -59,7 +60,9 @@ pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionReadersTask>) {
 
     let mut is_init_finished = false;
 
-    while let Some(task) = poller.poll().await {
+    while let Some((task, span)) = poller.poll().await {
+        let _entered = span.enter();
+
         match task {
             DatabaseCollectionReadersTask::NewCollection(task) => {
                 state.new_collection(task);
@@ -85,6 +88,12 @@ pub async fn run(_: (), mut poller: TaskPoller<DatabaseCollectionReadersTask>) {
             DatabaseCollectionReadersTask::GetMinimumGenerationIdLocks(task) => {
                 state.get_minimum_generation_id_locks(task);
             }
+            DatabaseCollectionReadersTask::DropCollection(task) => {
+                state.drop_collection(task);
+            }
+            DatabaseCollectionReadersTask::RenameCollection(task) => {
+                state.rename_collection(task);
+            }
             DatabaseCollectionReadersTask::Finish => {
                 return;
             }
@@ -254,7 +263,7 @@ impl ReadersState {
 
         let collection_name_reader_name_key = ArcStringPair(owner_collection_name, reader_name);
 
-        let reader = self.all_readers.get(&collection_name_reader_name_key);
+        let reader = self.all_readers.remove(&collection_name_reader_name_key);
         let Some(reader) = reader else {
             return;
         };
@@ -275,6 +284,102 @@ impl ReadersState {
         }
     }
 
+    fn drop_collection(&mut self, task: DropCollectionReadersTask) {
+        let DropCollectionReadersTask {
+            collection_name,
+            sender,
+        } = task;
+
+        let dropped_readers: Vec<Arc<Reader>> = self
+            .all_readers
+            .values()
+            .filter(|reader| {
+                reader.owner_collection_name == collection_name
+                    || reader.to_collection_name == collection_name
+            })
+            .cloned()
+            .collect();
+
+        for reader in dropped_readers {
+            self.delete_reader(DeleteReaderTask {
+                owner_collection_name: reader.owner_collection_name.clone(),
+                reader_name: reader.reader_name.clone(),
+            });
+        }
+
+        // Collection with the same name can be created later, it should not inherit state
+        self.collections.remove(&collection_name);
+        self.changed_readers_pointing_to_collections
+            .remove(&collection_name);
+
+        sender.send(()).unwrap_or(());
+    }
+
+    fn rename_collection(&mut self, task: RenameCollectionReadersTask) {
+        let RenameCollectionReadersTask {
+            collection_name,
+            new_collection_name,
+            sender,
+        } = task;
+
+        let renamed_readers: Vec<Arc<Reader>> = self
+            .all_readers
+            .values()
+            .filter(|reader| {
+                reader.owner_collection_name == collection_name
+                    || reader.to_collection_name == collection_name
+            })
+            .cloned()
+            .collect();
+
+        for reader in &renamed_readers {
+            let collection_name_reader_name_key = ArcStringPairRef(
+                reader.owner_collection_name.as_ref(),
+                reader.reader_name.as_ref(),
+            );
+
+            self.all_readers.remove(&collection_name_reader_name_key);
+
+            if let Some(collection) = self.collections.get(&reader.to_collection_name) {
+                collection
+                    .readers_pointing_to_collection
+                    .borrow_mut()
+                    .remove(&collection_name_reader_name_key);
+            }
+        }
+
+        // Keep minimum generation id, otherwise GC could remove records
+        // that are still needed by readers
+        if let Some(collection) = self.collections.remove(&collection_name) {
+            if !self.collections.contains_key(&new_collection_name) {
+                self.collections
+                    .insert(new_collection_name.clone(), collection);
+            }
+        }
+        self.changed_readers_pointing_to_collections
+            .remove(&collection_name);
+
+        let rename = |name: &CollectionName| {
+            if *name == collection_name {
+                new_collection_name.clone()
+            } else {
+                name.clone()
+            }
+        };
+
+        for reader in renamed_readers {
+            self.update_reader(UpdateReaderTask {
+                owner_collection_name: rename(&reader.owner_collection_name),
+                to_collection_name: Some(rename(&reader.to_collection_name)),
+                reader_name: reader.reader_name.clone(),
+                generation_id: reader.generation_id.clone(),
+                sender: None,
+            });
+        }
+
+        sender.send(()).unwrap_or(());
+    }
+
     fn get_readers_pointing_to_collection_except_this_one(
         &mut self,
         task: GetReadersPointingToCollectionTask,
@@ -0,0 +1,84 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::generations_lag::GenerationsLagOk;
+use crate::collection::Collection;
+use crate::common::reader::ReaderRecord;
+use crate::database::Database;
+
+pub struct ReaderStatus {
+    pub reader: ReaderRecord,
+    // Reader without collection name points to its own collection
+    pub target_collection_name: String,
+    // Not present if the target collection was deleted
+    pub target: Option<GenerationsLagOk>,
+}
+
+impl Database {
+    pub async fn readers_status(
+        &self,
+        collection: &Collection,
+    ) -> Result<Vec<ReaderStatus>, CollectionMethodError> {
+        let readers = collection.list_readers().await?.items;
+
+        let mut result = Vec::with_capacity(readers.len());
+
+        for reader in readers {
+            result.push(self.reader_status_of(collection, reader).await?);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn reader_status(
+        &self,
+        collection: &Collection,
+        reader_name: &str,
+    ) -> Result<ReaderStatus, CollectionMethodError> {
+        let reader = collection
+            .list_readers()
+            .await?
+            .items
+            .into_iter()
+            .find(|reader| reader.reader_name == reader_name)
+            .ok_or(CollectionMethodError::NoSuchReader)?;
+
+        self.reader_status_of(collection, reader).await
+    }
+
+    async fn reader_status_of(
+        &self,
+        collection: &Collection,
+        reader: ReaderRecord,
+    ) -> Result<ReaderStatus, CollectionMethodError> {
+        let target_collection_name = reader
+            .collection_name
+            .clone()
+            .unwrap_or_else(|| collection.get_name().to_string());
+
+        let target_collection = if reader.is_orphaned {
+            None
+        } else {
+            self.get_collection(&target_collection_name).await
+        };
+
+        let target = match target_collection {
+            Some(target_collection) => {
+                match target_collection
+                    .generations_lag(reader.generation_id.clone())
+                    .await
+                {
+                    Ok(lag) => Some(lag),
+                    // Deleted while status was collected
+                    Err(CollectionMethodError::NoSuchCollection) => None,
+                    Err(err) => return Err(err),
+                }
+            }
+            None => None,
+        };
+
+        Ok(ReaderStatus {
+            reader,
+            target_collection_name,
+            target,
+        })
+    }
+}
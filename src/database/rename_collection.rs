@@ -0,0 +1,70 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::open::{CollectionOpenError, CollectionOpenOptions};
+use crate::collection::Collection;
+use crate::database::Database;
+use crate::raw_db::RawDbError;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub enum RenameCollectionError {
+    NoSuchCollection,
+    AlreadyExist,
+    RawDb(RawDbError),
+    CollectionMethod(CollectionMethodError),
+    CollectionOpen(CollectionOpenError),
+}
+
+impl Database {
+    pub async fn rename_collection(
+        &self,
+        id: &str,
+        new_id: &str,
+    ) -> Result<Arc<Collection>, RenameCollectionError> {
+        let _guard = self.collections_alter_lock.lock().await;
+
+        let collections = self.collections.read().await;
+        if collections.contains_key(new_id) {
+            return Err(RenameCollectionError::AlreadyExist);
+        }
+        let collection = collections
+            .get(id)
+            .cloned()
+            .ok_or(RenameCollectionError::NoSuchCollection)?;
+        drop(collections);
+
+        let is_manual = collection.is_manual();
+        let if_value_changed = collection.is_if_value_changed();
+        let settings = collection.get_settings().await;
+
+        let result = collection.rename_collection(new_id);
+
+        drop(collection);
+
+        result.await.map_err(|err| match err {
+            CollectionMethodError::NoSuchCollection => RenameCollectionError::NoSuchCollection,
+            err => RenameCollectionError::CollectionMethod(err),
+        })?;
+
+        let collection = Collection::open(CollectionOpenOptions {
+            config: self.config.clone(),
+            name: new_id.to_string(),
+            is_manual,
+            if_value_changed,
+            settings,
+            data_path: &self.data_path,
+            database_inner: self.inner.clone(),
+        })
+        .await
+        .map_err(RenameCollectionError::CollectionOpen)?;
+
+        let mut collections = self.collections.write().await;
+        collections.insert(new_id.to_string(), collection.clone());
+        drop(collections);
+
+        self.inner
+            .finish_rename_collection_sync(id)
+            .map_err(RenameCollectionError::RawDb)?;
+
+        Ok(collection)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::collection::methods::abort_generation::AbortGenerationOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::{Collection, CommitGenerationUpdateReader};
+use crate::common::{IsByteArray, OwnedGenerationId};
+use crate::database::trash::now_ms;
+use crate::database::Database;
+use crate::messages::generations::{
+    CommitManualGenerationError, CommitTransactionTask, DatabaseCollectionGenerationsTask,
+    TransactionCollectionCommit,
+};
+use crate::protos::database_meta::{
+    TransactionCollectionRecord, TransactionReaderUpdate, TransactionRecord,
+};
+use crate::raw_db::RawDbError;
+use crate::util::async_sync_call::async_sync_call;
+use crate::util::base62;
+use protobuf::Message;
+use std::sync::Arc;
+
+pub struct TransactionCollection {
+    pub collection_name: String,
+    pub generation_id: OwnedGenerationId,
+}
+
+pub struct CommitTransactionCollection {
+    pub collection_name: String,
+    pub generation_id: OwnedGenerationId,
+    pub update_readers: Option<Vec<CommitGenerationUpdateReader>>,
+}
+
+#[derive(Debug)]
+pub enum TransactionError {
+    NoSuchCollection(String),
+    NotManualCollection(String),
+    DuplicateCollection(String),
+    // Record is written, but not all generations are committed,
+    // they will be committed on the next `Database::open`
+    Interrupted(RawDbError),
+    Protobuf(protobuf::Error),
+    CollectionMethod(CollectionMethodError),
+}
+
+impl From<protobuf::Error> for TransactionError {
+    fn from(err: protobuf::Error) -> Self {
+        TransactionError::Protobuf(err)
+    }
+}
+
+impl From<CollectionMethodError> for TransactionError {
+    fn from(err: CollectionMethodError) -> Self {
+        TransactionError::CollectionMethod(err)
+    }
+}
+
+fn generate_transaction_id() -> String {
+    let mut id = String::with_capacity(22);
+    id.push_str(&base62::from_u64(now_ms()));
+    id.push_str(&base62::from_u64(rand::random()));
+
+    id
+}
+
+impl Database {
+    // Collections are in the order of `names`, all of them should be manual
+    async fn get_transaction_collections<'a>(
+        &self,
+        names: impl Iterator<Item = &'a str>,
+    ) -> Result<Vec<Arc<Collection>>, TransactionError> {
+        let collections = self.collections.read().await;
+
+        let mut result: Vec<Arc<Collection>> = Vec::new();
+
+        for name in names {
+            let collection = collections
+                .get(name)
+                .ok_or_else(|| TransactionError::NoSuchCollection(name.to_string()))?;
+
+            if !collection.is_manual() {
+                return Err(TransactionError::NotManualCollection(name.to_string()));
+            }
+
+            if result.iter().any(|item| item.get_name() == name) {
+                return Err(TransactionError::DuplicateCollection(name.to_string()));
+            }
+
+            result.push(collection.clone());
+        }
+
+        Ok(result)
+    }
+
+    // Generations are not started atomically, already started ones are kept on error
+    pub async fn start_transaction(
+        &self,
+        collections: Vec<TransactionCollection>,
+        abort_outdated: bool,
+    ) -> Result<(), TransactionError> {
+        let items = self
+            .get_transaction_collections(
+                collections
+                    .iter()
+                    .map(|collection| collection.collection_name.as_str()),
+            )
+            .await?;
+
+        for (collection, options) in items.into_iter().zip(collections) {
+            collection
+                .start_generation(StartGenerationOptions {
+                    generation_id: options.generation_id,
+                    abort_outdated,
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // Either all generations are committed or none of them
+    pub async fn commit_transaction(
+        &self,
+        mut collections: Vec<CommitTransactionCollection>,
+    ) -> Result<(), TransactionError> {
+        // Collections can't be renamed or cloned in the middle of the commit
+        let _guard = self.collections_alter_lock.lock().await;
+
+        // Generations are locked in the same order by all transactions
+        collections.sort_by(|a, b| a.collection_name.cmp(&b.collection_name));
+
+        let items = self
+            .get_transaction_collections(
+                collections
+                    .iter()
+                    .map(|collection| collection.collection_name.as_str()),
+            )
+            .await?;
+
+        let mut record = TransactionRecord::new();
+        let mut commits = Vec::with_capacity(collections.len());
+        let mut minimum_generation_id_locks = Vec::with_capacity(collections.len());
+
+        for (collection, options) in items.iter().zip(collections) {
+            let CommitTransactionCollection {
+                collection_name,
+                generation_id,
+                update_readers,
+            } = options;
+
+            let locks = collection
+                .lock_readers_minimum_generation_ids(update_readers.as_deref())
+                .await?;
+            minimum_generation_id_locks.push(locks);
+
+            let mut collection_record = TransactionCollectionRecord::new();
+            collection_record.collection_name = collection_name;
+            collection_record.generation_id = generation_id.get_byte_array().to_vec();
+            if let Some(update_readers) = &update_readers {
+                for update in update_readers {
+                    let mut reader_update = TransactionReaderUpdate::new();
+                    reader_update.reader_name = update.reader_name.to_string();
+                    reader_update.generation_id = update.generation_id.get_byte_array().to_vec();
+
+                    collection_record.update_readers.push(reader_update);
+                }
+            }
+            record.collections.push(collection_record);
+
+            commits.push(TransactionCollectionCommit {
+                collection_id: collection.generations_id(),
+                generation_id,
+                update_readers,
+            });
+        }
+
+        let record = record.write_to_bytes()?;
+
+        let _: () = async_sync_call(|sender| {
+            self.inner
+                .add_generations_task(DatabaseCollectionGenerationsTask::CommitTransaction(
+                    CommitTransactionTask {
+                        collections: commits,
+                        transaction_id: generate_transaction_id(),
+                        record,
+                        sender,
+                    },
+                ))
+        })
+        .await
+        .map_err(CollectionMethodError::OneshotRecv)?
+        .map_err(|err| match err {
+            CommitManualGenerationError::TransactionInterrupted(err) => {
+                TransactionError::Interrupted(err)
+            }
+            err => CollectionMethodError::from(err).into(),
+        })?;
+
+        drop(minimum_generation_id_locks);
+
+        Ok(())
+    }
+
+    // Generations are not aborted atomically, already aborted ones are kept on error
+    pub async fn abort_transaction(
+        &self,
+        collections: Vec<TransactionCollection>,
+    ) -> Result<(), TransactionError> {
+        let items = self
+            .get_transaction_collections(
+                collections
+                    .iter()
+                    .map(|collection| collection.collection_name.as_str()),
+            )
+            .await?;
+
+        for (collection, options) in items.into_iter().zip(collections) {
+            collection
+                .abort_generation(AbortGenerationOptions {
+                    generation_id: options.generation_id,
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,446 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::transforms::runner::{run_transform, TransformRunnerOptions};
+use crate::database::Database;
+use crate::protos::database_meta::transform_step_record::Step;
+use crate::protos::database_meta::{
+    TransformKeyPrefixFilter, TransformKeyRemap, TransformRecord, TransformSourceRecord,
+    TransformStepRecord, TransformValueProjection,
+};
+use crate::raw_db::RawDbError;
+use crate::transform::builtin::{KeyPrefixFilter, KeyRemap, ValueProjection};
+use crate::transform::{Transform, TransformPipeline};
+use crate::util::tokio::{spawn, spawn_blocking};
+use protobuf::Message;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+mod runner;
+
+const PREFIX: &str = "transform:";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransformSource {
+    pub collection_name: String,
+    // Reader in the target collection, created if not exists,
+    // transform continues from its position after restart
+    pub reader_name: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TransformStep {
+    KeyPrefixFilter {
+        prefix: Box<[u8]>,
+    },
+    KeyRemap {
+        from_prefix: Box<[u8]>,
+        to_prefix: Box<[u8]>,
+    },
+    ValueProjection {
+        fields: Vec<String>,
+    },
+}
+
+pub struct CreateTransformOptions {
+    pub sources: Vec<TransformSource>,
+    // Should be a manual collection, generations of it are committed by the transform
+    pub target_collection_name: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransformInfo {
+    pub name: String,
+    pub sources: Vec<TransformSource>,
+    pub target_collection_name: String,
+    // `None` for custom transforms, they are not persisted
+    pub steps: Option<Vec<TransformStep>>,
+}
+
+pub struct RunningTransform {
+    info: TransformInfo,
+    stop_sender: watch::Sender<bool>,
+}
+
+#[derive(Debug)]
+pub enum TransformError {
+    AlreadyExist,
+    NoSuchTransform,
+    NoSources,
+    NoSuchCollection(String),
+    TargetIsNotManual,
+    TargetIsSource,
+    DuplicateReader(String),
+    // Step is not known to this version, probably it was created by a newer one
+    UnknownStep,
+    RawDb(RawDbError),
+    Protobuf(protobuf::Error),
+    CollectionMethod(CollectionMethodError),
+}
+
+impl From<RawDbError> for TransformError {
+    fn from(err: RawDbError) -> Self {
+        TransformError::RawDb(err)
+    }
+}
+
+impl From<protobuf::Error> for TransformError {
+    fn from(err: protobuf::Error) -> Self {
+        TransformError::Protobuf(err)
+    }
+}
+
+impl From<CollectionMethodError> for TransformError {
+    fn from(err: CollectionMethodError) -> Self {
+        TransformError::CollectionMethod(err)
+    }
+}
+
+fn transform_record_key(name: &str) -> String {
+    let mut key = String::with_capacity(PREFIX.len() + name.len());
+    key.push_str(PREFIX);
+    key.push_str(name);
+
+    key
+}
+
+impl TransformStep {
+    pub fn to_transform(&self) -> Box<dyn Transform> {
+        match self {
+            TransformStep::KeyPrefixFilter { prefix } => Box::new(KeyPrefixFilter {
+                prefix: prefix.clone(),
+            }),
+            TransformStep::KeyRemap {
+                from_prefix,
+                to_prefix,
+            } => Box::new(KeyRemap {
+                from_prefix: from_prefix.clone(),
+                to_prefix: to_prefix.clone(),
+            }),
+            TransformStep::ValueProjection { fields } => Box::new(ValueProjection {
+                fields: fields.clone(),
+            }),
+        }
+    }
+
+    fn from_record(record: TransformStepRecord) -> Result<Self, TransformError> {
+        let Some(step) = record.step else {
+            return Err(TransformError::UnknownStep);
+        };
+
+        let step = match step {
+            Step::KeyPrefixFilter(filter) => TransformStep::KeyPrefixFilter {
+                prefix: filter.prefix.into_boxed_slice(),
+            },
+            Step::KeyRemap(remap) => TransformStep::KeyRemap {
+                from_prefix: remap.from_prefix.into_boxed_slice(),
+                to_prefix: remap.to_prefix.into_boxed_slice(),
+            },
+            Step::ValueProjection(projection) => TransformStep::ValueProjection {
+                fields: projection.fields,
+            },
+        };
+
+        Ok(step)
+    }
+
+    fn to_record(&self) -> TransformStepRecord {
+        let step = match self {
+            TransformStep::KeyPrefixFilter { prefix } => {
+                let mut filter = TransformKeyPrefixFilter::new();
+                filter.prefix = prefix.to_vec();
+                Step::KeyPrefixFilter(filter)
+            }
+            TransformStep::KeyRemap {
+                from_prefix,
+                to_prefix,
+            } => {
+                let mut remap = TransformKeyRemap::new();
+                remap.from_prefix = from_prefix.to_vec();
+                remap.to_prefix = to_prefix.to_vec();
+                Step::KeyRemap(remap)
+            }
+            TransformStep::ValueProjection { fields } => {
+                let mut projection = TransformValueProjection::new();
+                projection.fields = fields.clone();
+                Step::ValueProjection(projection)
+            }
+        };
+
+        let mut record = TransformStepRecord::new();
+        record.step = Some(step);
+
+        record
+    }
+}
+
+fn steps_to_transform(steps: &[TransformStep]) -> Arc<dyn Transform> {
+    Arc::new(TransformPipeline::new(
+        steps.iter().map(TransformStep::to_transform).collect(),
+    ))
+}
+
+impl TransformInfo {
+    fn from_record(record: TransformRecord) -> Result<Self, TransformError> {
+        let steps = record
+            .steps
+            .into_iter()
+            .map(TransformStep::from_record)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            name: record.name,
+            sources: record
+                .sources
+                .into_iter()
+                .map(|source| TransformSource {
+                    collection_name: source.collection_name,
+                    reader_name: source.reader_name,
+                })
+                .collect(),
+            target_collection_name: record.target_collection_name,
+            steps: Some(steps),
+        })
+    }
+
+    fn to_record(&self) -> TransformRecord {
+        let mut record = TransformRecord::new();
+        record.name = self.name.clone();
+        record.target_collection_name = self.target_collection_name.clone();
+
+        for source in &self.sources {
+            let mut source_record = TransformSourceRecord::new();
+            source_record.collection_name = source.collection_name.clone();
+            source_record.reader_name = source.reader_name.clone();
+
+            record.sources.push(source_record);
+        }
+
+        if let Some(steps) = &self.steps {
+            record.steps = steps.iter().map(TransformStep::to_record).collect();
+        }
+
+        record
+    }
+}
+
+impl Database {
+    pub async fn create_transform(
+        &self,
+        name: &str,
+        options: CreateTransformOptions,
+        steps: Vec<TransformStep>,
+    ) -> Result<(), TransformError> {
+        let transform = steps_to_transform(&steps);
+
+        self.add_transform(name, options, Some(steps), transform)
+            .await
+    }
+
+    // Custom transforms are not persisted, they should be started again after
+    // every `Database::open`, then they will continue from their readers
+    pub async fn start_custom_transform(
+        &self,
+        name: &str,
+        options: CreateTransformOptions,
+        transform: Arc<dyn Transform>,
+    ) -> Result<(), TransformError> {
+        self.add_transform(name, options, None, transform).await
+    }
+
+    async fn add_transform(
+        &self,
+        name: &str,
+        options: CreateTransformOptions,
+        steps: Option<Vec<TransformStep>>,
+        transform: Arc<dyn Transform>,
+    ) -> Result<(), TransformError> {
+        let mut transforms = self.transforms.lock().await;
+        if transforms.contains_key(name) {
+            return Err(TransformError::AlreadyExist);
+        }
+
+        let CreateTransformOptions {
+            sources,
+            target_collection_name,
+        } = options;
+
+        let info = TransformInfo {
+            name: name.to_string(),
+            sources,
+            target_collection_name,
+            steps,
+        };
+
+        self.prepare_transform(&info).await?;
+
+        if info.steps.is_some() {
+            let record = info.to_record().write_to_bytes()?;
+
+            self.database_raw_db
+                .put_cf(
+                    DATABASE_RAW_DB_CF,
+                    transform_record_key(name).as_bytes(),
+                    &record,
+                )
+                .await?;
+        }
+
+        self.spawn_transform(&mut transforms, info, transform);
+
+        Ok(())
+    }
+
+    // Validates collections and creates missing readers
+    async fn prepare_transform(&self, info: &TransformInfo) -> Result<(), TransformError> {
+        if info.sources.is_empty() {
+            return Err(TransformError::NoSources);
+        }
+
+        let target = self
+            .get_transform_collection(&info.target_collection_name)
+            .await?;
+
+        if !target.is_manual() {
+            return Err(TransformError::TargetIsNotManual);
+        }
+
+        for (index, source) in info.sources.iter().enumerate() {
+            if source.collection_name == info.target_collection_name {
+                return Err(TransformError::TargetIsSource);
+            }
+
+            if info.sources[..index]
+                .iter()
+                .any(|other| other.reader_name == source.reader_name)
+            {
+                return Err(TransformError::DuplicateReader(source.reader_name.clone()));
+            }
+
+            self.get_transform_collection(&source.collection_name)
+                .await?;
+        }
+
+        for source in &info.sources {
+            let result = target
+                .create_reader(CreateReaderOptions {
+                    reader_name: source.reader_name.clone(),
+                    collection_name: Some(source.collection_name.clone()),
+                    generation_id: None,
+                })
+                .await;
+
+            match result {
+                Ok(()) | Err(CollectionMethodError::ReaderAlreadyExists(_)) => {}
+                Err(err) => {
+                    return Err(TransformError::CollectionMethod(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_transform_collection(
+        &self,
+        name: &str,
+    ) -> Result<Arc<Collection>, TransformError> {
+        self.get_collection(name)
+            .await
+            .ok_or_else(|| TransformError::NoSuchCollection(name.to_string()))
+    }
+
+    fn spawn_transform(
+        &self,
+        transforms: &mut HashMap<String, RunningTransform>,
+        info: TransformInfo,
+        transform: Arc<dyn Transform>,
+    ) {
+        let (stop_sender, stop_receiver) = watch::channel(false);
+
+        spawn(run_transform(TransformRunnerOptions {
+            collections: self.collections.clone(),
+            name: info.name.clone(),
+            sources: info.sources.clone(),
+            target_collection_name: info.target_collection_name.clone(),
+            transform,
+            stop_receiver,
+            database_stop_receiver: self.inner.stop_receiver(),
+        }));
+
+        transforms.insert(info.name.clone(), RunningTransform { info, stop_sender });
+    }
+
+    // Readers of the transform are kept, they should be deleted manually if needed
+    pub async fn delete_transform(&self, name: &str) -> Result<(), TransformError> {
+        let mut transforms = self.transforms.lock().await;
+
+        let Some(transform) = transforms.remove(name) else {
+            return Err(TransformError::NoSuchTransform);
+        };
+
+        if transform.info.steps.is_some() {
+            let key = transform_record_key(name);
+            let database_raw_db = self.database_raw_db.clone();
+
+            let _: () = spawn_blocking(move || {
+                database_raw_db.delete_cf_sync(DATABASE_RAW_DB_CF, key.as_bytes())
+            })
+            .await
+            .map_err(RawDbError::from)??;
+        }
+
+        transform.stop_sender.send(true).unwrap_or(());
+
+        Ok(())
+    }
+
+    pub async fn list_transforms(&self) -> Vec<TransformInfo> {
+        let transforms = self.transforms.lock().await;
+
+        let mut items: Vec<TransformInfo> = transforms
+            .values()
+            .map(|transform| transform.info.clone())
+            .collect();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        items
+    }
+
+    pub(crate) async fn start_persisted_transforms(&self) -> Result<(), TransformError> {
+        let records = self
+            .database_raw_db
+            .get_range_cf(DATABASE_RAW_DB_CF, b"transform:", b"transform;")
+            .await?;
+
+        let mut transforms = self.transforms.lock().await;
+
+        for (key, value) in records {
+            let record = TransformRecord::parse_from_bytes(&value)?;
+
+            // Transform with unknown steps is not started, it would produce wrong records
+            let info = match TransformInfo::from_record(record) {
+                Ok(info) => info,
+                Err(err) => {
+                    let key = String::from_utf8_lossy(&key);
+                    tracing::error!(?err, %key, "transform record error");
+                    continue;
+                }
+            };
+
+            // Collections could be deleted while transform is stored, it will be retried
+            // by the runner, so the database can be opened anyway
+            if let Err(err) = self.prepare_transform(&info).await {
+                tracing::error!(?err, transform = %info.name, "transform preparation error");
+            }
+
+            let transform = steps_to_transform(info.steps.as_deref().unwrap_or(&[]));
+
+            self.spawn_transform(&mut transforms, info, transform);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::diff::{
+    AbortDiffCursorOptions, DiffOk, DiffOptions, ReadDiffCursorOptions,
+};
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::methods::update_reader::UpdateReaderOptions;
+use crate::collection::{Collection, CommitGenerationUpdateReader};
+use crate::common::generation_id::GenerationIdSource;
+use crate::common::reader::ReaderDef;
+use crate::common::{KeyValueUpdate, KeyValueUpdateNewOptions, OwnedGenerationId};
+use crate::database::generations::collection::GenerationIdNextGenerationIdPair;
+use crate::database::transforms::{TransformError, TransformSource};
+use crate::transform::{Transform, TransformItem};
+use crate::util::async_spawns::watch_is_true_or_end;
+use futures::future::select_all;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, RwLock};
+use tokio::time::sleep;
+
+// Sources are checked with this interval even if they are not changed,
+// also it is a delay before retry after errors
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct TransformRunnerOptions {
+    pub collections: Arc<RwLock<HashMap<String, Arc<Collection>>>>,
+    pub name: String,
+    pub sources: Vec<TransformSource>,
+    pub target_collection_name: String,
+    pub transform: Arc<dyn Transform>,
+    pub stop_receiver: watch::Receiver<bool>,
+    pub database_stop_receiver: watch::Receiver<bool>,
+}
+
+// Diffs every source from its reader in the target, puts transformed records
+// to a new generation of the target and commits it together with readers
+pub async fn run_transform(options: TransformRunnerOptions) {
+    let mut stop_receiver = options.stop_receiver.clone();
+    let mut database_stop_receiver = options.database_stop_receiver.clone();
+
+    loop {
+        let result = get_generation_pair_receivers(&options).await;
+
+        let wait_for_changes = match result {
+            Ok(receivers) => match run_transform_iteration(&options).await {
+                Ok(()) => Some(receivers),
+                Err(err) => {
+                    tracing::error!(?err, transform = %options.name, "transform error");
+                    None
+                }
+            },
+            Err(err) => {
+                tracing::error!(?err, transform = %options.name, "transform error");
+                None
+            }
+        };
+
+        let changed = async move {
+            let Some(mut receivers) = wait_for_changes else {
+                sleep(RETRY_INTERVAL).await;
+                return;
+            };
+
+            let changes = receivers
+                .iter_mut()
+                .map(|receiver| Box::pin(receiver.changed()));
+
+            tokio::select! {
+                _ = select_all(changes) => {}
+                _ = sleep(RETRY_INTERVAL) => {}
+            }
+        };
+
+        tokio::select! {
+            _ = changed => {}
+            _ = watch_is_true_or_end(&mut stop_receiver) => {
+                return;
+            }
+            _ = watch_is_true_or_end(&mut database_stop_receiver) => {
+                return;
+            }
+        }
+    }
+}
+
+async fn get_collection(
+    options: &TransformRunnerOptions,
+    name: &str,
+) -> Result<Arc<Collection>, TransformError> {
+    let collections = options.collections.read().await;
+
+    collections
+        .get(name)
+        .cloned()
+        .ok_or_else(|| TransformError::NoSuchCollection(name.to_string()))
+}
+
+// Receivers are marked as seen before diffs, so commits made during
+// an iteration will trigger the next one
+async fn get_generation_pair_receivers(
+    options: &TransformRunnerOptions,
+) -> Result<Vec<watch::Receiver<GenerationIdNextGenerationIdPair>>, TransformError> {
+    let mut receivers = Vec::with_capacity(options.sources.len());
+
+    for source in &options.sources {
+        let collection = get_collection(options, &source.collection_name).await?;
+
+        let mut receiver = collection.generation_pair_receiver.clone();
+        receiver.borrow_and_update();
+
+        receivers.push(receiver);
+    }
+
+    Ok(receivers)
+}
+
+async fn run_transform_iteration(options: &TransformRunnerOptions) -> Result<(), TransformError> {
+    let target = get_collection(options, &options.target_collection_name).await?;
+
+    // Started on the first put
+    let mut next_generation_id: Option<OwnedGenerationId> = None;
+    let mut update_readers = Vec::new();
+
+    for source in &options.sources {
+        let collection = get_collection(options, &source.collection_name).await?;
+
+        let mut diff = collection
+            .diff(DiffOptions {
+                from_generation_id: GenerationIdSource::Reader(ReaderDef {
+                    collection_name: Some(options.target_collection_name.clone()),
+                    reader_name: source.reader_name.clone(),
+                }),
+                to_generation_id_loose: None,
+                omit_intermediate_values: true,
+            })
+            .await?;
+
+        let from_generation_id = diff
+            .from_generation_id
+            .clone()
+            .unwrap_or_else(OwnedGenerationId::empty);
+        let to_generation_id = diff.to_generation_id.clone();
+
+        if from_generation_id == to_generation_id {
+            continue;
+        }
+
+        // Cursor should be aborted if the diff is not read to the end
+        let mut open_cursor_id: Option<Box<str>> = None;
+
+        let result = async {
+            loop {
+                let DiffOk {
+                    items, cursor_id, ..
+                } = diff;
+
+                open_cursor_id = cursor_id.clone();
+
+                let updates: Vec<KeyValueUpdate> = items
+                    .into_iter()
+                    .filter_map(|item| {
+                        options.transform.apply(
+                            &source.collection_name,
+                            TransformItem {
+                                key: item.key,
+                                value: item.to_value,
+                            },
+                        )
+                    })
+                    .map(|item| {
+                        KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                            key: item.key,
+                            value: item.value,
+                            if_not_present: false,
+                            if_value_changed: None,
+                        })
+                    })
+                    .collect();
+
+                if !updates.is_empty() {
+                    let generation_id = match &next_generation_id {
+                        Some(generation_id) => generation_id.clone(),
+                        None => {
+                            let generation_id = start_target_generation(&target).await?;
+                            next_generation_id = Some(generation_id.clone());
+                            generation_id
+                        }
+                    };
+
+                    target
+                        .put_many(CollectionPutManyOptions {
+                            items: updates,
+                            generation_id: Some(generation_id),
+                            phantom_id: None,
+                        })
+                        .await?;
+                }
+
+                let Some(cursor_id) = cursor_id else {
+                    break;
+                };
+
+                diff = collection
+                    .read_diff_cursor(ReadDiffCursorOptions { cursor_id })
+                    .await?;
+            }
+
+            Ok::<(), TransformError>(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            if let Some(cursor_id) = open_cursor_id {
+                if let Err(err) = collection
+                    .abort_diff_cursor(AbortDiffCursorOptions { cursor_id })
+                    .await
+                {
+                    tracing::error!(?err, transform = %options.name, "diff cursor abort error");
+                }
+            }
+
+            return Err(err);
+        }
+
+        update_readers.push(CommitGenerationUpdateReader {
+            reader_name: Arc::from(source.reader_name.as_str()),
+            generation_id: to_generation_id,
+        });
+    }
+
+    let Some(generation_id) = next_generation_id else {
+        // Everything was filtered out, only readers are moved
+        for update in update_readers {
+            target
+                .update_reader(UpdateReaderOptions {
+                    reader_name: update.reader_name.to_string(),
+                    generation_id: Some(update.generation_id),
+                })
+                .await?;
+        }
+
+        return Ok(());
+    };
+
+    target
+        .commit_generation(CommitGenerationOptions {
+            generation_id,
+            update_readers: Some(update_readers),
+        })
+        .await?;
+
+    Ok(())
+}
+
+// If the previous run was interrupted, the same generation will be started again
+// and records that were put to it will be overwritten
+async fn start_target_generation(target: &Collection) -> Result<OwnedGenerationId, TransformError> {
+    let current_generation_id = target.generation_pair().generation_id;
+
+    let generation_id = if current_generation_id == OwnedGenerationId::empty() {
+        OwnedGenerationId::zero_64bits().incremented()
+    } else {
+        current_generation_id.incremented()
+    };
+
+    target
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id.clone(),
+            abort_outdated: true,
+        })
+        .await?;
+
+    Ok(generation_id)
+}
@@ -0,0 +1,203 @@
+use crate::collection::open::{CollectionOpenError, CollectionOpenOptions};
+use crate::collection::settings::CollectionSettings;
+use crate::collection::Collection;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::Database;
+use crate::protos::database_meta::{CollectionRecord, TrashRecord};
+use crate::raw_db::RawDbError;
+use crate::util::base62;
+use protobuf::Message;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const TRASH_DIR: &str = "_trash";
+
+const PREFIX: &str = "trash:";
+
+type TrashRecords = Vec<(String, TrashRecord)>;
+
+type RawRecords = Vec<(Box<[u8]>, Box<[u8]>)>;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TrashItem {
+    pub id: String,
+    pub collection_name: String,
+    pub is_manual: bool,
+    // Unix timestamp in milliseconds
+    pub deleted_at: u64,
+}
+
+#[derive(Debug)]
+pub enum TrashError {
+    NoSuchCollection,
+    AlreadyExist,
+    // Trash record without collection record, contains trash id
+    InvalidRecord(String),
+    RawDb(RawDbError),
+    Protobuf(protobuf::Error),
+    Io(std::io::Error),
+    CollectionOpen(CollectionOpenError),
+}
+
+impl From<RawDbError> for TrashError {
+    fn from(err: RawDbError) -> Self {
+        TrashError::RawDb(err)
+    }
+}
+
+impl From<protobuf::Error> for TrashError {
+    fn from(err: protobuf::Error) -> Self {
+        TrashError::Protobuf(err)
+    }
+}
+
+impl From<std::io::Error> for TrashError {
+    fn from(err: std::io::Error) -> Self {
+        TrashError::Io(err)
+    }
+}
+
+pub fn get_trash_path(data_path: &Path, trash_id: &str) -> PathBuf {
+    data_path.join(TRASH_DIR).join(trash_id)
+}
+
+pub fn trash_record_key(trash_id: &str) -> String {
+    let mut key = String::with_capacity(PREFIX.len() + trash_id.len());
+    key.push_str(PREFIX);
+    key.push_str(trash_id);
+
+    key
+}
+
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// Ids are ordered by the deletion time, random part is to not collide
+// when the same collection is deleted twice in a millisecond
+pub fn generate_trash_id() -> String {
+    let mut id = String::with_capacity(22);
+    id.push_str(&base62::from_u64(now_ms()));
+    id.push_str(&base62::from_u64(rand::random()));
+
+    id
+}
+
+pub fn parse_trash_records(records: RawRecords) -> Result<TrashRecords, TrashError> {
+    records
+        .into_iter()
+        .map(|(key, value)| {
+            let trash_id = String::from_utf8_lossy(&key[PREFIX.len()..]).into_owned();
+            let record = TrashRecord::parse_from_bytes(&value)?;
+
+            Ok((trash_id, record))
+        })
+        .collect()
+}
+
+pub fn take_collection_record(
+    trash_id: &str,
+    record: TrashRecord,
+) -> Result<CollectionRecord, TrashError> {
+    record
+        .collection
+        .into_option()
+        .ok_or_else(|| TrashError::InvalidRecord(trash_id.to_string()))
+}
+
+pub fn remove_dir_if_exists(path: &Path) -> Result<(), std::io::Error> {
+    match std::fs::remove_dir_all(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+impl Database {
+    async fn read_trash_records(&self) -> Result<TrashRecords, TrashError> {
+        let records = self
+            .database_raw_db
+            .get_range_cf(DATABASE_RAW_DB_CF, b"trash:", b"trash;")
+            .await?;
+
+        parse_trash_records(records)
+    }
+
+    pub async fn trash_list(&self) -> Result<Vec<TrashItem>, TrashError> {
+        let records = self.read_trash_records().await?;
+
+        let items = records
+            .into_iter()
+            .map(|(id, record)| TrashItem {
+                id,
+                collection_name: record.collection.id.clone(),
+                is_manual: record.collection.is_manual,
+                deleted_at: record.deleted_at,
+            })
+            .collect();
+
+        Ok(items)
+    }
+
+    // Restores the most recently deleted collection with this name
+    pub async fn restore_collection(
+        &self,
+        collection_name: &str,
+    ) -> Result<Arc<Collection>, TrashError> {
+        let _trash_guard = self.inner.trash_lock.lock().await;
+        let _alter_guard = self.collections_alter_lock.lock().await;
+
+        let collections = self.collections.read().await;
+        if collections.contains_key(collection_name) {
+            return Err(TrashError::AlreadyExist);
+        }
+        drop(collections);
+
+        let records = self.read_trash_records().await?;
+
+        let (trash_id, record) = records
+            .into_iter()
+            .rev()
+            .find(|(_, record)| record.collection.id == collection_name)
+            .ok_or(TrashError::NoSuchCollection)?;
+
+        let collection_record = take_collection_record(&trash_id, record)?;
+
+        let trash_path = get_trash_path(&self.data_path, &trash_id);
+        let collection_path = Collection::get_path(&self.data_path, collection_name);
+
+        // Files are moved first, if process will crash before records update,
+        // restoration will be finished on the next `Database::open`
+        std::fs::rename(trash_path, collection_path)?;
+
+        self.inner
+            .finish_restore_collection_sync(&trash_id, &collection_record)?;
+
+        let collection = Collection::open(CollectionOpenOptions {
+            config: self.config.clone(),
+            name: collection_name.to_string(),
+            is_manual: collection_record.is_manual,
+            if_value_changed: collection_record.if_value_changed,
+            settings: CollectionSettings::from_record(&collection_record.settings),
+            data_path: &self.data_path,
+            database_inner: self.inner.clone(),
+        })
+        .await
+        .map_err(TrashError::CollectionOpen)?;
+
+        let mut collections = self.collections.write().await;
+        collections.insert(collection_name.to_string(), collection.clone());
+        drop(collections);
+
+        Ok(collection)
+    }
+
+    #[inline]
+    pub async fn purge_trash(&self) -> Result<(), TrashError> {
+        self.inner.purge_trash().await
+    }
+}
@@ -0,0 +1,79 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::settings::{CollectionSettings, CollectionSettingsUpdate};
+use crate::database::clone_collection::collection_record_key;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::Database;
+use crate::protos::database_meta::CollectionRecord;
+use crate::raw_db::RawDbError;
+use protobuf::{Message, MessageField};
+
+#[derive(Debug)]
+pub enum UpdateCollectionSettingsError {
+    NoSuchCollection,
+    RawDb(RawDbError),
+    Protobuf(protobuf::Error),
+    CollectionMethod(CollectionMethodError),
+}
+
+impl From<RawDbError> for UpdateCollectionSettingsError {
+    fn from(err: RawDbError) -> Self {
+        UpdateCollectionSettingsError::RawDb(err)
+    }
+}
+
+impl From<protobuf::Error> for UpdateCollectionSettingsError {
+    fn from(err: protobuf::Error) -> Self {
+        UpdateCollectionSettingsError::Protobuf(err)
+    }
+}
+
+impl Database {
+    // Returns settings after the update
+    pub async fn update_collection_settings(
+        &self,
+        id: &str,
+        update: CollectionSettingsUpdate,
+    ) -> Result<CollectionSettings, UpdateCollectionSettingsError> {
+        let _guard = self.collections_alter_lock.lock().await;
+
+        let collection = self
+            .get_collection(id)
+            .await
+            .ok_or(UpdateCollectionSettingsError::NoSuchCollection)?;
+
+        let collection_record_key = collection_record_key(id);
+
+        let collection_record = self
+            .database_raw_db
+            .get_cf(DATABASE_RAW_DB_CF, collection_record_key.as_bytes())
+            .await?
+            .ok_or(UpdateCollectionSettingsError::NoSuchCollection)?;
+        let mut collection_record = CollectionRecord::parse_from_bytes(&collection_record)?;
+
+        let mut settings = collection.get_settings().await;
+        settings.apply(update);
+
+        collection_record.settings = MessageField::some(settings.to_record());
+        let collection_record = collection_record.write_to_bytes()?;
+
+        self.database_raw_db
+            .put_cf(
+                DATABASE_RAW_DB_CF,
+                collection_record_key.as_bytes(),
+                &collection_record,
+            )
+            .await?;
+
+        collection
+            .update_settings(settings)
+            .await
+            .map_err(|err| match err {
+                CollectionMethodError::NoSuchCollection => {
+                    UpdateCollectionSettingsError::NoSuchCollection
+                }
+                err => UpdateCollectionSettingsError::CollectionMethod(err),
+            })?;
+
+        Ok(settings)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::http::errors::HttpError;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+// Roles are cumulative, `write` includes `read` and `admin` includes both
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum AccessRole {
+    // get, query, diff, watch, list readers, export
+    Read,
+    // put, generations, phantoms, transactions, readers changes
+    Write,
+    // create, delete, rename, clone to, update settings, restore from trash
+    Admin,
+}
+
+impl AccessRole {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "read" => Some(AccessRole::Read),
+            "write" => Some(AccessRole::Write),
+            "admin" => Some(AccessRole::Admin),
+            _ => None,
+        }
+    }
+
+    fn forbidden_error(&self) -> HttpError {
+        HttpError::Forbidden(match self {
+            AccessRole::Read => "read access is required",
+            AccessRole::Write => "write access is required",
+            AccessRole::Admin => "admin access is required",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessGrant {
+    // Collection name, `*` matches any number of any characters
+    pub pattern: String,
+    pub role: AccessRole,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct ApiToken {
+    pub name: String,
+    pub token: String,
+    pub grants: Vec<AccessGrant>,
+}
+
+// Token itself should not appear in logs
+impl Debug for ApiToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiToken")
+            .field("name", &self.name)
+            .field("grants", &self.grants)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Access {
+    // No tokens are configured, everything is allowed
+    Unrestricted,
+    Token(Arc<ApiToken>),
+}
+
+impl Access {
+    // Highest role of all grants that are matching the collection name
+    pub fn collection_role(&self, collection_name: &str) -> Option<AccessRole> {
+        match self {
+            Access::Unrestricted => Some(AccessRole::Admin),
+            Access::Token(token) => token
+                .grants
+                .iter()
+                .filter(|grant| matches_pattern(&grant.pattern, collection_name))
+                .map(|grant| grant.role)
+                .max(),
+        }
+    }
+
+    pub fn check_collection(
+        &self,
+        collection_name: &str,
+        role: AccessRole,
+    ) -> Result<(), HttpError> {
+        match self.collection_role(collection_name) {
+            Some(granted) if granted >= role => Ok(()),
+            _ => Err(role.forbidden_error()),
+        }
+    }
+
+    // For operations that are not bound to a single collection, like backups and transforms
+    pub fn check_server_admin(&self) -> Result<(), HttpError> {
+        let is_admin = match self {
+            Access::Unrestricted => true,
+            Access::Token(token) => token
+                .grants
+                .iter()
+                .any(|grant| grant.pattern == "*" && grant.role == AccessRole::Admin),
+        };
+
+        if is_admin {
+            Ok(())
+        } else {
+            Err(AccessRole::Admin.forbidden_error())
+        }
+    }
+}
+
+// `authorization` is the value of the `Authorization` header, `Bearer <token>`
+pub fn authenticate(
+    tokens: &[Arc<ApiToken>],
+    authorization: Option<&str>,
+) -> Result<Access, HttpError> {
+    if tokens.is_empty() {
+        return Ok(Access::Unrestricted);
+    }
+
+    let token = authorization
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .ok_or(HttpError::Unauthorized)?;
+
+    let mut found = None;
+
+    // All tokens are compared to not leak which of them is closer
+    for api_token in tokens {
+        if constant_time_eq(api_token.token.as_bytes(), token.as_bytes()) {
+            found = Some(api_token.clone());
+        }
+    }
+
+    found.map(Access::Token).ok_or(HttpError::Unauthorized)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+
+    // There is always at least one part
+    let first = parts.next().unwrap();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` in the pattern
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        let Some(index) = rest.find(part) else {
+            return false;
+        };
+
+        rest = &rest[(index + part.len())..];
+    }
+
+    rest.ends_with(last)
+}
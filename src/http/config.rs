@@ -0,0 +1,81 @@
+use crate::http::auth::ApiToken;
+use crate::http::constants::{
+    CREATE_COLLECTION_REQUEST_MAX_BYTES, DIFF_START_REQUEST_MAX_BYTES,
+    GET_KEYS_AROUND_REQUEST_MAX_BYTES, GET_REQUEST_MAX_BYTES, PUT_MANY_REQUEST_MAX_BYTES,
+    PUT_REQUEST_MAX_BYTES, QUERY_START_REQUEST_MAX_BYTES, READER_REQUEST_MAX_BYTES,
+};
+use std::fmt::{Display, Formatter};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ListenAddress {
+    Tcp(SocketAddr),
+    // Written as `unix:/path/to/socket`
+    Unix(PathBuf),
+}
+
+impl FromStr for ListenAddress {
+    type Err = std::net::AddrParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.strip_prefix("unix:") {
+            Some(path) if !path.is_empty() => Ok(ListenAddress::Unix(PathBuf::from(path))),
+            _ => value.parse().map(ListenAddress::Tcp),
+        }
+    }
+}
+
+impl Display for ListenAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListenAddress::Tcp(address) => write!(f, "{}", address),
+            ListenAddress::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+// PEM files, TLS is used for all TCP listeners
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpConfig {
+    pub listen_addresses: Vec<ListenAddress>,
+    pub tls: Option<TlsConfig>,
+    // Authentication is disabled if there are no tokens
+    pub tokens: Vec<Arc<ApiToken>>,
+
+    // Request body limits, in bytes
+    pub create_collection_request_max_bytes: usize,
+    pub get_request_max_bytes: usize,
+    pub get_keys_around_request_max_bytes: usize,
+    pub put_request_max_bytes: usize,
+    pub put_many_request_max_bytes: usize,
+    pub query_start_request_max_bytes: usize,
+    pub diff_start_request_max_bytes: usize,
+    pub reader_request_max_bytes: usize,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            listen_addresses: vec![ListenAddress::Tcp(SocketAddr::from(([127, 0, 0, 1], 3030)))],
+            tls: None,
+            tokens: Vec::new(),
+            create_collection_request_max_bytes: CREATE_COLLECTION_REQUEST_MAX_BYTES,
+            get_request_max_bytes: GET_REQUEST_MAX_BYTES,
+            get_keys_around_request_max_bytes: GET_KEYS_AROUND_REQUEST_MAX_BYTES,
+            put_request_max_bytes: PUT_REQUEST_MAX_BYTES,
+            put_many_request_max_bytes: PUT_MANY_REQUEST_MAX_BYTES,
+            query_start_request_max_bytes: QUERY_START_REQUEST_MAX_BYTES,
+            diff_start_request_max_bytes: DIFF_START_REQUEST_MAX_BYTES,
+            reader_request_max_bytes: READER_REQUEST_MAX_BYTES,
+        }
+    }
+}
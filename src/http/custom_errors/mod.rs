@@ -1,5 +1,54 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::common::GenerationId;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::errors::HttpError;
+use crate::util::str_serialization::StrSerializationType;
+use serde::Serialize;
 
 pub fn no_such_collection_error() -> HttpError {
     HttpError::CustomJson400(r#"{"error":"noSuchCollection"}"#)
 }
+
+pub fn outdated_generation_error() -> HttpError {
+    HttpError::CustomJson400(r#"{"error":"outdatedGeneration"}"#)
+}
+
+pub fn orphaned_reader_error() -> HttpError {
+    HttpError::CustomJson400(r#"{"error":"orphanedReader"}"#)
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerationIdLessThanMinimumJsonData {
+    error: &'static str,
+    minimum_generation_id: EncodedGenerationIdJsonData,
+}
+
+pub fn generation_id_less_than_minimum_error(minimum_generation_id: GenerationId<'_>) -> HttpError {
+    let data = GenerationIdLessThanMinimumJsonData {
+        error: "generationIdLessThanMinimum",
+        minimum_generation_id: EncodedGenerationIdJsonData::encode(
+            minimum_generation_id,
+            StrSerializationType::Utf8,
+        ),
+    };
+
+    match serde_json::to_string(&data) {
+        Ok(json) => HttpError::CustomJsonString400(json),
+        Err(_) => HttpError::PublicInternal500("generationIdLessThanMinimum serialization"),
+    }
+}
+
+// Errors of collection methods that are common for all routes,
+// the rest are logged and not exposed to the client
+pub fn collection_method_error(err: CollectionMethodError, operation: &str) -> HttpError {
+    match err {
+        CollectionMethodError::GenerationIdLessThanMinimum(minimum_generation_id) => {
+            generation_id_less_than_minimum_error(minimum_generation_id.as_ref())
+        }
+        err => {
+            tracing::error!(?err, "{} error", operation);
+            HttpError::Unspecified
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::collection::settings::{CollectionSettings, CollectionSettingsUpdate};
+use crate::http::errors::HttpError;
+use crate::util::json::serde::deserialize_nullable;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionSettingsJsonData {
+    pub commit_delay_ms: u64,
+    pub max_generation_age_ms: Option<u64>,
+    pub max_generation_puts: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionSettingsUpdateJsonData {
+    pub commit_delay_ms: Option<u64>,
+    // `null` resets the value
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub max_generation_age_ms: Option<Option<u64>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub max_generation_puts: Option<Option<u32>>,
+}
+
+impl From<CollectionSettings> for CollectionSettingsJsonData {
+    fn from(settings: CollectionSettings) -> Self {
+        Self {
+            commit_delay_ms: settings.commit_delay.as_millis() as u64,
+            max_generation_age_ms: settings
+                .max_generation_age
+                .map(|duration| duration.as_millis() as u64),
+            max_generation_puts: settings.max_generation_puts.map(NonZeroU32::get),
+        }
+    }
+}
+
+impl CollectionSettingsUpdateJsonData {
+    pub fn into_update(self) -> Result<CollectionSettingsUpdate, HttpError> {
+        let max_generation_puts = match self.max_generation_puts {
+            Some(Some(puts)) => Some(Some(
+                NonZeroU32::new(puts)
+                    .ok_or(HttpError::Generic400("maxGenerationPuts should be > 0"))?,
+            )),
+            Some(None) => Some(None),
+            None => None,
+        };
+
+        Ok(CollectionSettingsUpdate {
+            commit_delay: self.commit_delay_ms.map(Duration::from_millis),
+            max_generation_age: self
+                .max_generation_age_ms
+                .map(|age| age.map(Duration::from_millis)),
+            max_generation_puts,
+        })
+    }
+}
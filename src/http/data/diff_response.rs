@@ -4,17 +4,17 @@ use crate::common::GenerationId;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::key_value_diff::KeyValueDiffJsonData;
 use crate::util::str_serialization::StrSerializationType;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DiffResponseJsonData {
-    from_generation_id: EncodedGenerationIdJsonData,
-    to_generation_id: EncodedGenerationIdJsonData,
-    items: Vec<KeyValueDiffJsonData>,
-    cursor_id: Option<Box<str>>,
+    pub from_generation_id: EncodedGenerationIdJsonData,
+    pub to_generation_id: EncodedGenerationIdJsonData,
+    pub items: Vec<KeyValueDiffJsonData>,
+    pub cursor_id: Option<Box<str>>,
 }
 
 impl From<DiffOk> for DiffResponseJsonData {
@@ -9,8 +9,8 @@ use serde_with::skip_serializing_none;
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EncodedGenerationIdJsonData {
-    value: String,
-    encoding: Option<String>,
+    pub value: String,
+    pub encoding: Option<String>,
 }
 
 impl EncodedGenerationIdJsonData {
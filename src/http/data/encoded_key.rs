@@ -9,8 +9,8 @@ use serde_with::skip_serializing_none;
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EncodedKeyJsonData {
-    value: String,
-    encoding: Option<String>,
+    pub value: String,
+    pub encoding: Option<String>,
 }
 
 impl EncodedKeyJsonData {
@@ -47,4 +47,8 @@ impl EncodedKeyJsonData {
             )))
         })
     }
+
+    pub fn into_collection_key(self) -> Result<OwnedCollectionKey, HttpError> {
+        self.decode(&StringDecoder::new(StrSerializationType::Utf8))
+    }
 }
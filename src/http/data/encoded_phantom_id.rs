@@ -10,8 +10,8 @@ use serde_with::skip_serializing_none;
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EncodedPhantomIdJsonData {
-    value: String,
-    encoding: Option<String>,
+    pub value: String,
+    pub encoding: Option<String>,
 }
 
 impl EncodedPhantomIdJsonData {
@@ -10,8 +10,8 @@ use serde_with::skip_serializing_none;
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EncodedValueJsonData {
-    value: String,
-    encoding: Option<String>,
+    pub value: String,
+    pub encoding: Option<String>,
 }
 
 impl From<OwnedCollectionValue> for EncodedValueJsonData {
@@ -37,21 +37,6 @@ impl EncodedValueJsonData {
         }
     }
 
-    pub fn encode_opt_vec(items: Vec<Option<OwnedCollectionValue>>) -> Vec<Option<Self>> {
-        let mut result = Vec::with_capacity(items.len());
-
-        for item in items {
-            let Some(value) = item else {
-                result.push(None);
-                continue;
-            };
-
-            result.push(Some(Self::encode(value)));
-        }
-
-        result
-    }
-
     pub fn into_collection_value(self) -> Result<OwnedCollectionValue, HttpError> {
         let encoding = StrSerializationType::from_opt_str(self.encoding)
             .map_err(|_| HttpError::Generic400("invalid encoding"))?;
@@ -2,15 +2,15 @@ use crate::common::KeyValue;
 
 use crate::http::data::encoded_key::EncodedKeyJsonData;
 use crate::http::data::encoded_value::EncodedValueJsonData;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KeyValueJsonData {
-    key: EncodedKeyJsonData,
-    value: EncodedValueJsonData,
+    pub key: EncodedKeyJsonData,
+    pub value: EncodedValueJsonData,
 }
 
 impl From<KeyValue> for KeyValueJsonData {
@@ -1,19 +1,48 @@
-use crate::common::{KeyValueDiff, OwnedCollectionValue};
+use crate::common::{IntermediateValue, KeyValueDiff, OwnedCollectionValue};
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::encoded_value::EncodedValueJsonData;
 
 use crate::http::data::encoded_key::EncodedKeyJsonData;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::util::json::serde::deserialize_nullable;
+use crate::util::str_serialization::StrSerializationType;
+
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KeyValueDiffJsonData {
-    key: EncodedKeyJsonData,
+    pub key: EncodedKeyJsonData,
+
+    // `null` if the key was absent
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub from_value: Option<Option<EncodedValueJsonData>>,
+    pub intermediate_values: Vec<IntermediateValueJsonData>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub to_value: Option<Option<EncodedValueJsonData>>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntermediateValueJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
 
-    from_value: Option<Option<EncodedValueJsonData>>,
-    intermediate_values: Vec<Option<EncodedValueJsonData>>,
-    to_value: Option<Option<EncodedValueJsonData>>,
+    #[serialize_always]
+    pub value: Option<EncodedValueJsonData>,
+}
+
+impl From<IntermediateValue> for IntermediateValueJsonData {
+    fn from(item: IntermediateValue) -> Self {
+        Self {
+            generation_id: EncodedGenerationIdJsonData::encode(
+                item.generation_id.as_ref(),
+                StrSerializationType::Utf8,
+            ),
+            value: item.value.map(EncodedValueJsonData::from),
+        }
+    }
 }
 
 impl From<KeyValueDiff> for KeyValueDiffJsonData {
@@ -21,7 +50,11 @@ impl From<KeyValueDiff> for KeyValueDiffJsonData {
         Self {
             key: EncodedKeyJsonData::encode(kv.key),
             from_value: opt_value_to_nullable_encoded_value(kv.from_value),
-            intermediate_values: EncodedValueJsonData::encode_opt_vec(kv.intermediate_values),
+            intermediate_values: kv
+                .intermediate_values
+                .into_iter()
+                .map(IntermediateValueJsonData::from)
+                .collect(),
             to_value: opt_value_to_nullable_encoded_value(kv.to_value),
         }
     }
@@ -5,18 +5,31 @@ use crate::util::json::serde::deserialize_strict_null;
 
 use crate::http::data::encoded_key::EncodedKeyJsonData;
 use crate::http::data::encoded_value::EncodedValueJsonData;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KeyValueUpdateJsonData {
-    key: EncodedKeyJsonData,
-    if_not_present: Option<bool>,
+    pub key: EncodedKeyJsonData,
+    pub if_not_present: Option<bool>,
+    pub if_value_changed: Option<bool>,
 
+    #[serialize_always]
     #[serde(deserialize_with = "deserialize_strict_null")]
-    value: Option<EncodedValueJsonData>,
+    pub value: Option<EncodedValueJsonData>,
+}
+
+impl From<KeyValueUpdate> for KeyValueUpdateJsonData {
+    fn from(update: KeyValueUpdate) -> Self {
+        Self {
+            key: EncodedKeyJsonData::encode(update.key.into_owned()),
+            if_not_present: update.if_not_present.then_some(true),
+            if_value_changed: update.if_value_changed,
+            value: update.value.map(EncodedValueJsonData::encode),
+        }
+    }
 }
 
 impl KeyValueUpdateJsonData {
@@ -30,6 +43,7 @@ impl KeyValueUpdateJsonData {
             key,
             value,
             if_not_present,
+            if_value_changed: self.if_value_changed,
         }))
     }
 }
@@ -1,3 +1,4 @@
+pub mod collection_settings;
 pub mod diff_response;
 pub mod encoded_generation_id;
 pub mod encoded_key;
@@ -6,5 +7,7 @@ pub mod encoded_value;
 pub mod key_value;
 pub mod key_value_diff;
 pub mod key_value_update;
+pub(crate) mod protobuf;
 pub mod query_response;
 pub mod reader_record;
+pub mod reader_status;
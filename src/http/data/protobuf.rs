@@ -0,0 +1,112 @@
+use crate::collection::methods::diff::DiffOk;
+use crate::collection::methods::query::QueryOk;
+use crate::common::{
+    IntermediateValue, IsByteArray, KeyValue, KeyValueDiff, KeyValueUpdate,
+    KeyValueUpdateNewOptions, OwnedCollectionKey, OwnedCollectionValue, OwnedGenerationId,
+    OwnedPhantomId,
+};
+use crate::http::errors::HttpError;
+use crate::protos::http_api;
+
+pub fn decode_key(bytes: Vec<u8>) -> Result<OwnedCollectionKey, HttpError> {
+    OwnedCollectionKey::from_boxed_slice(bytes.into_boxed_slice()).or(Err(HttpError::Generic400(
+        "invalid key, length should be <= 16777215",
+    )))
+}
+
+pub fn decode_generation_id(bytes: Vec<u8>) -> Result<OwnedGenerationId, HttpError> {
+    OwnedGenerationId::from_boxed_slice(bytes.into_boxed_slice())
+        .or(Err(HttpError::Generic400("invalid generationId size")))
+}
+
+pub fn decode_phantom_id(bytes: Vec<u8>) -> Result<OwnedPhantomId, HttpError> {
+    if bytes.is_empty() {
+        return Err(HttpError::Generic400(
+            "invalid phantomId, it cannot be empty",
+        ));
+    }
+
+    OwnedPhantomId::from_boxed_slice(bytes.into_boxed_slice()).or(Err(HttpError::Generic400(
+        "invalid phantomId, length should be <= 255",
+    )))
+}
+
+pub fn decode_key_value_update(
+    update: http_api::KeyValueUpdate,
+) -> Result<KeyValueUpdate, HttpError> {
+    Ok(KeyValueUpdate::new(KeyValueUpdateNewOptions {
+        key: decode_key(update.key)?,
+        value: update.value.map(|value| OwnedCollectionValue::new(&value)),
+        if_not_present: update.if_not_present.unwrap_or(false),
+        if_value_changed: update.if_value_changed,
+    }))
+}
+
+pub fn encode_key_value(kv: KeyValue) -> http_api::KeyValue {
+    let mut result = http_api::KeyValue::new();
+    result.key = kv.key.get_byte_array().to_vec();
+    result.value = kv.value.get_value().to_vec();
+
+    result
+}
+
+fn encode_value(value: Option<OwnedCollectionValue>) -> Option<Vec<u8>> {
+    value.map(|value| value.get_value().to_vec())
+}
+
+pub fn encode_key_value_diff(kv: KeyValueDiff) -> http_api::KeyValueDiff {
+    let mut result = http_api::KeyValueDiff::new();
+    result.key = kv.key.get_byte_array().to_vec();
+    result.from_value = encode_value(kv.from_value);
+    result.intermediate_values = kv
+        .intermediate_values
+        .into_iter()
+        .map(|item: IntermediateValue| {
+            let mut value = http_api::IntermediateValue::new();
+            value.generation_id = item.generation_id.get_byte_array().to_vec();
+            value.value = encode_value(item.value);
+            value
+        })
+        .collect();
+    result.to_value = encode_value(kv.to_value);
+
+    result
+}
+
+impl From<QueryOk> for http_api::QueryResponse {
+    fn from(data: QueryOk) -> Self {
+        let QueryOk {
+            generation_id,
+            items,
+            cursor_id,
+        } = data;
+
+        let mut result = Self::new();
+        result.generation_id = generation_id.get_byte_array().to_vec();
+        result.items = items.into_iter().map(encode_key_value).collect();
+        result.cursor_id = cursor_id.map(String::from);
+
+        result
+    }
+}
+
+impl From<DiffOk> for http_api::DiffResponse {
+    fn from(data: DiffOk) -> Self {
+        let DiffOk {
+            from_generation_id,
+            to_generation_id,
+            items,
+            cursor_id,
+        } = data;
+
+        let mut result = Self::new();
+        result.from_generation_id = from_generation_id
+            .map(|generation_id| generation_id.get_byte_array().to_vec())
+            .unwrap_or_default();
+        result.to_generation_id = to_generation_id.get_byte_array().to_vec();
+        result.items = items.into_iter().map(encode_key_value_diff).collect();
+        result.cursor_id = cursor_id.map(String::from);
+
+        result
+    }
+}
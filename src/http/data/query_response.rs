@@ -2,16 +2,16 @@ use crate::collection::methods::query::QueryOk;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::key_value::KeyValueJsonData;
 use crate::util::str_serialization::StrSerializationType;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryResponseJsonData {
-    generation_id: EncodedGenerationIdJsonData,
-    items: Vec<KeyValueJsonData>,
-    cursor_id: Option<Box<str>>,
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub items: Vec<KeyValueJsonData>,
+    pub cursor_id: Option<Box<str>>,
 }
 
 impl From<QueryOk> for QueryResponseJsonData {
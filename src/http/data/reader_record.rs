@@ -8,7 +8,7 @@ use crate::util::str_serialization::StrSerializationType;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReaderDiffFromDefJsonData {
     pub reader_name: String,
@@ -16,19 +16,20 @@ pub struct ReaderDiffFromDefJsonData {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReaderRecordJsonData {
-    reader_name: String,
-    collection_name: Option<String>,
-    generation_id: EncodedGenerationIdJsonData,
+    pub reader_name: String,
+    pub collection_name: Option<String>,
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub is_orphaned: Option<bool>,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateReaderJsonData {
-    reader_name: String,
-    generation_id: EncodedGenerationIdJsonData,
+    pub reader_name: String,
+    pub generation_id: EncodedGenerationIdJsonData,
 }
 
 impl From<ReaderRecord> for ReaderRecordJsonData {
@@ -37,6 +38,7 @@ impl From<ReaderRecord> for ReaderRecordJsonData {
             reader_name,
             collection_name,
             generation_id,
+            is_orphaned,
         } = kv;
 
         Self {
@@ -46,6 +48,7 @@ impl From<ReaderRecord> for ReaderRecordJsonData {
                 GenerationId::from_opt_owned(&generation_id).unwrap_or(GenerationId::empty()),
                 StrSerializationType::Utf8,
             ),
+            is_orphaned: is_orphaned.then_some(true),
         }
     }
 }
@@ -0,0 +1,54 @@
+use crate::common::GenerationId;
+use crate::database::readers_status::ReaderStatus;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::util::str_serialization::StrSerializationType;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReaderStatusJsonData {
+    pub collection_name: String,
+    pub reader_name: String,
+    pub target_collection_name: String,
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub is_orphaned: Option<bool>,
+    // Lag is present only if the target collection exists and is readable
+    pub target_generation_id: Option<EncodedGenerationIdJsonData>,
+    pub generations_behind: Option<u64>,
+    pub changed_keys_count: Option<u64>,
+}
+
+impl ReaderStatusJsonData {
+    pub fn encode(collection_name: &str, status: ReaderStatus) -> Self {
+        let ReaderStatus {
+            reader,
+            target_collection_name,
+            target,
+        } = status;
+
+        let generation_id =
+            GenerationId::from_opt_owned(&reader.generation_id).unwrap_or(GenerationId::empty());
+        let target_generation_id = target.as_ref().map(|target| {
+            EncodedGenerationIdJsonData::encode(
+                target.generation_id.as_ref(),
+                StrSerializationType::Utf8,
+            )
+        });
+
+        Self {
+            collection_name: collection_name.to_string(),
+            generation_id: EncodedGenerationIdJsonData::encode(
+                generation_id,
+                StrSerializationType::Utf8,
+            ),
+            reader_name: reader.reader_name,
+            target_collection_name,
+            is_orphaned: reader.is_orphaned.then_some(true),
+            target_generation_id,
+            generations_behind: target.as_ref().map(|target| target.lag.generations_count),
+            changed_keys_count: target.as_ref().map(|target| target.lag.changed_keys_count),
+        }
+    }
+}
@@ -5,10 +5,38 @@ pub enum HttpError {
     Generic400(&'static str),
     GenericString400(String),
     CustomJson400(&'static str),
+    CustomJsonString400(String),
     /** max_size */
     TooBigPayload(usize),
     InvalidJson(String),
+    InvalidProtobuf(String),
     PublicInternal500(&'static str),
     MethodNotAllowed,
+    // Token is missing or unknown
+    Unauthorized,
+    // Token has no access to the collection
+    Forbidden(&'static str),
     ContentTypeUnsupported(&'static str),
 }
+
+impl HttpError {
+    // `error` label of metrics
+    pub fn class(&self) -> &'static str {
+        match self {
+            HttpError::NotFound => "not_found",
+            HttpError::Unspecified => "unspecified",
+            HttpError::Generic400(_) => "generic_400",
+            HttpError::GenericString400(_) => "generic_string_400",
+            HttpError::CustomJson400(_) => "custom_json_400",
+            HttpError::CustomJsonString400(_) => "custom_json_string_400",
+            HttpError::TooBigPayload(_) => "too_big_payload",
+            HttpError::InvalidJson(_) => "invalid_json",
+            HttpError::InvalidProtobuf(_) => "invalid_protobuf",
+            HttpError::PublicInternal500(_) => "public_internal_500",
+            HttpError::MethodNotAllowed => "method_not_allowed",
+            HttpError::Unauthorized => "unauthorized",
+            HttpError::Forbidden(_) => "forbidden",
+            HttpError::ContentTypeUnsupported(_) => "content_type_unsupported",
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::util::prometheus::PrometheusWriter;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Upper bounds of request duration buckets, in seconds
+const DURATION_BUCKETS: [f64; 10] = [0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 1.0, 5.0, 30.0];
+
+// Route of requests that are not matched by any route
+pub const UNKNOWN_ROUTE: &str = "unknown";
+
+#[derive(Default)]
+pub struct HttpMetrics {
+    // By route name, like `/collections/:id/get`
+    routes: Mutex<BTreeMap<String, RouteMetrics>>,
+}
+
+#[derive(Default)]
+struct RouteMetrics {
+    statuses: BTreeMap<u16, u64>,
+    errors: BTreeMap<&'static str, u64>,
+    // Not cumulative, summed on writing
+    duration_buckets: [u64; DURATION_BUCKETS.len()],
+    duration_sum: f64,
+    count: u64,
+}
+
+impl HttpMetrics {
+    // Duration is until the response head, streaming bodies are not counted
+    pub fn observe(
+        &self,
+        route: &str,
+        status: u16,
+        error_class: Option<&'static str>,
+        duration: Duration,
+    ) {
+        let seconds = duration.as_secs_f64();
+
+        let mut routes = self.routes.lock().unwrap();
+
+        let metrics = match routes.get_mut(route) {
+            Some(metrics) => metrics,
+            None => routes.entry(route.to_string()).or_default(),
+        };
+
+        *metrics.statuses.entry(status).or_default() += 1;
+
+        if let Some(error_class) = error_class {
+            *metrics.errors.entry(error_class).or_default() += 1;
+        }
+
+        if let Some(index) = DURATION_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            metrics.duration_buckets[index] += 1;
+        }
+
+        metrics.duration_sum += seconds;
+        metrics.count += 1;
+    }
+
+    pub fn write(&self, writer: &mut PrometheusWriter) {
+        let routes = self.routes.lock().unwrap();
+
+        writer.metric(
+            "diffbelt_http_requests_total",
+            "counter",
+            "HTTP requests by route and status",
+        );
+        for (route, metrics) in routes.iter() {
+            for (status, count) in &metrics.statuses {
+                writer.sample(
+                    "diffbelt_http_requests_total",
+                    &[("route", route), ("status", &status.to_string())],
+                    count,
+                );
+            }
+        }
+
+        writer.metric(
+            "diffbelt_http_errors_total",
+            "counter",
+            "Failed HTTP requests by route and error class",
+        );
+        for (route, metrics) in routes.iter() {
+            for (error_class, count) in &metrics.errors {
+                writer.sample(
+                    "diffbelt_http_errors_total",
+                    &[("route", route), ("error", error_class)],
+                    count,
+                );
+            }
+        }
+
+        writer.metric(
+            "diffbelt_http_request_duration_seconds",
+            "histogram",
+            "Time until the response head is ready",
+        );
+        for (route, metrics) in routes.iter() {
+            let mut cumulative = 0;
+
+            for (bound, count) in DURATION_BUCKETS.iter().zip(metrics.duration_buckets) {
+                cumulative += count;
+
+                writer.sample(
+                    "diffbelt_http_request_duration_seconds_bucket",
+                    &[("route", route), ("le", &bound.to_string())],
+                    cumulative,
+                );
+            }
+
+            writer.sample(
+                "diffbelt_http_request_duration_seconds_bucket",
+                &[("route", route), ("le", "+Inf")],
+                metrics.count,
+            );
+            writer.sample(
+                "diffbelt_http_request_duration_seconds_sum",
+                &[("route", route)],
+                metrics.duration_sum,
+            );
+            writer.sample(
+                "diffbelt_http_request_duration_seconds_count",
+                &[("route", route)],
+                metrics.count,
+            );
+        }
+    }
+}
@@ -1,9 +1,14 @@
+pub mod auth;
+pub mod config;
 pub mod constants;
 pub mod custom_errors;
 pub mod data;
 pub mod errors;
+pub mod metrics;
 pub mod request;
+pub mod request_id;
 pub mod routing;
 pub mod server;
+pub mod tls;
 mod util;
 pub mod validation;
@@ -1,3 +1,5 @@
+use crate::http::auth::Access;
+use futures::TryStreamExt;
 use hyper::body::{Buf, Bytes, HttpBody};
 use hyper::{Body, Request as HyperRequest};
 pub use request_trait::*;
@@ -9,6 +11,7 @@ mod request_trait;
 
 pub struct HyperRequestWrapped {
     inner: HyperRequest<Body>,
+    access: Access,
 }
 
 pub struct FullBody {
@@ -17,8 +20,11 @@ pub struct FullBody {
 }
 
 impl HyperRequestWrapped {
-    pub fn from(request: HyperRequest<Body>) -> Self {
-        Self { inner: request }
+    pub fn new(request: HyperRequest<Body>, access: Access) -> Self {
+        Self {
+            inner: request,
+            access,
+        }
     }
 }
 
@@ -31,6 +37,10 @@ impl Request for HyperRequestWrapped {
         self.inner.uri().path()
     }
 
+    fn access(&self) -> &Access {
+        &self.access
+    }
+
     fn query_params(&self) -> Result<Vec<(Cow<str>, Cow<str>)>, ()> {
         let query = self.inner.uri().query();
         let Some(query) = query else {
@@ -118,6 +128,12 @@ impl Request for HyperRequestWrapped {
             Ok(full)
         })
     }
+
+    fn into_body_reader(self) -> BodyReader {
+        let body = TryStreamExt::map_err(self.inner.into_body(), std::io::Error::other);
+
+        Box::new(body.into_async_read())
+    }
 }
 
 // async fn read_body_capped(body: Body) -> Result<impl Reader, T::Error>
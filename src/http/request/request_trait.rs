@@ -1,5 +1,7 @@
+use crate::http::auth::Access;
 use crate::http::request::FullBody;
 use futures::future::BoxFuture;
+use futures::io::AsyncBufRead;
 use std::borrow::Cow;
 
 pub enum RequestReadError {
@@ -9,9 +11,14 @@ pub enum RequestReadError {
 
 pub type IntoFullBodyAsReadReturn = BoxFuture<'static, Result<FullBody, RequestReadError>>;
 
+// Body is read as it arrives, for bodies that are too large to be read fully
+pub type BodyReader = Box<dyn AsyncBufRead + Send + Unpin>;
+
 pub trait Request {
     fn method(&self) -> &str;
     fn get_path(&self) -> &str;
+    // Token of the request, checked by `AccessValidation`
+    fn access(&self) -> &Access;
     fn query_params(&self) -> Result<Vec<(Cow<str>, Cow<str>)>, ()>;
     fn get_header(&self, name: &str) -> Option<&str>;
     fn reduce_multi_header<R, F: FnMut(R, &str) -> R>(
@@ -21,4 +28,5 @@ pub trait Request {
         initial: R,
     ) -> R;
     fn into_full_body_as_read(self, max_size: usize) -> IntoFullBodyAsReadReturn;
+    fn into_body_reader(self) -> BodyReader;
 }
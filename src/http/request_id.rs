@@ -0,0 +1,27 @@
+use crate::util::base62;
+use hyper::HeaderMap;
+
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+const REQUEST_ID_MAX_LENGTH: usize = 128;
+
+// Id from the client or a proxy is kept to correlate logs, if it is safe to log it as is
+pub fn get_or_generate_request_id(headers: &HeaderMap) -> String {
+    let id = headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid_request_id(id));
+
+    match id {
+        Some(id) => id.to_string(),
+        None => base62::from_u64(rand::random()).to_string(),
+    }
+}
+
+pub fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= REQUEST_ID_MAX_LENGTH
+        && id
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"-_.:".contains(&byte))
+}
@@ -10,7 +10,7 @@ use crate::http::request::{HyperRequestWrapped, Request};
 
 pub mod register_routes;
 pub mod response;
-mod routes;
+pub mod routes;
 
 pub struct StaticRouteOptions {
     pub context: Arc<Context>,
@@ -32,6 +32,8 @@ pub type PatternRouteFn<T> = fn(options: PatternRouteOptions<T>) -> PatternRoute
 
 pub struct PatternRoute {
     pub path: Regex,
+    // For metrics, like `/collections/:id/get`
+    pub name: String,
     pub handler: Box<
         dyn Fn(StaticRouteOptions, &Regex) -> Result<PatternRouteFnResult, StaticRouteOptions>
             + Sync
@@ -97,9 +99,24 @@ impl Routing {
             }))
         };
 
+        let name = pattern_route_name(&path);
+
         self.pattern_routes.push(PatternRoute {
             path,
+            name,
             handler: Box::new(handler),
         })
     }
 }
+
+fn pattern_route_name(path: &Regex) -> String {
+    lazy_static::lazy_static! {
+        static ref GROUP: Regex = Regex::new(r"\(\?P<(?P<name>[^>]+)>[^)]*\)").unwrap();
+    }
+
+    let pattern = path.as_str();
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    let pattern = pattern.strip_suffix('$').unwrap_or(pattern);
+
+    GROUP.replace_all(pattern, ":$name").into_owned()
+}
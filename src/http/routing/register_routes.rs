@@ -1,15 +1,23 @@
 use crate::context::Context;
+use crate::http::routing::routes::admin::backup::register_backup_route;
+use crate::http::routing::routes::admin::log::register_log_route;
 use crate::http::routing::routes::collection::by_id::register_collection_by_id_route;
+use crate::http::routing::routes::collection::clone::register_clone_collection_route;
 use crate::http::routing::routes::collection::create::register_create_collection_route;
+use crate::http::routing::routes::collection::export::register_export_collection_route;
 use crate::http::routing::routes::collection::generation_id_stream::register_collection_generation_id_stream_route;
+use crate::http::routing::routes::collection::import::register_import_collection_route;
 use crate::http::routing::routes::collection::list::register_list_collections_route;
+use crate::http::routing::routes::collection::rename::register_rename_collection_route;
 use crate::http::routing::routes::diff::by_id::register_next_diff_route;
 use crate::http::routing::routes::diff::start::register_start_diff_route;
 use crate::http::routing::routes::generation::abort::register_abort_generation_route;
 use crate::http::routing::routes::generation::commit::register_commit_generation_route;
+use crate::http::routing::routes::generation::events::register_generation_events_route;
 use crate::http::routing::routes::generation::start::register_start_generation_route;
 use crate::http::routing::routes::get::register_get_route;
 use crate::http::routing::routes::get_keys_around::register_get_keys_around_route;
+use crate::http::routing::routes::metrics::register_metrics_route;
 use crate::http::routing::routes::phantom::start::register_start_phantom_route;
 use crate::http::routing::routes::put::register_put_route;
 use crate::http::routing::routes::put_many::register_put_many_route;
@@ -17,7 +25,17 @@ use crate::http::routing::routes::query::by_id::register_next_query_route;
 use crate::http::routing::routes::query::start::register_start_query_route;
 use crate::http::routing::routes::reader::by_name::register_reader_by_name_route;
 use crate::http::routing::routes::reader::by_root::register_readers_root_route;
+use crate::http::routing::routes::reader::list_all::register_list_all_readers_route;
 use crate::http::routing::routes::root::register_root_route;
+use crate::http::routing::routes::transaction::abort::register_abort_transaction_route;
+use crate::http::routing::routes::transaction::commit::register_commit_transaction_route;
+use crate::http::routing::routes::transaction::start::register_start_transaction_route;
+use crate::http::routing::routes::transform::by_name::register_transform_by_name_route;
+use crate::http::routing::routes::transform::create::register_create_transform_route;
+use crate::http::routing::routes::transform::list::register_list_transforms_route;
+use crate::http::routing::routes::trash::list::register_list_trash_route;
+use crate::http::routing::routes::trash::restore::register_restore_collection_route;
+use crate::http::routing::routes::watch::register_watch_route;
 
 pub fn register_routes(context: &mut Context) {
     register_root_route(context);
@@ -29,14 +47,32 @@ pub fn register_routes(context: &mut Context) {
     register_collection_generation_id_stream_route(context);
     register_list_collections_route(context);
     register_create_collection_route(context);
+    register_rename_collection_route(context);
+    register_clone_collection_route(context);
+    register_export_collection_route(context);
+    register_import_collection_route(context);
     register_readers_root_route(context);
     register_reader_by_name_route(context);
+    register_list_all_readers_route(context);
     register_start_generation_route(context);
     register_abort_generation_route(context);
     register_commit_generation_route(context);
+    register_generation_events_route(context);
+    register_start_transaction_route(context);
+    register_abort_transaction_route(context);
+    register_commit_transaction_route(context);
+    register_list_transforms_route(context);
+    register_create_transform_route(context);
+    register_transform_by_name_route(context);
     register_start_query_route(context);
     register_next_query_route(context);
     register_start_diff_route(context);
     register_next_diff_route(context);
     register_start_phantom_route(context);
+    register_list_trash_route(context);
+    register_restore_collection_route(context);
+    register_watch_route(context);
+    register_backup_route(context);
+    register_log_route(context);
+    register_metrics_route(context);
 }
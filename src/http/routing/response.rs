@@ -1,3 +1,5 @@
+use hyper::Body;
+
 pub struct BaseResponse {
     pub status: u16,
     pub content_type: &'static str,
@@ -27,8 +29,15 @@ pub struct BytesVecResponse {
     pub bytes: Vec<u8>,
 }
 
+// Body is written after the handler returns, see `http::util::stream_writer`
+pub struct StreamResponse {
+    pub base: BaseResponse,
+    pub body: Body,
+}
+
 pub enum Response {
     StaticStr(StaticStrResponse),
     String(StringResponse),
     BytesVec(BytesVecResponse),
+    Stream(StreamResponse),
 }
@@ -0,0 +1,91 @@
+use crate::context::Context;
+use crate::database::backup::{BackupCollection, BackupOk, BackupOptions};
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation};
+use crate::util::str_serialization::StrSerializationType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    incremental: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupCollectionJsonData {
+    collection_name: String,
+    generation_id: EncodedGenerationIdJsonData,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    backup_id: String,
+    collections: Vec<BackupCollectionJsonData>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_utf8_json_by_default()?;
+        request.require_server_admin()?;
+
+        let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+        let data: RequestJsonData = read_json(body)?;
+
+        let Some(path) = context.config.backup_path.clone() else {
+            return Err(HttpError::Generic400("backup_path is not configured"));
+        };
+
+        let result = context
+            .database
+            .backup(BackupOptions {
+                path,
+                incremental: data.incremental.unwrap_or(false),
+            })
+            .await;
+
+        let BackupOk {
+            backup_id,
+            collections,
+        } = result.map_err(|err| {
+            tracing::error!(?err, "admin/backup error");
+            HttpError::Unspecified
+        })?;
+
+        let collections = collections
+            .into_iter()
+            .map(
+                |BackupCollection {
+                     name,
+                     generation_id,
+                 }| BackupCollectionJsonData {
+                    collection_name: name,
+                    generation_id: EncodedGenerationIdJsonData::encode(
+                        generation_id.as_ref(),
+                        StrSerializationType::Utf8,
+                    ),
+                },
+            )
+            .collect();
+
+        create_ok_json_response(&ResponseJsonData {
+            backup_id,
+            collections,
+        })
+    })
+}
+
+pub fn register_backup_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_post_route("/admin/backup", handler);
+}
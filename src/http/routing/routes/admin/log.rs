@@ -0,0 +1,73 @@
+use crate::context::Context;
+use crate::http::errors::HttpError;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation};
+use crate::logging::LoggingError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    filter: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    filter: String,
+}
+
+fn get_handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.require_server_admin()?;
+
+        create_ok_json_response(&ResponseJsonData {
+            filter: context.logging.filter(),
+        })
+    })
+}
+
+fn set_handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_utf8_json_by_default()?;
+        request.require_server_admin()?;
+
+        let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+        let data: RequestJsonData = read_json(body)?;
+
+        context
+            .logging
+            .set_filter(&data.filter)
+            .map_err(|err| match err {
+                LoggingError::InvalidFilter(reason) => HttpError::GenericString400(reason),
+                err => {
+                    tracing::error!(?err, "admin/log error");
+                    HttpError::Unspecified
+                }
+            })?;
+
+        tracing::info!(filter = data.filter.as_str(), "log filter is changed");
+
+        create_ok_json_response(&ResponseJsonData {
+            filter: data.filter,
+        })
+    })
+}
+
+pub fn register_log_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_get_route("/admin/log", get_handler);
+    context
+        .routing
+        .add_static_post_route("/admin/log", set_handler);
+}
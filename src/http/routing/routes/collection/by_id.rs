@@ -1,11 +1,14 @@
 use crate::context::Context;
 
+use crate::http::auth::AccessRole;
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 use crate::http::routing::routes::collection::delete::delete_collection;
 use crate::http::routing::routes::collection::get::get_collection;
+use crate::http::routing::routes::collection::update::update_collection;
 use crate::http::routing::{PatternRouteFnResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::validation::AccessValidation;
 
 use crate::http::custom_errors::no_such_collection_error;
 use regex::Regex;
@@ -16,6 +19,12 @@ fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> PatternRouteFnResult {
         let request = options.request;
         let collection_name = options.groups.0;
 
+        let role = match request.method() {
+            "GET" => AccessRole::Read,
+            _ => AccessRole::Admin,
+        };
+        request.require_collection_access(&collection_name, role)?;
+
         let result = context.database.get_collection(&collection_name).await;
 
         let Some(collection) = result else {
@@ -25,6 +34,11 @@ fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> PatternRouteFnResult {
         match request.method() {
             "GET" => get_collection(request, collection).await,
             "DELETE" => delete_collection(collection).await,
+            "PATCH" => {
+                drop(collection);
+
+                update_collection(request, &context, &collection_name).await
+            }
             _ => Err(HttpError::MethodNotAllowed),
         }
     })
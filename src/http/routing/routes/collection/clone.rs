@@ -0,0 +1,122 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::common::constants::MAX_COLLECTION_NAME_LENGTH;
+use crate::context::Context;
+use crate::database::clone_collection::{CloneCollectionError, CloneCollectionOptions};
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::{generation_id_less_than_minimum_error, no_such_collection_error};
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
+use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
+use crate::util::str_serialization::StrSerializationType;
+use diffbelt_macro::fn_box_pin_async;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    new_collection_name: String,
+    generation_id: Option<EncodedGenerationIdJsonData>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    generation_id: EncodedGenerationIdJsonData,
+}
+
+#[fn_box_pin_async]
+async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult {
+    let context = options.context;
+    let request = options.request;
+    let collection_name = options.groups.0;
+
+    request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+    request.allow_only_utf8_json_by_default()?;
+
+    // Request is consumed by the body reading
+    let access = request.access().clone();
+
+    let body = read_limited_body(
+        request,
+        context.config.http.create_collection_request_max_bytes,
+    )
+    .await?;
+    let data: RequestJsonData = read_json(body)?;
+
+    let RequestJsonData {
+        new_collection_name,
+        generation_id,
+    } = data;
+
+    if new_collection_name.len() > MAX_COLLECTION_NAME_LENGTH {
+        return Err(HttpError::Generic400("newCollectionName cannot be > 512"));
+    }
+
+    access.check_collection(&new_collection_name, AccessRole::Admin)?;
+
+    let generation_id = EncodedGenerationIdJsonData::decode_opt(generation_id)?;
+
+    let result = context
+        .database
+        .clone_collection(
+            &collection_name,
+            &new_collection_name,
+            CloneCollectionOptions { generation_id },
+        )
+        .await;
+
+    let collection = match result {
+        Ok(collection) => collection,
+        Err(CloneCollectionError::NoSuchCollection) => {
+            return Err(no_such_collection_error());
+        }
+        Err(CloneCollectionError::AlreadyExist) => {
+            return Err(HttpError::Generic400(
+                "collection with such id already exists",
+            ));
+        }
+        Err(CloneCollectionError::CollectionMethod(
+            CollectionMethodError::GenerationIdLessThanMinimum(minimum_generation_id),
+        )) => {
+            return Err(generation_id_less_than_minimum_error(
+                minimum_generation_id.as_ref(),
+            ));
+        }
+        Err(CloneCollectionError::CollectionMethod(
+            CollectionMethodError::GenerationIdNotCommitted(_),
+        )) => {
+            return Err(HttpError::Generic400("generationId is not committed"));
+        }
+        Err(err) => {
+            tracing::error!(?err, "collection/clone error");
+            return Err(HttpError::Unspecified);
+        }
+    };
+
+    let generation_id = collection.generation_pair().generation_id;
+
+    let response = ResponseJsonData {
+        generation_id: EncodedGenerationIdJsonData::encode(
+            generation_id.as_ref(),
+            StrSerializationType::Utf8,
+        ),
+    };
+
+    create_ok_json_response(&response)
+}
+
+pub fn register_clone_collection_route(context: &mut Context) {
+    context.routing.add_pattern_route(
+        Regex::new("^/collections/(?P<id>[^/]+)/clone$").unwrap(),
+        id_only_group,
+        handler,
+    );
+}
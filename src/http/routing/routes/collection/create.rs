@@ -2,8 +2,9 @@ use crate::common::constants::MAX_COLLECTION_NAME_LENGTH;
 
 use crate::context::Context;
 use crate::database::create_collection::{CreateCollectionError, CreateCollectionOptions};
-use crate::http::constants::CREATE_COLLECTION_REQUEST_MAX_BYTES;
+use crate::http::auth::AccessRole;
 use crate::http::errors::HttpError;
+use crate::http::request::Request;
 
 use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
 
@@ -12,25 +13,30 @@ use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_json_response;
 use crate::http::validation::ContentTypeValidation;
 
+use crate::collection::settings::CollectionSettings;
+use crate::http::data::collection_settings::CollectionSettingsUpdateJsonData;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::util::str_serialization::StrSerializationType;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CreateCollectionRequestJsonData {
-    collection_name: String,
-    is_manual: bool,
+pub struct CreateCollectionRequestJsonData {
+    pub collection_name: String,
+    pub is_manual: bool,
+    pub if_value_changed: Option<bool>,
     // Only for manual collections
-    initial_generation_id: Option<EncodedGenerationIdJsonData>,
+    pub initial_generation_id: Option<EncodedGenerationIdJsonData>,
+    pub settings: Option<CollectionSettingsUpdateJsonData>,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ResponseJsonData {
-    generation_id: EncodedGenerationIdJsonData,
+pub struct CreateCollectionResponseJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
 }
 
 fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
@@ -40,7 +46,14 @@ fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
 
         request.allow_only_utf8_json_by_default()?;
 
-        let body = read_limited_body(request, CREATE_COLLECTION_REQUEST_MAX_BYTES).await?;
+        // Request is consumed by the body reading
+        let access = request.access().clone();
+
+        let body = read_limited_body(
+            request,
+            context.config.http.create_collection_request_max_bytes,
+        )
+        .await?;
         let data: CreateCollectionRequestJsonData = read_json(body)?;
 
         let collection_name = data.collection_name;
@@ -50,6 +63,8 @@ fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
             return Err(HttpError::Generic400("collectionName cannot be > 512"));
         }
 
+        access.check_collection(&collection_name, AccessRole::Admin)?;
+
         let initial_generation_id =
             EncodedGenerationIdJsonData::decode_opt(data.initial_generation_id)?;
 
@@ -59,9 +74,21 @@ fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
             ));
         }
 
+        let mut settings = CollectionSettings::default();
+        if let Some(update) = data.settings {
+            settings.apply(update.into_update()?);
+        }
+
         let result = context
             .database
-            .create_collection(&collection_name, CreateCollectionOptions { is_manual })
+            .create_collection(
+                &collection_name,
+                CreateCollectionOptions {
+                    is_manual,
+                    if_value_changed: data.if_value_changed.unwrap_or(false),
+                    settings,
+                },
+            )
             .await;
 
         let collection = match result {
@@ -72,7 +99,7 @@ fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
                         "collection with such id already exists",
                     )),
                     _ => {
-                        eprintln!("create collection error {:?}", err);
+                        tracing::error!(?err, "create collection error");
                         Err(HttpError::Unspecified)
                     }
                 };
@@ -81,7 +108,7 @@ fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
 
         let generation_id = collection.generation_pair().generation_id;
 
-        let response = ResponseJsonData {
+        let response = CreateCollectionResponseJsonData {
             generation_id: EncodedGenerationIdJsonData::encode(
                 generation_id.as_ref(),
                 StrSerializationType::Utf8,
@@ -19,7 +19,7 @@ pub async fn delete_collection(collection: Arc<Collection>) -> Result<Response,
         return match err {
             CollectionMethodError::NoSuchCollection => create_ok_no_error_json_response(),
             _ => {
-                eprintln!("delete collection error {:?}", err);
+                tracing::error!(?err, "delete collection error");
                 Err(HttpError::Unspecified)
             }
         };
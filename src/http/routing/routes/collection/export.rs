@@ -0,0 +1,116 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::context::Context;
+use crate::database::dump::export::{CollectionExport, ExportCollectionOptions};
+use crate::database::dump::DumpError;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::{generation_id_less_than_minimum_error, no_such_collection_error};
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
+use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::stream_writer::StreamWriter;
+use crate::http::validation::{
+    AccessValidation, BodyFormat, ContentTypeValidation, MethodsValidation,
+};
+use crate::util::tokio::spawn;
+use diffbelt_macro::fn_box_pin_async;
+use regex::Regex;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    generation_id: Option<EncodedGenerationIdJsonData>,
+    history: Option<bool>,
+}
+
+#[fn_box_pin_async]
+async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult {
+    let context = options.context;
+    let request = options.request;
+    let collection_name = options.groups.0;
+
+    request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+    request.allow_only_utf8_json_by_default()?;
+
+    let body =
+        read_limited_body(request, context.config.http.query_start_request_max_bytes).await?;
+    let data: RequestJsonData = read_json(body)?;
+
+    let generation_id = EncodedGenerationIdJsonData::decode_opt(data.generation_id)?;
+
+    let result = context
+        .database
+        .export_collection(
+            &collection_name,
+            ExportCollectionOptions {
+                generation_id,
+                history: data.history.unwrap_or(false),
+            },
+        )
+        .await;
+
+    let export = match result {
+        Ok(export) => export,
+        Err(DumpError::NoSuchCollection) => {
+            return Err(no_such_collection_error());
+        }
+        Err(DumpError::CollectionMethod(CollectionMethodError::GenerationIdLessThanMinimum(
+            minimum_generation_id,
+        ))) => {
+            return Err(generation_id_less_than_minimum_error(
+                minimum_generation_id.as_ref(),
+            ));
+        }
+        Err(DumpError::CollectionMethod(CollectionMethodError::GenerationIdNotCommitted(_))) => {
+            return Err(HttpError::Generic400("generationId is not committed"));
+        }
+        Err(err) => {
+            tracing::error!(?err, "collection/export error");
+            return Err(HttpError::Unspecified);
+        }
+    };
+
+    let (writer, response) = StreamWriter::new(BodyFormat::Json);
+
+    spawn(write_export(export, writer));
+
+    Ok(response)
+}
+
+async fn write_export(mut export: CollectionExport, mut writer: StreamWriter) {
+    loop {
+        let lines = match export.next_pack().await {
+            Ok(Some(lines)) => lines,
+            Ok(None) => {
+                return;
+            }
+            Err(err) => {
+                tracing::error!(?err, "collection/export error");
+                writer.abort();
+                return;
+            }
+        };
+
+        for line in lines {
+            if writer.write_json(&line).is_err() {
+                return;
+            }
+        }
+
+        if writer.flush().await.is_err() {
+            return;
+        }
+    }
+}
+
+pub fn register_export_collection_route(context: &mut Context) {
+    context.routing.add_pattern_route(
+        Regex::new("^/collections/(?P<id>[^/]+)/export$").unwrap(),
+        id_only_group,
+        handler,
+    );
+}
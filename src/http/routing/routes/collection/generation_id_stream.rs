@@ -9,11 +9,12 @@ use crate::http::routing::{PatternRouteFnResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 
 use crate::common::OwnedGenerationId;
+use crate::http::auth::AccessRole;
 use crate::http::custom_errors::no_such_collection_error;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::routing::response::Response;
 use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::MethodsValidation;
+use crate::http::validation::{AccessValidation, MethodsValidation};
 use crate::util::str_serialization::StrSerializationType;
 use regex::Regex;
 use serde::Serialize;
@@ -35,6 +36,7 @@ fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> PatternRouteFnResult {
         let collection_name = options.groups.0;
 
         request.allow_only_methods(&["GET"])?;
+        request.require_collection_access(&collection_name, AccessRole::Read)?;
 
         let result = context.database.get_collection(&collection_name).await;
 
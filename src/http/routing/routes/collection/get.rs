@@ -4,14 +4,16 @@ use crate::http::errors::HttpError;
 use crate::http::request::Request;
 use crate::http::routing::response::Response;
 use crate::http::util::response::create_ok_json_response;
+use crate::util::json::serde::deserialize_nullable;
 use crate::util::str_serialization::StrSerializationType;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use std::ops::Deref;
 
 use crate::database::generations::collection::GenerationIdNextGenerationIdPair;
+use crate::http::data::collection_settings::CollectionSettingsJsonData;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use std::sync::Arc;
 
@@ -48,12 +50,15 @@ impl ApplyPart for Option<NextGenerationIdPart> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GetCollectionResponseJsonData {
-    is_manual: bool,
-    generation_id: Option<EncodedGenerationIdJsonData>,
-    next_generation_id: Option<Option<EncodedGenerationIdJsonData>>,
+pub struct GetCollectionResponseJsonData {
+    pub is_manual: bool,
+    pub if_value_changed: bool,
+    pub settings: CollectionSettingsJsonData,
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub next_generation_id: Option<Option<EncodedGenerationIdJsonData>>,
 }
 
 pub async fn get_collection(
@@ -91,6 +96,8 @@ pub async fn get_collection(
 
     let mut response = GetCollectionResponseJsonData {
         is_manual: collection.is_manual(),
+        if_value_changed: collection.is_if_value_changed(),
+        settings: collection.get_settings().await.into(),
         generation_id: None,
         next_generation_id: None,
     };
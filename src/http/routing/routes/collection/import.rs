@@ -0,0 +1,86 @@
+use crate::context::Context;
+use crate::database::dump::import::{ImportCollectionOk, ImportCollectionOptions};
+use crate::database::dump::DumpError;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation};
+use crate::util::str_serialization::StrSerializationType;
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    collection_name: String,
+    generation_id: EncodedGenerationIdJsonData,
+    records: u64,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_utf8_ndjson_by_default()?;
+        // Collection name is known only after the dump header is read
+        request.require_server_admin()?;
+
+        // Body is not limited, only its lines are
+        let result = context
+            .database
+            .import_collection(
+                request.into_body_reader(),
+                ImportCollectionOptions {
+                    max_line_bytes: context.config.http.put_many_request_max_bytes,
+                },
+            )
+            .await;
+
+        let ImportCollectionOk {
+            collection,
+            records,
+        } = match result {
+            Ok(result) => result,
+            Err(DumpError::AlreadyExist) => {
+                return Err(HttpError::Generic400(
+                    "collection with such id already exists",
+                ));
+            }
+            Err(DumpError::InvalidDump(reason)) => {
+                return Err(HttpError::GenericString400(reason));
+            }
+            Err(DumpError::UnsupportedVersion(version)) => {
+                return Err(HttpError::GenericString400(format!(
+                    "dump version {} is not supported",
+                    version
+                )));
+            }
+            Err(DumpError::Read(_)) => {
+                return Err(HttpError::Generic400("io"));
+            }
+            Err(err) => {
+                tracing::error!(?err, "collection/import error");
+                return Err(HttpError::Unspecified);
+            }
+        };
+
+        let generation_id = collection.generation_pair().generation_id;
+
+        create_ok_json_response(&ResponseJsonData {
+            collection_name: collection.get_name().to_string(),
+            generation_id: EncodedGenerationIdJsonData::encode(
+                generation_id.as_ref(),
+                StrSerializationType::Utf8,
+            ),
+            records,
+        })
+    })
+}
+
+pub fn register_import_collection_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_post_route("/collections/import", handler);
+}
@@ -7,26 +7,26 @@ use std::sync::Arc;
 use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
 
 use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::MethodsValidation;
+use crate::http::validation::{AccessValidation, MethodsValidation};
 
 use crate::collection::Collection;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ListCollectionsItemJsonData {
-    name: String,
-    is_manual: bool,
+pub struct ListCollectionsItemJsonData {
+    pub name: String,
+    pub is_manual: bool,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ListCollectionsResponseJsonData {
-    items: Vec<ListCollectionsItemJsonData>,
+pub struct ListCollectionsResponseJsonData {
+    pub items: Vec<ListCollectionsItemJsonData>,
 }
 
 fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
@@ -40,6 +40,7 @@ fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
 
         let items: FuturesOrdered<_> = collections
             .into_iter()
+            .filter(|collection| request.can_read_collection(collection.get_name()))
             .map(|collection: Arc<Collection>| async move {
                 ListCollectionsItemJsonData {
                     name: collection.get_name().to_string(),
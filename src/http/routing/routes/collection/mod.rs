@@ -1,6 +1,11 @@
 pub mod by_id;
+pub mod clone;
 pub mod create;
 mod delete;
+pub mod export;
 pub mod generation_id_stream;
-mod get;
+pub mod get;
+pub mod import;
 pub mod list;
+pub mod rename;
+mod update;
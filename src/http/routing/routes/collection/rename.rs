@@ -0,0 +1,82 @@
+use crate::common::constants::MAX_COLLECTION_NAME_LENGTH;
+use crate::context::Context;
+use crate::database::rename_collection::RenameCollectionError;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::no_such_collection_error;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
+use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_no_error_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
+use diffbelt_macro::fn_box_pin_async;
+use regex::Regex;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    new_collection_name: String,
+}
+
+#[fn_box_pin_async]
+async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult {
+    let context = options.context;
+    let request = options.request;
+    let collection_name = options.groups.0;
+
+    request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Admin)?;
+    request.allow_only_utf8_json_by_default()?;
+
+    // Request is consumed by the body reading
+    let access = request.access().clone();
+
+    let body = read_limited_body(
+        request,
+        context.config.http.create_collection_request_max_bytes,
+    )
+    .await?;
+    let data: RequestJsonData = read_json(body)?;
+
+    let new_collection_name = data.new_collection_name;
+
+    if new_collection_name.len() > MAX_COLLECTION_NAME_LENGTH {
+        return Err(HttpError::Generic400("newCollectionName cannot be > 512"));
+    }
+
+    access.check_collection(&new_collection_name, AccessRole::Admin)?;
+
+    let result = context
+        .database
+        .rename_collection(&collection_name, &new_collection_name)
+        .await;
+
+    match result {
+        Ok(_) => {}
+        Err(RenameCollectionError::NoSuchCollection) => {
+            return Err(no_such_collection_error());
+        }
+        Err(RenameCollectionError::AlreadyExist) => {
+            return Err(HttpError::Generic400(
+                "collection with such id already exists",
+            ));
+        }
+        Err(err) => {
+            tracing::error!(?err, "collection/rename error");
+            return Err(HttpError::Unspecified);
+        }
+    }
+
+    create_ok_no_error_json_response()
+}
+
+pub fn register_rename_collection_route(context: &mut Context) {
+    context.routing.add_pattern_route(
+        Regex::new("^/collections/(?P<id>[^/]+)/rename$").unwrap(),
+        id_only_group,
+        handler,
+    );
+}
@@ -0,0 +1,65 @@
+use crate::context::Context;
+use crate::database::update_collection_settings::UpdateCollectionSettingsError;
+use crate::http::custom_errors::no_such_collection_error;
+use crate::http::data::collection_settings::{
+    CollectionSettingsJsonData, CollectionSettingsUpdateJsonData,
+};
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::response::Response;
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::ContentTypeValidation;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    settings: CollectionSettingsUpdateJsonData,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    settings: CollectionSettingsJsonData,
+}
+
+pub async fn update_collection(
+    request: impl Request,
+    context: &Context,
+    collection_name: &str,
+) -> Result<Response, HttpError> {
+    request.allow_only_utf8_json_by_default()?;
+
+    let body = read_limited_body(
+        request,
+        context.config.http.create_collection_request_max_bytes,
+    )
+    .await?;
+    let data: RequestJsonData = read_json(body)?;
+
+    let update = data.settings.into_update()?;
+
+    let result = context
+        .database
+        .update_collection_settings(collection_name, update)
+        .await;
+
+    let settings = match result {
+        Ok(settings) => settings,
+        Err(UpdateCollectionSettingsError::NoSuchCollection) => {
+            return Err(no_such_collection_error());
+        }
+        Err(err) => {
+            tracing::error!(?err, "update collection error");
+            return Err(HttpError::Unspecified);
+        }
+    };
+
+    let response = ResponseJsonData {
+        settings: settings.into(),
+    };
+
+    create_ok_json_response(&response)
+}
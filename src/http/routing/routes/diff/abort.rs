@@ -21,7 +21,7 @@ pub async fn abort_cursor(
     let _ = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("diff/abort error {:?}", err);
+            tracing::error!(?err, "diff/abort error");
             return Err(HttpError::Unspecified);
         }
     };
@@ -1,34 +1,38 @@
-use crate::collection::methods::diff::ReadDiffCursorOptions;
+use crate::collection::methods::diff::{DiffOk, ReadDiffCursorOptions};
 use std::sync::Arc;
 
 use crate::collection::Collection;
 
 use crate::http::data::diff_response::DiffResponseJsonData;
 
+use crate::http::custom_errors::collection_method_error;
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 
 use crate::http::routing::response::Response;
 
-use crate::http::util::response::create_ok_json_response;
+use crate::http::util::response::{create_ok_json_response, create_ok_protobuf_response};
+use crate::http::validation::{BodyFormat, ContentTypeValidation};
+use crate::protos::http_api;
 
 pub async fn read_cursor(
-    _request: impl Request,
+    request: impl Request,
     collection: Arc<Collection>,
     cursor_id: Box<str>,
 ) -> Result<Response, HttpError> {
+    let response_format = request.accepted_body_format(BodyFormat::Json);
     let options = ReadDiffCursorOptions { cursor_id };
 
     let result = collection.read_diff_cursor(options).await;
 
-    let result = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("diff/next error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
-    };
+    let result = result.map_err(|err| collection_method_error(err, "diff/next"))?;
 
-    let response = DiffResponseJsonData::from(result);
-    create_ok_json_response(&response)
+    create_diff_response(result, response_format)
+}
+
+pub fn create_diff_response(result: DiffOk, format: BodyFormat) -> Result<Response, HttpError> {
+    match format {
+        BodyFormat::Json => create_ok_json_response(&DiffResponseJsonData::from(result)),
+        BodyFormat::Protobuf => create_ok_protobuf_response(&http_api::DiffResponse::from(result)),
+    }
 }
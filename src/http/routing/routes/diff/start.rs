@@ -1,34 +1,46 @@
 use crate::collection::methods::diff::DiffOptions;
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use crate::common::generation_id::GenerationIdSource;
 use crate::common::reader::ReaderDef;
 use crate::common::OwnedGenerationId;
 use crate::context::Context;
-use crate::http::constants::DIFF_START_REQUEST_MAX_BYTES;
-use crate::http::data::diff_response::DiffResponseJsonData;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::data::protobuf::decode_generation_id;
 use crate::http::data::reader_record::ReaderDiffFromDefJsonData;
 
+use crate::http::custom_errors::collection_method_error;
 use crate::http::errors::HttpError;
+use crate::http::routing::routes::diff::next::create_diff_response;
+use crate::http::routing::routes::diff::stream::create_diff_stream_response;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 
+use crate::http::auth::AccessRole;
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::get_collection::get_collection;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
-use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
-
-#[derive(Deserialize)]
+use crate::http::util::read_protobuf::read_protobuf;
+use crate::http::validation::{
+    AccessValidation, BodyFormat, ContentTypeValidation, MethodsValidation,
+};
+use crate::protos::http_api;
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    from_generation_id: Option<EncodedGenerationIdJsonData>,
-    to_generation_id: Option<EncodedGenerationIdJsonData>,
+pub struct StartDiffRequestJsonData {
+    pub from_generation_id: Option<EncodedGenerationIdJsonData>,
+    pub to_generation_id: Option<EncodedGenerationIdJsonData>,
+
+    pub from_reader: Option<ReaderDiffFromDefJsonData>,
 
-    from_reader: Option<ReaderDiffFromDefJsonData>,
+    pub omit_intermediate_values: Option<bool>,
+    // Stream all records in one response instead of cursor packs
+    pub stream: Option<bool>,
 }
 
 #[fn_box_pin_async]
@@ -38,39 +50,78 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
-    request.allow_only_utf8_json_by_default()?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+    let body_format = request.allow_only_utf8_json_or_protobuf_by_default()?;
+    let response_format = request.accepted_body_format(body_format);
+
+    let body = read_limited_body(request, context.config.http.diff_start_request_max_bytes).await?;
+    let (options, is_stream) = match body_format {
+        BodyFormat::Json => decode_json_request(read_json(body)?)?,
+        BodyFormat::Protobuf => decode_protobuf_request(read_protobuf(body)?)?,
+    };
+
+    let collection = get_collection(&context, &collection_name).await?;
+
+    let result = collection.diff(options).await;
+
+    let result = result.map_err(|err| collection_method_error(err, "query/diff"))?;
+
+    if is_stream {
+        return Ok(create_diff_stream_response(
+            collection,
+            result,
+            response_format,
+        ));
+    }
 
-    let body = read_limited_body(request, DIFF_START_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    create_diff_response(result, response_format)
+}
 
+fn decode_json_request(data: StartDiffRequestJsonData) -> Result<(DiffOptions, bool), HttpError> {
     let from_generation_id = EncodedGenerationIdJsonData::decode_opt(data.from_generation_id)?;
     let to_generation_id = EncodedGenerationIdJsonData::decode_opt(data.to_generation_id)?;
 
-    let from_generation_id = into_from_generation_id_source(from_generation_id, data.from_reader)?;
-    let collection = get_collection(&context, &collection_name).await?;
+    let reader = data.from_reader.map(|reader| ReaderDef {
+        collection_name: reader.collection_name,
+        reader_name: reader.reader_name,
+    });
 
     let options = DiffOptions {
-        from_generation_id,
+        from_generation_id: into_from_generation_id_source(from_generation_id, reader)?,
         to_generation_id_loose: to_generation_id,
+        omit_intermediate_values: data.omit_intermediate_values.unwrap_or(true),
     };
 
-    let result = collection.diff(options).await;
+    Ok((options, data.stream.unwrap_or(false)))
+}
 
-    let result = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("query/diff error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
+fn decode_protobuf_request(data: http_api::DiffRequest) -> Result<(DiffOptions, bool), HttpError> {
+    let from_generation_id = data
+        .from_generation_id
+        .map(decode_generation_id)
+        .transpose()?;
+    let to_generation_id = data
+        .to_generation_id
+        .map(decode_generation_id)
+        .transpose()?;
+
+    let reader = data.from_reader.into_option().map(|reader| ReaderDef {
+        collection_name: reader.collection_name,
+        reader_name: reader.reader_name,
+    });
+
+    let options = DiffOptions {
+        from_generation_id: into_from_generation_id_source(from_generation_id, reader)?,
+        to_generation_id_loose: to_generation_id,
+        omit_intermediate_values: data.omit_intermediate_values.unwrap_or(true),
     };
 
-    let response = DiffResponseJsonData::from(result);
-    create_ok_json_response(&response)
+    Ok((options, data.stream.unwrap_or(false)))
 }
 
 fn into_from_generation_id_source(
     from_generation_id: Option<OwnedGenerationId>,
-    reader: Option<ReaderDiffFromDefJsonData>,
+    reader: Option<ReaderDef>,
 ) -> Result<GenerationIdSource, HttpError> {
     match from_generation_id {
         Some(generation_id) => return Ok(GenerationIdSource::Value(Some(generation_id))),
@@ -78,13 +129,12 @@ fn into_from_generation_id_source(
     }
 
     let Some(reader) = reader else {
-        return Err(HttpError::Generic400("either fromGenerationId or readerId should be present"));
+        return Err(HttpError::Generic400(
+            "either fromGenerationId or readerId should be present",
+        ));
     };
 
-    Ok(GenerationIdSource::Reader(ReaderDef {
-        collection_name: reader.collection_name,
-        reader_name: reader.reader_name,
-    }))
+    Ok(GenerationIdSource::Reader(reader))
 }
 
 pub fn register_start_diff_route(context: &mut Context) {
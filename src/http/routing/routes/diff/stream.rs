@@ -0,0 +1,69 @@
+use crate::collection::methods::diff::{AbortDiffCursorOptions, DiffOk, ReadDiffCursorOptions};
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::KeyValueDiff;
+use crate::http::data::diff_response::DiffResponseJsonData;
+use crate::http::data::key_value_diff::KeyValueDiffJsonData;
+use crate::http::data::protobuf::encode_key_value_diff;
+use crate::http::routing::response::Response;
+use crate::http::util::cursor_stream::{create_cursor_stream_response, CursorStreamPage};
+use crate::http::util::stream_writer::{StreamClosed, StreamWriter};
+use crate::http::validation::BodyFormat;
+use crate::protos::http_api;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+// One `KeyValueDiff` per record after the header
+pub fn create_diff_stream_response(
+    collection: Arc<Collection>,
+    result: DiffOk,
+    format: BodyFormat,
+) -> Response {
+    create_cursor_stream_response(collection, result, format)
+}
+
+impl CursorStreamPage for DiffOk {
+    type Item = KeyValueDiff;
+
+    const ROUTE: &'static str = "diff/stream";
+
+    fn take_items_and_cursor_id(&mut self) -> (Vec<KeyValueDiff>, Option<Box<str>>) {
+        (std::mem::take(&mut self.items), self.cursor_id.take())
+    }
+
+    fn write_header(self, writer: &mut StreamWriter) -> Result<(), StreamClosed> {
+        match writer.format() {
+            BodyFormat::Json => writer.write_json(&DiffResponseJsonData::from(self)),
+            BodyFormat::Protobuf => writer.write_protobuf(&http_api::DiffResponse::from(self)),
+        }
+    }
+
+    fn write_item(item: KeyValueDiff, writer: &mut StreamWriter) -> Result<(), StreamClosed> {
+        match writer.format() {
+            BodyFormat::Json => writer.write_json(&KeyValueDiffJsonData::from(item)),
+            BodyFormat::Protobuf => writer.write_protobuf(&encode_key_value_diff(item)),
+        }
+    }
+
+    fn read_cursor(
+        collection: Arc<Collection>,
+        cursor_id: Box<str>,
+    ) -> BoxFuture<'static, Result<Self, CollectionMethodError>> {
+        Box::pin(async move {
+            collection
+                .read_diff_cursor(ReadDiffCursorOptions { cursor_id })
+                .await
+        })
+    }
+
+    fn abort_cursor(
+        collection: Arc<Collection>,
+        cursor_id: Box<str>,
+    ) -> BoxFuture<'static, Result<(), CollectionMethodError>> {
+        Box::pin(async move {
+            collection
+                .abort_diff_cursor(AbortDiffCursorOptions { cursor_id })
+                .await
+        })
+    }
+}
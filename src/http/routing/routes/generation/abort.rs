@@ -1,28 +1,30 @@
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use crate::collection::methods::abort_generation::AbortGenerationOptions;
 
 use crate::context::Context;
-use crate::http::constants::READER_REQUEST_MAX_BYTES;
 
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 
 use crate::http::errors::HttpError;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 
+use crate::http::auth::AccessRole;
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::get_collection::get_collection;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_no_error_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    generation_id: EncodedGenerationIdJsonData,
+pub struct AbortGenerationRequestJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
 }
 
 #[fn_box_pin_async]
@@ -32,12 +34,13 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Write)?;
     request.allow_only_utf8_json_by_default()?;
 
-    let body = read_limited_body(request, READER_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+    let data: AbortGenerationRequestJsonData = read_json(body)?;
 
-    let RequestJsonData { generation_id } = data;
+    let AbortGenerationRequestJsonData { generation_id } = data;
 
     let generation_id = generation_id.into_generation_id()?;
 
@@ -50,7 +53,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let _ = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("generation/abort error {:?}", err);
+            tracing::error!(?err, "generation/abort error");
             return Err(HttpError::Unspecified);
         }
     };
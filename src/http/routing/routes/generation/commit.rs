@@ -1,16 +1,17 @@
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use crate::collection::methods::commit_generation::CommitGenerationOptions;
 
 use crate::context::Context;
-use crate::http::constants::READER_REQUEST_MAX_BYTES;
 
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::reader_record::UpdateReaderJsonData;
 
-use crate::http::errors::HttpError;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::collection_method_error;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::encoding::StringDecoder;
@@ -18,15 +19,16 @@ use crate::http::util::get_collection::get_collection;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_no_error_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
 use crate::util::option::lift_result_from_option;
 use crate::util::str_serialization::StrSerializationType;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    generation_id: EncodedGenerationIdJsonData,
-    update_readers: Option<Vec<UpdateReaderJsonData>>,
+pub struct CommitGenerationRequestJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub update_readers: Option<Vec<UpdateReaderJsonData>>,
 }
 
 #[fn_box_pin_async]
@@ -36,12 +38,13 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Write)?;
     request.allow_only_utf8_json_by_default()?;
 
-    let body = read_limited_body(request, READER_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+    let data: CommitGenerationRequestJsonData = read_json(body)?;
 
-    let RequestJsonData {
+    let CommitGenerationRequestJsonData {
         generation_id,
         update_readers,
     } = data;
@@ -61,13 +64,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
 
     let result = collection.commit_generation(options).await;
 
-    let _ = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("generation/commit error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
-    };
+    result.map_err(|err| collection_method_error(err, "generation/commit"))?;
 
     create_ok_no_error_json_response()
 }
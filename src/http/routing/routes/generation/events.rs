@@ -0,0 +1,216 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::common::OwnedGenerationId;
+use crate::context::Context;
+use crate::database::generations::collection::GenerationIdNextGenerationIdPair;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::no_such_collection_error;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::response::Response;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::stream_writer::StreamWriter;
+use crate::http::validation::{AccessValidation, MethodsValidation};
+use crate::util::str_serialization::StrSerializationType;
+use crate::util::tokio::spawn;
+use futures::future::select_all;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::select;
+use tokio::sync::watch;
+use tokio::time::sleep;
+
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationEventJsonData {
+    pub collection_name: String,
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub next_generation_id: Option<EncodedGenerationIdJsonData>,
+    // Number of puts to `generationId`
+    pub changed_keys: u32,
+}
+
+struct Subscription {
+    collection_name: String,
+    collection: Arc<Collection>,
+    receiver: watch::Receiver<GenerationIdNextGenerationIdPair>,
+    // Last sent one
+    generation_id: Option<OwnedGenerationId>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_methods(&["GET"])?;
+
+        let params = request
+            .query_params()
+            .map_err(|_| HttpError::Generic400("invalidQueryParams"))?;
+
+        let mut collections: Vec<(String, Arc<Collection>)> = Vec::new();
+
+        for (key, value) in params {
+            if key.deref() != "collectionName" {
+                continue;
+            }
+
+            let collection_name = value.into_owned();
+
+            request.require_collection_access(&collection_name, AccessRole::Read)?;
+
+            let is_duplicate = collections.iter().any(|(name, _)| name == &collection_name);
+            if is_duplicate {
+                continue;
+            }
+
+            let Some(collection) = context.database.get_collection(&collection_name).await else {
+                return Err(no_such_collection_error());
+            };
+
+            collections.push((collection_name, collection));
+        }
+
+        if collections.is_empty() {
+            return Err(HttpError::Generic400(
+                "at least one collectionName should be present",
+            ));
+        }
+
+        Ok(create_generation_events_response(collections))
+    })
+}
+
+pub fn create_generation_events_response(collections: Vec<(String, Arc<Collection>)>) -> Response {
+    let subscriptions = collections
+        .into_iter()
+        .map(|(collection_name, collection)| Subscription {
+            collection_name,
+            receiver: collection.generation_pair_receiver.clone(),
+            collection,
+            generation_id: None,
+        })
+        .collect();
+
+    let (writer, response) = StreamWriter::new_event_stream();
+
+    spawn(write_generation_events(subscriptions, writer));
+
+    response
+}
+
+// Committed generations after the last sent one, the current generation is always the last
+async fn get_new_generations(
+    subscription: &Subscription,
+    pair: &GenerationIdNextGenerationIdPair,
+) -> Result<Vec<(OwnedGenerationId, u32)>, CollectionMethodError> {
+    let collection = &subscription.collection;
+
+    let Some(last_generation_id) = &subscription.generation_id else {
+        let size = collection
+            .generation_size(pair.generation_id.clone())
+            .await?;
+
+        return Ok(vec![(pair.generation_id.clone(), size)]);
+    };
+
+    let mut generations = collection
+        .generation_sizes(last_generation_id.clone(), pair.generation_id.clone())
+        .await?;
+
+    // Empty generations are not stored
+    if generations.last().map(|(generation_id, _)| generation_id) != Some(&pair.generation_id) {
+        generations.push((pair.generation_id.clone(), 0));
+    }
+
+    Ok(generations)
+}
+
+// First events are current generations, then one event per commit, several commits
+// made before the events were sent are read from the stored generation sizes
+async fn write_generation_events(mut subscriptions: Vec<Subscription>, mut writer: StreamWriter) {
+    loop {
+        for subscription in &mut subscriptions {
+            let pair = subscription.receiver.borrow_and_update().clone();
+
+            if subscription.generation_id.as_ref() == Some(&pair.generation_id) {
+                continue;
+            }
+
+            let generations = match get_new_generations(subscription, &pair).await {
+                Ok(generations) => generations,
+                Err(err) => {
+                    tracing::error!(?err, "generations/events error");
+                    writer.abort();
+                    return;
+                }
+            };
+
+            for (generation_id, changed_keys) in generations {
+                // Next generation is known only for the current one
+                let next_generation_id = if generation_id == pair.generation_id {
+                    pair.next_generation_id.as_ref()
+                } else {
+                    None
+                };
+
+                let event = GenerationEventJsonData {
+                    collection_name: subscription.collection_name.clone(),
+                    generation_id: EncodedGenerationIdJsonData::encode(
+                        generation_id.as_ref(),
+                        StrSerializationType::Utf8,
+                    ),
+                    next_generation_id: next_generation_id.map(|generation_id| {
+                        EncodedGenerationIdJsonData::encode(
+                            generation_id.as_ref(),
+                            StrSerializationType::Utf8,
+                        )
+                    }),
+                    changed_keys,
+                };
+
+                if writer.write_event("generation", &event).is_err() {
+                    return;
+                }
+            }
+
+            subscription.generation_id = Some(pair.generation_id);
+        }
+
+        if writer.flush().await.is_err() {
+            return;
+        }
+
+        let changed = select_all(
+            subscriptions
+                .iter_mut()
+                .map(|subscription| Box::pin(subscription.receiver.changed())),
+        );
+
+        select! {
+            (result, _, _) = changed => {
+                // Collection was closed
+                if result.is_err() {
+                    return;
+                }
+            },
+            _ = sleep(KEEP_ALIVE_INTERVAL) => {
+                writer.write_keep_alive();
+            },
+        }
+    }
+}
+
+pub fn register_generation_events_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_get_route("/generations/events", handler);
+}
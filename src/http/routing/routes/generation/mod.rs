@@ -1,3 +1,4 @@
 pub mod abort;
 pub mod commit;
+pub mod events;
 pub mod start;
@@ -2,28 +2,30 @@ use crate::collection::methods::start_generation::StartGenerationOptions;
 
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use crate::context::Context;
-use crate::http::constants::READER_REQUEST_MAX_BYTES;
 
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 
 use crate::http::errors::HttpError;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 
+use crate::http::auth::AccessRole;
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::get_collection::get_collection;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_no_error_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    generation_id: EncodedGenerationIdJsonData,
-    abort_outdated: Option<bool>,
+pub struct StartGenerationRequestJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub abort_outdated: Option<bool>,
 }
 
 #[fn_box_pin_async]
@@ -33,12 +35,13 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Write)?;
     request.allow_only_utf8_json_by_default()?;
 
-    let body = read_limited_body(request, READER_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+    let data: StartGenerationRequestJsonData = read_json(body)?;
 
-    let RequestJsonData {
+    let StartGenerationRequestJsonData {
         generation_id,
         abort_outdated,
     } = data;
@@ -57,7 +60,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let _ = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("generation/start error {:?}", err);
+            tracing::error!(?err, "generation/start error");
             return Err(HttpError::Unspecified);
         }
     };
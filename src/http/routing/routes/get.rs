@@ -1,42 +1,50 @@
 use crate::collection::methods::get::CollectionGetOptions;
 
+use crate::common::IsByteArray;
 use crate::context::Context;
-use crate::http::constants::GET_REQUEST_MAX_BYTES;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::collection_method_error;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::encoded_key::EncodedKeyJsonData;
 use crate::http::data::encoded_phantom_id::EncodedPhantomIdJsonData;
 use crate::http::data::key_value::KeyValueJsonData;
+use crate::http::data::protobuf::{decode_generation_id, decode_key, decode_phantom_id, encode_key_value};
 use crate::http::errors::HttpError;
+use crate::protos::http_api;
 
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::encoding::StringDecoder;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
-use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::util::read_protobuf::read_protobuf;
+use crate::http::util::response::{create_ok_json_response, create_ok_protobuf_response};
+use crate::http::validation::{
+    AccessValidation, BodyFormat, ContentTypeValidation, MethodsValidation,
+};
 use crate::util::str_serialization::StrSerializationType;
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GetRequestJsonData {
-    key: EncodedKeyJsonData,
-    generation_id: Option<EncodedGenerationIdJsonData>,
-    phantom_id: Option<EncodedPhantomIdJsonData>,
+pub struct GetRequestJsonData {
+    pub key: EncodedKeyJsonData,
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
+    pub phantom_id: Option<EncodedPhantomIdJsonData>,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GetResponseJsonData {
-    generation_id: EncodedGenerationIdJsonData,
+pub struct GetResponseJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
 
     #[serialize_always]
-    item: Option<KeyValueJsonData>,
+    pub item: Option<KeyValueJsonData>,
 }
 
 #[fn_box_pin_async]
@@ -46,45 +54,63 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
-    request.allow_only_utf8_json_by_default()?;
-
-    let body = read_limited_body(request, GET_REQUEST_MAX_BYTES).await?;
-    let data: GetRequestJsonData = read_json(body)?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+    let body_format = request.allow_only_utf8_json_or_protobuf_by_default()?;
+    let response_format = request.accepted_body_format(body_format);
+
+    let body = read_limited_body(request, context.config.http.get_request_max_bytes).await?;
+    let options = match body_format {
+        BodyFormat::Json => decode_json_request(read_json(body)?)?,
+        BodyFormat::Protobuf => decode_protobuf_request(read_protobuf(body)?)?,
+    };
 
     let collection = context.database.get_collection(&collection_name).await;
     let Some(collection) = collection else { return Err(HttpError::Generic400("no such collection")); };
 
-    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+    let result = collection.get(options).await;
 
-    let key = EncodedKeyJsonData::decode(data.key, &decoder)?;
-    let generation_id = EncodedGenerationIdJsonData::decode_opt(data.generation_id)?;
-    let phantom_id = EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?;
+    let result = result.map_err(|err| collection_method_error(err, "get"))?;
 
-    let options = CollectionGetOptions {
-        key,
-        generation_id,
-        phantom_id,
-    };
+    match response_format {
+        BodyFormat::Json => {
+            let response = GetResponseJsonData {
+                generation_id: EncodedGenerationIdJsonData::encode(
+                    result.generation_id.as_ref(),
+                    StrSerializationType::Utf8,
+                ),
+                item: result.item.map(|item| item.into()),
+            };
 
-    let result = collection.get(options).await;
+            create_ok_json_response(&response)
+        }
+        BodyFormat::Protobuf => {
+            let mut response = http_api::GetResponse::new();
+            response.generation_id = result.generation_id.get_byte_array().to_vec();
+            response.item = result.item.map(encode_key_value).into();
 
-    let result = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("get error {:?}", err);
-            return Err(HttpError::Unspecified);
+            create_ok_protobuf_response(&response)
         }
-    };
+    }
+}
 
-    let response = GetResponseJsonData {
-        generation_id: EncodedGenerationIdJsonData::encode(
-            result.generation_id.as_ref(),
-            StrSerializationType::Utf8,
-        ),
-        item: result.item.map(|item| item.into()),
-    };
+fn decode_json_request(data: GetRequestJsonData) -> Result<CollectionGetOptions, HttpError> {
+    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+    Ok(CollectionGetOptions {
+        key: EncodedKeyJsonData::decode(data.key, &decoder)?,
+        generation_id: EncodedGenerationIdJsonData::decode_opt(data.generation_id)?,
+        phantom_id: EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?,
+    })
+}
 
-    create_ok_json_response(&response)
+fn decode_protobuf_request(
+    data: http_api::GetRequest,
+) -> Result<CollectionGetOptions, HttpError> {
+    Ok(CollectionGetOptions {
+        key: decode_key(data.key)?,
+        generation_id: data.generation_id.map(decode_generation_id).transpose()?,
+        phantom_id: data.phantom_id.map(decode_phantom_id).transpose()?,
+    })
 }
 
 pub fn register_get_route(context: &mut Context) {
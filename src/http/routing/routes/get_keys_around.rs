@@ -1,9 +1,10 @@
 use crate::collection::methods::get_keys_around::CollectionGetKeysAroundOptions;
 
 use crate::context::Context;
-use crate::http::constants::GET_KEYS_AROUND_REQUEST_MAX_BYTES;
+use crate::http::auth::AccessRole;
 use crate::http::data::encoded_key::EncodedKeyJsonData;
 
+use crate::http::custom_errors::collection_method_error;
 use crate::http::errors::HttpError;
 
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
@@ -14,7 +15,7 @@ use crate::http::util::encoding::StringDecoder;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
 use crate::util::str_serialization::StrSerializationType;
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
@@ -55,9 +56,11 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
     request.allow_only_utf8_json_by_default()?;
 
-    let body = read_limited_body(request, GET_KEYS_AROUND_REQUEST_MAX_BYTES).await?;
+    let body =
+        read_limited_body(request, context.config.http.get_keys_around_request_max_bytes).await?;
     let data: RequestJsonData = read_json(body)?;
 
     let collection = context.database.get_collection(&collection_name).await;
@@ -82,13 +85,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
 
     let result = collection.get_keys_around(options).await;
 
-    let result = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("get_keys_around error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
-    };
+    let result = result.map_err(|err| collection_method_error(err, "get_keys_around"))?;
 
     let response = ResponseJsonData {
         generation_id: EncodedGenerationIdJsonData::encode(
@@ -99,7 +96,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
         right: EncodedKeyJsonData::encode_vec(result.right),
         has_more_on_the_left: result.has_more_on_the_left,
         has_more_on_the_right: result.has_more_on_the_right,
-        found_key: true,
+        found_key: result.found_key,
     };
 
     create_ok_json_response(&response)
@@ -0,0 +1,288 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::metrics::CollectionMetrics;
+use crate::collection::Collection;
+use crate::common::{IsByteArray, OwnedGenerationId};
+use crate::context::Context;
+use crate::http::errors::HttpError;
+use crate::http::routing::response::{BaseResponse, Response, StringResponse};
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::validation::{AccessValidation, MethodsValidation};
+use crate::raw_db::properties::RawDbProperties;
+use crate::util::prometheus::PrometheusWriter;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type RawDbPropertyFn = fn(&RawDbProperties) -> u64;
+
+struct ReaderLag {
+    collection_name: String,
+    reader_name: String,
+    target_collection_name: String,
+    // Generations with puts in the target collection that are not read yet
+    generations_count: u64,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_methods(&["GET"])?;
+
+        let mut collections = context.database.collections_list().await;
+        collections.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+
+        // Readers can point to collections that are not readable by the token
+        let collections_by_name: HashMap<String, Arc<Collection>> = collections
+            .iter()
+            .map(|collection| (collection.get_name().to_string(), collection.clone()))
+            .collect();
+
+        let mut items = Vec::with_capacity(collections.len());
+
+        for collection in collections {
+            if !request.can_read_collection(collection.get_name()) {
+                continue;
+            }
+
+            match collection.metrics().await {
+                Ok(metrics) => items.push((collection.get_name().to_string(), metrics)),
+                // Deleted while metrics were collected
+                Err(CollectionMethodError::NoSuchCollection) => {}
+                Err(err) => {
+                    tracing::error!(?err, "metrics error");
+                    return Err(HttpError::Unspecified);
+                }
+            }
+        }
+
+        let mut reader_lags = Vec::new();
+
+        for (name, metrics) in &items {
+            for reader in &metrics.readers {
+                if reader.is_orphaned {
+                    continue;
+                }
+
+                let target_name = reader.collection_name.as_deref().unwrap_or(name);
+                let Some(target) = collections_by_name.get(target_name) else {
+                    continue;
+                };
+
+                match target.generations_lag(reader.generation_id.clone()).await {
+                    Ok(lag) => reader_lags.push(ReaderLag {
+                        collection_name: name.clone(),
+                        reader_name: reader.reader_name.clone(),
+                        target_collection_name: target_name.to_string(),
+                        generations_count: lag.lag.generations_count,
+                    }),
+                    Err(CollectionMethodError::NoSuchCollection) => {}
+                    Err(err) => {
+                        tracing::error!(?err, "metrics error");
+                        return Err(HttpError::Unspecified);
+                    }
+                }
+            }
+        }
+
+        let mut writer = PrometheusWriter::default();
+
+        context.http_metrics.write(&mut writer);
+        write_collections_metrics(&mut writer, &items, &reader_lags);
+
+        Ok(Response::String(StringResponse {
+            base: BaseResponse {
+                content_type: "text/plain; version=0.0.4; charset=utf-8",
+                ..Default::default()
+            },
+            str: writer.finish(),
+        }))
+    })
+}
+
+fn write_collections_metrics(
+    writer: &mut PrometheusWriter,
+    items: &[(String, CollectionMetrics)],
+    reader_lags: &[ReaderLag],
+) {
+    writer.metric(
+        "diffbelt_collection_generation_id",
+        "gauge",
+        "Current generation id, only for ids up to 8 bytes",
+    );
+    for (name, metrics) in items {
+        if let Some(generation_id) = generation_id_as_number(&metrics.generation_id) {
+            writer.sample(
+                "diffbelt_collection_generation_id",
+                &[("collection", name)],
+                generation_id,
+            );
+        }
+    }
+
+    writer.metric(
+        "diffbelt_collection_commits_total",
+        "counter",
+        "Generations committed since the server start",
+    );
+    for (name, metrics) in items {
+        writer.sample(
+            "diffbelt_collection_commits_total",
+            &[("collection", name)],
+            metrics.commits_count,
+        );
+    }
+
+    writer.metric(
+        "diffbelt_collection_next_generation_size",
+        "gauge",
+        "Puts to the generation in progress",
+    );
+    for (name, metrics) in items {
+        if let Some(size) = metrics.next_generation_size {
+            writer.sample(
+                "diffbelt_collection_next_generation_size",
+                &[("collection", name)],
+                size,
+            );
+        }
+    }
+
+    writer.metric(
+        "diffbelt_collection_cursors",
+        "gauge",
+        "Live query and diff cursors",
+    );
+    for (name, metrics) in items {
+        writer.sample(
+            "diffbelt_collection_cursors",
+            &[("collection", name), ("type", "query")],
+            metrics.query_cursors_count,
+        );
+        writer.sample(
+            "diffbelt_collection_cursors",
+            &[("collection", name), ("type", "diff")],
+            metrics.diff_cursors_count,
+        );
+    }
+
+    writer.metric(
+        "diffbelt_gc_removed_records_total",
+        "counter",
+        "Records removed by the garbage collector since the server start",
+    );
+    for (name, metrics) in items {
+        writer.sample(
+            "diffbelt_gc_removed_records_total",
+            &[("collection", name)],
+            metrics.gc_removed_records,
+        );
+    }
+
+    writer.metric(
+        "diffbelt_gc_batches_total",
+        "counter",
+        "Cleanup batches of the garbage collector since the server start",
+    );
+    for (name, metrics) in items {
+        writer.sample(
+            "diffbelt_gc_batches_total",
+            &[("collection", name)],
+            metrics.gc_batches,
+        );
+    }
+
+    writer.metric(
+        "diffbelt_gc_lag_generations",
+        "gauge",
+        "Generations with puts below the minimum generation id of readers that are not collected yet",
+    );
+    for (name, metrics) in items {
+        writer.sample(
+            "diffbelt_gc_lag_generations",
+            &[("collection", name)],
+            metrics.gc_lag.generations_count,
+        );
+    }
+
+    writer.metric(
+        "diffbelt_reader_lag_generations",
+        "gauge",
+        "Generations with puts of the target collection that are not read by the reader yet",
+    );
+    for lag in reader_lags {
+        writer.sample(
+            "diffbelt_reader_lag_generations",
+            &[
+                ("collection", &lag.collection_name),
+                ("reader", &lag.reader_name),
+                ("target_collection", &lag.target_collection_name),
+            ],
+            lag.generations_count,
+        );
+    }
+
+    let rocksdb_metrics: [(&str, &str, RawDbPropertyFn); 6] = [
+        (
+            "diffbelt_rocksdb_estimate_num_keys",
+            "Estimated number of keys in all column families",
+            |properties| properties.estimate_num_keys,
+        ),
+        (
+            "diffbelt_rocksdb_sst_files_bytes",
+            "Size of all SST files",
+            |properties| properties.total_sst_files_size,
+        ),
+        (
+            "diffbelt_rocksdb_memtables_bytes",
+            "Size of all memtables",
+            |properties| properties.memtables_size,
+        ),
+        (
+            "diffbelt_rocksdb_pending_compaction_bytes",
+            "Estimated bytes that compaction needs to rewrite",
+            |properties| properties.pending_compaction_bytes,
+        ),
+        (
+            "diffbelt_rocksdb_running_compactions",
+            "Currently running compactions",
+            |properties| properties.running_compactions,
+        ),
+        (
+            "diffbelt_rocksdb_running_flushes",
+            "Currently running flushes",
+            |properties| properties.running_flushes,
+        ),
+    ];
+
+    for (metric_name, help, get_value) in rocksdb_metrics {
+        writer.metric(metric_name, "gauge", help);
+        for (name, metrics) in items {
+            writer.sample(
+                metric_name,
+                &[("collection", name)],
+                get_value(&metrics.raw_db),
+            );
+        }
+    }
+}
+
+// Generation ids of non-manual collections are 8 bytes big-endian counters,
+// manual collections can have ids of any length
+fn generation_id_as_number(generation_id: &OwnedGenerationId) -> Option<u64> {
+    let bytes = generation_id.get_byte_array();
+
+    if bytes.len() > 8 {
+        return None;
+    }
+
+    Some(
+        bytes
+            .iter()
+            .fold(0u64, |acc, byte| (acc << 8) | (*byte as u64)),
+    )
+}
+
+pub fn register_metrics_route(context: &mut Context) {
+    context.routing.add_static_get_route("/metrics", handler);
+}
@@ -1,11 +1,17 @@
+pub mod admin;
 pub mod collection;
 pub mod diff;
 pub mod generation;
 pub mod get;
 pub mod get_keys_around;
+pub mod metrics;
 pub mod phantom;
 pub mod put;
 pub mod put_many;
 pub mod query;
 pub mod reader;
 pub mod root;
+pub mod transaction;
+pub mod transform;
+pub mod trash;
+pub mod watch;
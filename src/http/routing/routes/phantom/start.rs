@@ -13,8 +13,9 @@ use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::get_collection::get_collection;
 
+use crate::http::auth::AccessRole;
 use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
 use crate::util::str_serialization::StrSerializationType;
 
 #[skip_serializing_none]
@@ -31,6 +32,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Write)?;
     request.allow_only_utf8_json_by_default()?;
 
     let collection = get_collection(&context, &collection_name).await?;
@@ -40,7 +42,7 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let phantom_id = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("phantom/start error {:?}", err);
+            tracing::error!(?err, "phantom/start error");
             return Err(HttpError::Unspecified);
         }
     };
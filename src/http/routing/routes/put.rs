@@ -1,43 +1,52 @@
 use crate::context::Context;
+use crate::http::auth::AccessRole;
 use crate::http::errors::HttpError;
 use crate::http::routing::response::{BaseResponse, BytesVecResponse, Response};
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::validation::{
+    AccessValidation, BodyFormat, ContentTypeValidation, MethodsValidation,
+};
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
 
 use crate::collection::methods::put::CollectionPutOptions;
 
-use crate::http::constants::PUT_REQUEST_MAX_BYTES;
-
 use crate::http::util::encoding::StringDecoder;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
+use crate::http::util::read_protobuf::read_protobuf;
+use crate::http::util::response::create_ok_protobuf_response;
 
+use crate::common::IsByteArray;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::encoded_phantom_id::EncodedPhantomIdJsonData;
 use crate::http::data::key_value_update::KeyValueUpdateJsonData;
+use crate::http::data::protobuf::{
+    decode_generation_id, decode_key_value_update, decode_phantom_id,
+};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::get_collection::get_collection;
+use crate::protos::http_api;
 use crate::util::str_serialization::StrSerializationType;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct PutRequestJsonData {
-    item: KeyValueUpdateJsonData,
+pub struct PutRequestJsonData {
+    pub item: KeyValueUpdateJsonData,
 
-    generation_id: Option<EncodedGenerationIdJsonData>,
-    phantom_id: Option<EncodedPhantomIdJsonData>,
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
+    pub phantom_id: Option<EncodedPhantomIdJsonData>,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct PutResponseJsonData {
-    generation_id: EncodedGenerationIdJsonData,
-    was_put: Option<bool>,
+pub struct PutResponseJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub was_put: Option<bool>,
 }
 
 #[fn_box_pin_async]
@@ -47,43 +56,48 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
-    request.allow_only_utf8_json_by_default()?;
-
-    let body = read_limited_body(request, PUT_REQUEST_MAX_BYTES).await?;
-    let data: PutRequestJsonData = read_json(body)?;
+    request.require_collection_access(&collection_name, AccessRole::Write)?;
+    let body_format = request.allow_only_utf8_json_or_protobuf_by_default()?;
+    let response_format = request.accepted_body_format(body_format);
+
+    let body = read_limited_body(request, context.config.http.put_request_max_bytes).await?;
+    let options = match body_format {
+        BodyFormat::Json => decode_json_request(read_json(body)?)?,
+        BodyFormat::Protobuf => decode_protobuf_request(read_protobuf(body)?)?,
+    };
 
     let collection = get_collection(&context, &collection_name).await?;
 
-    let decoder = StringDecoder::new(StrSerializationType::Utf8);
-
-    let update = data.item.deserialize(&decoder)?;
-    let if_not_present = update.if_not_present;
-
-    let generation_id = EncodedGenerationIdJsonData::decode_opt(data.generation_id)?;
-    let phantom_id = EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?;
-
-    let options = CollectionPutOptions {
-        update,
-        generation_id,
-        phantom_id,
-    };
+    let update = &options.update;
+    let is_conditional = update.if_not_present
+        || update
+            .if_value_changed
+            .unwrap_or_else(|| collection.is_if_value_changed());
 
     let result = collection.put(options).await;
 
     let result = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("put error {:?}", err);
+            tracing::error!(?err, "put error");
             return Err(HttpError::Unspecified);
         }
     };
 
+    if response_format == BodyFormat::Protobuf {
+        let mut response = http_api::PutResponse::new();
+        response.generation_id = result.generation_id.get_byte_array().to_vec();
+        response.was_put = is_conditional.then_some(result.was_put);
+
+        return create_ok_protobuf_response(&response);
+    }
+
     let response = PutResponseJsonData {
         generation_id: EncodedGenerationIdJsonData::encode(
             result.generation_id.as_ref(),
             StrSerializationType::Utf8,
         ),
-        was_put: if if_not_present {
+        was_put: if is_conditional {
             Some(result.was_put)
         } else {
             None
@@ -103,6 +117,29 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     }))
 }
 
+fn decode_json_request(data: PutRequestJsonData) -> Result<CollectionPutOptions, HttpError> {
+    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+    Ok(CollectionPutOptions {
+        update: data.item.deserialize(&decoder)?,
+        generation_id: EncodedGenerationIdJsonData::decode_opt(data.generation_id)?,
+        phantom_id: EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?,
+    })
+}
+
+fn decode_protobuf_request(data: http_api::PutRequest) -> Result<CollectionPutOptions, HttpError> {
+    let item = data
+        .item
+        .into_option()
+        .ok_or(HttpError::Generic400("item should be present"))?;
+
+    Ok(CollectionPutOptions {
+        update: decode_key_value_update(item)?,
+        generation_id: data.generation_id.map(decode_generation_id).transpose()?,
+        phantom_id: data.phantom_id.map(decode_phantom_id).transpose()?,
+    })
+}
+
 pub fn register_put_route(context: &mut Context) {
     context.routing.add_pattern_route(
         Regex::new("^/collections/(?P<id>[^/]+)/put$").unwrap(),
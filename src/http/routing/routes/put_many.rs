@@ -2,11 +2,15 @@ use crate::collection::methods::put::CollectionPutManyOptions;
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
 
+use crate::common::IsByteArray;
 use crate::context::Context;
-use crate::http::constants::PUT_MANY_REQUEST_MAX_BYTES;
+use crate::http::auth::AccessRole;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 use crate::http::data::encoded_phantom_id::EncodedPhantomIdJsonData;
 use crate::http::data::key_value_update::KeyValueUpdateJsonData;
+use crate::http::data::protobuf::{
+    decode_generation_id, decode_key_value_update, decode_phantom_id,
+};
 use crate::http::errors::HttpError;
 use crate::http::routing::response::{BaseResponse, BytesVecResponse, Response};
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
@@ -15,24 +19,31 @@ use crate::http::util::encoding::StringDecoder;
 use crate::http::util::get_collection::get_collection;
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::util::read_protobuf::read_protobuf;
+use crate::http::util::response::create_ok_protobuf_response;
+use crate::http::validation::{
+    AccessValidation, BodyFormat, ContentTypeValidation, MethodsValidation,
+};
+use crate::protos::http_api;
 use crate::util::str_serialization::StrSerializationType;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct PutManyRequestJsonData {
-    items: Vec<KeyValueUpdateJsonData>,
-    generation_id: Option<EncodedGenerationIdJsonData>,
-    phantom_id: Option<EncodedPhantomIdJsonData>,
+pub struct PutManyRequestJsonData {
+    pub items: Vec<KeyValueUpdateJsonData>,
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
+    pub phantom_id: Option<EncodedPhantomIdJsonData>,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct PutManyResponseJsonData {
-    generation_id: EncodedGenerationIdJsonData,
+pub struct PutManyResponseJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub was_put: Option<Vec<bool>>,
 }
 
 #[fn_box_pin_async]
@@ -42,46 +53,55 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
-    request.allow_only_utf8_json_by_default()?;
-
-    let body = read_limited_body(request, PUT_MANY_REQUEST_MAX_BYTES).await?;
-    let data: PutManyRequestJsonData = read_json(body)?;
+    request.require_collection_access(&collection_name, AccessRole::Write)?;
+    let body_format = request.allow_only_utf8_json_or_protobuf_by_default()?;
+    let response_format = request.accepted_body_format(body_format);
+
+    let body = read_limited_body(request, context.config.http.put_many_request_max_bytes).await?;
+    let options = match body_format {
+        BodyFormat::Json => decode_json_request(read_json(body)?)?,
+        BodyFormat::Protobuf => decode_protobuf_request(read_protobuf(body)?)?,
+    };
 
     let collection = get_collection(&context, &collection_name).await?;
 
-    let decoder = StringDecoder::new(StrSerializationType::Utf8);
-
-    let mut items = Vec::with_capacity(data.items.len());
-
-    for item in data.items {
-        let update = item.deserialize(&decoder)?;
-        items.push(update);
-    }
-
-    let generation_id = EncodedGenerationIdJsonData::decode_opt(data.generation_id)?;
-    let phantom_id = EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?;
-
-    let options = CollectionPutManyOptions {
-        items,
-        generation_id,
-        phantom_id,
-    };
+    let is_conditional = options.items.iter().any(|update| {
+        update.if_not_present
+            || update
+                .if_value_changed
+                .unwrap_or_else(|| collection.is_if_value_changed())
+    });
 
     let result = collection.put_many(options).await;
 
     let result = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("put error {:?}", err);
+            tracing::error!(?err, "put_many error");
             return Err(HttpError::Unspecified);
         }
     };
 
+    if response_format == BodyFormat::Protobuf {
+        let mut response = http_api::PutManyResponse::new();
+        response.generation_id = result.generation_id.get_byte_array().to_vec();
+        if is_conditional {
+            response.was_put = result.was_put;
+        }
+
+        return create_ok_protobuf_response(&response);
+    }
+
     let response = PutManyResponseJsonData {
         generation_id: EncodedGenerationIdJsonData::encode(
             result.generation_id.as_ref(),
             StrSerializationType::Utf8,
         ),
+        was_put: if is_conditional {
+            Some(result.was_put)
+        } else {
+            None
+        },
     };
 
     let response = serde_json::to_vec(&response).or(Err(HttpError::PublicInternal500(
@@ -97,6 +117,40 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     }))
 }
 
+fn decode_json_request(
+    data: PutManyRequestJsonData,
+) -> Result<CollectionPutManyOptions, HttpError> {
+    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+    let mut items = Vec::with_capacity(data.items.len());
+
+    for item in data.items {
+        items.push(item.deserialize(&decoder)?);
+    }
+
+    Ok(CollectionPutManyOptions {
+        items,
+        generation_id: EncodedGenerationIdJsonData::decode_opt(data.generation_id)?,
+        phantom_id: EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?,
+    })
+}
+
+fn decode_protobuf_request(
+    data: http_api::PutManyRequest,
+) -> Result<CollectionPutManyOptions, HttpError> {
+    let mut items = Vec::with_capacity(data.items.len());
+
+    for item in data.items {
+        items.push(decode_key_value_update(item)?);
+    }
+
+    Ok(CollectionPutManyOptions {
+        items,
+        generation_id: data.generation_id.map(decode_generation_id).transpose()?,
+        phantom_id: data.phantom_id.map(decode_phantom_id).transpose()?,
+    })
+}
+
 pub fn register_put_many_route(context: &mut Context) {
     context.routing.add_pattern_route(
         Regex::new("^/collections/(?P<id>[^/]+)/putMany$").unwrap(),
@@ -21,7 +21,7 @@ pub async fn abort_cursor(
     let _ = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("query/abort error {:?}", err);
+            tracing::error!(?err, "query/abort error");
             return Err(HttpError::Unspecified);
         }
     };
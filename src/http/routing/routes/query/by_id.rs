@@ -3,6 +3,7 @@ use regex::Regex;
 
 use crate::context::Context;
 
+use crate::http::auth::AccessRole;
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 use crate::http::routing::routes::query::abort::abort_cursor;
@@ -10,6 +11,7 @@ use crate::http::routing::routes::query::next::read_cursor;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_with_name_group, IdWithNameGroup};
 use crate::http::util::get_collection::get_collection;
+use crate::http::validation::AccessValidation;
 
 #[fn_box_pin_async]
 async fn handler(options: PatternRouteOptions<IdWithNameGroup>) -> HttpHandlerResult {
@@ -18,6 +20,8 @@ async fn handler(options: PatternRouteOptions<IdWithNameGroup>) -> HttpHandlerRe
     let collection_name = options.groups.id;
     let cursor_id = options.groups.name;
 
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+
     let collection = get_collection(&context, &collection_name).await?;
 
     match request.method() {
@@ -2,3 +2,4 @@ mod abort;
 pub mod by_id;
 mod next;
 pub mod start;
+pub mod stream;
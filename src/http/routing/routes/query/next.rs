@@ -2,33 +2,37 @@ use std::sync::Arc;
 
 use crate::collection::Collection;
 
-use crate::collection::methods::query::ReadQueryCursorOptions;
+use crate::collection::methods::query::{QueryOk, ReadQueryCursorOptions};
 
+use crate::http::custom_errors::collection_method_error;
 use crate::http::data::query_response::QueryResponseJsonData;
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 
 use crate::http::routing::response::Response;
 
-use crate::http::util::response::create_ok_json_response;
+use crate::http::util::response::{create_ok_json_response, create_ok_protobuf_response};
+use crate::http::validation::{BodyFormat, ContentTypeValidation};
+use crate::protos::http_api;
 
 pub async fn read_cursor(
-    _request: impl Request,
+    request: impl Request,
     collection: Arc<Collection>,
     cursor_id: Box<str>,
 ) -> Result<Response, HttpError> {
+    let response_format = request.accepted_body_format(BodyFormat::Json);
     let options = ReadQueryCursorOptions { cursor_id };
 
     let result = collection.read_query_cursor(options).await;
 
-    let result = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("query/start error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
-    };
+    let result = result.map_err(|err| collection_method_error(err, "query/next"))?;
 
-    let response = QueryResponseJsonData::from(result);
-    create_ok_json_response(&response)
+    create_query_response(result, response_format)
+}
+
+pub fn create_query_response(result: QueryOk, format: BodyFormat) -> Result<Response, HttpError> {
+    match format {
+        BodyFormat::Json => create_ok_json_response(&QueryResponseJsonData::from(result)),
+        BodyFormat::Protobuf => create_ok_protobuf_response(&http_api::QueryResponse::from(result)),
+    }
 }
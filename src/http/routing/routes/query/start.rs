@@ -1,30 +1,48 @@
 use diffbelt_macro::fn_box_pin_async;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use crate::collection::methods::query::QueryOptions;
 use crate::context::Context;
-use crate::http::constants::QUERY_START_REQUEST_MAX_BYTES;
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::data::encoded_key::EncodedKeyJsonData;
 use crate::http::data::encoded_phantom_id::EncodedPhantomIdJsonData;
 
-use crate::http::data::query_response::QueryResponseJsonData;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::collection_method_error;
+use crate::http::data::protobuf::{decode_generation_id, decode_key, decode_phantom_id};
 use crate::http::errors::HttpError;
+use crate::http::routing::routes::query::next::create_query_response;
+use crate::http::routing::routes::query::stream::create_query_stream_response;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::encoding::StringDecoder;
 use crate::http::util::get_collection::get_collection;
+use crate::http::util::key_bounds::{from_key_bound, to_key_bound};
 use crate::http::util::read_body::read_limited_body;
 use crate::http::util::read_json::read_json;
-use crate::http::util::response::create_ok_json_response;
-use crate::http::validation::{ContentTypeValidation, MethodsValidation};
+use crate::http::util::read_protobuf::read_protobuf;
+use crate::http::validation::{
+    AccessValidation, BodyFormat, ContentTypeValidation, MethodsValidation,
+};
+use crate::protos::http_api;
 use crate::util::str_serialization::StrSerializationType;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    generation_id: Option<EncodedGenerationIdJsonData>,
-    phantom_id: Option<EncodedPhantomIdJsonData>,
+pub struct StartQueryRequestJsonData {
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
+    pub phantom_id: Option<EncodedPhantomIdJsonData>,
+    pub from_key: Option<EncodedKeyJsonData>,
+    pub from_key_exclusive: Option<bool>,
+    pub to_key: Option<EncodedKeyJsonData>,
+    pub to_key_inclusive: Option<bool>,
+    pub prefix: Option<EncodedKeyJsonData>,
+    pub reverse: Option<bool>,
+    // Stream all records in one response instead of cursor packs
+    pub stream: Option<bool>,
 }
 
 #[fn_box_pin_async]
@@ -34,35 +52,68 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
     let collection_name = options.groups.0;
 
     request.allow_only_methods(&["POST"])?;
-    request.allow_only_utf8_json_by_default()?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+    let body_format = request.allow_only_utf8_json_or_protobuf_by_default()?;
+    let response_format = request.accepted_body_format(body_format);
 
-    let body = read_limited_body(request, QUERY_START_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    let body =
+        read_limited_body(request, context.config.http.query_start_request_max_bytes).await?;
+    let (options, is_stream) = match body_format {
+        BodyFormat::Json => decode_json_request(read_json(body)?)?,
+        BodyFormat::Protobuf => decode_protobuf_request(read_protobuf(body)?)?,
+    };
 
-    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+    let collection = get_collection(&context, &collection_name).await?;
+
+    let result = collection.query(options).await;
 
-    let generation_id = EncodedGenerationIdJsonData::decode_opt(data.generation_id)?;
-    let phantom_id = EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?;
+    let result = result.map_err(|err| collection_method_error(err, "query/start"))?;
 
-    let collection = get_collection(&context, &collection_name).await?;
+    if is_stream {
+        return Ok(create_query_stream_response(
+            collection,
+            result,
+            response_format,
+        ));
+    }
+
+    create_query_response(result, response_format)
+}
+
+fn decode_json_request(data: StartQueryRequestJsonData) -> Result<(QueryOptions, bool), HttpError> {
+    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+    let from_key = data.from_key.map(|key| key.decode(&decoder)).transpose()?;
+    let to_key = data.to_key.map(|key| key.decode(&decoder)).transpose()?;
 
     let options = QueryOptions {
-        generation_id,
-        phantom_id,
+        generation_id: EncodedGenerationIdJsonData::decode_opt(data.generation_id)?,
+        phantom_id: EncodedPhantomIdJsonData::decode_opt(data.phantom_id, &decoder)?,
+        from_key: from_key_bound(from_key, data.from_key_exclusive),
+        to_key: to_key_bound(to_key, data.to_key_inclusive),
+        prefix: data.prefix.map(|key| key.decode(&decoder)).transpose()?,
+        reverse: data.reverse.unwrap_or(false),
     };
 
-    let result = collection.query(options).await;
+    Ok((options, data.stream.unwrap_or(false)))
+}
+
+fn decode_protobuf_request(
+    data: http_api::QueryRequest,
+) -> Result<(QueryOptions, bool), HttpError> {
+    let from_key = data.from_key.map(decode_key).transpose()?;
+    let to_key = data.to_key.map(decode_key).transpose()?;
 
-    let result = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("query/start error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
+    let options = QueryOptions {
+        generation_id: data.generation_id.map(decode_generation_id).transpose()?,
+        phantom_id: data.phantom_id.map(decode_phantom_id).transpose()?,
+        from_key: from_key_bound(from_key, data.from_key_exclusive),
+        to_key: to_key_bound(to_key, data.to_key_inclusive),
+        prefix: data.prefix.map(decode_key).transpose()?,
+        reverse: data.reverse.unwrap_or(false),
     };
 
-    let response = QueryResponseJsonData::from(result);
-    create_ok_json_response(&response)
+    Ok((options, data.stream.unwrap_or(false)))
 }
 
 pub fn register_start_query_route(context: &mut Context) {
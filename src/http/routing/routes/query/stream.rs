@@ -0,0 +1,69 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::query::{AbortQueryCursorOptions, QueryOk, ReadQueryCursorOptions};
+use crate::collection::Collection;
+use crate::common::KeyValue;
+use crate::http::data::key_value::KeyValueJsonData;
+use crate::http::data::protobuf::encode_key_value;
+use crate::http::data::query_response::QueryResponseJsonData;
+use crate::http::routing::response::Response;
+use crate::http::util::cursor_stream::{create_cursor_stream_response, CursorStreamPage};
+use crate::http::util::stream_writer::{StreamClosed, StreamWriter};
+use crate::http::validation::BodyFormat;
+use crate::protos::http_api;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+// One `KeyValue` per record after the header
+pub fn create_query_stream_response(
+    collection: Arc<Collection>,
+    result: QueryOk,
+    format: BodyFormat,
+) -> Response {
+    create_cursor_stream_response(collection, result, format)
+}
+
+impl CursorStreamPage for QueryOk {
+    type Item = KeyValue;
+
+    const ROUTE: &'static str = "query/stream";
+
+    fn take_items_and_cursor_id(&mut self) -> (Vec<KeyValue>, Option<Box<str>>) {
+        (std::mem::take(&mut self.items), self.cursor_id.take())
+    }
+
+    fn write_header(self, writer: &mut StreamWriter) -> Result<(), StreamClosed> {
+        match writer.format() {
+            BodyFormat::Json => writer.write_json(&QueryResponseJsonData::from(self)),
+            BodyFormat::Protobuf => writer.write_protobuf(&http_api::QueryResponse::from(self)),
+        }
+    }
+
+    fn write_item(item: KeyValue, writer: &mut StreamWriter) -> Result<(), StreamClosed> {
+        match writer.format() {
+            BodyFormat::Json => writer.write_json(&KeyValueJsonData::from(item)),
+            BodyFormat::Protobuf => writer.write_protobuf(&encode_key_value(item)),
+        }
+    }
+
+    fn read_cursor(
+        collection: Arc<Collection>,
+        cursor_id: Box<str>,
+    ) -> BoxFuture<'static, Result<Self, CollectionMethodError>> {
+        Box::pin(async move {
+            collection
+                .read_query_cursor(ReadQueryCursorOptions { cursor_id })
+                .await
+        })
+    }
+
+    fn abort_cursor(
+        collection: Arc<Collection>,
+        cursor_id: Box<str>,
+    ) -> BoxFuture<'static, Result<(), CollectionMethodError>> {
+        Box::pin(async move {
+            collection
+                .abort_query_cursor(AbortQueryCursorOptions { cursor_id })
+                .await
+        })
+    }
+}
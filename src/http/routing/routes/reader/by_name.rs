@@ -3,14 +3,17 @@ use regex::Regex;
 
 use crate::context::Context;
 
+use crate::http::auth::AccessRole;
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 use crate::http::routing::routes::reader::delete::delete_reader;
+use crate::http::routing::routes::reader::status::reader_status;
 use crate::http::routing::routes::reader::update::update_reader;
 use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_with_name_group, IdWithNameGroup};
 
 use crate::http::util::get_collection::get_collection;
+use crate::http::validation::AccessValidation;
 
 #[fn_box_pin_async]
 async fn handler(options: PatternRouteOptions<IdWithNameGroup>) -> HttpHandlerResult {
@@ -19,10 +22,17 @@ async fn handler(options: PatternRouteOptions<IdWithNameGroup>) -> HttpHandlerRe
     let collection_name = options.groups.id;
     let reader_name = options.groups.name;
 
+    let role = match request.method() {
+        "GET" => AccessRole::Read,
+        _ => AccessRole::Write,
+    };
+    request.require_collection_access(&collection_name, role)?;
+
     let collection = get_collection(&context, &collection_name).await?;
 
     match request.method() {
-        "PUT" => update_reader(request, collection, reader_name.to_string()).await,
+        "GET" => reader_status(request, &context, collection, reader_name.to_string()).await,
+        "PUT" => update_reader(request, &context, collection, reader_name.to_string()).await,
         "DELETE" => delete_reader(request, collection, reader_name.to_string()).await,
         _ => Err(HttpError::MethodNotAllowed),
     }
@@ -5,6 +5,7 @@ use serde_with::skip_serializing_none;
 
 use crate::context::Context;
 
+use crate::http::auth::AccessRole;
 use crate::http::data::reader_record::ReaderRecordJsonData;
 
 use crate::http::errors::HttpError;
@@ -16,7 +17,7 @@ use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
 use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
 use crate::http::util::get_collection::get_collection;
 
-use crate::http::validation::ContentTypeValidation;
+use crate::http::validation::{AccessValidation, ContentTypeValidation};
 
 #[skip_serializing_none]
 #[derive(Serialize)]
@@ -33,11 +34,17 @@ async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult
 
     request.allow_only_utf8_json_by_default()?;
 
+    let role = match request.method() {
+        "GET" => AccessRole::Read,
+        _ => AccessRole::Write,
+    };
+    request.require_collection_access(&collection_name, role)?;
+
     let collection = get_collection(&context, &collection_name).await?;
 
     match request.method() {
         "GET" => list_readers(request, collection).await,
-        "POST" => create_reader(request, collection).await,
+        "POST" => create_reader(request, &context, collection).await,
         _ => Err(HttpError::MethodNotAllowed),
     }
 }
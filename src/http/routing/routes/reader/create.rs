@@ -3,12 +3,14 @@ use std::sync::Arc;
 
 use crate::collection::Collection;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
-use crate::http::constants::READER_REQUEST_MAX_BYTES;
+use crate::context::Context;
 
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 
+use crate::http::custom_errors::collection_method_error;
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 use crate::http::routing::response::Response;
@@ -18,24 +20,26 @@ use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_no_error_json_response;
 use crate::http::validation::ContentTypeValidation;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    reader_name: String,
-    generation_id: Option<EncodedGenerationIdJsonData>,
-    collection_name: Option<String>,
+pub struct CreateReaderRequestJsonData {
+    pub reader_name: String,
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
+    pub collection_name: Option<String>,
 }
 
 pub async fn create_reader(
     request: impl Request,
+    context: &Context,
     collection: Arc<Collection>,
 ) -> Result<Response, HttpError> {
     request.allow_only_utf8_json_by_default()?;
 
-    let body = read_limited_body(request, READER_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+    let data: CreateReaderRequestJsonData = read_json(body)?;
 
-    let RequestJsonData {
+    let CreateReaderRequestJsonData {
         reader_name,
         generation_id,
         collection_name: reader_collection_name,
@@ -51,13 +55,7 @@ pub async fn create_reader(
 
     let result = collection.create_reader(options).await;
 
-    let _ = match result {
-        Ok(result) => result,
-        Err(err) => {
-            eprintln!("reader/create error {:?}", err);
-            return Err(HttpError::Unspecified);
-        }
-    };
+    result.map_err(|err| collection_method_error(err, "reader/create"))?;
 
     create_ok_no_error_json_response()
 }
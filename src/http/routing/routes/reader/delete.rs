@@ -21,7 +21,7 @@ pub async fn delete_reader(
     let _ = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("reader/delete error {:?}", err);
+            tracing::error!(?err, "reader/delete error");
             return Err(HttpError::Unspecified);
         }
     };
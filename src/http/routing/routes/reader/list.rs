@@ -5,15 +5,15 @@ use crate::http::errors::HttpError;
 use crate::http::request::Request;
 use crate::http::routing::response::Response;
 use crate::http::util::response::create_ok_json_response;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::sync::Arc;
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ResponseJsonData {
-    items: Vec<ReaderRecordJsonData>,
+pub struct ListReadersResponseJsonData {
+    pub items: Vec<ReaderRecordJsonData>,
 }
 
 pub async fn list_readers(
@@ -25,14 +25,14 @@ pub async fn list_readers(
     let result = match result {
         Ok(result) => result,
         Err(err) => {
-            eprintln!("reader/list error {:?}", err);
+            tracing::error!(?err, "reader/list error");
             return Err(HttpError::Unspecified);
         }
     };
 
     let ListReadersOk { items } = result;
 
-    let response = ResponseJsonData {
+    let response = ListReadersResponseJsonData {
         items: ReaderRecordJsonData::encode_vec(items),
     };
 
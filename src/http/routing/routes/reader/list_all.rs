@@ -0,0 +1,59 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::context::Context;
+use crate::http::data::reader_status::ReaderStatusJsonData;
+use crate::http::errors::HttpError;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, MethodsValidation};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAllReadersResponseJsonData {
+    pub items: Vec<ReaderStatusJsonData>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_methods(&["GET"])?;
+
+        let mut collections = context.database.collections_list().await;
+        collections.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+
+        let mut items = Vec::new();
+
+        for collection in collections {
+            if !request.can_read_collection(collection.get_name()) {
+                continue;
+            }
+
+            match context.database.readers_status(&collection).await {
+                Ok(statuses) => {
+                    for mut status in statuses {
+                        // Target collection can be not readable by the token
+                        if !request.can_read_collection(&status.target_collection_name) {
+                            status.target = None;
+                        }
+
+                        items.push(ReaderStatusJsonData::encode(collection.get_name(), status));
+                    }
+                }
+                // Deleted while status was collected
+                Err(CollectionMethodError::NoSuchCollection) => {}
+                Err(err) => {
+                    tracing::error!(?err, "readers/list error");
+                    return Err(HttpError::Unspecified);
+                }
+            }
+        }
+
+        create_ok_json_response(&ListAllReadersResponseJsonData { items })
+    })
+}
+
+pub fn register_list_all_readers_route(context: &mut Context) {
+    context.routing.add_static_get_route("/readers/", handler);
+}
@@ -2,5 +2,7 @@ pub mod by_name;
 pub mod by_root;
 pub mod create;
 pub mod delete;
-mod list;
-mod update;
+pub mod list;
+pub mod list_all;
+pub mod status;
+pub mod update;
@@ -0,0 +1,43 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::context::Context;
+use crate::http::data::reader_status::ReaderStatusJsonData;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::response::Response;
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::AccessValidation;
+use std::sync::Arc;
+
+pub async fn reader_status(
+    request: impl Request,
+    context: &Context,
+    collection: Arc<Collection>,
+    reader_name: String,
+) -> Result<Response, HttpError> {
+    let result = context
+        .database
+        .reader_status(&collection, &reader_name)
+        .await;
+
+    let mut status = match result {
+        Ok(status) => status,
+        Err(CollectionMethodError::NoSuchReader) => {
+            return Err(HttpError::Generic400("no such reader"));
+        }
+        Err(CollectionMethodError::NoSuchCollection) => {
+            return Err(HttpError::Generic400("no such collection"));
+        }
+        Err(err) => {
+            tracing::error!(?err, "reader/status error");
+            return Err(HttpError::Unspecified);
+        }
+    };
+
+    // Target collection can be not readable by the token
+    if !request.can_read_collection(&status.target_collection_name) {
+        status.target = None;
+    }
+
+    create_ok_json_response(&ReaderStatusJsonData::encode(collection.get_name(), status))
+}
@@ -1,13 +1,16 @@
+use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::methods::update_reader::UpdateReaderOptions;
 use std::sync::Arc;
 
 use crate::collection::Collection;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
-use crate::http::constants::READER_REQUEST_MAX_BYTES;
+use crate::context::Context;
 
 use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
 
+use crate::http::custom_errors::{collection_method_error, orphaned_reader_error};
 use crate::http::errors::HttpError;
 use crate::http::request::Request;
 
@@ -18,23 +21,25 @@ use crate::http::util::read_json::read_json;
 use crate::http::util::response::create_ok_no_error_json_response;
 use crate::http::validation::ContentTypeValidation;
 
-#[derive(Deserialize)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct RequestJsonData {
-    generation_id: Option<EncodedGenerationIdJsonData>,
+pub struct UpdateReaderRequestJsonData {
+    pub generation_id: Option<EncodedGenerationIdJsonData>,
 }
 
 pub async fn update_reader(
     request: impl Request,
+    context: &Context,
     collection: Arc<Collection>,
     reader_name: String,
 ) -> Result<Response, HttpError> {
     request.allow_only_utf8_json_by_default()?;
 
-    let body = read_limited_body(request, READER_REQUEST_MAX_BYTES).await?;
-    let data: RequestJsonData = read_json(body)?;
+    let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+    let data: UpdateReaderRequestJsonData = read_json(body)?;
 
-    let RequestJsonData { generation_id } = data;
+    let UpdateReaderRequestJsonData { generation_id } = data;
 
     let generation_id = EncodedGenerationIdJsonData::decode_opt(generation_id)?;
 
@@ -45,11 +50,13 @@ pub async fn update_reader(
 
     let result = collection.update_reader(options).await;
 
-    let _ = match result {
-        Ok(result) => result,
+    match result {
+        Ok(()) => {}
+        Err(CollectionMethodError::OrphanedReader) => {
+            return Err(orphaned_reader_error());
+        }
         Err(err) => {
-            eprintln!("reader/update error {:?}", err);
-            return Err(HttpError::Unspecified);
+            return Err(collection_method_error(err, "reader/update"));
         }
     };
 
@@ -0,0 +1,46 @@
+use crate::context::Context;
+use crate::http::request::Request;
+use crate::http::routing::routes::transaction::{
+    transaction_error_to_http_error, TransactionCollectionJsonData,
+};
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_no_error_json_response;
+use crate::http::validation::ContentTypeValidation;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    collections: Vec<TransactionCollectionJsonData>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_utf8_json_by_default()?;
+
+        // Request is consumed by the body reading
+        let access = request.access().clone();
+
+        let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+        let data: RequestJsonData = read_json(body)?;
+
+        let collections = TransactionCollectionJsonData::decode_vec(data.collections, &access)?;
+
+        let result = context.database.abort_transaction(collections).await;
+
+        result.map_err(|err| transaction_error_to_http_error(err, "abort"))?;
+
+        create_ok_no_error_json_response()
+    })
+}
+
+pub fn register_abort_transaction_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_post_route("/transaction/abort", handler);
+}
@@ -0,0 +1,75 @@
+use crate::context::Context;
+use crate::database::transaction::CommitTransactionCollection;
+use crate::http::auth::AccessRole;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::data::reader_record::UpdateReaderJsonData;
+use crate::http::request::Request;
+use crate::http::routing::routes::transaction::transaction_error_to_http_error;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::encoding::StringDecoder;
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_no_error_json_response;
+use crate::http::validation::ContentTypeValidation;
+use crate::util::option::lift_result_from_option;
+use crate::util::str_serialization::StrSerializationType;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitTransactionCollectionJsonData {
+    collection_name: String,
+    generation_id: EncodedGenerationIdJsonData,
+    update_readers: Option<Vec<UpdateReaderJsonData>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    collections: Vec<CommitTransactionCollectionJsonData>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_utf8_json_by_default()?;
+
+        // Request is consumed by the body reading
+        let access = request.access().clone();
+
+        let body = read_limited_body(request, context.config.http.reader_request_max_bytes).await?;
+        let data: RequestJsonData = read_json(body)?;
+
+        let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+        let mut collections = Vec::with_capacity(data.collections.len());
+
+        for item in data.collections {
+            access.check_collection(&item.collection_name, AccessRole::Write)?;
+
+            let update_readers = item
+                .update_readers
+                .map(|update_readers| UpdateReaderJsonData::decode_vec(update_readers, &decoder));
+
+            collections.push(CommitTransactionCollection {
+                collection_name: item.collection_name,
+                generation_id: item.generation_id.into_generation_id()?,
+                update_readers: lift_result_from_option(update_readers)?,
+            });
+        }
+
+        let result = context.database.commit_transaction(collections).await;
+
+        result.map_err(|err| transaction_error_to_http_error(err, "commit"))?;
+
+        create_ok_no_error_json_response()
+    })
+}
+
+pub fn register_commit_transaction_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_post_route("/transaction/commit", handler);
+}
@@ -0,0 +1,69 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::database::transaction::{TransactionCollection, TransactionError};
+use crate::http::auth::{Access, AccessRole};
+use crate::http::custom_errors::{
+    generation_id_less_than_minimum_error, no_such_collection_error, outdated_generation_error,
+};
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use serde::Deserialize;
+
+pub mod abort;
+pub mod commit;
+pub mod start;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionCollectionJsonData {
+    collection_name: String,
+    generation_id: EncodedGenerationIdJsonData,
+}
+
+impl TransactionCollectionJsonData {
+    fn decode_vec(
+        items: Vec<Self>,
+        access: &Access,
+    ) -> Result<Vec<TransactionCollection>, HttpError> {
+        let mut result = Vec::with_capacity(items.len());
+
+        for item in items {
+            access.check_collection(&item.collection_name, AccessRole::Write)?;
+
+            result.push(TransactionCollection {
+                collection_name: item.collection_name,
+                generation_id: item.generation_id.into_generation_id()?,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+fn transaction_error_to_http_error(err: TransactionError, method: &str) -> HttpError {
+    match err {
+        TransactionError::NoSuchCollection(_)
+        | TransactionError::CollectionMethod(CollectionMethodError::NoSuchCollection) => {
+            no_such_collection_error()
+        }
+        TransactionError::NotManualCollection(_) => {
+            HttpError::Generic400("transaction collections should be manual")
+        }
+        TransactionError::DuplicateCollection(_) => {
+            HttpError::Generic400("transaction collections should be unique")
+        }
+        TransactionError::CollectionMethod(CollectionMethodError::OutdatedGeneration) => {
+            outdated_generation_error()
+        }
+        TransactionError::CollectionMethod(CollectionMethodError::GenerationIdLessThanMinimum(
+            minimum_generation_id,
+        )) => generation_id_less_than_minimum_error(minimum_generation_id.as_ref()),
+        TransactionError::Interrupted(err) => {
+            tracing::error!(?err, "transaction/{} error", method);
+            HttpError::PublicInternal500("transaction will be finished on restart")
+        }
+        err => {
+            tracing::error!(?err, "transaction/{} error", method);
+            HttpError::Unspecified
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use diffbelt_macro::fn_box_pin_async;
+use regex::Regex;
+
+use crate::context::Context;
+use crate::http::routing::routes::transform::transform_error_to_http_error;
+use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
+use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::util::response::create_ok_no_error_json_response;
+use crate::http::validation::{AccessValidation, MethodsValidation};
+
+#[fn_box_pin_async]
+async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult {
+    let context = options.context;
+    let request = options.request;
+    let transform_name = options.groups.0;
+
+    request.allow_only_methods(&["DELETE"])?;
+    request.require_server_admin()?;
+
+    let result = context.database.delete_transform(&transform_name).await;
+
+    result.map_err(|err| transform_error_to_http_error(err, "delete"))?;
+
+    create_ok_no_error_json_response()
+}
+
+pub fn register_transform_by_name_route(context: &mut Context) {
+    context.routing.add_pattern_route(
+        Regex::new("^/transforms/(?P<id>[^/]+)$").unwrap(),
+        id_only_group,
+        handler,
+    );
+}
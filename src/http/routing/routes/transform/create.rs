@@ -0,0 +1,85 @@
+use crate::context::Context;
+use crate::database::transforms::{CreateTransformOptions, TransformSource};
+use crate::http::errors::HttpError;
+use crate::http::routing::routes::transform::{
+    transform_error_to_http_error, TransformSourceJsonData, TransformStepJsonData,
+};
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::encoding::StringDecoder;
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_no_error_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation};
+use crate::util::str_serialization::StrSerializationType;
+use serde::Deserialize;
+
+const MAX_TRANSFORM_NAME_LENGTH: usize = 512;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJsonData {
+    name: String,
+    sources: Vec<TransformSourceJsonData>,
+    target_collection_name: String,
+    steps: Option<Vec<TransformStepJsonData>>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_utf8_json_by_default()?;
+        // Transforms are running with full access to their sources and targets
+        request.require_server_admin()?;
+
+        let body = read_limited_body(
+            request,
+            context.config.http.create_collection_request_max_bytes,
+        )
+        .await?;
+        let data: RequestJsonData = read_json(body)?;
+
+        if data.name.is_empty() || data.name.len() > MAX_TRANSFORM_NAME_LENGTH {
+            return Err(HttpError::Generic400("name cannot be empty or > 512"));
+        }
+
+        let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+        let mut steps = Vec::new();
+        for step in data.steps.unwrap_or_default() {
+            steps.push(step.decode(&decoder)?);
+        }
+
+        let sources = data
+            .sources
+            .into_iter()
+            .map(|source| TransformSource {
+                collection_name: source.collection_name,
+                reader_name: source.reader_name,
+            })
+            .collect();
+
+        let result = context
+            .database
+            .create_transform(
+                &data.name,
+                CreateTransformOptions {
+                    sources,
+                    target_collection_name: data.target_collection_name,
+                },
+                steps,
+            )
+            .await;
+
+        result.map_err(|err| transform_error_to_http_error(err, "create"))?;
+
+        create_ok_no_error_json_response()
+    })
+}
+
+pub fn register_create_transform_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_post_route("/transforms/", handler);
+}
@@ -0,0 +1,67 @@
+use crate::context::Context;
+use crate::http::routing::routes::transform::{TransformSourceJsonData, TransformStepJsonData};
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, MethodsValidation};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransformJsonData {
+    name: String,
+    sources: Vec<TransformSourceJsonData>,
+    target_collection_name: String,
+    // Absent for custom transforms
+    steps: Option<Vec<TransformStepJsonData>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    items: Vec<TransformJsonData>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_methods(&["GET"])?;
+        request.require_server_admin()?;
+
+        let items = context
+            .database
+            .list_transforms()
+            .await
+            .into_iter()
+            .map(|info| TransformJsonData {
+                name: info.name,
+                sources: info
+                    .sources
+                    .into_iter()
+                    .map(|source| TransformSourceJsonData {
+                        collection_name: source.collection_name,
+                        reader_name: source.reader_name,
+                    })
+                    .collect(),
+                target_collection_name: info.target_collection_name,
+                steps: info.steps.map(|steps| {
+                    steps
+                        .into_iter()
+                        .map(TransformStepJsonData::encode)
+                        .collect()
+                }),
+            })
+            .collect();
+
+        create_ok_json_response(&ResponseJsonData { items })
+    })
+}
+
+pub fn register_list_transforms_route(context: &mut Context) {
+    context
+        .routing
+        .add_static_get_route("/transforms/", handler);
+}
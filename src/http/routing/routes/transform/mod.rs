@@ -0,0 +1,127 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::database::transforms::{TransformError, TransformStep};
+use crate::http::custom_errors::no_such_collection_error;
+use crate::http::errors::HttpError;
+use crate::http::util::encoding::StringDecoder;
+use crate::util::str_serialization::StrSerializationType;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+pub mod by_name;
+pub mod create;
+pub mod list;
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncodedPrefixJsonData {
+    value: String,
+    encoding: Option<String>,
+}
+
+impl EncodedPrefixJsonData {
+    fn encode(prefix: &[u8]) -> Self {
+        let (value, encoding) = StrSerializationType::Utf8.serialize_with_priority(prefix);
+
+        Self {
+            value,
+            encoding: encoding.to_optional_string(),
+        }
+    }
+
+    fn decode(self, decoder: &StringDecoder) -> Result<Box<[u8]>, HttpError> {
+        decoder.decode_field_with_map("prefix", self.value, "encoding", self.encoding, Ok)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum TransformStepJsonData {
+    KeyPrefixFilter {
+        prefix: EncodedPrefixJsonData,
+    },
+    #[serde(rename_all = "camelCase")]
+    KeyRemap {
+        from_prefix: EncodedPrefixJsonData,
+        to_prefix: EncodedPrefixJsonData,
+    },
+    ValueProjection {
+        fields: Vec<String>,
+    },
+}
+
+impl TransformStepJsonData {
+    fn encode(step: TransformStep) -> Self {
+        match step {
+            TransformStep::KeyPrefixFilter { prefix } => TransformStepJsonData::KeyPrefixFilter {
+                prefix: EncodedPrefixJsonData::encode(&prefix),
+            },
+            TransformStep::KeyRemap {
+                from_prefix,
+                to_prefix,
+            } => TransformStepJsonData::KeyRemap {
+                from_prefix: EncodedPrefixJsonData::encode(&from_prefix),
+                to_prefix: EncodedPrefixJsonData::encode(&to_prefix),
+            },
+            TransformStep::ValueProjection { fields } => {
+                TransformStepJsonData::ValueProjection { fields }
+            }
+        }
+    }
+
+    fn decode(self, decoder: &StringDecoder) -> Result<TransformStep, HttpError> {
+        let step = match self {
+            TransformStepJsonData::KeyPrefixFilter { prefix } => TransformStep::KeyPrefixFilter {
+                prefix: prefix.decode(decoder)?,
+            },
+            TransformStepJsonData::KeyRemap {
+                from_prefix,
+                to_prefix,
+            } => TransformStep::KeyRemap {
+                from_prefix: from_prefix.decode(decoder)?,
+                to_prefix: to_prefix.decode(decoder)?,
+            },
+            TransformStepJsonData::ValueProjection { fields } => {
+                TransformStep::ValueProjection { fields }
+            }
+        };
+
+        Ok(step)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransformSourceJsonData {
+    collection_name: String,
+    reader_name: String,
+}
+
+fn transform_error_to_http_error(err: TransformError, method: &str) -> HttpError {
+    match err {
+        TransformError::AlreadyExist => {
+            HttpError::Generic400("transform with such name already exists")
+        }
+        TransformError::NoSuchTransform => {
+            HttpError::CustomJson400(r#"{"error":"noSuchTransform"}"#)
+        }
+        TransformError::NoSources => HttpError::Generic400("transform should have sources"),
+        TransformError::NoSuchCollection(_)
+        | TransformError::CollectionMethod(CollectionMethodError::NoSuchCollection) => {
+            no_such_collection_error()
+        }
+        TransformError::TargetIsNotManual => {
+            HttpError::Generic400("transform target collection should be manual")
+        }
+        TransformError::TargetIsSource => {
+            HttpError::Generic400("transform target collection cannot be a source")
+        }
+        TransformError::DuplicateReader(_) => {
+            HttpError::Generic400("transform reader names should be unique")
+        }
+        err => {
+            tracing::error!(?err, "transforms/{} error", method);
+            HttpError::Unspecified
+        }
+    }
+}
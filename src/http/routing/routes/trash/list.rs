@@ -0,0 +1,65 @@
+use crate::context::Context;
+
+use crate::http::auth::AccessRole;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+use crate::http::routing::{StaticRouteFnFutureResult, StaticRouteOptions};
+
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::MethodsValidation;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TrashItemJsonData {
+    id: String,
+    collection_name: String,
+    is_manual: bool,
+    deleted_at: u64,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListTrashResponseJsonData {
+    items: Vec<TrashItemJsonData>,
+}
+
+fn handler(options: StaticRouteOptions) -> StaticRouteFnFutureResult {
+    Box::pin(async move {
+        let context = options.context;
+        let request = options.request;
+
+        request.allow_only_methods(&["GET"])?;
+
+        let items = match context.database.trash_list().await {
+            Ok(items) => items,
+            Err(err) => {
+                tracing::error!(?err, "trash list error");
+                return Err(HttpError::Unspecified);
+            }
+        };
+
+        let items = items
+            .into_iter()
+            .filter(|item| {
+                request.access().collection_role(&item.collection_name) == Some(AccessRole::Admin)
+            })
+            .map(|item| TrashItemJsonData {
+                id: item.id,
+                collection_name: item.collection_name,
+                is_manual: item.is_manual,
+                deleted_at: item.deleted_at,
+            })
+            .collect();
+
+        create_ok_json_response(&ListTrashResponseJsonData { items })
+    })
+}
+
+pub fn register_list_trash_route(context: &mut Context) {
+    context.routing.add_static_get_route("/trash/", handler);
+}
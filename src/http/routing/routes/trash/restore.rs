@@ -0,0 +1,68 @@
+use diffbelt_macro::fn_box_pin_async;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::database::trash::TrashError;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::no_such_collection_error;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::errors::HttpError;
+use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
+use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, MethodsValidation};
+use crate::util::str_serialization::StrSerializationType;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJsonData {
+    generation_id: EncodedGenerationIdJsonData,
+}
+
+#[fn_box_pin_async]
+async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult {
+    let context = options.context;
+    let request = options.request;
+    let collection_name = options.groups.0;
+
+    request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Admin)?;
+
+    let result = context.database.restore_collection(&collection_name).await;
+
+    let collection = match result {
+        Ok(collection) => collection,
+        Err(err) => {
+            return match err {
+                TrashError::NoSuchCollection => Err(no_such_collection_error()),
+                TrashError::AlreadyExist => Err(HttpError::Generic400(
+                    "collection with such id already exists",
+                )),
+                _ => {
+                    tracing::error!(?err, "trash/restore error");
+                    Err(HttpError::Unspecified)
+                }
+            };
+        }
+    };
+
+    let generation_id = collection.generation_pair().generation_id;
+
+    let response = ResponseJsonData {
+        generation_id: EncodedGenerationIdJsonData::encode(
+            generation_id.as_ref(),
+            StrSerializationType::Utf8,
+        ),
+    };
+
+    create_ok_json_response(&response)
+}
+
+pub fn register_restore_collection_route(context: &mut Context) {
+    context.routing.add_pattern_route(
+        Regex::new("^/trash/(?P<id>[^/]+)/restore$").unwrap(),
+        id_only_group,
+        handler,
+    );
+}
@@ -0,0 +1,100 @@
+use crate::collection::methods::watch::{WatchOk, WatchOptions};
+use crate::context::Context;
+use crate::http::auth::AccessRole;
+use crate::http::custom_errors::collection_method_error;
+use crate::http::data::encoded_generation_id::EncodedGenerationIdJsonData;
+use crate::http::data::encoded_key::EncodedKeyJsonData;
+use crate::http::routing::{HttpHandlerResult, PatternRouteOptions};
+use crate::http::util::common_groups::{id_only_group, IdOnlyGroup};
+use crate::http::util::encoding::StringDecoder;
+use crate::http::util::get_collection::get_collection;
+use crate::http::util::key_bounds::{from_key_bound, to_key_bound};
+use crate::http::util::read_body::read_limited_body;
+use crate::http::util::read_json::read_json;
+use crate::http::util::response::create_ok_json_response;
+use crate::http::validation::{AccessValidation, ContentTypeValidation, MethodsValidation};
+use crate::util::str_serialization::StrSerializationType;
+use diffbelt_macro::fn_box_pin_async;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::time::Duration;
+
+// Same as for `generationId/stream`
+const WATCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchRequestJsonData {
+    pub from_generation_id: Option<EncodedGenerationIdJsonData>,
+    pub from_key: Option<EncodedKeyJsonData>,
+    pub from_key_exclusive: Option<bool>,
+    pub to_key: Option<EncodedKeyJsonData>,
+    pub to_key_inclusive: Option<bool>,
+    pub prefix: Option<EncodedKeyJsonData>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchResponseJsonData {
+    pub generation_id: EncodedGenerationIdJsonData,
+    pub keys: Vec<EncodedKeyJsonData>,
+    pub has_more: Option<bool>,
+}
+
+#[fn_box_pin_async]
+async fn handler(options: PatternRouteOptions<IdOnlyGroup>) -> HttpHandlerResult {
+    let context = options.context;
+    let request = options.request;
+    let collection_name = options.groups.0;
+
+    request.allow_only_methods(&["POST"])?;
+    request.require_collection_access(&collection_name, AccessRole::Read)?;
+    request.allow_only_utf8_json_by_default()?;
+
+    let body =
+        read_limited_body(request, context.config.http.query_start_request_max_bytes).await?;
+    let data: WatchRequestJsonData = read_json(body)?;
+
+    let decoder = StringDecoder::new(StrSerializationType::Utf8);
+
+    let from_key = data.from_key.map(|key| key.decode(&decoder)).transpose()?;
+    let to_key = data.to_key.map(|key| key.decode(&decoder)).transpose()?;
+
+    let options = WatchOptions {
+        from_generation_id: EncodedGenerationIdJsonData::decode_opt(data.from_generation_id)?,
+        from_key: from_key_bound(from_key, data.from_key_exclusive),
+        to_key: to_key_bound(to_key, data.to_key_inclusive),
+        prefix: data.prefix.map(|key| key.decode(&decoder)).transpose()?,
+        timeout: WATCH_TIMEOUT,
+    };
+
+    let collection = get_collection(&context, &collection_name).await?;
+
+    let result = collection.watch(options).await;
+
+    let WatchOk {
+        generation_id,
+        keys,
+        has_more,
+    } = result.map_err(|err| collection_method_error(err, "watch"))?;
+
+    create_ok_json_response(&WatchResponseJsonData {
+        generation_id: EncodedGenerationIdJsonData::encode(
+            generation_id.as_ref(),
+            StrSerializationType::Utf8,
+        ),
+        keys: EncodedKeyJsonData::encode_vec(keys),
+        has_more: if has_more { Some(true) } else { None },
+    })
+}
+
+pub fn register_watch_route(context: &mut Context) {
+    context.routing.add_pattern_route(
+        Regex::new("^/collections/(?P<id>[^/]+)/watch$").unwrap(),
+        id_only_group,
+        handler,
+    );
+}
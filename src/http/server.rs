@@ -1,21 +1,36 @@
 use crate::context::Context;
+use crate::http::auth::{authenticate, Access};
+use crate::http::config::ListenAddress;
 use crate::http::errors::HttpError;
+use crate::http::metrics::UNKNOWN_ROUTE;
 use crate::http::request::HyperRequestWrapped;
+use crate::http::request_id::{get_or_generate_request_id, REQUEST_ID_HEADER};
 use crate::http::routing::response::{
-    BaseResponse, BytesVecResponse, Response as ResponseByRoute, StaticStrResponse, StringResponse,
+    BaseResponse, BytesVecResponse, Response as ResponseByRoute, StaticStrResponse, StreamResponse,
+    StringResponse,
 };
 use crate::http::routing::StaticRouteOptions;
+use crate::http::tls::{load_tls_acceptor, TlsLoadError};
 use hyper::http::HeaderValue;
-use hyper::service::{make_service_fn, service_fn};
-use hyper::{Body, Request, Response, Server, StatusCode};
+use hyper::server::conn::Http;
+use hyper::service::service_fn;
+use hyper::{Body, Request, Response, StatusCode};
 use std::convert::Infallible;
-
-use std::net::SocketAddr;
+use std::os::unix::fs::FileTypeExt;
 use std::sync::Arc;
-
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::{TcpListener, UnixListener};
+use tokio::time::sleep;
+use tokio_rustls::TlsAcceptor;
+use tracing::field::Empty;
+use tracing::Instrument;
+
+// `route_name` is set when the route is found
 async fn handle_request(
     context: Arc<Context>,
     req: Request<Body>,
+    route_name: &mut Option<String>,
 ) -> Result<Response<Body>, HttpError> {
     let uri = req.uri();
     let path_and_query = uri
@@ -24,18 +39,26 @@ async fn handle_request(
 
     let path = path_and_query.path();
 
+    let authorization = req
+        .headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok());
+    let access = authenticate(&context.config.http.tokens, authorization)?;
+
     let routing = &context.routing;
     let routes = routing.get_static_routes_by_method(req.method().as_str());
     let static_route = routes.and_then(|routes| routes.get(path));
 
     let static_route = match static_route {
         None => {
-            return handle_pattern_request(context, req).await;
+            return handle_pattern_request(context, req, access, route_name).await;
         }
         Some(static_route) => static_route,
     };
 
-    let request = HyperRequestWrapped::from(req);
+    *route_name = Some(path.to_string());
+
+    let request = HyperRequestWrapped::new(req, access);
 
     let result = static_route(StaticRouteOptions {
         context: context.clone(),
@@ -49,13 +72,15 @@ async fn handle_request(
 async fn handle_pattern_request(
     context: Arc<Context>,
     req: Request<Body>,
+    access: Access,
+    route_name: &mut Option<String>,
 ) -> Result<Response<Body>, HttpError> {
     let routing = &context.routing;
     let _path = req.uri().path();
 
     let mut options = StaticRouteOptions {
         context: context.clone(),
-        request: HyperRequestWrapped::from(req),
+        request: HyperRequestWrapped::new(req, access),
     };
 
     for route in &routing.pattern_routes {
@@ -65,6 +90,8 @@ async fn handle_pattern_request(
 
         match result {
             Ok(result) => {
+                *route_name = Some(route.name.clone());
+
                 let result = result.await?;
                 return handle_response(result).await;
             }
@@ -86,6 +113,7 @@ async fn handle_response(result: ResponseByRoute) -> Result<Response<Body>, Http
         ResponseByRoute::BytesVec(BytesVecResponse { base, bytes }) => {
             (Response::new(bytes.into()), base)
         }
+        ResponseByRoute::Stream(StreamResponse { base, body }) => (Response::new(body), base),
     };
 
     init_response(&mut response, &base)?;
@@ -109,101 +137,267 @@ fn init_response(response: &mut Response<Body>, base: &BaseResponse) -> Result<(
     Ok(())
 }
 
-pub async fn start_http_server(context: Arc<Context>) {
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3030));
+async fn serve_request(
+    context: Arc<Context>,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let started_at = Instant::now();
+    let mut route_name = None;
+
+    // Everything that is logged while handling the request, including task threads
+    // and RocksDB calls, is inside of this span
+    let request_id = get_or_generate_request_id(req.headers());
+    let span = tracing::info_span!(
+        "request",
+        request_id = request_id.as_str(),
+        method = req.method().as_str(),
+        path = req.uri().path(),
+        route = Empty,
+        status = Empty,
+    );
+
+    let result = handle_request(context.clone(), req, &mut route_name)
+        .instrument(span.clone())
+        .await;
+
+    let (mut response, error_class) = match result {
+        Ok(response) => (response, None),
+        Err(err) => {
+            let error_class = err.class();
+            (error_response(err), Some(error_class))
+        }
+    };
 
-    let make_svc = make_service_fn(|_conn| {
-        let context = context.clone();
+    context.http_metrics.observe(
+        route_name.as_deref().unwrap_or(UNKNOWN_ROUTE),
+        response.status().as_u16(),
+        error_class,
+        started_at.elapsed(),
+    );
 
-        let fun = move |req| {
-            let context = context.clone();
-
-            async move {
-                let result = handle_request(context, req).await;
-
-                match result {
-                    Ok(response) => Ok::<Response<Body>, Infallible>(response),
-                    Err(err) => {
-                        let mut is_json = true;
-
-                        let (status_code, body): (StatusCode, Body) = match err {
-                            HttpError::Unspecified => (
-                                StatusCode::INTERNAL_SERVER_ERROR,
-                                "{\"error\":\"500\"}".into(),
-                            ),
-                            HttpError::NotFound => {
-                                (StatusCode::NOT_FOUND, "{\"error\":\"404\"}".into())
-                            }
-                            HttpError::Generic400(reason)
-                            | HttpError::ContentTypeUnsupported(reason) => (
-                                StatusCode::BAD_REQUEST,
-                                format!(
-                                    "{{\"error\":\"400\",\"reason\":{}}}",
-                                    serde_json::json!(reason).to_string()
-                                )
-                                .into(),
-                            ),
-                            HttpError::CustomJson400(json) => {
-                                (StatusCode::BAD_REQUEST, json.into())
-                            }
-                            HttpError::GenericString400(reason) => {
-                                is_json = false;
-                                (
-                                    StatusCode::BAD_REQUEST,
-                                    format!(
-                                        "{{\"error\":\"400\",\"reason\":{}}}",
-                                        serde_json::json!(reason).to_string()
-                                    )
-                                    .into(),
-                                )
-                            }
-                            HttpError::TooBigPayload(max_size) => (
-                                StatusCode::PAYLOAD_TOO_LARGE,
-                                format!("{{\"error\":\"413\",\"bytesMax\":{}}}", max_size).into(),
-                            ),
-                            HttpError::InvalidJson(reason) => (
-                                StatusCode::BAD_REQUEST,
-                                format!(
-                                    "{{\"error\":\"400\",\"type\":\"invalidJson\",\"reason\":{}}}",
-                                    serde_json::json!(reason).to_string()
-                                )
-                                .into(),
-                            ),
-                            HttpError::PublicInternal500(str) => {
-                                is_json = false;
-                                (
-                                    StatusCode::INTERNAL_SERVER_ERROR,
-                                    format!("500, {}", str).into(),
-                                )
-                            }
-                            HttpError::MethodNotAllowed => {
-                                (StatusCode::METHOD_NOT_ALLOWED, "{\"error\":\"405\"}".into())
-                            }
-                        };
-
-                        let mut response = Response::new(body);
-                        *(response.status_mut()) = status_code;
-
-                        if is_json {
-                            let headers = response.headers_mut();
-                            headers.insert(
-                                "Content-Type",
-                                HeaderValue::from_static("application/json; charset=utf-8"),
-                            );
-                        }
+    if let Some(route_name) = &route_name {
+        span.record("route", route_name.as_str());
+    }
+    span.record("status", response.status().as_u16());
+
+    // Generated ids contain only safe characters, incoming ones are validated
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    Ok(response)
+}
 
-                        Ok(response)
+fn error_response(err: HttpError) -> Response<Body> {
+    let mut is_json = true;
+
+    let (status_code, body): (StatusCode, Body) = match err {
+        HttpError::Unspecified => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "{\"error\":\"500\"}".into(),
+        ),
+        HttpError::NotFound => (StatusCode::NOT_FOUND, "{\"error\":\"404\"}".into()),
+        HttpError::Generic400(reason) | HttpError::ContentTypeUnsupported(reason) => (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "{{\"error\":\"400\",\"reason\":{}}}",
+                serde_json::json!(reason).to_string()
+            )
+            .into(),
+        ),
+        HttpError::CustomJson400(json) => (StatusCode::BAD_REQUEST, json.into()),
+        HttpError::CustomJsonString400(json) => (StatusCode::BAD_REQUEST, json.into()),
+        HttpError::GenericString400(reason) => {
+            is_json = false;
+            (
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "{{\"error\":\"400\",\"reason\":{}}}",
+                    serde_json::json!(reason).to_string()
+                )
+                .into(),
+            )
+        }
+        HttpError::TooBigPayload(max_size) => (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!("{{\"error\":\"413\",\"bytesMax\":{}}}", max_size).into(),
+        ),
+        HttpError::InvalidJson(reason) => (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "{{\"error\":\"400\",\"type\":\"invalidJson\",\"reason\":{}}}",
+                serde_json::json!(reason).to_string()
+            )
+            .into(),
+        ),
+        HttpError::InvalidProtobuf(reason) => (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "{{\"error\":\"400\",\"type\":\"invalidProtobuf\",\"reason\":{}}}",
+                serde_json::json!(reason)
+            )
+            .into(),
+        ),
+        HttpError::PublicInternal500(str) => {
+            is_json = false;
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("500, {}", str).into(),
+            )
+        }
+        HttpError::MethodNotAllowed => {
+            (StatusCode::METHOD_NOT_ALLOWED, "{\"error\":\"405\"}".into())
+        }
+        HttpError::Unauthorized => (StatusCode::UNAUTHORIZED, "{\"error\":\"401\"}".into()),
+        HttpError::Forbidden(reason) => (
+            StatusCode::FORBIDDEN,
+            format!(
+                "{{\"error\":\"403\",\"reason\":{}}}",
+                serde_json::json!(reason)
+            )
+            .into(),
+        ),
+    };
+
+    let mut response = Response::new(body);
+    *(response.status_mut()) = status_code;
+
+    if status_code == StatusCode::UNAUTHORIZED {
+        let headers = response.headers_mut();
+        headers.insert("WWW-Authenticate", HeaderValue::from_static("Bearer"));
+    }
+
+    if is_json {
+        let headers = response.headers_mut();
+        headers.insert(
+            "Content-Type",
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        );
+    }
+
+    response
+}
+
+#[derive(Debug)]
+pub enum StartHttpServerError {
+    Tls(TlsLoadError),
+    Bind(ListenAddress, std::io::Error),
+}
+
+impl From<TlsLoadError> for StartHttpServerError {
+    fn from(err: TlsLoadError) -> Self {
+        StartHttpServerError::Tls(err)
+    }
+}
+
+enum Listener {
+    Tcp(TcpListener),
+    Unix(UnixListener),
+}
+
+impl Listener {
+    async fn bind(address: &ListenAddress) -> Result<Self, std::io::Error> {
+        match address {
+            ListenAddress::Tcp(address) => TcpListener::bind(address).await.map(Listener::Tcp),
+            ListenAddress::Unix(path) => {
+                // Socket file is left after the previous run, it cannot be reused
+                if let Ok(metadata) = std::fs::symlink_metadata(path) {
+                    if metadata.file_type().is_socket() {
+                        std::fs::remove_file(path)?;
                     }
                 }
+
+                UnixListener::bind(path).map(Listener::Unix)
             }
-        };
+        }
+    }
+}
+
+// Runs until the process exits, all listeners are bound before serving,
+// so wrong addresses and TLS files are reported right away
+pub async fn start_http_server(context: Arc<Context>) -> Result<(), StartHttpServerError> {
+    let http_config = &context.config.http;
+
+    let tls_acceptor = match &http_config.tls {
+        Some(tls) => Some(load_tls_acceptor(tls)?),
+        None => None,
+    };
+
+    let mut listeners = Vec::with_capacity(http_config.listen_addresses.len());
+
+    for address in &http_config.listen_addresses {
+        let listener = Listener::bind(address)
+            .await
+            .map_err(|err| StartHttpServerError::Bind(address.clone(), err))?;
+
+        tracing::info!(%address, "listening");
+
+        listeners.push(listener);
+    }
 
-        async { Ok::<_, Infallible>(service_fn(fun)) }
-    });
+    let tasks: Vec<_> = listeners
+        .into_iter()
+        .map(|listener| {
+            tokio::spawn(accept_connections(
+                context.clone(),
+                listener,
+                tls_acceptor.clone(),
+            ))
+        })
+        .collect();
+
+    for task in tasks {
+        let _ = task.await;
+    }
+
+    Ok(())
+}
+
+async fn accept_connections(
+    context: Arc<Context>,
+    listener: Listener,
+    tls_acceptor: Option<TlsAcceptor>,
+) {
+    loop {
+        let context = context.clone();
+
+        let result = match &listener {
+            Listener::Tcp(listener) => listener.accept().await.map(|(stream, _)| {
+                let tls_acceptor = tls_acceptor.clone();
 
-    let server = Server::bind(&addr).serve(make_svc);
+                tokio::spawn(async move {
+                    match tls_acceptor {
+                        Some(tls_acceptor) => {
+                            // Failed handshakes are problems of clients
+                            let Ok(stream) = tls_acceptor.accept(stream).await else {
+                                return;
+                            };
 
-    if let Err(e) = server.await {
-        eprintln!("server error: {}", e);
+                            serve_connection(context, stream).await;
+                        }
+                        None => serve_connection(context, stream).await,
+                    }
+                });
+            }),
+            Listener::Unix(listener) => listener.accept().await.map(|(stream, _)| {
+                tokio::spawn(serve_connection(context, stream));
+            }),
+        };
+
+        if let Err(err) = result {
+            // Like hyper does, errors like too many open files are not fatal
+            tracing::warn!(%err, "accept error");
+            sleep(Duration::from_secs(1)).await;
+        }
     }
 }
+
+async fn serve_connection<S>(context: Arc<Context>, stream: S)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let service = service_fn(move |req| serve_request(context.clone(), req));
+
+    // Connection errors are caused by clients, like closed connections, nothing to do with them
+    let _ = Http::new().serve_connection(stream, service).await;
+}
@@ -0,0 +1,57 @@
+use crate::http::config::TlsConfig;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+
+#[derive(Debug)]
+pub enum TlsLoadError {
+    Io(PathBuf, std::io::Error),
+    NoCertificates(PathBuf),
+    NoPrivateKey(PathBuf),
+    Rustls(tokio_rustls::rustls::Error),
+}
+
+pub fn load_tls_acceptor(config: &TlsConfig) -> Result<TlsAcceptor, TlsLoadError> {
+    let certs = read_pem_items(&config.cert_path)?
+        .into_iter()
+        .filter_map(|item| match item {
+            rustls_pemfile::Item::X509Certificate(cert) => Some(Certificate(cert)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if certs.is_empty() {
+        return Err(TlsLoadError::NoCertificates(config.cert_path.clone()));
+    }
+
+    let key = read_pem_items(&config.key_path)?
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .ok_or_else(|| TlsLoadError::NoPrivateKey(config.key_path.clone()))?;
+
+    let mut server_config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(TlsLoadError::Rustls)?;
+
+    // Connections are served by hyper with both HTTP/1 and HTTP/2 enabled
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+fn read_pem_items(path: &Path) -> Result<Vec<rustls_pemfile::Item>, TlsLoadError> {
+    let file = File::open(path).map_err(|err| TlsLoadError::Io(path.to_path_buf(), err))?;
+    let mut reader = BufReader::new(file);
+
+    rustls_pemfile::read_all(&mut reader).map_err(|err| TlsLoadError::Io(path.to_path_buf(), err))
+}
@@ -0,0 +1,91 @@
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::Collection;
+use crate::http::routing::response::Response;
+use crate::http::util::stream_writer::{StreamClosed, StreamWriter};
+use crate::http::validation::BodyFormat;
+use crate::util::tokio::spawn;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+// Page of a query or diff cursor, see `create_cursor_stream_response`
+pub trait CursorStreamPage: Sized + Send + 'static {
+    type Item: Send;
+
+    // Route name for logs, like "query/stream"
+    const ROUTE: &'static str;
+
+    // Leaves the page without items and cursor id, so it can be written as the header
+    fn take_items_and_cursor_id(&mut self) -> (Vec<Self::Item>, Option<Box<str>>);
+
+    fn write_header(self, writer: &mut StreamWriter) -> Result<(), StreamClosed>;
+
+    fn write_item(item: Self::Item, writer: &mut StreamWriter) -> Result<(), StreamClosed>;
+
+    fn read_cursor(
+        collection: Arc<Collection>,
+        cursor_id: Box<str>,
+    ) -> BoxFuture<'static, Result<Self, CollectionMethodError>>;
+
+    fn abort_cursor(
+        collection: Arc<Collection>,
+        cursor_id: Box<str>,
+    ) -> BoxFuture<'static, Result<(), CollectionMethodError>>;
+}
+
+// First record is a response without items, then one record per item,
+// pages are read from the cursor until it ends
+pub fn create_cursor_stream_response<P: CursorStreamPage>(
+    collection: Arc<Collection>,
+    page: P,
+    format: BodyFormat,
+) -> Response {
+    let (writer, response) = StreamWriter::new(format);
+
+    spawn(write_cursor_stream(collection, page, writer));
+
+    response
+}
+
+async fn write_cursor_stream<P: CursorStreamPage>(
+    collection: Arc<Collection>,
+    mut page: P,
+    mut writer: StreamWriter,
+) {
+    let (mut items, mut cursor_id) = page.take_items_and_cursor_id();
+
+    let mut is_written = page.write_header(&mut writer);
+
+    loop {
+        if is_written.is_ok() {
+            is_written = items
+                .into_iter()
+                .try_for_each(|item| P::write_item(item, &mut writer));
+        }
+        if is_written.is_ok() {
+            is_written = writer.flush().await;
+        }
+
+        if is_written.is_err() {
+            if let Some(cursor_id) = cursor_id {
+                let _ = P::abort_cursor(collection, cursor_id).await;
+            }
+
+            return;
+        }
+
+        let Some(current_cursor_id) = cursor_id else {
+            return;
+        };
+
+        let mut page = match P::read_cursor(collection.clone(), current_cursor_id).await {
+            Ok(page) => page,
+            Err(err) => {
+                tracing::error!(?err, "{} error", P::ROUTE);
+                writer.abort();
+                return;
+            }
+        };
+
+        (items, cursor_id) = page.take_items_and_cursor_id();
+    }
+}
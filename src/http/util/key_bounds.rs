@@ -0,0 +1,26 @@
+use crate::common::OwnedCollectionKey;
+use std::ops::Bound;
+
+// `fromKey` is inclusive by default
+pub fn from_key_bound(
+    key: Option<OwnedCollectionKey>,
+    is_exclusive: Option<bool>,
+) -> Bound<OwnedCollectionKey> {
+    match key {
+        Some(key) if is_exclusive.unwrap_or(false) => Bound::Excluded(key),
+        Some(key) => Bound::Included(key),
+        None => Bound::Unbounded,
+    }
+}
+
+// `toKey` is exclusive by default
+pub fn to_key_bound(
+    key: Option<OwnedCollectionKey>,
+    is_inclusive: Option<bool>,
+) -> Bound<OwnedCollectionKey> {
+    match key {
+        Some(key) if is_inclusive.unwrap_or(false) => Bound::Included(key),
+        Some(key) => Bound::Excluded(key),
+        None => Bound::Unbounded,
+    }
+}
@@ -1,6 +1,10 @@
 pub mod common_groups;
+pub mod cursor_stream;
 pub mod encoding;
 pub mod get_collection;
+pub mod key_bounds;
 pub mod read_body;
 pub mod read_json;
+pub mod read_protobuf;
 pub mod response;
+pub mod stream_writer;
@@ -0,0 +1,7 @@
+use crate::http::errors::HttpError;
+use crate::http::request::FullBody;
+use protobuf::Message;
+
+pub fn read_protobuf<M: Message>(mut body: FullBody) -> Result<M, HttpError> {
+    M::parse_from_reader(&mut body).map_err(|err| HttpError::InvalidProtobuf(err.to_string()))
+}
@@ -1,5 +1,6 @@
 use crate::http::errors::HttpError;
 use crate::http::routing::response::{BaseResponse, BytesVecResponse, Response, StaticStrResponse};
+use protobuf::Message;
 use serde::Serialize;
 
 pub fn create_ok_static_str_json_response<E>(str: &'static str) -> Result<Response, E> {
@@ -29,3 +30,19 @@ pub fn create_ok_json_response<T: Serialize>(response: &T) -> Result<Response, H
         bytes: response,
     }))
 }
+
+pub fn create_ok_protobuf_response<M: Message>(response: &M) -> Result<Response, HttpError> {
+    let response = response
+        .write_to_bytes()
+        .or(Err(HttpError::PublicInternal500(
+            "result serialization failed",
+        )))?;
+
+    Ok(Response::BytesVec(BytesVecResponse {
+        base: BaseResponse {
+            content_type: "application/x-protobuf",
+            ..Default::default()
+        },
+        bytes: response,
+    }))
+}
@@ -0,0 +1,104 @@
+use crate::http::routing::response::{BaseResponse, Response, StreamResponse};
+use crate::http::validation::BodyFormat;
+use hyper::body::{Bytes, Sender};
+use hyper::Body;
+use protobuf::Message;
+use serde::Serialize;
+
+// Client has disconnected or the record cannot be serialized
+#[derive(Debug)]
+pub struct StreamClosed;
+
+// Records are buffered until `flush()`, it waits until hyper accepts the chunk,
+// so reading of the next pack is paused while the client does not read
+pub struct StreamWriter {
+    sender: Sender,
+    format: BodyFormat,
+    buffer: Vec<u8>,
+}
+
+impl StreamWriter {
+    pub fn new(format: BodyFormat) -> (Self, Response) {
+        let content_type = match format {
+            BodyFormat::Json => "application/x-ndjson; charset=utf-8",
+            BodyFormat::Protobuf => "application/x-protobuf",
+        };
+
+        Self::with_content_type(format, content_type)
+    }
+
+    // Server-Sent Events, records are written by `write_event()`
+    pub fn new_event_stream() -> (Self, Response) {
+        Self::with_content_type(BodyFormat::Json, "text/event-stream; charset=utf-8")
+    }
+
+    fn with_content_type(format: BodyFormat, content_type: &'static str) -> (Self, Response) {
+        let (sender, body) = Body::channel();
+
+        let writer = Self {
+            sender,
+            format,
+            buffer: Vec::new(),
+        };
+
+        let response = Response::Stream(StreamResponse {
+            base: BaseResponse {
+                content_type,
+                ..Default::default()
+            },
+            body,
+        });
+
+        (writer, response)
+    }
+
+    pub fn format(&self) -> BodyFormat {
+        self.format
+    }
+
+    // One JSON per line
+    pub fn write_json<T: Serialize>(&mut self, record: &T) -> Result<(), StreamClosed> {
+        serde_json::to_writer(&mut self.buffer, record).or(Err(StreamClosed))?;
+        self.buffer.push(b'\n');
+
+        Ok(())
+    }
+
+    // Varint length prefix and the message, like `writeDelimitedTo` in other implementations
+    pub fn write_protobuf<M: Message>(&mut self, record: &M) -> Result<(), StreamClosed> {
+        record
+            .write_length_delimited_to_vec(&mut self.buffer)
+            .or(Err(StreamClosed))
+    }
+
+    // JSON in `data` field, it has no newlines, so it is always one line
+    pub fn write_event<T: Serialize>(&mut self, event: &str, data: &T) -> Result<(), StreamClosed> {
+        self.buffer.extend_from_slice(b"event: ");
+        self.buffer.extend_from_slice(event.as_bytes());
+        self.buffer.extend_from_slice(b"\ndata: ");
+        serde_json::to_writer(&mut self.buffer, data).or(Err(StreamClosed))?;
+        self.buffer.extend_from_slice(b"\n\n");
+
+        Ok(())
+    }
+
+    // Ignored by SSE clients, used to detect disconnected ones
+    pub fn write_keep_alive(&mut self) {
+        self.buffer.extend_from_slice(b":\n\n");
+    }
+
+    pub async fn flush(&mut self) -> Result<(), StreamClosed> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let chunk = Bytes::from(std::mem::take(&mut self.buffer));
+
+        self.sender.send_data(chunk).await.or(Err(StreamClosed))
+    }
+
+    // Client will get an error instead of a truncated but successful response
+    pub fn abort(self) {
+        self.sender.abort();
+    }
+}
@@ -0,0 +1,33 @@
+use crate::http::auth::AccessRole;
+use crate::http::errors::HttpError;
+use crate::http::request::Request;
+
+pub trait AccessValidation: Request {
+    fn require_collection_access(
+        &self,
+        collection_name: &str,
+        role: AccessRole,
+    ) -> Result<(), HttpError>;
+    fn require_server_admin(&self) -> Result<(), HttpError>;
+    fn can_read_collection(&self, collection_name: &str) -> bool;
+}
+
+impl<T: Request> AccessValidation for T {
+    fn require_collection_access(
+        &self,
+        collection_name: &str,
+        role: AccessRole,
+    ) -> Result<(), HttpError> {
+        self.access().check_collection(collection_name, role)
+    }
+
+    fn require_server_admin(&self) -> Result<(), HttpError> {
+        self.access().check_server_admin()
+    }
+
+    fn can_read_collection(&self, collection_name: &str) -> bool {
+        self.access()
+            .check_collection(collection_name, AccessRole::Read)
+            .is_ok()
+    }
+}
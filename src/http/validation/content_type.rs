@@ -4,8 +4,18 @@ use crate::http::request::Request;
 use regex::Regex;
 use regex::RegexBuilder;
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BodyFormat {
+    Json,
+    Protobuf,
+}
+
 pub trait ContentTypeValidation: Request {
     fn allow_only_utf8_json_by_default(&self) -> Result<(), HttpError>;
+    fn allow_only_utf8_json_or_protobuf_by_default(&self) -> Result<BodyFormat, HttpError>;
+    fn allow_only_utf8_ndjson_by_default(&self) -> Result<(), HttpError>;
+    // First of JSON/protobuf types in the `Accept` header, `default` if there are none of them
+    fn accepted_body_format(&self, default: BodyFormat) -> BodyFormat;
 }
 
 impl<T: Request> ContentTypeValidation for T {
@@ -22,6 +32,56 @@ impl<T: Request> ContentTypeValidation for T {
             "supported Content-Types: application/json, supported charsets: utf-8",
         ))
     }
+
+    fn allow_only_utf8_json_or_protobuf_by_default(&self) -> Result<BodyFormat, HttpError> {
+        let content_type = self.get_header("Content-Type");
+
+        let Some(content_type) = content_type else { return Ok(BodyFormat::Json); };
+
+        if is_utf8_json_content_type(content_type) {
+            return Ok(BodyFormat::Json);
+        }
+
+        if is_protobuf_content_type(content_type) {
+            return Ok(BodyFormat::Protobuf);
+        }
+
+        Err(HttpError::ContentTypeUnsupported(
+            "supported Content-Types: application/json, application/x-protobuf, supported charsets: utf-8",
+        ))
+    }
+
+    fn allow_only_utf8_ndjson_by_default(&self) -> Result<(), HttpError> {
+        let content_type = self.get_header("Content-Type");
+
+        let Some(content_type) = content_type else { return Ok(()); };
+
+        if is_utf8_ndjson_content_type(content_type) {
+            return Ok(());
+        }
+
+        Err(HttpError::ContentTypeUnsupported(
+            "supported Content-Types: application/x-ndjson, supported charsets: utf-8",
+        ))
+    }
+
+    fn accepted_body_format(&self, default: BodyFormat) -> BodyFormat {
+        let Some(accept) = self.get_header("Accept") else { return default; };
+
+        for media_type in accept.split(',') {
+            let media_type = media_type.split(';').next().unwrap_or("").trim();
+
+            if media_type.eq_ignore_ascii_case("application/json") {
+                return BodyFormat::Json;
+            }
+
+            if is_protobuf_content_type(media_type) {
+                return BodyFormat::Protobuf;
+            }
+        }
+
+        default
+    }
 }
 
 fn is_utf8_json_content_type(value: &str) -> bool {
@@ -37,9 +97,28 @@ fn is_utf8_json_content_type(value: &str) -> bool {
     RE.is_match(value)
 }
 
+fn is_utf8_ndjson_content_type(value: &str) -> bool {
+    lazy_static::lazy_static! {
+        static ref RE: Regex =
+            RegexBuilder::new("^application/x-ndjson(;\\s*charset=utf-8)?$")
+                .case_insensitive(true)
+                .build()
+                .unwrap();
+    }
+
+    RE.is_match(value)
+}
+
+fn is_protobuf_content_type(value: &str) -> bool {
+    value.eq_ignore_ascii_case("application/x-protobuf")
+        || value.eq_ignore_ascii_case("application/protobuf")
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::http::validation::content_type::is_utf8_json_content_type;
+    use crate::http::validation::content_type::{
+        is_protobuf_content_type, is_utf8_json_content_type, is_utf8_ndjson_content_type,
+    };
 
     #[test]
     fn test_utf8_json_validation() {
@@ -52,4 +131,22 @@ mod tests {
             "application/json; charset=abracadabra"
         ));
     }
+
+    #[test]
+    fn test_utf8_ndjson_validation() {
+        assert!(is_utf8_ndjson_content_type("application/x-ndjson"));
+        assert!(is_utf8_ndjson_content_type(
+            "application/x-ndjson; charset=utf-8"
+        ));
+
+        assert!(!is_utf8_ndjson_content_type("application/json"));
+    }
+
+    #[test]
+    fn test_protobuf_validation() {
+        assert!(is_protobuf_content_type("application/x-protobuf"));
+        assert!(is_protobuf_content_type("application/protobuf"));
+
+        assert!(!is_protobuf_content_type("application/json"));
+    }
 }
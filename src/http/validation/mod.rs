@@ -1,5 +1,7 @@
-pub use content_type::ContentTypeValidation;
+pub use access::AccessValidation;
+pub use content_type::{BodyFormat, ContentTypeValidation};
 pub use method::MethodsValidation;
 
+mod access;
 mod content_type;
 mod method;
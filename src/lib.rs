@@ -0,0 +1,127 @@
+//! Diffbelt as an embedded library, without the HTTP server.
+//!
+//! [`Database`] manages collections, [`Collection`] has all the operations with records:
+//! get/put/query, diffs, readers and manual generations. Options and results of the methods
+//! are exported from the crate root next to them, errors are [`CollectionMethodError`] for
+//! collection methods and per-operation enums for database methods.
+//!
+//! ```no_run
+//! use diffbelt::{
+//!     CollectionPutOptions, CreateCollectionOptions, Database, DatabaseConfig,
+//!     DatabaseOpenOptions, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+//!     OwnedCollectionValue,
+//! };
+//! use std::path::PathBuf;
+//! use std::sync::Arc;
+//!
+//! # async fn run() {
+//! let database = Database::open(DatabaseOpenOptions {
+//!     data_path: &PathBuf::from("/tmp/diffbelt"),
+//!     config: Arc::new(DatabaseConfig::default()),
+//! })
+//! .await
+//! .unwrap();
+//!
+//! let collection = database
+//!     .create_collection("example", CreateCollectionOptions::default())
+//!     .await
+//!     .unwrap();
+//!
+//! collection
+//!     .put(CollectionPutOptions {
+//!         update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+//!             key: OwnedCollectionKey::from_boxed_slice(b"key".to_vec().into_boxed_slice())
+//!                 .unwrap(),
+//!             value: Some(OwnedCollectionValue::new(b"value")),
+//!             if_not_present: false,
+//!             if_value_changed: None,
+//!         }),
+//!         generation_id: None,
+//!         phantom_id: None,
+//!     })
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! All methods are async and should be called inside of the tokio runtime,
+//! [`create_main_tokio_runtime`] creates a suitable one.
+//!
+//! Other modules are public only for the `diffbelt` binary, they are not a stable API.
+
+mod collection;
+mod common;
+#[doc(hidden)]
+pub mod config;
+#[doc(hidden)]
+pub mod context;
+#[doc(hidden)]
+pub mod database;
+#[doc(hidden)]
+pub mod http;
+#[doc(hidden)]
+pub mod logging;
+#[doc(hidden)]
+pub mod messages;
+mod protos;
+mod raw_db;
+#[cfg(test)]
+mod tests;
+mod transform;
+#[doc(hidden)]
+pub mod util;
+
+// Database
+pub use crate::database::clone_collection::{CloneCollectionError, CloneCollectionOptions};
+pub use crate::database::config::{DatabaseConfig, DeletedCollectionReadersMode};
+pub use crate::database::create_collection::{CreateCollectionError, CreateCollectionOptions};
+pub use crate::database::open::{DatabaseOpenError, DatabaseOpenOptions};
+pub use crate::database::rename_collection::RenameCollectionError;
+pub use crate::database::Database;
+pub use crate::raw_db::{RawDbConfig, RawDbError};
+pub use crate::util::tokio_runtime::create_main_tokio_runtime;
+
+// Collection
+pub use crate::collection::methods::errors::CollectionMethodError;
+pub use crate::collection::settings::CollectionSettings;
+pub use crate::collection::Collection;
+
+// Records
+pub use crate::common::generation_id::GenerationIdSource;
+pub use crate::common::{
+    IsByteArray, KeyValue, KeyValueDiff, KeyValueUpdate, KeyValueUpdateNewOptions,
+    OwnedCollectionKey, OwnedCollectionValue, OwnedGenerationId, OwnedPhantomId,
+};
+
+// get/put/query/diff
+pub use crate::collection::methods::diff::{
+    AbortDiffCursorOptions, DiffOk, DiffOptions, ReadDiffCursorOptions,
+};
+pub use crate::collection::methods::get::{CollectionGetOk, CollectionGetOptions};
+pub use crate::collection::methods::put::{
+    CollectionPutManyOk, CollectionPutManyOptions, CollectionPutManyResult, CollectionPutOk,
+    CollectionPutOptions, CollectionPutResult,
+};
+pub use crate::collection::methods::query::{
+    AbortQueryCursorOptions, QueryOk, QueryOptions, ReadQueryCursorOptions,
+};
+
+// Readers and manual generations
+pub use crate::collection::methods::abort_generation::AbortGenerationOptions;
+pub use crate::collection::methods::commit_generation::CommitGenerationOptions;
+pub use crate::collection::methods::create_reader::CreateReaderOptions;
+pub use crate::collection::methods::delete_reader::DeleteReaderOptions;
+pub use crate::collection::methods::list_readers::ListReadersOk;
+pub use crate::collection::methods::start_generation::StartGenerationOptions;
+pub use crate::collection::methods::update_reader::UpdateReaderOptions;
+pub use crate::collection::CommitGenerationUpdateReader;
+pub use crate::common::reader::{ReaderDef, ReaderRecord};
+
+// Transactions and transforms
+pub use crate::database::transaction::{
+    CommitTransactionCollection, TransactionCollection, TransactionError,
+};
+pub use crate::database::transforms::{
+    CreateTransformOptions, TransformError, TransformInfo, TransformSource, TransformStep,
+};
+pub use crate::transform::{Transform, TransformItem, TransformPipeline};
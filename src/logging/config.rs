@@ -0,0 +1,22 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LogFormat {
+    Text,
+    // One JSON object per line, with fields of the current span
+    Json,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogConfig {
+    // `EnvFilter` directives, like `info` or `info,diffbelt::raw_db=debug`
+    pub filter: String,
+    pub format: LogFormat,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            filter: "info".to_string(),
+            format: LogFormat::Text,
+        }
+    }
+}
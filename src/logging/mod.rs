@@ -0,0 +1,104 @@
+use crate::logging::config::{LogConfig, LogFormat};
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
+
+pub mod config;
+
+pub type LogSubscriber = Box<dyn Subscriber + Send + Sync>;
+
+type FilterHandle = reload::Handle<EnvFilter, Registry>;
+
+#[derive(Debug)]
+pub enum LoggingError {
+    InvalidFilter(String),
+    // Subscriber was dropped
+    Reload(reload::Error),
+    AlreadyInstalled,
+}
+
+impl From<reload::Error> for LoggingError {
+    fn from(err: reload::Error) -> Self {
+        LoggingError::Reload(err)
+    }
+}
+
+// Filter of the installed subscriber, it can be changed at runtime by `POST /admin/log`
+pub struct Logging {
+    // Not present if the subscriber is not installed, filters are only validated then
+    handle: Option<FilterHandle>,
+    filter: Mutex<String>,
+}
+
+impl Default for Logging {
+    fn default() -> Self {
+        Self {
+            handle: None,
+            filter: Mutex::new(LogConfig::default().filter),
+        }
+    }
+}
+
+impl Logging {
+    // Logs are written to stderr, stdout is used by `diffbelt export`.
+    // Spans are logged on close with their timings, so every request
+    // and every RocksDB call (on `debug` level) has its duration
+    pub fn new(config: &LogConfig) -> Result<(Self, LogSubscriber), LoggingError> {
+        let (filter, handle) = reload::Layer::new(parse_filter(&config.filter)?);
+
+        let fmt_layer = fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_ansi(std::io::stderr().is_terminal())
+            .with_span_events(FmtSpan::CLOSE);
+
+        let subscriber: LogSubscriber = match config.format {
+            LogFormat::Text => Box::new(Registry::default().with(filter).with(fmt_layer)),
+            LogFormat::Json => Box::new(Registry::default().with(filter).with(fmt_layer.json())),
+        };
+
+        let logging = Self {
+            handle: Some(handle),
+            filter: Mutex::new(config.filter.clone()),
+        };
+
+        Ok((logging, subscriber))
+    }
+
+    pub fn init(config: &LogConfig) -> Result<Self, LoggingError> {
+        let (logging, subscriber) = Self::new(config)?;
+
+        tracing::subscriber::set_global_default(subscriber)
+            .map_err(|_| LoggingError::AlreadyInstalled)?;
+
+        Ok(logging)
+    }
+
+    pub fn filter(&self) -> String {
+        self.filter.lock().unwrap().clone()
+    }
+
+    pub fn set_filter(&self, filter: &str) -> Result<(), LoggingError> {
+        let parsed = parse_filter(filter)?;
+
+        let mut current = self.filter.lock().unwrap();
+
+        if let Some(handle) = &self.handle {
+            handle.reload(parsed)?;
+        }
+
+        *current = filter.to_string();
+
+        Ok(())
+    }
+}
+
+pub fn parse_filter(filter: &str) -> Result<EnvFilter, LoggingError> {
+    if filter.trim().is_empty() {
+        return Err(LoggingError::InvalidFilter("filter is empty".to_string()));
+    }
+
+    EnvFilter::try_new(filter).map_err(|err| LoggingError::InvalidFilter(err.to_string()))
+}
@@ -1,66 +1,257 @@
+use std::io::Write;
 use std::sync::Arc;
 
-use crate::config::{Config, ReadConfigFromEnvError};
-use crate::context::Context;
-use crate::database::open::DatabaseOpenOptions;
-use crate::database::Database;
-use crate::http::routing;
-use crate::http::server::start_http_server;
-use crate::util::tokio_runtime::create_main_tokio_runtime;
-
-mod collection;
-mod common;
-mod config;
-mod context;
-mod database;
-mod http;
-pub mod messages;
-mod protos;
-mod raw_db;
-#[cfg(test)]
-mod tests;
-mod util;
+use diffbelt::config::{Config, ReadConfigError};
+use diffbelt::context::Context;
+use diffbelt::database::backup::{restore_database_backup_sync, BackupError, RestoreBackupOptions};
+use diffbelt::database::dump::export::ExportCollectionOptions;
+use diffbelt::database::dump::import::{ImportCollectionOk, ImportCollectionOptions};
+use diffbelt::database::dump::DumpError;
+use diffbelt::http::routing;
+use diffbelt::http::server::{start_http_server, StartHttpServerError};
+use diffbelt::http::tls::TlsLoadError;
+use diffbelt::logging::Logging;
+use diffbelt::util::tokio_runtime::create_main_tokio_runtime;
+use diffbelt::{Database, DatabaseOpenOptions};
 
-async fn run() {
-    let config = Config::read_from_env();
+fn read_config() -> Config {
+    let config = Config::read();
     let config = match config {
         Ok(config) => config,
-        Err(err) => match err {
-            ReadConfigFromEnvError::VarNotPresent(name) => {
-                eprintln!("ENV variable \"{}\" not specified", name);
-                std::process::exit(1);
+        Err(err) => {
+            match err {
+                ReadConfigError::VarNotPresent(name) => {
+                    eprintln!("ENV variable \"{}\" not specified", name);
+                }
+                ReadConfigError::Invalid(errors) => {
+                    for error in errors {
+                        eprintln!("Invalid config: {}", error);
+                    }
+                }
+                ReadConfigError::Io(err) => {
+                    eprintln!("Cannot read config file: {}", err);
+                }
+                ReadConfigError::Toml(err) => {
+                    eprintln!("Cannot parse config file: {}", err);
+                }
+                ReadConfigError::Json(err) => {
+                    eprintln!("Cannot parse config file: {}", err);
+                }
+                rest => panic!("Config reading error: {:?}", rest),
             }
-            rest => panic!("Config reading error: {:?}", rest),
-        },
+            std::process::exit(1);
+        }
     };
-    let config = Arc::new(config);
 
-    if config.is_clear {
-        std::fs::remove_dir_all(&config.data_path).expect("cannot remove data_path");
+    for warning in &config.warnings {
+        eprintln!("Config warning: {}", warning);
+    }
+
+    config
+}
+
+// `diffbelt restore <backupId>`, restores the backup from `backup_path` to empty `data_path`
+fn restore(backup_id: &str) {
+    let config = read_config();
+
+    let Some(backup_path) = &config.backup_path else {
+        eprintln!("ENV variable \"DIFFBELT_BACKUP_PATH\" not specified");
+        std::process::exit(1);
+    };
+
+    let result = restore_database_backup_sync(RestoreBackupOptions {
+        path: backup_path,
+        backup_id,
+        data_path: &config.data_path,
+        raw_db_config: &config.database.raw_db,
+    });
+
+    match result {
+        Ok(()) => {}
+        Err(BackupError::NoSuchBackup) => {
+            eprintln!("No backup \"{}\" in {}", backup_id, backup_path.display());
+            std::process::exit(1);
+        }
+        Err(BackupError::DataPathNotEmpty) => {
+            eprintln!("{} is not empty", config.data_path.display());
+            std::process::exit(1);
+        }
+        Err(err) => panic!("Restore error: {:?}", err),
     }
+}
+
+// Logs go to stderr, so they are not mixed with the dump of `diffbelt export`
+fn init_logging(config: &Config) -> Logging {
+    Logging::init(&config.log).expect("Cannot init logging")
+}
 
-    let database = Database::open(DatabaseOpenOptions {
+async fn open_database(config: &Config) -> Database {
+    Database::open(DatabaseOpenOptions {
         data_path: &config.data_path,
-        config: Arc::new(Default::default()),
+        config: config.database.clone(),
     })
     .await
-    .expect("Cannot open database");
+    .expect("Cannot open database")
+}
+
+// `diffbelt export <collectionName> [--history]`, writes the dump of the current generation
+// to stdout, server should be stopped
+async fn export(collection_name: &str, history: bool) {
+    let config = read_config();
+    let _logging = init_logging(&config);
+    let database = open_database(&config).await;
+
+    let result = database
+        .export_collection(
+            collection_name,
+            ExportCollectionOptions {
+                generation_id: None,
+                history,
+            },
+        )
+        .await;
+
+    let mut export = match result {
+        Ok(export) => export,
+        Err(DumpError::NoSuchCollection) => {
+            eprintln!("No collection \"{}\"", collection_name);
+            std::process::exit(1);
+        }
+        Err(err) => panic!("Export error: {:?}", err),
+    };
+
+    let mut output = std::io::BufWriter::new(std::io::stdout().lock());
+
+    while let Some(lines) = export.next_pack().await.expect("Export error") {
+        for line in lines {
+            serde_json::to_writer(&mut output, &line).expect("Cannot write dump");
+            output.write_all(b"\n").expect("Cannot write dump");
+        }
+    }
+
+    output.flush().expect("Cannot write dump");
+}
+
+// `diffbelt import`, reads the dump from stdin, server should be stopped
+async fn import() {
+    let config = read_config();
+    let _logging = init_logging(&config);
+    let database = open_database(&config).await;
+
+    let input = futures::io::AllowStdIo::new(std::io::stdin().lock());
+
+    let result = database
+        .import_collection(
+            input,
+            ImportCollectionOptions {
+                max_line_bytes: config.http.put_many_request_max_bytes,
+            },
+        )
+        .await;
+
+    match result {
+        Ok(ImportCollectionOk {
+            collection,
+            records,
+        }) => {
+            eprintln!(
+                "Imported {} records to \"{}\"",
+                records,
+                collection.get_name()
+            );
+        }
+        Err(DumpError::AlreadyExist) => {
+            eprintln!("Collection already exists");
+            std::process::exit(1);
+        }
+        Err(DumpError::InvalidDump(reason)) => {
+            eprintln!("Invalid dump: {}", reason);
+            std::process::exit(1);
+        }
+        Err(DumpError::UnsupportedVersion(version)) => {
+            eprintln!("Dump version {} is not supported", version);
+            std::process::exit(1);
+        }
+        Err(err) => panic!("Import error: {:?}", err),
+    }
+}
+
+async fn run() {
+    let config = Arc::new(read_config());
+    let logging = init_logging(&config);
+
+    if config.is_clear {
+        std::fs::remove_dir_all(&config.data_path).expect("cannot remove data_path");
+    }
+
+    let database = open_database(&config).await;
 
     let mut context = Context {
         config,
         routing: routing::Routing::new(),
         database: Arc::new(database),
+        http_metrics: Default::default(),
+        logging,
     };
 
     routing::register_routes::register_routes(&mut context);
 
     let context = Arc::new(context);
 
-    start_http_server(context).await;
+    let Err(err) = start_http_server(context).await else {
+        return;
+    };
+
+    match err {
+        StartHttpServerError::Bind(address, err) => {
+            tracing::error!("Cannot listen on {}: {}", address, err);
+        }
+        StartHttpServerError::Tls(TlsLoadError::Io(path, err)) => {
+            tracing::error!("Cannot read {}: {}", path.display(), err);
+        }
+        StartHttpServerError::Tls(TlsLoadError::NoCertificates(path)) => {
+            tracing::error!("No certificates in {}", path.display());
+        }
+        StartHttpServerError::Tls(TlsLoadError::NoPrivateKey(path)) => {
+            tracing::error!("No private key in {}", path.display());
+        }
+        StartHttpServerError::Tls(TlsLoadError::Rustls(err)) => {
+            tracing::error!("Invalid TLS certificate or key: {}", err);
+        }
+    }
+
+    std::process::exit(1);
 }
 
 fn main() {
-    let runtime = create_main_tokio_runtime().unwrap();
+    let args: Vec<String> = std::env::args().collect();
+
+    match args.get(1).map(String::as_str) {
+        Some("restore") => {
+            let Some(backup_id) = args.get(2) else {
+                eprintln!("Usage: diffbelt restore <backupId>");
+                std::process::exit(1);
+            };
 
-    runtime.block_on(run());
+            restore(backup_id);
+        }
+        Some("export") => {
+            let Some(collection_name) = args.get(2) else {
+                eprintln!("Usage: diffbelt export <collectionName> [--history]");
+                std::process::exit(1);
+            };
+            let history = args.get(3).map(String::as_str) == Some("--history");
+
+            let runtime = create_main_tokio_runtime().unwrap();
+            runtime.block_on(export(collection_name, history));
+        }
+        Some("import") => {
+            let runtime = create_main_tokio_runtime().unwrap();
+            runtime.block_on(import());
+        }
+        _ => {
+            let runtime = create_main_tokio_runtime().unwrap();
+            runtime.block_on(run());
+        }
+    }
 }
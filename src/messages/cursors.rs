@@ -55,7 +55,6 @@ pub struct AbortCursorTask<T: CursorType> {
     pub sender: oneshot::Sender<Result<(), CursorError>>,
 }
 
-#[cfg(test)]
 pub struct GetCollectionCursorsCountTask<T: CursorType> {
     pub cursor_type: PhantomData<T>,
     pub collection_id: InnerCursorsCollectionId,
@@ -69,8 +68,6 @@ pub enum DatabaseCollectionSpecificCursorsTask<T: CursorType> {
     FinishQueryCursor(FinishCursorTask<T>),
     FullyFinishQueryCursor(FullyFinishCursorTask<T>),
     AbortQueryCursor(AbortCursorTask<T>),
-
-    #[cfg(test)]
     GetCollectionQueryCursorsCount(GetCollectionCursorsCountTask<T>),
 }
 
@@ -3,7 +3,8 @@ use crate::common::collection::CollectionName;
 use crate::common::OwnedGenerationId;
 use crate::database::DatabaseInner;
 use crate::util::auto_sender_on_drop::AutoSenderOnDrop;
-use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::{Arc, Mutex};
 use tokio::sync::{oneshot, watch, RwLock};
 
 pub enum GarbageCollectorCommonError {
@@ -13,6 +14,25 @@ pub enum GarbageCollectorCommonError {
 pub struct NewCollectionTaskResponse {
     pub id: usize,
     pub drop_handle: AutoSenderOnDrop<()>,
+    pub stats: Arc<GarbageCollectorStats>,
+}
+
+// Updated by the garbage collector, read by metrics
+pub struct GarbageCollectorStats {
+    pub removed_records: AtomicU64,
+    pub batches: AtomicU64,
+    // All generations less than it are collected
+    pub collected_generation_id: Mutex<OwnedGenerationId>,
+}
+
+impl Default for GarbageCollectorStats {
+    fn default() -> Self {
+        Self {
+            removed_records: AtomicU64::new(0),
+            batches: AtomicU64::new(0),
+            collected_generation_id: Mutex::new(OwnedGenerationId::empty()),
+        }
+    }
 }
 
 pub struct GarbageCollectorNewCollectionTask {
@@ -1,3 +1,4 @@
+use crate::collection::settings::CollectionSettings;
 use crate::collection::util::collection_raw_db::CollectionRawDb;
 use crate::collection::CommitGenerationUpdateReader;
 use crate::common::OwnedGenerationId;
@@ -7,6 +8,7 @@ use crate::database::generations::collection::{
 use crate::database::generations::next_generation_lock::GenerationIdLock;
 use crate::database::DatabaseInner;
 use crate::raw_db::RawDbError;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::sync::{oneshot, watch, RwLock};
 
@@ -18,10 +20,13 @@ pub struct NewCollectionGenerationsTaskResponse {
 pub struct NewCollectionGenerationsTask {
     pub name: Arc<str>,
     pub is_manual: bool,
+    pub settings: CollectionSettings,
     pub generation_id: OwnedGenerationId,
     pub next_generation_id: Option<OwnedGenerationId>,
     pub db: CollectionRawDb,
     pub is_deleted: Arc<RwLock<bool>>,
+    // Incremented on every commit
+    pub commits_count: Arc<AtomicU64>,
     pub sender: oneshot::Sender<Result<NewCollectionGenerationsTaskResponse, RawDbError>>,
 }
 
@@ -68,6 +73,9 @@ pub enum CommitManualGenerationError {
     OutdatedGeneration,
     RawDb(RawDbError),
     NoSuchCollection,
+    // Some generations of the transaction are not committed, transaction record
+    // is kept and they will be committed on the next `Database::open`
+    TransactionInterrupted(RawDbError),
 }
 
 pub struct CommitManualGenerationTask {
@@ -84,6 +92,27 @@ pub struct AbortManualGenerationTask {
     pub generation_id: OwnedGenerationId,
 }
 
+pub struct TransactionCollectionCommit {
+    pub collection_id: InnerGenerationsCollectionId,
+    pub generation_id: OwnedGenerationId,
+    pub update_readers: Option<Vec<CommitGenerationUpdateReader>>,
+}
+
+pub struct CommitTransactionTask {
+    // Collections are locked in this order
+    pub collections: Vec<TransactionCollectionCommit>,
+    pub transaction_id: String,
+    // Serialized `TransactionRecord`
+    pub record: Vec<u8>,
+    pub sender: oneshot::Sender<Result<(), CommitManualGenerationError>>,
+}
+
+pub struct UpdateCollectionSettingsTask {
+    pub collection_id: InnerGenerationsCollectionId,
+    pub settings: CollectionSettings,
+    pub sender: oneshot::Sender<()>,
+}
+
 pub enum DatabaseCollectionGenerationsTask {
     Init(Arc<DatabaseInner>),
     NewCollection(NewCollectionGenerationsTask),
@@ -93,4 +122,7 @@ pub enum DatabaseCollectionGenerationsTask {
     StartManualGenerationId(StartManualGenerationIdTask),
     AbortManualGeneration(AbortManualGenerationTask),
     CommitManualGeneration(CommitManualGenerationTask),
+    CommitTransaction(CommitTransactionTask),
+
+    UpdateCollectionSettings(UpdateCollectionSettingsTask),
 }
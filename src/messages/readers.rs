@@ -35,6 +35,20 @@ pub struct DeleteReaderTask {
     pub reader_name: ReaderName,
 }
 
+// Forget all readers owned by the collection or pointing to it
+pub struct DropCollectionReadersTask {
+    pub collection_name: CollectionName,
+    pub sender: oneshot::Sender<()>,
+}
+
+// Readers owned by the collection or pointing to it are moved to the new name,
+// state of the collection (including minimum generation id) is kept
+pub struct RenameCollectionReadersTask {
+    pub collection_name: CollectionName,
+    pub new_collection_name: CollectionName,
+    pub sender: oneshot::Sender<()>,
+}
+
 pub struct CollectionNameReaderName {
     pub owner_collection_name: CollectionName,
     pub reader_name: ReaderName,
@@ -64,6 +78,8 @@ pub enum DatabaseCollectionReadersTask {
     DeleteReader(DeleteReaderTask),
     GetReadersPointingToCollectionExceptThisOne(GetReadersPointingToCollectionTask),
     GetMinimumGenerationIdLocks(GetMinimumGenerationIdLocksTask),
+    DropCollection(DropCollectionReadersTask),
+    RenameCollection(RenameCollectionReadersTask),
     InitFinish,
     Finish,
 }
@@ -0,0 +1,32 @@
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::WriteBatchWithTransaction;
+
+impl RawDb {
+    // All puts and deletes are applied atomically
+    pub fn put_and_delete_many_cf_sync(
+        &self,
+        cf_name: &str,
+        puts: &[(&[u8], &[u8])],
+        deletes: &[&[u8]],
+    ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("put_and_delete_many_cf");
+
+        let db = self.db.get_db();
+
+        let cf = db.cf_handle(cf_name).ok_or(RawDbError::CfHandle)?;
+
+        let mut batch = WriteBatchWithTransaction::<false>::default();
+
+        for (key, value) in puts {
+            batch.put_cf(&cf, key, value);
+        }
+
+        for key in deletes {
+            batch.delete_cf(&cf, key);
+        }
+
+        db.write(batch)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::checkpoint::Checkpoint;
+
+impl RawDb {
+    // Files are hard-linked if `path` is on the same filesystem, so it's cheap
+    pub fn create_checkpoint_sync(&self, path: &str) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("create_checkpoint");
+
+        let db = self.db.get_db();
+
+        let checkpoint = Checkpoint::new(db)?;
+        checkpoint.create_checkpoint(path)?;
+
+        Ok(())
+    }
+}
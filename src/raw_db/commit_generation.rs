@@ -1,7 +1,7 @@
 use crate::collection::constants::COLLECTION_CF_META;
 use crate::common::{GenerationId, IsByteArray};
 use crate::raw_db::update_reader::RawDbUpdateReaderOptions;
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use rocksdb::WriteBatchWithTransaction;
 
 pub struct RawDbUpdateReader<'a> {
@@ -20,6 +20,8 @@ impl RawDb {
         &self,
         options: RawDbCommitGenerationOptions<'_>,
     ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("commit_generation");
+
         let RawDbCommitGenerationOptions {
             generation_id,
             next_generation_id,
@@ -1,7 +1,8 @@
 use crate::collection::util::record_key::{OwnedRecordKey, RecordKey};
 use crate::common::IsByteArray;
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use crate::util::bytes::decrement;
+use crate::util::tokio::spawn_blocking;
 use rocksdb::{Direction, IteratorMode, ReadOptions};
 
 pub struct ContainsExistingCollectionRecordOptions<'a> {
@@ -16,7 +17,9 @@ impl RawDb {
         let db = self.db.clone();
         let record_key = options.record_key.to_owned();
 
-        tokio::task::spawn_blocking(move || {
+        spawn_blocking(move || {
+            let _span = rocksdb_span("contains_existing_collection_record");
+
             let db = db.get_db();
 
             let mut lower_record_key = record_key.clone();
@@ -1,7 +1,10 @@
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::WriteBatchWithTransaction;
 
 impl RawDb {
     pub fn delete_cf_sync(&self, cf_name: &str, key: &[u8]) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("delete_cf");
+
         let db = self.db.get_db();
 
         let cf = db.cf_handle(cf_name).ok_or(RawDbError::CfHandle)?;
@@ -9,4 +12,23 @@ impl RawDb {
 
         Ok(())
     }
+
+    // All keys are deleted atomically
+    pub fn delete_many_cf_sync(&self, cf_name: &str, keys: &[&[u8]]) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("delete_many_cf");
+
+        let db = self.db.get_db();
+
+        let cf = db.cf_handle(cf_name).ok_or(RawDbError::CfHandle)?;
+
+        let mut batch = WriteBatchWithTransaction::<false>::default();
+
+        for key in keys {
+            batch.delete_cf(&cf, key);
+        }
+
+        db.write(batch)?;
+
+        Ok(())
+    }
 }
@@ -1,8 +1,10 @@
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use rocksdb::Options;
 
 impl RawDb {
     pub fn destroy(path: &str) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("destroy");
+
         let opts = Options::default();
         rocksdb::DB::destroy(&opts, path).map_err(|err| RawDbError::RocksDb(err))?;
 
@@ -3,7 +3,7 @@ use crate::common::{GenerationId, KeyValueDiff, OwnedCollectionKey, OwnedGenerat
 use crate::raw_db::diff_collection_records::state::in_memory::InMemoryChangedKeysIter;
 use crate::raw_db::diff_collection_records::state::single_generation::SingleGenerationChangedKeysIter;
 use crate::raw_db::diff_collection_records::state::{DiffState, DiffStateMode, DiffStateNewResult};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 
 mod state;
 
@@ -12,6 +12,7 @@ pub struct DiffCollectionRecordsOptions<'a> {
     // Not loose if `prev_diff_state` is specified
     pub to_generation_id_loose: GenerationId<'a>,
     pub prev_diff_state: Option<&'a DiffCursorState>,
+    pub omit_intermediate_values: bool,
     pub limit: usize,
     pub records_to_view_limit: usize,
     pub total_count_in_generations_limit: usize,
@@ -23,10 +24,15 @@ pub struct DiffCollectionRecordsOk {
     pub next_diff_state: Option<DiffCursorState>,
 }
 
+// Value of the changed key after `from_generation_id` with its generation
+type GenerationValue = (OwnedGenerationId, Box<[u8]>);
+
 pub struct DiffCursorState {
     changed_key: OwnedCollectionKey,
     first_value: Option<Box<[u8]>>,
     last_value: Option<Box<[u8]>>,
+    // Filled instead of `last_value` if intermediate values are not omitted
+    intermediate_values: Vec<GenerationValue>,
     next_record_key: OwnedRecordKey,
 }
 
@@ -35,10 +41,13 @@ impl RawDb {
         &self,
         options: DiffCollectionRecordsOptions<'_>,
     ) -> Result<DiffCollectionRecordsOk, RawDbError> {
+        let _span = rocksdb_span("diff_collection_records");
+
         let DiffCollectionRecordsOptions {
             from_generation_id,
             to_generation_id_loose,
             prev_diff_state,
+            omit_intermediate_values,
             limit,
             records_to_view_limit,
             total_count_in_generations_limit,
@@ -52,6 +61,7 @@ impl RawDb {
                 from_generation_id,
                 to_generation_id_loose,
                 prev_state,
+                omit_intermediate_values,
                 limit,
                 records_to_view_limit,
             )?,
@@ -59,6 +69,7 @@ impl RawDb {
                 db,
                 from_generation_id,
                 to_generation_id_loose,
+                omit_intermediate_values,
                 limit,
                 records_to_view_limit,
                 total_count_in_generations_limit,
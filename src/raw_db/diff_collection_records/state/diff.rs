@@ -1,20 +1,24 @@
 use crate::collection::util::record_key::{OwnedParsedRecordKey, OwnedRecordKey, ParsedRecordKey};
 use crate::common::{
-    CollectionKey, GenerationId, IsByteArray, KeyValueDiff, OwnedCollectionKey,
+    CollectionKey, GenerationId, IntermediateValue, IsByteArray, KeyValueDiff, OwnedCollectionKey,
     OwnedCollectionValue, PhantomId,
 };
 use crate::raw_db::diff_collection_records::state::{DiffState, PrevDiffState};
-use crate::raw_db::diff_collection_records::{DiffCollectionRecordsOk, DiffCursorState};
+use crate::raw_db::diff_collection_records::{
+    DiffCollectionRecordsOk, DiffCursorState, GenerationValue,
+};
 use crate::raw_db::RawDbError;
 use crate::util::option::lift_result_from_option;
 use crate::util::owned_peek::OwnedPeek;
 use rocksdb::{DBIterator, Direction, IteratorMode};
+use std::mem;
 
 struct KeyProcessing {
     record_key: OwnedParsedRecordKey,
     value: Box<[u8]>,
     first_value: Option<Box<[u8]>>,
     last_value: Option<Box<[u8]>>,
+    intermediate_values: Vec<GenerationValue>,
 }
 
 type RecordKeyWithValue = (OwnedParsedRecordKey, Box<[u8]>);
@@ -40,6 +44,7 @@ impl DiffState<'_> {
             records_to_view_left,
             pack_limit,
             prev_state,
+            omit_intermediate_values,
         } = self;
 
         let mut pack_size_left = *pack_limit;
@@ -52,6 +57,7 @@ impl DiffState<'_> {
             Some(PrevDiffState {
                 first_value,
                 last_value,
+                intermediate_values,
                 next_record_key,
             }) => {
                 let iterator_mode =
@@ -73,6 +79,7 @@ impl DiffState<'_> {
                         value,
                         first_value: first_value.map(|bytes| bytes.into()),
                         last_value: last_value.map(|bytes| bytes.into()),
+                        intermediate_values: intermediate_values.to_vec(),
                     },
                 )
             }
@@ -117,6 +124,7 @@ impl DiffState<'_> {
                                     value,
                                     first_value: None,
                                     last_value: None,
+                                    intermediate_values: Vec::new(),
                                 },
                             ))),
                             Some(Ok(changed_key)),
@@ -164,6 +172,7 @@ impl DiffState<'_> {
                 value,
                 mut first_value,
                 mut last_value,
+                mut intermediate_values,
             } = if record_key.collection_key != changed_key.as_ref() {
                 // jump to required key
                 iterator_mode_for_collection_key(changed_key.as_ref(), |iterator_mode| {
@@ -182,6 +191,7 @@ impl DiffState<'_> {
                     value,
                     first_value: None,
                     last_value: None,
+                    intermediate_values: Vec::new(),
                 }
             } else {
                 db_next_item
@@ -194,15 +204,17 @@ impl DiffState<'_> {
                     phantom_id,
                 } = record_key.get_parsed();
 
+                // Record of the next key will be counted when it will be processed,
+                // cursor should not stop on it while current key is not finished
+                if collection_key != changed_key.as_ref() {
+                    return HandleDbRecordResult::CollectionKeyChanged((record_key, value));
+                }
+
                 *records_to_view_left -= 1;
 
                 if *records_to_view_left <= 0 {
                     return HandleDbRecordResult::Finish(record_key);
                 }
-
-                if collection_key != changed_key.as_ref() {
-                    return HandleDbRecordResult::CollectionKeyChanged((record_key, value));
-                }
                 if phantom_id.is_some() || generation_id > (*to_generation_id).as_ref() {
                     return HandleDbRecordResult::Continue;
                 }
@@ -210,8 +222,11 @@ impl DiffState<'_> {
                 // If `from_generation_id` is None, `first_value` should be None
                 if generation_id.less_or_equal_with_opt_or(*from_generation_id, false) {
                     first_value = Some(value);
-                } else {
+                } else if *omit_intermediate_values {
                     last_value = Some(value);
+                } else {
+                    // Last of them will be used as `last_value` in `handle_item`
+                    intermediate_values.push((generation_id.to_owned(), value));
                 }
 
                 HandleDbRecordResult::Continue
@@ -230,6 +245,7 @@ impl DiffState<'_> {
                                 changed_key,
                                 first_value,
                                 last_value,
+                                intermediate_values,
                                 next_record_key: OwnedRecordKey::from_owned_parsed_record_key(
                                     record_key,
                                 ),
@@ -261,6 +277,7 @@ impl DiffState<'_> {
                                     changed_key,
                                     first_value,
                                     last_value,
+                                    intermediate_values,
                                     next_record_key: OwnedRecordKey::from_owned_parsed_record_key(
                                         record_key,
                                     ),
@@ -278,6 +295,7 @@ impl DiffState<'_> {
                         value,
                         first_value: None,
                         last_value: None,
+                        intermediate_values: Vec::new(),
                     },
                     None => {
                         // End of iterator
@@ -290,6 +308,7 @@ impl DiffState<'_> {
                             &mut items,
                             &mut first_value,
                             &mut last_value,
+                            &mut intermediate_values,
                         )?;
 
                         return Ok(DiffCollectionRecordsOk {
@@ -306,6 +325,7 @@ impl DiffState<'_> {
                 &mut items,
                 &mut first_value,
                 &mut last_value,
+                &mut intermediate_values,
             )?;
             pack_size_left -= 1;
 
@@ -318,6 +338,7 @@ impl DiffState<'_> {
                     value: _,
                     first_value,
                     last_value,
+                    intermediate_values,
                 } = db_next_item;
 
                 return Ok(DiffCollectionRecordsOk {
@@ -327,6 +348,7 @@ impl DiffState<'_> {
                         changed_key,
                         first_value,
                         last_value,
+                        intermediate_values,
                         next_record_key: OwnedRecordKey::from_owned_parsed_record_key(record_key),
                     }),
                 });
@@ -346,16 +368,29 @@ fn handle_item(
     items: &mut Vec<KeyValueDiff>,
     first_value: &mut Option<Box<[u8]>>,
     last_value: &mut Option<Box<[u8]>>,
+    intermediate_values: &mut Vec<GenerationValue>,
 ) -> Result<(), RawDbError> {
+    let mut intermediate_values = mem::take(intermediate_values);
+
+    // If intermediate values are not omitted, `last_value` is the last of them
+    let last_value = match intermediate_values.pop() {
+        Some((_, value)) => Some(value),
+        None => last_value.take(),
+    };
+
     items.push(KeyValueDiff {
         key: collection_key.to_owned(),
         from_value: first_value
             .take()
             .and_then(|bytes| OwnedCollectionValue::from_boxed_slice_opt(bytes)),
-        intermediate_values: Vec::with_capacity(0),
-        to_value: last_value
-            .take()
-            .and_then(|bytes| OwnedCollectionValue::from_boxed_slice_opt(bytes)),
+        intermediate_values: intermediate_values
+            .into_iter()
+            .map(|(generation_id, value)| IntermediateValue {
+                generation_id,
+                value: OwnedCollectionValue::from_boxed_slice_opt(value),
+            })
+            .collect(),
+        to_value: last_value.and_then(OwnedCollectionValue::from_boxed_slice_opt),
     });
 
     Ok(())
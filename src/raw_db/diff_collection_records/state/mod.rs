@@ -4,7 +4,7 @@ use crate::collection::util::record_key::RecordKey;
 use crate::common::{
     CollectionKey, GenerationId, IsByteArray, OwnedCollectionKey, OwnedGenerationId,
 };
-use crate::raw_db::diff_collection_records::DiffCursorState;
+use crate::raw_db::diff_collection_records::{DiffCursorState, GenerationValue};
 use crate::raw_db::RawDbError;
 use crate::util::bytes::to_u32_be_unchecked;
 use rocksdb::{BoundColumnFamily, Direction, IteratorMode, ReadOptions};
@@ -28,6 +28,7 @@ pub enum DiffStateMode {
 pub struct PrevDiffState<'a> {
     first_value: Option<&'a [u8]>,
     last_value: Option<&'a [u8]>,
+    intermediate_values: &'a [GenerationValue],
     next_record_key: RecordKey<'a>,
 }
 
@@ -36,6 +37,7 @@ pub struct DiffState<'a> {
     from_generation_id: Option<GenerationId<'a>>,
     to_generation_id: OwnedGenerationId,
     prev_state: Option<PrevDiffState<'a>>,
+    omit_intermediate_values: bool,
     records_to_view_left: usize,
     pack_limit: usize,
 }
@@ -50,6 +52,7 @@ impl<'a> DiffState<'a> {
         db: &'a rocksdb::DB,
         from_generation_id: Option<GenerationId<'a>>,
         to_generation_id_loose: GenerationId<'a>,
+        omit_intermediate_values: bool,
         pack_limit: usize,
         records_to_view_limit: usize,
         total_count_in_generations_limit: usize,
@@ -113,6 +116,7 @@ impl<'a> DiffState<'a> {
                         from_generation_id,
                         to_generation_id: generation_id,
                         prev_state: None,
+                        omit_intermediate_values,
                         records_to_view_left: records_to_view_limit,
                         pack_limit,
                     },
@@ -159,6 +163,7 @@ impl<'a> DiffState<'a> {
                 from_generation_id,
                 to_generation_id,
                 prev_state: None,
+                omit_intermediate_values,
                 records_to_view_left: records_to_view_limit,
                 pack_limit,
             },
@@ -171,6 +176,7 @@ impl<'a> DiffState<'a> {
         from_generation_id: Option<GenerationId<'a>>,
         to_generation_id: GenerationId<'a>,
         prev_state: &'a DiffCursorState,
+        omit_intermediate_values: bool,
         pack_limit: usize,
         records_to_view_limit: usize,
     ) -> Result<DiffStateNewResult<'a>, RawDbError> {
@@ -182,6 +188,7 @@ impl<'a> DiffState<'a> {
             changed_key,
             first_value,
             last_value,
+            intermediate_values,
             next_record_key,
         } = prev_state;
 
@@ -205,8 +212,10 @@ impl<'a> DiffState<'a> {
                     last_value: last_value
                         .as_ref()
                         .map(|bytes| AsRef::<[u8]>::as_ref(bytes)),
+                    intermediate_values,
                     next_record_key: next_record_key.as_ref(),
                 }),
+                omit_intermediate_values,
                 records_to_view_left: records_to_view_limit,
                 pack_limit,
             },
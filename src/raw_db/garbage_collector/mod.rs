@@ -2,7 +2,7 @@ use crate::collection::constants::COLLECTION_CF_GENERATIONS;
 use crate::collection::util::generation_key::{GenerationKey, OwnedGenerationKey};
 use crate::collection::util::record_key::{OwnedRecordKey, RecordKey};
 use crate::common::{CollectionKey, GenerationId, IsByteArray, PhantomId};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use rocksdb::{Direction, IteratorMode, ReadOptions, WriteBatchWithTransaction, DB};
 use std::cmp::Ordering;
 use std::num::NonZeroUsize;
@@ -20,10 +20,13 @@ pub enum CleanupResult {
 }
 
 impl RawDb {
+    // Also returns the number of removed records
     pub fn cleanup_generations_less_than_sync(
         &self,
         options: CleanupGenerationsLessThanOptions<'_>,
-    ) -> Result<CleanupResult, RawDbError> {
+    ) -> Result<(CleanupResult, usize), RawDbError> {
+        let _span = rocksdb_span("cleanup_generations_less_than");
+
         let CleanupGenerationsLessThanOptions {
             generation_less_than,
             mut continue_from_record_key,
@@ -53,6 +56,7 @@ impl RawDb {
         };
 
         let mut result = CleanupResult::Finished;
+        let mut removed_records = 0;
 
         let mut batch = WriteBatchWithTransaction::<false>::default();
 
@@ -85,6 +89,7 @@ impl RawDb {
                 continue_from_record_key,
                 &mut records_limit,
                 &mut lookups_limit,
+                &mut removed_records,
             )?;
 
             match key_result {
@@ -106,7 +111,7 @@ impl RawDb {
 
         let _: () = db.write(batch)?;
 
-        Ok(result)
+        Ok((result, removed_records))
     }
 }
 
@@ -122,6 +127,7 @@ fn cleanup_collection_key(
     continue_from_record_key: Option<OwnedRecordKey>,
     records_limit: &mut usize,
     lookups_limit: &mut usize,
+    removed_records: &mut usize,
 ) -> Result<CleanupCollectionKeyResult, RawDbError> {
     let records_iterator = {
         let record_key = {
@@ -175,6 +181,7 @@ fn cleanup_collection_key(
             if let Some(prev_key) = prev_key.take() {
                 // We should not delete last present record, so always remove previous one
                 batch.delete(&prev_key);
+                *removed_records += 1;
             }
         }
 
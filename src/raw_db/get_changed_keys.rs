@@ -0,0 +1,161 @@
+use crate::collection::constants::COLLECTION_CF_GENERATIONS;
+use crate::collection::util::generation_key::{GenerationKey, OwnedGenerationKey};
+use crate::common::constants::MAX_GENERATION_ID_LENGTH;
+use crate::common::key_range::KeyRange;
+use crate::common::{
+    CollectionKey, GenerationId, IsByteArray, OwnedCollectionKey, OwnedGenerationId,
+};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::{Direction, IteratorMode, ReadOptions};
+use std::collections::BTreeSet;
+use std::ops::Bound;
+
+pub struct GetChangedKeysOptions<'a> {
+    // Exclusive
+    pub from_generation_id: GenerationId<'a>,
+    // Inclusive
+    pub to_generation_id: GenerationId<'a>,
+    pub key_range: KeyRange<'a>,
+    pub limit: usize,
+}
+
+pub struct GetChangedKeysOk {
+    // Sorted, at most `limit` keys
+    pub keys: Vec<OwnedCollectionKey>,
+    pub has_more: bool,
+}
+
+impl RawDb {
+    // Keys of the range that were put in generations `(from, to]`, phantom puts are not counted
+    pub fn get_changed_keys_sync(
+        &self,
+        options: GetChangedKeysOptions<'_>,
+    ) -> Result<GetChangedKeysOk, RawDbError> {
+        let _span = rocksdb_span("get_changed_keys");
+
+        let GetChangedKeysOptions {
+            from_generation_id,
+            to_generation_id,
+            key_range,
+            limit,
+        } = options;
+
+        let db = self.db.get_db();
+
+        let generations_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let to_generation_key = OwnedGenerationKey::new(
+            to_generation_id.incremented().as_ref(),
+            CollectionKey::empty(),
+        )
+        .or(Err(RawDbError::InvalidGenerationKey))?;
+
+        let mut keys = BTreeSet::new();
+        let mut has_more = false;
+
+        let Some(first_generation_id) = next_generation_id(from_generation_id) else {
+            return Ok(GetChangedKeysOk {
+                keys: Vec::new(),
+                has_more,
+            });
+        };
+
+        let mut opts = ReadOptions::default();
+        opts.set_iterate_upper_bound(to_generation_key.get_byte_array());
+
+        // Keys of each generation are sorted, so only the range part of the generation is read
+        let start_key = range_start_key(first_generation_id.as_ref(), &key_range)?;
+        let mut iterator = db.iterator_cf_opt(
+            &generations_cf,
+            opts,
+            IteratorMode::From(start_key.get_byte_array(), Direction::Forward),
+        );
+
+        while let Some(result) = iterator.next() {
+            let (key, _): (Box<[u8]>, Box<[u8]>) = result?;
+
+            let generation_key =
+                GenerationKey::validate(&key).or(Err(RawDbError::InvalidGenerationKey))?;
+
+            let generation_id = generation_key.get_generation_id();
+            let collection_key = generation_key.get_collection_key();
+
+            if key_range.is_above_to(collection_key) {
+                let Some(next_generation_id) = next_generation_id(generation_id) else {
+                    break;
+                };
+
+                let start_key = range_start_key(next_generation_id.as_ref(), &key_range)?;
+                iterator.set_mode(IteratorMode::From(
+                    start_key.get_byte_array(),
+                    Direction::Forward,
+                ));
+                continue;
+            }
+
+            if key_range.is_below_from(collection_key) {
+                // Start of the next generation, excluded lower bound itself is just skipped
+                if collection_key.get_byte_array() < range_lower_key(&key_range).get_byte_array() {
+                    let start_key = range_start_key(generation_id, &key_range)?;
+                    iterator.set_mode(IteratorMode::From(
+                        start_key.get_byte_array(),
+                        Direction::Forward,
+                    ));
+                }
+                continue;
+            }
+
+            let collection_key = collection_key.to_owned();
+
+            if keys.contains(&collection_key) {
+                continue;
+            }
+
+            if keys.len() >= limit {
+                has_more = true;
+                break;
+            }
+
+            keys.insert(collection_key);
+        }
+
+        Ok(GetChangedKeysOk {
+            keys: keys.into_iter().collect(),
+            has_more,
+        })
+    }
+}
+
+fn range_lower_key<'a>(key_range: &KeyRange<'a>) -> CollectionKey<'a> {
+    match key_range.from {
+        Bound::Included(key) | Bound::Excluded(key) => key,
+        Bound::Unbounded => CollectionKey::empty(),
+    }
+}
+
+fn range_start_key(
+    generation_id: GenerationId<'_>,
+    key_range: &KeyRange<'_>,
+) -> Result<OwnedGenerationKey, RawDbError> {
+    OwnedGenerationKey::new(generation_id, range_lower_key(key_range))
+        .or(Err(RawDbError::InvalidGenerationKey))
+}
+
+// Smallest generation id that is greater than `generation_id`
+fn next_generation_id(generation_id: GenerationId<'_>) -> Option<OwnedGenerationId> {
+    let mut bytes = generation_id.get_byte_array().to_vec();
+
+    if bytes.len() < MAX_GENERATION_ID_LENGTH {
+        bytes.push(0);
+    } else {
+        while bytes.last() == Some(&0xff) {
+            bytes.pop();
+        }
+
+        *bytes.last_mut()? += 1;
+    }
+
+    Some(OwnedGenerationId::from_boxed_slice(bytes.into_boxed_slice()).unwrap())
+}
@@ -1,11 +1,15 @@
 use crate::collection::util::record_key::{OwnedRecordKey, RecordKey};
 use crate::common::{IsByteArray, OwnedCollectionValue};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use crate::util::bytes::decrement;
+use crate::util::tokio::spawn_blocking;
 use rocksdb::{Direction, IteratorMode, ReadOptions};
 
 pub struct GetCollectionRecordOptions<'a> {
     pub record_key: RecordKey<'a>,
+    // For a phantom record key, records without phantom id are visible too
+    // (as in query), the phantom record wins if it is in the same generation
+    pub include_real_records: bool,
 }
 
 impl RawDb {
@@ -15,8 +19,11 @@ impl RawDb {
     ) -> Result<Option<(OwnedRecordKey, OwnedCollectionValue)>, RawDbError> {
         let db = self.db.clone();
         let record_key = options.record_key.to_owned();
+        let include_real_records = options.include_real_records;
+
+        spawn_blocking(move || {
+            let _span = rocksdb_span("get_collection_record");
 
-        tokio::task::spawn_blocking(move || {
             let db = db.get_db();
 
             let mut lower_record_key = record_key.clone();
@@ -43,7 +50,11 @@ impl RawDb {
                 if item_record_key.get_collection_key() != collection_key {
                     break;
                 }
-                if item_record_key.get_phantom_id() != phantom_id {
+                let item_phantom_id = item_record_key.get_phantom_id();
+                let is_visible_real_record =
+                    include_real_records && item_phantom_id.get_byte_array().is_empty();
+
+                if item_phantom_id != phantom_id && !is_visible_real_record {
                     continue;
                 }
 
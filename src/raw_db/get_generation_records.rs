@@ -0,0 +1,117 @@
+use crate::collection::constants::COLLECTION_CF_GENERATIONS;
+use crate::collection::util::generation_key::{GenerationKey, OwnedGenerationKey};
+use crate::collection::util::record_key::OwnedRecordKey;
+use crate::common::{
+    CollectionKey, GenerationId, IsByteArray, OwnedCollectionKey, OwnedCollectionValue,
+    OwnedGenerationId, PhantomId,
+};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::{Direction, IteratorMode, ReadOptions};
+
+pub struct GetGenerationRecordsOptions<'a> {
+    // Exclusive, from the first generation if not specified
+    pub after: Option<GenerationKey<'a>>,
+    // Inclusive
+    pub to_generation_id: GenerationId<'a>,
+    pub limit: usize,
+}
+
+pub struct GenerationRecord {
+    pub generation_id: OwnedGenerationId,
+    pub key: OwnedCollectionKey,
+    // `None` if the key was deleted in this generation
+    pub value: Option<OwnedCollectionValue>,
+}
+
+pub struct GetGenerationRecordsOk {
+    // Ordered by generation id, then by key
+    pub items: Vec<GenerationRecord>,
+    // Pass it as `after` to get the next records, `None` if there are no more
+    pub last_generation_key: Option<OwnedGenerationKey>,
+}
+
+impl RawDb {
+    // Non-phantom records of generations up to `to_generation_id`, records that were
+    // already removed by GC are skipped
+    pub fn get_generation_records_sync(
+        &self,
+        options: GetGenerationRecordsOptions<'_>,
+    ) -> Result<GetGenerationRecordsOk, RawDbError> {
+        let _span = rocksdb_span("get_generation_records");
+
+        let GetGenerationRecordsOptions {
+            after,
+            to_generation_id,
+            limit,
+        } = options;
+
+        let db = self.db.get_db();
+
+        let generations_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let to_generation_key = OwnedGenerationKey::new(
+            to_generation_id.incremented().as_ref(),
+            CollectionKey::empty(),
+        )
+        .or(Err(RawDbError::InvalidGenerationKey))?;
+
+        let mut opts = ReadOptions::default();
+        opts.set_iterate_upper_bound(to_generation_key.get_byte_array());
+
+        let iterator_mode = match &after {
+            Some(after) => IteratorMode::From(after.value, Direction::Forward),
+            None => IteratorMode::Start,
+        };
+
+        let iterator = db.iterator_cf_opt(&generations_cf, opts, iterator_mode);
+
+        let mut items = Vec::with_capacity(limit);
+        let mut last_generation_key = None;
+
+        for result in iterator {
+            let (key, _): (Box<[u8]>, Box<[u8]>) = result?;
+
+            if let Some(after) = &after {
+                if key.as_ref() == after.value {
+                    continue;
+                }
+            }
+
+            if items.len() >= limit {
+                break;
+            }
+
+            let generation_key =
+                GenerationKey::validate(&key).or(Err(RawDbError::InvalidGenerationKey))?;
+            let generation_id = generation_key.get_generation_id();
+            let collection_key = generation_key.get_collection_key();
+
+            let record_key = OwnedRecordKey::new(collection_key, generation_id, PhantomId::empty())
+                .or(Err(RawDbError::InvalidRecordKey))?;
+
+            let Some(value) = db.get(record_key.get_byte_array())? else {
+                continue;
+            };
+
+            items.push(GenerationRecord {
+                generation_id: generation_id.to_owned(),
+                key: collection_key.to_owned(),
+                value: OwnedCollectionValue::from_boxed_slice_opt(value.into_boxed_slice()),
+            });
+
+            last_generation_key = Some(OwnedGenerationKey { value: key.clone() });
+        }
+
+        // Iterator ended before the limit, so there is nothing more
+        if items.len() < limit {
+            last_generation_key = None;
+        }
+
+        Ok(GetGenerationRecordsOk {
+            items,
+            last_generation_key,
+        })
+    }
+}
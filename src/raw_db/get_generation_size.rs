@@ -0,0 +1,24 @@
+use crate::collection::constants::COLLECTION_CF_GENERATIONS_SIZE;
+use crate::common::{GenerationId, IsByteArray};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use crate::util::bytes::to_u32_be_unchecked;
+
+impl RawDb {
+    // Number of puts to the generation, phantom puts are not counted
+    pub fn get_generation_size_sync(
+        &self,
+        generation_id: GenerationId<'_>,
+    ) -> Result<u32, RawDbError> {
+        let _span = rocksdb_span("get_generation_size");
+
+        let db = self.db.get_db();
+
+        let generations_size_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS_SIZE)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let size = db.get_cf(&generations_size_cf, generation_id.get_byte_array())?;
+
+        Ok(size.map(|bytes| to_u32_be_unchecked(&bytes)).unwrap_or(0))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::collection::constants::COLLECTION_CF_GENERATIONS_SIZE;
+use crate::common::{GenerationId, IsByteArray, OwnedGenerationId};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use crate::util::bytes::to_u32_be_unchecked;
+use rocksdb::{Direction, IteratorMode, ReadOptions};
+
+impl RawDb {
+    // Generations in range `(from_generation_id, to_generation_id]` with their sizes,
+    // empty generations are not stored, so they are not returned
+    pub fn get_generation_sizes_sync(
+        &self,
+        from_generation_id: GenerationId<'_>,
+        to_generation_id: GenerationId<'_>,
+    ) -> Result<Vec<(OwnedGenerationId, u32)>, RawDbError> {
+        let _span = rocksdb_span("get_generation_sizes");
+
+        let db = self.db.get_db();
+
+        let generations_size_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS_SIZE)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let upper_generation_id = to_generation_id.incremented();
+
+        let mut opts = ReadOptions::default();
+        opts.set_iterate_upper_bound(upper_generation_id.get_byte_array());
+
+        let iterator_mode =
+            IteratorMode::From(from_generation_id.get_byte_array(), Direction::Forward);
+
+        let iterator = db.iterator_cf_opt(&generations_size_cf, opts, iterator_mode);
+
+        let mut sizes = Vec::new();
+
+        for item in iterator {
+            let (key, value) = item?;
+
+            let generation_id = GenerationId::new_unchecked(&key);
+
+            if generation_id <= from_generation_id {
+                continue;
+            }
+
+            sizes.push((generation_id.to_owned(), to_u32_be_unchecked(&value)));
+        }
+
+        Ok(sizes)
+    }
+}
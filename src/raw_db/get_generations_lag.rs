@@ -0,0 +1,62 @@
+use crate::collection::constants::COLLECTION_CF_GENERATIONS_SIZE;
+use crate::common::{GenerationId, IsByteArray};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use crate::util::bytes::to_u32_be_unchecked;
+use rocksdb::{Direction, IteratorMode, ReadOptions};
+
+pub struct GenerationsLag {
+    // Generations with puts after `from_generation_id`
+    pub generations_count: u64,
+    // Sum of their sizes, same key put in several generations is counted several times
+    pub changed_keys_count: u64,
+}
+
+impl RawDb {
+    // Generations in range `(from_generation_id, to_generation_id]`,
+    // empty generations are not stored, so they are not counted
+    pub fn get_generations_lag_sync(
+        &self,
+        from_generation_id: Option<GenerationId<'_>>,
+        to_generation_id: GenerationId<'_>,
+    ) -> Result<GenerationsLag, RawDbError> {
+        let _span = rocksdb_span("get_generations_lag");
+
+        let db = self.db.get_db();
+
+        let generations_size_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS_SIZE)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let upper_generation_id = to_generation_id.incremented();
+
+        let mut opts = ReadOptions::default();
+        opts.set_iterate_upper_bound(upper_generation_id.get_byte_array());
+
+        let iterator_mode = match &from_generation_id {
+            Some(id) => IteratorMode::From(id.get_byte_array(), Direction::Forward),
+            None => IteratorMode::Start,
+        };
+
+        let iterator = db.iterator_cf_opt(&generations_size_cf, opts, iterator_mode);
+
+        let mut lag = GenerationsLag {
+            generations_count: 0,
+            changed_keys_count: 0,
+        };
+
+        for item in iterator {
+            let (key, value) = item?;
+
+            let generation_id = GenerationId::new_unchecked(&key);
+
+            if generation_id.less_or_equal_with_opt_or(from_generation_id, false) {
+                continue;
+            }
+
+            lag.generations_count += 1;
+            lag.changed_keys_count += to_u32_be_unchecked(&value) as u64;
+        }
+
+        Ok(lag)
+    }
+}
@@ -1,14 +1,18 @@
 use crate::collection::util::record_key::RecordKey;
+use crate::common::key_range::KeyRange;
 use crate::common::{CollectionKey, GenerationId, OwnedCollectionKey, PhantomId};
 use crate::raw_db::query::{
     QueryDirection, QueryDirectionBackward, QueryDirectionForward, QueryKeysOnly, QueryOptions,
     QueryState,
 };
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use rocksdb::DB;
+use std::ops::Bound;
 
 pub struct RawDbGetKeysAroundOptions<'a> {
     pub record_key: RecordKey<'a>,
+    // If false and key is not present, keys around the place where it would be are returned
+    pub require_key_existance: bool,
     pub limit: usize,
     pub records_to_view_limit: usize,
 }
@@ -18,6 +22,7 @@ pub struct RawDbGetKeysAroundResult {
     pub right: Vec<OwnedCollectionKey>,
     pub has_more_on_the_left: bool,
     pub has_more_on_the_right: bool,
+    pub found_key: bool,
 }
 
 impl RawDb {
@@ -25,10 +30,13 @@ impl RawDb {
         &self,
         options: RawDbGetKeysAroundOptions<'_>,
     ) -> Result<RawDbGetKeysAroundResult, RawDbError> {
+        let _span = rocksdb_span("keys_around");
+
         let record_key = options.record_key;
         let generation_id = record_key.get_generation_id();
         let phantom_id = record_key.get_phantom_id();
 
+        let require_key_existance = options.require_key_existance;
         let limit = options.limit;
         let records_to_view_limit = options.records_to_view_limit;
 
@@ -39,56 +47,96 @@ impl RawDb {
             right: Vec::with_capacity(limit),
             has_more_on_the_left: false,
             has_more_on_the_right: false,
+            found_key: false,
         };
 
         let start_key = record_key.get_collection_key();
         let phantom_id = phantom_id.to_opt_if_empty();
 
-        process_direction(
+        result.found_key = process_direction(
             QueryDirectionForward,
-            db,
-            start_key,
-            generation_id,
-            phantom_id,
-            limit,
-            records_to_view_limit,
-            &mut result.has_more_on_the_right,
-            &mut result.right,
+            ProcessDirectionOptions {
+                db,
+                start_key,
+                generation_id,
+                phantom_id,
+                require_key_existance,
+                limit,
+                records_to_view_limit,
+                has_more: &mut result.has_more_on_the_right,
+                result: &mut result.right,
+            },
         )?;
 
+        // If key was not found in the forward direction, it is not present,
+        // so it is not required for the backward one
         process_direction(
             QueryDirectionBackward,
-            db,
-            start_key,
-            generation_id,
-            phantom_id,
-            limit,
-            records_to_view_limit,
-            &mut result.has_more_on_the_left,
-            &mut result.left,
+            ProcessDirectionOptions {
+                db,
+                start_key,
+                generation_id,
+                phantom_id,
+                require_key_existance: require_key_existance && result.found_key,
+                limit,
+                records_to_view_limit,
+                has_more: &mut result.has_more_on_the_left,
+                result: &mut result.left,
+            },
         )?;
 
         Ok(result)
     }
 }
 
-fn process_direction<D: QueryDirection>(
-    direction: D,
-    db: &DB,
-    start_key: CollectionKey<'_>,
-    generation_id: GenerationId<'_>,
-    phantom_id: Option<PhantomId<'_>>,
+// Returns is `start_key` found
+struct ProcessDirectionOptions<'a> {
+    db: &'a DB,
+    start_key: CollectionKey<'a>,
+    generation_id: GenerationId<'a>,
+    phantom_id: Option<PhantomId<'a>>,
+    require_key_existance: bool,
     limit: usize,
     records_to_view_limit: usize,
-    has_more: &mut bool,
-    result: &mut Vec<OwnedCollectionKey>,
-) -> Result<(), RawDbError> {
+    has_more: &'a mut bool,
+    // Keys are appended in the order of `direction`
+    result: &'a mut Vec<OwnedCollectionKey>,
+}
+
+fn process_direction<D: QueryDirection>(
+    direction: D,
+    options: ProcessDirectionOptions<'_>,
+) -> Result<bool, RawDbError> {
+    let ProcessDirectionOptions {
+        db,
+        start_key,
+        generation_id,
+        phantom_id,
+        require_key_existance,
+        limit,
+        records_to_view_limit,
+        has_more,
+        result,
+    } = options;
+
+    let key_range = if D::is_forward() {
+        KeyRange {
+            from: Bound::Included(start_key),
+            to: Bound::Unbounded,
+        }
+    } else {
+        KeyRange {
+            from: Bound::Unbounded,
+            to: Bound::Included(start_key),
+        }
+    };
+
     let mut query = QueryState::new(
         db,
         QueryOptions {
             kind: QueryKeysOnly,
             direction,
-            start_key: Some(start_key),
+            key_range,
             generation_id,
             phantom_id,
             continuation_state: None,
@@ -96,23 +144,35 @@ fn process_direction<D: QueryDirection>(
         },
     )?;
 
-    let mut count = 0;
-
-    {
-        let item = query.next();
-        let Some(item) = item else {
-            return Err(RawDbError::CursorDidNotFoundRecord);
-        };
-
-        let key = item?;
-        let collection_key = key.get_collection_key();
-
-        if collection_key != start_key {
-            return Err(RawDbError::CursorDidNotFoundRecord);
+    let mut found_key = false;
+    let mut first_item = None;
+
+    match query.next() {
+        Some(item) => {
+            let key = item?;
+
+            if key.get_collection_key() == start_key {
+                found_key = true;
+            } else if require_key_existance {
+                return Err(RawDbError::CursorDidNotFoundRecord);
+            } else {
+                // Key is absent, so the first item is the nearest one
+                first_item = Some(key);
+            }
+        }
+        None => {
+            if require_key_existance {
+                return Err(RawDbError::CursorDidNotFoundRecord);
+            }
         }
     }
 
-    for item in query.by_ref() {
+    for (count, item) in first_item
+        .map(Ok)
+        .into_iter()
+        .chain(query.by_ref())
+        .enumerate()
+    {
         if count >= limit {
             *has_more = true;
             break;
@@ -121,9 +181,7 @@ fn process_direction<D: QueryDirection>(
         let item = item?;
 
         result.push(item.get_collection_key().to_owned());
-
-        count += 1;
     }
 
-    Ok(())
+    Ok(found_key)
 }
@@ -1,4 +1,5 @@
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use crate::util::tokio::spawn_blocking;
 use rocksdb::{Direction, IteratorMode, ReadOptions};
 
 impl RawDb {
@@ -14,7 +15,9 @@ impl RawDb {
         let db = self.db.clone();
         let cf_name = cf_name.to_string();
 
-        tokio::task::spawn_blocking(move || {
+        spawn_blocking(move || {
+            let _span = rocksdb_span("get_range_cf");
+
             let db = db.get_db();
 
             let iterator_mode = IteratorMode::From(&from_key, Direction::Forward);
@@ -45,6 +48,8 @@ impl RawDb {
         from_key: &[u8],
         to_key: &[u8],
     ) -> Result<Vec<(Box<[u8]>, Box<[u8]>)>, RawDbError> {
+        let _span = rocksdb_span("get_range_cf");
+
         let from_key = from_key.to_owned().into_boxed_slice();
         let to_key = to_key.to_owned().into_boxed_slice();
 
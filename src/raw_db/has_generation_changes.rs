@@ -1,7 +1,7 @@
 use crate::collection::constants::COLLECTION_CF_GENERATIONS;
 use crate::collection::util::generation_key::OwnedGenerationKey;
 use crate::common::{CollectionKey, GenerationId, IsByteArray};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 use rocksdb::{Direction, IteratorMode};
 
 pub struct HasGenerationChangesOptions<'a> {
@@ -13,6 +13,8 @@ impl RawDb {
         &self,
         options: HasGenerationChangesOptions<'_>,
     ) -> Result<bool, RawDbError> {
+        let _span = rocksdb_span("has_generation_changes");
+
         let generation_id = options.generation_id;
 
         let db = self.db.get_db();
@@ -2,23 +2,34 @@ use rocksdb::{ColumnFamilyDescriptor, MergeOperands, Options, DB, DEFAULT_COLUMN
 
 use std::cmp::Ordering;
 
+use crate::util::tokio::spawn_blocking;
 use std::sync::Arc;
+use tracing::span::EnteredSpan;
 
+pub mod batch;
+pub mod checkpoint;
 pub mod commit_generation;
 pub mod contains_existing_collection_record;
 pub mod delete;
 pub mod destroy;
 pub mod diff_collection_records;
 pub mod garbage_collector;
+pub mod get_changed_keys;
 pub mod get_collection_record;
+pub mod get_generation_records;
+pub mod get_generation_size;
+pub mod get_generation_sizes;
+pub mod get_generations_lag;
 pub mod get_keys_around;
 pub mod get_range;
 pub mod has_generation_changes;
+pub mod properties;
 pub mod put;
 pub mod put_collection_record;
 pub mod put_many_collection_records;
 pub mod query;
 pub mod query_collection_records;
+pub mod remove_all_records_after_generation;
 pub mod remove_all_records_of_generation;
 pub mod update_reader;
 
@@ -45,6 +56,8 @@ impl DbWrap {
 
 pub struct RawDb {
     path: String,
+    // Including the default one
+    cf_names: Vec<String>,
     db: Arc<DbWrap>,
 }
 
@@ -69,6 +82,7 @@ pub enum RawDbError {
     InvalidGenerationId,
     UpdateReader,
     NoSuchReader,
+    OrphanedReader,
     CursorDidNotFoundRecord,
     DiffNoChangedKeyRecord,
 }
@@ -85,6 +99,12 @@ impl From<tokio::task::JoinError> for RawDbError {
     }
 }
 
+// Every call is timed by its span, durations are logged when the span is closed,
+// enabled by `diffbelt::raw_db=debug` log filter
+pub(crate) fn rocksdb_span(operation: &'static str) -> EnteredSpan {
+    tracing::debug_span!("rocksdb", operation).entered()
+}
+
 impl RawDb {
     pub async fn get_cf(&self, cf_name: &str, key: &[u8]) -> Result<Option<Box<[u8]>>, RawDbError> {
         let key = key.to_owned().into_boxed_slice();
@@ -92,7 +112,9 @@ impl RawDb {
         let db = self.db.clone();
         let cf_name = cf_name.to_string();
 
-        tokio::task::spawn_blocking(move || {
+        spawn_blocking(move || {
+            let _span = rocksdb_span("get_cf");
+
             let db = db.get_db();
 
             let cf = db.cf_handle(&cf_name).ok_or(RawDbError::CfHandle)?;
@@ -104,6 +126,8 @@ impl RawDb {
     }
 
     pub fn get_cf_sync(&self, cf_name: &str, key: &[u8]) -> Result<Option<Box<[u8]>>, RawDbError> {
+        let _span = rocksdb_span("get_cf");
+
         let db = self.db.get_db();
 
         let cf = db.cf_handle(&cf_name).ok_or(RawDbError::CfHandle)?;
@@ -134,10 +158,49 @@ pub struct RawDbColumnFamily {
     pub merge: Option<RawDbMerge>,
 }
 
+// RocksDB tuning, options that are not set are left with RocksDB defaults
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RawDbConfig {
+    pub max_open_files: Option<i32>,
+    pub max_background_jobs: Option<i32>,
+    pub increase_parallelism: Option<i32>,
+    // Per column family
+    pub write_buffer_size: Option<usize>,
+    pub max_write_buffer_number: Option<i32>,
+    pub target_file_size_base: Option<u64>,
+}
+
+impl RawDbConfig {
+    fn apply_to_db(&self, opts: &mut Options) {
+        if let Some(parallelism) = self.increase_parallelism {
+            opts.increase_parallelism(parallelism);
+        }
+        if let Some(max_open_files) = self.max_open_files {
+            opts.set_max_open_files(max_open_files);
+        }
+        if let Some(max_background_jobs) = self.max_background_jobs {
+            opts.set_max_background_jobs(max_background_jobs);
+        }
+    }
+
+    fn apply_to_cf(&self, opts: &mut Options) {
+        if let Some(write_buffer_size) = self.write_buffer_size {
+            opts.set_write_buffer_size(write_buffer_size);
+        }
+        if let Some(max_write_buffer_number) = self.max_write_buffer_number {
+            opts.set_max_write_buffer_number(max_write_buffer_number);
+        }
+        if let Some(target_file_size_base) = self.target_file_size_base {
+            opts.set_target_file_size_base(target_file_size_base);
+        }
+    }
+}
+
 pub struct RawDbOptions<'a> {
     pub path: &'a str,
     pub comparator: Option<RawDbComparator>,
     pub column_families: Vec<RawDbColumnFamily>,
+    pub config: &'a RawDbConfig,
 }
 
 #[derive(Debug)]
@@ -153,16 +216,21 @@ impl From<rocksdb::Error> for RawDbOpenError {
 
 impl RawDb {
     pub fn open_raw_db(options: RawDbOptions) -> Result<RawDb, RawDbOpenError> {
+        let _span = rocksdb_span("open_raw_db");
+
         let path = options.path;
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
         opts.create_missing_column_families(true);
+        options.config.apply_to_db(&mut opts);
 
         let mut column_family_descriptors: Vec<ColumnFamilyDescriptor> =
             Vec::with_capacity(options.column_families.len() + 1);
+        let mut cf_names = Vec::with_capacity(options.column_families.len() + 1);
 
         let mut default_cf_opts = Options::default();
+        options.config.apply_to_cf(&mut default_cf_opts);
         match options.comparator {
             Some(comparator) => {
                 default_cf_opts.set_comparator(&comparator.name, comparator.compare_fn)
@@ -173,9 +241,11 @@ impl RawDb {
             DEFAULT_COLUMN_FAMILY_NAME,
             default_cf_opts,
         ));
+        cf_names.push(DEFAULT_COLUMN_FAMILY_NAME.to_string());
 
         for family in options.column_families {
             let mut cf_opts = Options::default();
+            options.config.apply_to_cf(&mut cf_opts);
 
             family.comparator.as_ref().map(|comparator| {
                 cf_opts.set_comparator(&comparator.name, comparator.compare_fn);
@@ -186,6 +256,7 @@ impl RawDb {
             });
 
             column_family_descriptors.push(ColumnFamilyDescriptor::new(&family.name, cf_opts));
+            cf_names.push(family.name);
         }
 
         let db = DB::open_cf_descriptors(&opts, path, column_family_descriptors)?;
@@ -194,6 +265,7 @@ impl RawDb {
 
         return Ok(RawDb {
             path: path.to_string(),
+            cf_names,
             db: Arc::new(DbWrap {
                 inner: Some(db),
                 is_alive_sender,
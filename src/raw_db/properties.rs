@@ -0,0 +1,45 @@
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::DEFAULT_COLUMN_FAMILY_NAME;
+
+#[derive(Clone, Debug, Default)]
+pub struct RawDbProperties {
+    // Sums of all column families
+    pub estimate_num_keys: u64,
+    pub total_sst_files_size: u64,
+    pub memtables_size: u64,
+    pub pending_compaction_bytes: u64,
+    // Whole db
+    pub running_compactions: u64,
+    pub running_flushes: u64,
+}
+
+impl RawDb {
+    pub fn get_properties_sync(&self) -> Result<RawDbProperties, RawDbError> {
+        let _span = rocksdb_span("get_properties");
+
+        let db = self.db.get_db();
+
+        let mut properties = RawDbProperties::default();
+
+        for cf_name in &self.cf_names {
+            let cf = db.cf_handle(cf_name).ok_or(RawDbError::CfHandle)?;
+
+            let get = |name: &str| -> Result<u64, RawDbError> {
+                Ok(db.property_int_value_cf(&cf, name)?.unwrap_or(0))
+            };
+
+            properties.estimate_num_keys += get("rocksdb.estimate-num-keys")?;
+            properties.total_sst_files_size += get("rocksdb.total-sst-files-size")?;
+            properties.memtables_size += get("rocksdb.cur-size-all-mem-tables")?;
+            properties.pending_compaction_bytes +=
+                get("rocksdb.estimate-pending-compaction-bytes")?;
+
+            if cf_name == DEFAULT_COLUMN_FAMILY_NAME {
+                properties.running_compactions = get("rocksdb.num-running-compactions")?;
+                properties.running_flushes = get("rocksdb.num-running-flushes")?;
+            }
+        }
+
+        Ok(properties)
+    }
+}
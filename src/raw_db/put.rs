@@ -1,4 +1,5 @@
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use crate::util::tokio::spawn_blocking;
 
 impl RawDb {
     pub async fn put_cf(&self, cf_name: &str, key: &[u8], value: &[u8]) -> Result<(), RawDbError> {
@@ -8,7 +9,9 @@ impl RawDb {
         let db = self.db.clone();
         let cf_name = cf_name.to_string();
 
-        tokio::task::spawn_blocking(move || {
+        spawn_blocking(move || {
+            let _span = rocksdb_span("put_cf");
+
             let db = db.get_db();
 
             let cf = db.cf_handle(&cf_name).ok_or(RawDbError::CfHandle)?;
@@ -25,6 +28,8 @@ impl RawDb {
         key: &'_ [u8],
         value: &'_ [u8],
     ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("put_cf");
+
         let db = self.db.get_db();
 
         let cf = db.cf_handle(cf_name).ok_or(RawDbError::CfHandle)?;
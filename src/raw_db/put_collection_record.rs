@@ -1,10 +1,11 @@
 use crate::collection::util::generation_key::OwnedGenerationKey;
 use crate::collection::util::record_key::RecordKey;
 use crate::common::{CollectionValue, IsByteArray, OwnedCollectionValue};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 
 use crate::collection::constants::{COLLECTION_CF_GENERATIONS, COLLECTION_CF_GENERATIONS_SIZE};
 use crate::util::bytes::ONE_U32_BE;
+use crate::util::tokio::spawn_blocking;
 use rocksdb::WriteBatchWithTransaction;
 
 pub struct PutCollectionRecordOptions<'a> {
@@ -21,7 +22,9 @@ impl RawDb {
         let record_key = options.record_key.to_owned();
         let value: Option<OwnedCollectionValue> = options.value.map(|x| x.to_owned());
 
-        tokio::task::spawn_blocking(move || {
+        spawn_blocking(move || {
+            let _span = rocksdb_span("put_collection_record");
+
             let db = db.get_db();
 
             let generations_cf = db
@@ -1,11 +1,12 @@
 use crate::collection::util::generation_key::OwnedGenerationKey;
 use crate::collection::util::record_key::OwnedRecordKey;
 use crate::common::{IsByteArray, OwnedCollectionValue};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 
 use crate::collection::constants::{COLLECTION_CF_GENERATIONS, COLLECTION_CF_GENERATIONS_SIZE};
 use crate::raw_db::put_collection_record::unwrap_option_ref_or;
 use crate::util::bytes::ONE_U32_BE;
+use crate::util::tokio::spawn_blocking;
 use rocksdb::WriteBatchWithTransaction;
 
 pub struct PutManyCollectionRecordsItem {
@@ -25,7 +26,9 @@ impl RawDb {
         let db = self.db.clone();
         let items = options.items;
 
-        tokio::task::spawn_blocking(move || {
+        spawn_blocking(move || {
+            let _span = rocksdb_span("put_many_collection_records");
+
             let db = db.get_db();
 
             let generations_cf = db
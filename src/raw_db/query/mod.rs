@@ -1,4 +1,5 @@
 use crate::collection::util::record_key::{OwnedParsedRecordKey, OwnedRecordKey, ParsedRecordKey};
+use crate::common::key_range::{KeyRange, OwnedKeyRange};
 use crate::common::{
     CollectionKey, CollectionValue, GenerationId, IsByteArray, OwnedGenerationId, OwnedPhantomId,
     PhantomId,
@@ -7,6 +8,7 @@ use crate::raw_db::RawDbError;
 use rocksdb::{DBIteratorWithThreadMode, Direction, IteratorMode, DB};
 
 use std::mem;
+use std::ops::Bound;
 
 pub struct QueryKeyValue;
 pub struct QueryKeysOnly;
@@ -22,7 +24,9 @@ pub struct ContinuationState {
 pub struct QueryOptions<'a, K: QueryKind, D: QueryDirection> {
     pub kind: K,
     pub direction: D,
-    pub start_key: Option<CollectionKey<'a>>,
+    // Iteration starts from `from` bound for forward direction and from `to` for backward,
+    // records outside of the range are never returned
+    pub key_range: KeyRange<'a>,
     pub generation_id: GenerationId<'a>,
     pub phantom_id: Option<PhantomId<'a>>,
     pub continuation_state: Option<ContinuationState>,
@@ -110,6 +114,9 @@ impl QueryKind for QueryKeysOnly {
 
 pub trait QueryDirection {
     fn is_forward() -> bool;
+    fn get_start_bound<'a>(key_range: &KeyRange<'a>) -> Bound<CollectionKey<'a>>;
+    fn is_before_start(key_range: &KeyRange<'_>, key: CollectionKey<'_>) -> bool;
+    fn is_after_end(key_range: &KeyRange<'_>, key: CollectionKey<'_>) -> bool;
     fn get_initial_record_generation_id() -> GenerationId<'static>;
     fn get_initial_record_phantom_id() -> PhantomId<'static>;
     fn get_default_iterator_mode() -> IteratorMode<'static>;
@@ -125,6 +132,15 @@ impl QueryDirection for QueryDirectionBackward {
     fn is_forward() -> bool {
         false
     }
+    fn get_start_bound<'a>(key_range: &KeyRange<'a>) -> Bound<CollectionKey<'a>> {
+        key_range.to
+    }
+    fn is_before_start(key_range: &KeyRange<'_>, key: CollectionKey<'_>) -> bool {
+        key_range.is_above_to(key)
+    }
+    fn is_after_end(key_range: &KeyRange<'_>, key: CollectionKey<'_>) -> bool {
+        key_range.is_below_from(key)
+    }
     fn get_initial_record_generation_id() -> GenerationId<'static> {
         GenerationId::max_value()
     }
@@ -154,6 +170,15 @@ impl QueryDirection for QueryDirectionForward {
     fn is_forward() -> bool {
         true
     }
+    fn get_start_bound<'a>(key_range: &KeyRange<'a>) -> Bound<CollectionKey<'a>> {
+        key_range.from
+    }
+    fn is_before_start(key_range: &KeyRange<'_>, key: CollectionKey<'_>) -> bool {
+        key_range.is_below_from(key)
+    }
+    fn is_after_end(key_range: &KeyRange<'_>, key: CollectionKey<'_>) -> bool {
+        key_range.is_above_to(key)
+    }
     fn get_initial_record_generation_id() -> GenerationId<'static> {
         GenerationId::empty()
     }
@@ -193,6 +218,7 @@ pub struct QueryState<'a, K: QueryKind, D: QueryDirection> {
     direction: D,
     generation_id: OwnedGenerationId,
     phantom_id: Option<OwnedPhantomId>,
+    key_range: OwnedKeyRange,
     records_seen: usize,
     records_to_view_limit: usize,
     iterator: Option<DBIteratorWithThreadMode<'a, DB>>,
@@ -205,7 +231,7 @@ impl<'a, K: QueryKind, D: QueryDirection> QueryState<'a, K, D> {
         let QueryOptions {
             kind,
             direction,
-            start_key,
+            key_range,
             generation_id,
             phantom_id,
             continuation_state,
@@ -221,10 +247,10 @@ impl<'a, K: QueryKind, D: QueryDirection> QueryState<'a, K, D> {
         };
 
         let mut iterator =
-            create_iterator::<D>(db, start_key, generation_id, phantom_id, next_iterator_key)?;
+            create_iterator::<D>(db, &key_range, generation_id, phantom_id, next_iterator_key)?;
 
         let initialization_result =
-            initialize_last_and_next::<K>(db, &mut iterator, last_candidate_key)?;
+            initialize_last_and_next::<K, D>(db, &mut iterator, &key_range, last_candidate_key)?;
 
         match initialization_result {
             InitializationResult::End => Ok(Self {
@@ -233,6 +259,7 @@ impl<'a, K: QueryKind, D: QueryDirection> QueryState<'a, K, D> {
                 direction,
                 generation_id: generation_id.to_owned(),
                 phantom_id: None,
+                key_range: OwnedKeyRange::full(),
                 records_seen: 0,
                 records_to_view_limit,
                 iterator: None,
@@ -248,6 +275,7 @@ impl<'a, K: QueryKind, D: QueryDirection> QueryState<'a, K, D> {
                 direction,
                 generation_id: generation_id.to_owned(),
                 phantom_id: phantom_id.map(|x| x.to_owned()),
+                key_range: key_range.to_owned(),
                 records_seen: 0,
                 records_to_view_limit,
                 iterator: Some(iterator),
@@ -266,9 +294,17 @@ impl<'a, K: QueryKind, D: QueryDirection> QueryState<'a, K, D> {
             return None;
         }
 
+        let last_candidate_key = self.last_record.key.to_owned_record_key();
+        let next_iterator_key = match self.next_record {
+            Some(next_record) => next_record.key.to_owned_record_key(),
+            // Iterator is ended, but last record is not handled yet. Iteration from it
+            // will give it as the next record too, and it will be deduplicated
+            None => last_candidate_key.clone(),
+        };
+
         Some(ContinuationState {
-            last_candidate_key: self.last_record.key.to_owned_record_key(),
-            next_iterator_key: self.next_record.unwrap().key.to_owned_record_key(),
+            last_candidate_key,
+            next_iterator_key,
         })
     }
 
@@ -312,6 +348,14 @@ impl<'a, K: QueryKind, D: QueryDirection> QueryState<'a, K, D> {
                 value: K::value_from_box(value),
             };
 
+            if D::is_after_end(
+                &self.key_range.as_ref(),
+                new_next_record.key.get_collection_key(),
+            ) {
+                // Rest of the records are out of the range, handle it as the end of iterator
+                break;
+            }
+
             let action = handle_last_and_next::<D>(
                 generation_id,
                 phantom_id,
@@ -480,7 +524,7 @@ fn is_need_to_push_last_record(
 
 fn create_iterator<'a, D: QueryDirection>(
     db: &'a DB,
-    start_key: Option<CollectionKey<'_>>,
+    key_range: &KeyRange<'_>,
     _generation_id: GenerationId<'_>,
     _phantom_id: Option<PhantomId<'_>>,
     next_iterator_key: Option<OwnedRecordKey>,
@@ -491,8 +535,9 @@ fn create_iterator<'a, D: QueryDirection>(
                 IteratorMode::From(next_iterator_key.get_byte_array(), D::get_direction());
             Ok(db.iterator(iterator_mode))
         }
-        None => match start_key {
-            Some(start_key) => {
+        None => match D::get_start_bound(key_range) {
+            // Excluded key is skipped during the initialization
+            Bound::Included(start_key) | Bound::Excluded(start_key) => {
                 let record_key = OwnedRecordKey::new(
                     start_key,
                     D::get_initial_record_generation_id(),
@@ -504,7 +549,7 @@ fn create_iterator<'a, D: QueryDirection>(
                     IteratorMode::From(record_key.get_byte_array(), D::get_direction());
                 Ok(db.iterator(iterator_mode))
             }
-            None => {
+            Bound::Unbounded => {
                 let iterator_mode = D::get_default_iterator_mode();
                 Ok(db.iterator(iterator_mode))
             }
@@ -520,9 +565,10 @@ enum InitializationResult<K: QueryKind> {
     },
 }
 
-fn initialize_last_and_next<K: QueryKind>(
+fn initialize_last_and_next<K: QueryKind, D: QueryDirection>(
     db: &DB,
     iterator: &mut DBIteratorWithThreadMode<'_, DB>,
+    key_range: &KeyRange<'_>,
     last_candidate_key: Option<OwnedRecordKey>,
 ) -> Result<InitializationResult<K>, RawDbError> {
     let is_continuation = last_candidate_key.is_some();
@@ -539,7 +585,7 @@ fn initialize_last_and_next<K: QueryKind>(
                 value: K::value_from_vec(value),
             }
         }
-        None => {
+        None => loop {
             let kv = iterator.next();
             let Some(kv) = kv else {
                 return Ok(InitializationResult::End);
@@ -550,15 +596,41 @@ fn initialize_last_and_next<K: QueryKind>(
             let key = OwnedParsedRecordKey::from_boxed_slice(key)
                 .map_err(|_| RawDbError::InvalidRecordKey)?;
 
-            IterationKvRecord {
+            let collection_key = key.get_collection_key();
+
+            // Only excluded start key can be here
+            if D::is_before_start(key_range, collection_key) {
+                continue;
+            }
+
+            if D::is_after_end(key_range, collection_key) {
+                return Ok(InitializationResult::End);
+            }
+
+            break IterationKvRecord {
                 key,
                 value: K::value_from_box(value),
+            };
+        },
+    };
+
+    let next_key = match iterator.next() {
+        Some(kv) => {
+            let (key, value) = kv?;
+
+            let key = OwnedParsedRecordKey::from_boxed_slice(key)
+                .map_err(|_| RawDbError::InvalidRecordKey)?;
+
+            if D::is_after_end(key_range, key.get_collection_key()) {
+                None
+            } else {
+                Some((key, value))
             }
         }
+        None => None,
     };
 
-    let kv = iterator.next();
-    let Some(kv) = kv else {
+    let Some((key, value)) = next_key else {
         if is_continuation {
             return Err(RawDbError::CursorDidNotFoundRecord);
         }
@@ -569,11 +641,6 @@ fn initialize_last_and_next<K: QueryKind>(
         });
     };
 
-    let (key, value) = kv?;
-
-    let key =
-        OwnedParsedRecordKey::from_boxed_slice(key).map_err(|_| RawDbError::InvalidRecordKey)?;
-
     let next_record = IterationKvRecord {
         key,
         value: K::value_from_box(value),
@@ -1,14 +1,20 @@
 use crate::collection::util::record_key::{OwnedRecordKey, RecordKey};
+use crate::common::key_range::KeyRange;
 use crate::common::{GenerationId, KeyValue, OwnedCollectionValue, PhantomId};
 
 use crate::raw_db::query::{
-    ContinuationState, QueryDirectionForward, QueryKeyValue, QueryOptions, QueryState,
+    ContinuationState, QueryDirection, QueryDirectionBackward, QueryDirectionForward,
+    QueryKeyValue, QueryOptions, QueryState,
 };
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::DB;
 
 pub struct QueryCollectionRecordsOptions<'a> {
     pub generation_id: GenerationId<'a>,
     pub phantom_id: Option<PhantomId<'a>>,
+    pub key_range: KeyRange<'a>,
+    // If true, records are returned in descending keys order
+    pub reverse: bool,
     // Specified if query has lower bound
     // if `last_record_key` is specified, this MUST be too
     // TODO: receive `LastAndNextRecordKey` or this field by enum to lower chance of misuse
@@ -34,61 +40,77 @@ impl RawDb {
         &self,
         options: QueryCollectionRecordsOptions<'_>,
     ) -> Result<QueryCollectionRecordsResult, RawDbError> {
-        let QueryCollectionRecordsOptions {
+        let _span = rocksdb_span("query_collection_records");
+
+        let db = self.db.get_db();
+
+        if options.reverse {
+            query_collection_records(db, QueryDirectionBackward, options)
+        } else {
+            query_collection_records(db, QueryDirectionForward, options)
+        }
+    }
+}
+
+fn query_collection_records<D: QueryDirection>(
+    db: &DB,
+    direction: D,
+    options: QueryCollectionRecordsOptions<'_>,
+) -> Result<QueryCollectionRecordsResult, RawDbError> {
+    let QueryCollectionRecordsOptions {
+        generation_id,
+        phantom_id,
+        key_range,
+        reverse: _,
+        from_record_key,
+        last_record_key,
+        limit,
+        records_to_view_limit,
+    } = options;
+
+    let mut count = 0usize;
+    let mut result = Vec::with_capacity(limit);
+
+    let mut query = QueryState::new(
+        db,
+        QueryOptions {
+            kind: QueryKeyValue,
+            direction,
+            key_range,
             generation_id,
             phantom_id,
-            from_record_key,
-            last_record_key,
-            limit,
+            continuation_state: last_record_key
+                .as_ref()
+                .map(|last_record| ContinuationState {
+                    last_candidate_key: last_record.to_owned(),
+                    next_iterator_key: from_record_key.as_ref().unwrap().to_owned(),
+                }),
             records_to_view_limit,
-        } = options;
+        },
+    )?;
 
-        let db = self.db.get_db();
+    for item in query.by_ref() {
+        let item = item?;
 
-        let mut count = 0usize;
-        let mut result = Vec::with_capacity(limit);
-
-        let mut query = QueryState::new(
-            db,
-            QueryOptions {
-                kind: QueryKeyValue,
-                direction: QueryDirectionForward,
-                start_key: from_record_key.as_ref().map(|x| x.get_collection_key()),
-                generation_id,
-                phantom_id,
-                continuation_state: last_record_key
-                    .as_ref()
-                    .map(|last_record| ContinuationState {
-                        last_candidate_key: last_record.to_owned(),
-                        next_iterator_key: from_record_key.as_ref().unwrap().to_owned(),
-                    }),
-                records_to_view_limit,
-            },
-        )?;
-
-        for item in query.by_ref() {
-            let item = item?;
-
-            result.push(KeyValue {
-                key: item.key.get_collection_key().to_owned(),
-                value: OwnedCollectionValue::from_boxed_slice(item.value),
-            });
-
-            count += 1;
-
-            if count >= limit {
-                break;
-            }
-        }
+        result.push(KeyValue {
+            key: item.key.get_collection_key().to_owned(),
+            value: OwnedCollectionValue::from_boxed_slice(item.value),
+        });
 
-        let continuation = query.into_continuation();
+        count += 1;
 
-        Ok(QueryCollectionRecordsResult {
-            items: result,
-            last_and_next_record_key: continuation.map(|continuation| LastAndNextRecordKey {
-                last: continuation.last_candidate_key,
-                next: continuation.next_iterator_key,
-            }),
-        })
+        if count >= limit {
+            break;
+        }
     }
+
+    let continuation = query.into_continuation();
+
+    Ok(QueryCollectionRecordsResult {
+        items: result,
+        last_and_next_record_key: continuation.map(|continuation| LastAndNextRecordKey {
+            last: continuation.last_candidate_key,
+            next: continuation.next_iterator_key,
+        }),
+    })
 }
@@ -0,0 +1,87 @@
+use crate::collection::constants::{COLLECTION_CF_GENERATIONS, COLLECTION_CF_GENERATIONS_SIZE};
+use crate::collection::util::generation_key::{GenerationKey, OwnedGenerationKey};
+use crate::collection::util::record_key::{OwnedRecordKey, RecordKey};
+use crate::common::{CollectionKey, GenerationId, IsByteArray, PhantomId};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::{Direction, IteratorMode, WriteBatchWithTransaction};
+
+pub struct RemoveAllRecordsAfterGenerationSyncOptions<'a> {
+    pub generation_id: GenerationId<'a>,
+}
+
+impl RawDb {
+    // Removes records of all generations that are greater than `generation_id`
+    pub fn remove_all_records_after_generation_sync(
+        &self,
+        options: RemoveAllRecordsAfterGenerationSyncOptions<'_>,
+    ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("remove_all_records_after_generation");
+
+        let generation_id = options.generation_id;
+
+        let mut batch = WriteBatchWithTransaction::<false>::default();
+
+        let db = self.db.get_db();
+
+        let generations_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS)
+            .ok_or(RawDbError::CfHandle)?;
+        let generations_size_cf = db
+            .cf_handle(COLLECTION_CF_GENERATIONS_SIZE)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let generation_key = OwnedGenerationKey::new(generation_id, CollectionKey::empty())
+            .or(Err(RawDbError::InvalidGenerationKey))?;
+
+        let iterator_mode = IteratorMode::From(generation_key.get_byte_array(), Direction::Forward);
+
+        let iterator = db.iterator_cf(&generations_cf, iterator_mode);
+
+        for item in iterator {
+            let (key, _) = item?;
+            let item_generation_key =
+                GenerationKey::validate(&key).or(Err(RawDbError::InvalidGenerationKey))?;
+
+            let item_generation_id = item_generation_key.get_generation_id();
+
+            if item_generation_id <= generation_id {
+                continue;
+            }
+
+            let collection_key = item_generation_key.get_collection_key();
+
+            batch.delete_cf(&generations_cf, &key);
+            batch.delete_cf(&generations_size_cf, item_generation_id.get_byte_array());
+
+            let record_key =
+                OwnedRecordKey::new(collection_key, item_generation_id, PhantomId::empty())
+                    .or(Err(RawDbError::InvalidRecordKey))?;
+
+            // Real record and records of all phantoms for this key and generation.
+            // Phantom puts are not written to the generations cf, so phantom-only keys
+            // are not found here, their phantom ids are not issued by the collection again
+            let records_iterator = db.iterator(IteratorMode::From(
+                record_key.get_byte_array(),
+                Direction::Forward,
+            ));
+
+            for record in records_iterator {
+                let (record_key, _) = record?;
+                let item_record_key =
+                    RecordKey::validate(&record_key).or(Err(RawDbError::InvalidRecordKey))?;
+
+                if item_record_key.get_collection_key() != collection_key
+                    || item_record_key.get_generation_id() != item_generation_id
+                {
+                    break;
+                }
+
+                batch.delete(&record_key);
+            }
+        }
+
+        db.write(batch)?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,9 @@
 use crate::collection::constants::{COLLECTION_CF_GENERATIONS, COLLECTION_CF_GENERATIONS_SIZE};
 use crate::collection::util::generation_key::{GenerationKey, OwnedGenerationKey};
 use crate::collection::util::record_key::OwnedRecordKey;
-use crate::common::{CollectionKey, GenerationId, IsByteArray, IsByteArrayMut, PhantomId};
-use crate::raw_db::{RawDb, RawDbError};
-use crate::util::bytes::increment;
-use rocksdb::{Direction, IteratorMode, ReadOptions, WriteBatchWithTransaction};
+use crate::common::{CollectionKey, GenerationId, IsByteArray, PhantomId};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
+use rocksdb::{Direction, IteratorMode, WriteBatchWithTransaction};
 
 pub struct RemoveAllRecordsOfGenerationSyncOptions<'a> {
     pub generation_id: GenerationId<'a>,
@@ -15,6 +14,8 @@ impl RawDb {
         &self,
         options: RemoveAllRecordsOfGenerationSyncOptions<'_>,
     ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("remove_all_records_of_generation");
+
         let generation_id = options.generation_id;
 
         let mut batch = WriteBatchWithTransaction::<false>::default();
@@ -31,17 +32,11 @@ impl RawDb {
         let generation_key = OwnedGenerationKey::new(generation_id, CollectionKey::empty())
             .or(Err(RawDbError::InvalidGenerationKey))?;
 
-        let mut upper_generation_key = generation_key.clone();
-        let upper_generation_key_bytes = upper_generation_key.get_byte_array_mut();
-        increment(upper_generation_key_bytes);
-
+        // Upper bound is not set, incremented key with empty collection key is invalid
+        // for the comparator, iteration is stopped on the first key of other generation
         let iterator_mode = IteratorMode::From(generation_key.get_byte_array(), Direction::Forward);
-        let mut opts = ReadOptions::default();
-        opts.set_iterate_upper_bound(upper_generation_key_bytes);
-
-        let db = self.db.get_db();
 
-        let iterator = db.iterator_cf_opt(&generations_cf, opts, iterator_mode);
+        let iterator = db.iterator_cf(&generations_cf, iterator_mode);
 
         for item in iterator {
             let (key, _) = item?;
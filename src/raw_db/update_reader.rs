@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use crate::common::reader::ReaderState;
 use crate::common::{GenerationId, IsByteArray};
-use crate::raw_db::{RawDb, RawDbError};
+use crate::raw_db::{rocksdb_span, RawDb, RawDbError};
 
 pub struct RawDbCreateReaderOptions<'a> {
     pub reader_name: &'a str,
@@ -22,6 +22,15 @@ pub struct RawDbDeleteReaderOptions<'a> {
     pub reader_name: &'a str,
 }
 
+pub struct RawDbOrphanReaderOptions<'a> {
+    pub reader_name: &'a str,
+}
+
+pub struct RawDbRetargetReaderOptions<'a> {
+    pub reader_name: &'a str,
+    pub collection_name: &'a str,
+}
+
 pub enum RawDbCreateReaderResult {
     Created,
     AlreadyExists(OwnedReaderValue),
@@ -29,6 +38,8 @@ pub enum RawDbCreateReaderResult {
 
 impl RawDb {
     pub fn get_reader_sync(&self, reader_name: &str) -> Result<ReaderState, RawDbError> {
+        let _span = rocksdb_span("get_reader");
+
         let db = self.db.get_db();
 
         let meta_cf = db
@@ -48,6 +59,10 @@ impl RawDb {
 
                 let reader_value = reader_value.as_ref();
 
+                if reader_value.is_orphaned() {
+                    return Err(RawDbError::OrphanedReader);
+                }
+
                 // TODO: parse method
                 let collection_name = {
                     let collection_name = reader_value.get_collection_name();
@@ -72,6 +87,8 @@ impl RawDb {
         &self,
         options: RawDbCreateReaderOptions<'_>,
     ) -> Result<RawDbCreateReaderResult, RawDbError> {
+        let _span = rocksdb_span("create_reader");
+
         let db = self.db.get_db();
 
         let meta_cf = db
@@ -116,6 +133,8 @@ impl RawDb {
         &self,
         options: RawDbUpdateReaderOptions<'_>,
     ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("update_reader");
+
         let db = self.db.get_db();
 
         let meta_cf = db
@@ -136,6 +155,10 @@ impl RawDb {
         };
 
         let old_value = ReaderValue::from_slice(&value).or(Err(RawDbError::InvalidReaderValue))?;
+        if old_value.is_orphaned() {
+            return Err(RawDbError::OrphanedReader);
+        }
+
         let collection_name = old_value.get_collection_name();
         let generation_id = options.generation_id;
 
@@ -153,6 +176,8 @@ impl RawDb {
         meta_cf: Arc<BoundColumnFamily>,
         options: RawDbUpdateReaderOptions<'_>,
     ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("update_reader_batch");
+
         let mut key = String::with_capacity("reader:".len() + options.reader_name.len());
         key.push_str("reader:");
         key.push_str(options.reader_name);
@@ -167,6 +192,10 @@ impl RawDb {
         };
 
         let old_value = ReaderValue::from_slice(&value).or(Err(RawDbError::InvalidReaderValue))?;
+        if old_value.is_orphaned() {
+            return Err(RawDbError::OrphanedReader);
+        }
+
         let collection_name = old_value.get_collection_name();
         let generation_id = options.generation_id;
 
@@ -182,6 +211,8 @@ impl RawDb {
         &self,
         options: RawDbDeleteReaderOptions<'_>,
     ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("delete_reader");
+
         let db = self.db.get_db();
 
         let meta_cf = db
@@ -196,4 +227,66 @@ impl RawDb {
 
         Ok(())
     }
+
+    pub fn orphan_reader_sync(
+        &self,
+        options: RawDbOrphanReaderOptions<'_>,
+    ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("orphan_reader");
+
+        let db = self.db.get_db();
+
+        let meta_cf = db
+            .cf_handle(COLLECTION_CF_META)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let mut key = String::with_capacity("reader:".len() + options.reader_name.len());
+        key.push_str("reader:");
+        key.push_str(options.reader_name);
+
+        let value = db.get_cf(&meta_cf, &key)?;
+
+        let Some(value) = value else {
+            // Reader was deleted concurrently, nothing to orphan
+            return Ok(());
+        };
+
+        let old_value = ReaderValue::from_slice(&value).or(Err(RawDbError::InvalidReaderValue))?;
+        let new_value = old_value.to_orphaned();
+
+        db.put_cf(&meta_cf, &key, new_value.get_byte_array())?;
+
+        Ok(())
+    }
+
+    pub fn retarget_reader_sync(
+        &self,
+        options: RawDbRetargetReaderOptions<'_>,
+    ) -> Result<(), RawDbError> {
+        let _span = rocksdb_span("retarget_reader");
+
+        let db = self.db.get_db();
+
+        let meta_cf = db
+            .cf_handle(COLLECTION_CF_META)
+            .ok_or(RawDbError::CfHandle)?;
+
+        let mut key = String::with_capacity("reader:".len() + options.reader_name.len());
+        key.push_str("reader:");
+        key.push_str(options.reader_name);
+
+        let value = db.get_cf(&meta_cf, &key)?;
+
+        let Some(value) = value else {
+            // Reader was deleted concurrently, nothing to retarget
+            return Ok(());
+        };
+
+        let old_value = ReaderValue::from_slice(&value).or(Err(RawDbError::InvalidReaderValue))?;
+        let new_value = old_value.with_collection_name(options.collection_name)?;
+
+        db.put_cf(&meta_cf, &key, new_value.get_byte_array())?;
+
+        Ok(())
+    }
 }
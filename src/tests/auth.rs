@@ -0,0 +1,231 @@
+use crate::http::auth::{authenticate, matches_pattern, Access, AccessGrant, AccessRole, ApiToken};
+use crate::http::config::{HttpConfig, ListenAddress};
+use crate::http::errors::HttpError;
+use crate::http::server::start_http_server;
+use crate::tests::http_server::{create_context, send_unix_request};
+use crate::tests::temp_dir::TempDir;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::sync::Arc;
+
+fn token(name: &str, grants: &[(&str, AccessRole)]) -> Arc<ApiToken> {
+    Arc::new(ApiToken {
+        name: name.to_string(),
+        token: format!("{}-secret", name),
+        grants: grants
+            .iter()
+            .map(|(pattern, role)| AccessGrant {
+                pattern: pattern.to_string(),
+                role: *role,
+            })
+            .collect(),
+    })
+}
+
+#[test]
+fn matches_pattern_test() {
+    assert!(matches_pattern("logs", "logs"));
+    assert!(!matches_pattern("logs", "logs2"));
+    assert!(!matches_pattern("logs", "log"));
+
+    assert!(matches_pattern("*", ""));
+    assert!(matches_pattern("*", "anything"));
+
+    assert!(matches_pattern("logs_*", "logs_"));
+    assert!(matches_pattern("logs_*", "logs_2023"));
+    assert!(!matches_pattern("logs_*", "log"));
+
+    assert!(matches_pattern("*_tmp", "logs_tmp"));
+    assert!(!matches_pattern("*_tmp", "logs_tmp2"));
+
+    assert!(matches_pattern("a*b*c", "abc"));
+    assert!(matches_pattern("a*b*c", "a-b-b-c"));
+    assert!(!matches_pattern("a*b*c", "a-c-b"));
+    // Parts should not overlap
+    assert!(!matches_pattern("ab*ba", "aba"));
+}
+
+#[test]
+fn access_roles_test() {
+    let tokens = vec![
+        token(
+            "reader",
+            &[
+                ("logs_*", AccessRole::Read),
+                ("logs_own", AccessRole::Write),
+            ],
+        ),
+        token("admin", &[("*", AccessRole::Admin)]),
+        token("partial_admin", &[("tmp_*", AccessRole::Admin)]),
+    ];
+
+    assert!(matches!(authenticate(&[], None), Ok(Access::Unrestricted)));
+    assert!(matches!(
+        authenticate(&tokens, None),
+        Err(HttpError::Unauthorized)
+    ));
+    assert!(matches!(
+        authenticate(&tokens, Some("reader-secret")),
+        Err(HttpError::Unauthorized)
+    ));
+    assert!(matches!(
+        authenticate(&tokens, Some("Bearer unknown")),
+        Err(HttpError::Unauthorized)
+    ));
+
+    let reader = authenticate(&tokens, Some("Bearer reader-secret")).unwrap();
+
+    assert_eq!(reader.collection_role("logs_a"), Some(AccessRole::Read));
+    // Highest of the matching grants
+    assert_eq!(reader.collection_role("logs_own"), Some(AccessRole::Write));
+    assert_eq!(reader.collection_role("other"), None);
+
+    assert!(reader.check_collection("logs_a", AccessRole::Read).is_ok());
+    assert!(matches!(
+        reader.check_collection("logs_a", AccessRole::Write),
+        Err(HttpError::Forbidden("write access is required"))
+    ));
+    assert!(reader
+        .check_collection("logs_own", AccessRole::Write)
+        .is_ok());
+    assert!(matches!(
+        reader.check_collection("other", AccessRole::Read),
+        Err(HttpError::Forbidden("read access is required"))
+    ));
+    assert!(reader.check_server_admin().is_err());
+
+    let admin = authenticate(&tokens, Some("Bearer admin-secret")).unwrap();
+    assert!(admin.check_collection("other", AccessRole::Admin).is_ok());
+    assert!(admin.check_server_admin().is_ok());
+
+    let partial_admin = authenticate(&tokens, Some("Bearer partial_admin-secret")).unwrap();
+    assert!(partial_admin
+        .check_collection("tmp_a", AccessRole::Admin)
+        .is_ok());
+    assert!(partial_admin.check_server_admin().is_err());
+
+    assert!(Access::Unrestricted.check_server_admin().is_ok());
+}
+
+#[test]
+fn http_tokens_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(http_tokens_test_inner());
+}
+
+async fn http_tokens_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.get_path_buf();
+    let socket_path = path.join("diffbelt.sock");
+
+    let context = create_context(
+        path.join("data"),
+        HttpConfig {
+            listen_addresses: vec![ListenAddress::Unix(socket_path.clone())],
+            tokens: vec![
+                token("reader", &[("logs_*", AccessRole::Read)]),
+                token("admin", &[("*", AccessRole::Admin)]),
+            ],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let server = tokio::spawn(start_http_server(context));
+
+    let response = send_unix_request(&socket_path, "GET", "/collections/", None, "").await;
+    assert!(response.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+    assert!(response.contains("\r\nwww-authenticate: Bearer\r\n"));
+
+    for name in ["logs_a", "other"] {
+        let response = send_unix_request(
+            &socket_path,
+            "POST",
+            "/collections/",
+            Some("admin-secret"),
+            &format!("{{\"collectionName\":\"{}\",\"isManual\":false}}", name),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+    }
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/collections/",
+        Some("reader-secret"),
+        "{\"collectionName\":\"logs_b\",\"isManual\":false}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+    assert!(response.ends_with("{\"error\":\"403\",\"reason\":\"admin access is required\"}"));
+
+    // Only readable collections are listed
+    let response = send_unix_request(
+        &socket_path,
+        "GET",
+        "/collections/",
+        Some("reader-secret"),
+        "",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("\"logs_a\""));
+    assert!(!response.contains("\"other\""));
+
+    let response = send_unix_request(
+        &socket_path,
+        "GET",
+        "/collections/logs_a",
+        Some("reader-secret"),
+        "",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "GET",
+        "/collections/other",
+        Some("reader-secret"),
+        "",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/collections/logs_a/put",
+        Some("reader-secret"),
+        "{\"item\":{\"key\":\"a\",\"value\":\"b\"}}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+    assert!(response.ends_with("{\"error\":\"403\",\"reason\":\"write access is required\"}"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/collections/logs_a/readers/",
+        Some("admin-secret"),
+        "{\"readerName\":\"to_other\",\"collectionName\":\"other\",\"generationId\":null}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+
+    // Lag of the reader is not shown if its target collection is not readable
+    for path in ["/collections/logs_a/readers/to_other", "/readers/"] {
+        let response =
+            send_unix_request(&socket_path, "GET", path, Some("reader-secret"), "").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+        assert!(response.contains("\"targetCollectionName\":\"other\""));
+        assert!(!response.contains("targetGenerationId"));
+        assert!(!response.contains("generationsBehind"));
+
+        let response = send_unix_request(&socket_path, "GET", path, Some("admin-secret"), "").await;
+        assert!(response.contains("\"targetGenerationId\""), "{}", response);
+        assert!(response.contains("\"generationsBehind\""));
+    }
+
+    server.abort();
+}
@@ -0,0 +1,151 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::common::IsByteArray;
+use crate::database::backup::{
+    restore_database_backup_sync, BackupError, BackupOptions, RestoreBackupOptions, SHARED_DIR,
+};
+use crate::database::open::DatabaseOpenOptions;
+use crate::database::Database;
+use crate::raw_db::RawDbConfig;
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::temp_dir::TempDir;
+use crate::tests::util::collection::{commit_value, create_collection, generation_id, get_value};
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::path::Path;
+use std::sync::Arc;
+
+#[test]
+fn backup_restore_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(backup_restore_test_inner());
+}
+
+async fn backup_restore_test_inner() {
+    let temp_database = TempDatabase::new().await;
+    let backup_dir = TempDir::new().unwrap();
+
+    let database = temp_database.get_database();
+    let backup_path = backup_dir.get_path_buf();
+
+    let first = create_collection(database, "first").await;
+    let second = create_collection(database, "second").await;
+
+    commit_value(&first, b"key", b"a", b"1").await;
+    commit_value(&second, b"key", b"b", b"1").await;
+
+    second
+        .create_reader(CreateReaderOptions {
+            reader_name: "reader".to_string(),
+            collection_name: Some("first".to_string()),
+            generation_id: Some(generation_id(b"1")),
+        })
+        .await
+        .unwrap();
+
+    // Deleted collections are not in the backup
+    let deleted = create_collection(database, "deleted").await;
+    let deleting = deleted.delete_collection();
+    drop(deleted);
+    deleting.await.unwrap();
+    assert_eq!(database.trash_list().await.unwrap().len(), 1);
+
+    let checkpoint = database
+        .backup(BackupOptions {
+            path: backup_path.clone(),
+            incremental: false,
+        })
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = checkpoint
+        .collections
+        .iter()
+        .map(|collection| collection.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["first", "second"]);
+    assert_eq!(
+        checkpoint.collections[0].generation_id.get_byte_array(),
+        b"1"
+    );
+
+    let incremental = database
+        .backup(BackupOptions {
+            path: backup_path.clone(),
+            incremental: true,
+        })
+        .await
+        .unwrap();
+
+    commit_value(&first, b"key", b"c", b"2").await;
+
+    let next_incremental = database
+        .backup(BackupOptions {
+            path: backup_path.clone(),
+            incremental: true,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        next_incremental.collections[0]
+            .generation_id
+            .get_byte_array(),
+        b"2"
+    );
+
+    // Table files are shared by incremental backups
+    assert!(backup_path.join(SHARED_DIR).is_dir());
+
+    for (backup_id, expected_generation_id, expected_value) in [
+        (checkpoint.backup_id.as_str(), b"1", b"a"),
+        (incremental.backup_id.as_str(), b"1", b"a"),
+        (next_incremental.backup_id.as_str(), b"2", b"c"),
+    ] {
+        let data_dir = TempDir::new().unwrap();
+
+        restore(backup_path, backup_id, data_dir.get_path_buf()).unwrap();
+
+        let restored = Database::open(DatabaseOpenOptions {
+            data_path: data_dir.get_path_buf(),
+            config: Arc::new(Default::default()),
+        })
+        .await
+        .unwrap();
+
+        let first = restored.get_collection("first").await.unwrap();
+        assert_eq!(
+            first.generation_pair().generation_id.get_byte_array(),
+            expected_generation_id
+        );
+        assert_eq!(
+            get_value(&first, b"key", None).await,
+            Some(expected_value.to_vec())
+        );
+
+        let second = restored.get_collection("second").await.unwrap();
+        assert_eq!(get_value(&second, b"key", None).await, Some(b"b".to_vec()));
+
+        let readers = second.list_readers().await.unwrap().items;
+        assert_eq!(readers.len(), 1);
+        assert_eq!(readers[0].collection_name.as_deref(), Some("first"));
+
+        assert!(restored.get_collection("deleted").await.is_none());
+        assert!(restored.trash_list().await.unwrap().is_empty());
+
+        // Restore never overwrites existing data
+        drop(restored);
+        let result = restore(backup_path, backup_id, data_dir.get_path_buf());
+        assert!(matches!(result, Err(BackupError::DataPathNotEmpty)));
+    }
+
+    let data_dir = TempDir::new().unwrap();
+    let result = restore(backup_path, "unknown", data_dir.get_path_buf());
+    assert!(matches!(result, Err(BackupError::NoSuchBackup)));
+}
+
+fn restore(path: &Path, backup_id: &str, data_path: &Path) -> Result<(), BackupError> {
+    restore_database_backup_sync(RestoreBackupOptions {
+        path,
+        backup_id,
+        data_path,
+        raw_db_config: &RawDbConfig::default(),
+    })
+}
@@ -0,0 +1,162 @@
+use crate::collection::methods::put::CollectionPutOptions;
+use crate::collection::settings::{CollectionSettings, CollectionSettingsUpdate};
+use crate::collection::Collection;
+use crate::common::{
+    KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey, OwnedCollectionValue,
+    OwnedGenerationId,
+};
+use crate::database::clone_collection::CloneCollectionOptions;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::num::NonZeroU32;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+#[test]
+fn max_generation_puts_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(max_generation_puts_test_inner());
+}
+
+async fn max_generation_puts_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "test",
+            CreateCollectionOptions {
+                is_manual: false,
+                settings: CollectionSettings {
+                    commit_delay: Duration::from_secs(60),
+                    max_generation_age: None,
+                    max_generation_puts: NonZeroU32::new(3),
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    put_value(&collection, b"a").await;
+    put_value(&collection, b"b").await;
+
+    sleep(Duration::from_millis(200)).await;
+
+    assert_eq!(
+        collection.generation_pair().generation_id,
+        OwnedGenerationId::zero_64bits()
+    );
+
+    put_value(&collection, b"c").await;
+
+    wait_for_commit(&collection).await;
+}
+
+#[test]
+fn update_collection_settings_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(update_collection_settings_test_inner());
+}
+
+async fn update_collection_settings_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "test",
+            CreateCollectionOptions {
+                is_manual: false,
+                settings: CollectionSettings {
+                    commit_delay: Duration::from_secs(60),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    put_value(&collection, b"a").await;
+
+    sleep(Duration::from_millis(200)).await;
+
+    assert_eq!(
+        collection.generation_pair().generation_id,
+        OwnedGenerationId::zero_64bits()
+    );
+
+    let settings = database
+        .update_collection_settings(
+            "test",
+            CollectionSettingsUpdate {
+                commit_delay: Some(Duration::from_millis(10)),
+                max_generation_age: Some(Some(Duration::from_secs(1))),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let expected_settings = CollectionSettings {
+        commit_delay: Duration::from_millis(10),
+        max_generation_age: Some(Duration::from_secs(1)),
+        max_generation_puts: None,
+    };
+
+    assert_eq!(settings, expected_settings);
+
+    // Already scheduled generation is committed with the new delay
+    wait_for_commit(&collection).await;
+
+    // Settings are persisted in the collection record, clone is opened from it
+    let clone = database
+        .clone_collection(
+            "test",
+            "clone",
+            CloneCollectionOptions {
+                generation_id: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(clone.get_settings().await, expected_settings);
+}
+
+async fn put_value(collection: &Collection, key: &[u8]) {
+    collection
+        .put(CollectionPutOptions {
+            update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: OwnedCollectionKey::from_boxed_slice(key.to_vec().into_boxed_slice()).unwrap(),
+                value: Some(OwnedCollectionValue::new(b"value")),
+                if_not_present: false,
+                if_value_changed: None,
+            }),
+            generation_id: None,
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+}
+
+async fn wait_for_commit(collection: &Collection) {
+    let mut generation_pair_receiver = collection.generation_pair_receiver.clone();
+
+    loop {
+        if generation_pair_receiver.borrow_and_update().generation_id
+            > OwnedGenerationId::zero_64bits()
+        {
+            break;
+        }
+
+        timeout(Duration::from_secs(2), generation_pair_receiver.changed())
+            .await
+            .expect("generation is not committed")
+            .unwrap();
+    }
+}
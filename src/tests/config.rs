@@ -0,0 +1,389 @@
+use crate::config::{Config, ReadConfigError};
+use crate::database::config::DeletedCollectionReadersMode;
+use crate::http::auth::{AccessGrant, AccessRole, ApiToken};
+use crate::http::config::{ListenAddress, TlsConfig};
+use crate::logging::config::{LogConfig, LogFormat};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn vars(vars: &[(&str, &str)]) -> impl Iterator<Item = (String, String)> {
+    vars.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+#[test]
+fn config_file_with_env_overrides_test() {
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[http]
+listen_address = "0.0.0.0:8080"
+put_request_max_bytes = 1024
+
+[database]
+query_pack_limit = 10
+gc_records_limit = 50
+deleted_collection_readers = "orphan"
+trash_retention_secs = 60
+
+[rocksdb]
+max_open_files = 256
+"#,
+    )
+    .unwrap();
+
+    let config = Config::from_value(
+        value,
+        vars(&[
+            ("DIFFBELT_DATABASE_QUERY_PACK_LIMIT", "20"),
+            ("DIFFBELT_ROCKSDB_WRITE_BUFFER_SIZE", "1048576"),
+            ("DIFFBELT_CLEAR", "1"),
+            ("DIFFBELT_CONFIG", "/etc/diffbelt.toml"),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(config.data_path, PathBuf::from("/var/lib/diffbelt"));
+    assert!(config.is_clear);
+    assert_eq!(
+        config.http.listen_addresses,
+        vec![ListenAddress::Tcp(
+            "0.0.0.0:8080".parse::<SocketAddr>().unwrap()
+        )]
+    );
+    assert_eq!(config.http.put_request_max_bytes, 1024);
+    assert_eq!(config.http.put_many_request_max_bytes, 32 * 1024 * 1024);
+    assert_eq!(config.database.query_pack_limit, 20);
+    assert_eq!(config.database.gc_records_limit.get(), 50);
+    assert_eq!(
+        config.database.deleted_collection_readers,
+        DeletedCollectionReadersMode::Orphan
+    );
+    assert_eq!(config.database.trash_retention, Duration::from_secs(60));
+    assert_eq!(config.database.raw_db.max_open_files, Some(256));
+    assert_eq!(config.database.raw_db.write_buffer_size, Some(1048576));
+}
+
+#[test]
+fn config_json_file_test() {
+    let value = serde_json::from_str(r#"{"http": {"listen_address": "127.0.0.1:4000"}}"#).unwrap();
+
+    let config = Config::from_value(
+        value,
+        vars(&[
+            ("DIFFBELT_DATA_PATH", "/tmp/diffbelt"),
+            ("DIFFBELT_BACKUP_PATH", "/tmp/diffbelt-backups"),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(config.data_path, PathBuf::from("/tmp/diffbelt"));
+    assert_eq!(
+        config.backup_path,
+        Some(PathBuf::from("/tmp/diffbelt-backups"))
+    );
+    assert_eq!(
+        config.http.listen_addresses,
+        vec![ListenAddress::Tcp(
+            "127.0.0.1:4000".parse::<SocketAddr>().unwrap()
+        )]
+    );
+}
+
+#[test]
+fn config_listen_addresses_test() {
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[http]
+listen_address = ["127.0.0.1:3030", "unix:/run/diffbelt.sock"]
+tls_cert_path = "/etc/diffbelt/cert.pem"
+tls_key_path = "/etc/diffbelt/key.pem"
+"#,
+    )
+    .unwrap();
+
+    let config = Config::from_value(value, vars(&[])).unwrap();
+
+    assert_eq!(
+        config.http.listen_addresses,
+        vec![
+            ListenAddress::Tcp("127.0.0.1:3030".parse::<SocketAddr>().unwrap()),
+            ListenAddress::Unix(PathBuf::from("/run/diffbelt.sock")),
+        ]
+    );
+    assert_eq!(
+        config.http.tls,
+        Some(TlsConfig {
+            cert_path: PathBuf::from("/etc/diffbelt/cert.pem"),
+            key_path: PathBuf::from("/etc/diffbelt/key.pem"),
+        })
+    );
+
+    let value = toml::from_str("data_path = \"/var/lib/diffbelt\"").unwrap();
+
+    let config = Config::from_value(
+        value,
+        vars(&[(
+            "DIFFBELT_HTTP_LISTEN_ADDRESS",
+            "0.0.0.0:3030, unix:/tmp/diffbelt.sock",
+        )]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.http.listen_addresses,
+        vec![
+            ListenAddress::Tcp("0.0.0.0:3030".parse::<SocketAddr>().unwrap()),
+            ListenAddress::Unix(PathBuf::from("/tmp/diffbelt.sock")),
+        ]
+    );
+    assert_eq!(config.http.tls, None);
+
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[http]
+listen_address = ["localhost", "unix:"]
+tls_cert_path = "/etc/diffbelt/cert.pem"
+"#,
+    )
+    .unwrap();
+
+    let Err(ReadConfigError::Invalid(errors)) = Config::from_value(value, vars(&[])) else {
+        panic!("config should be invalid");
+    };
+
+    assert_eq!(
+        errors,
+        vec![
+            "http.listen_address \"localhost\" is invalid".to_string(),
+            "http.listen_address \"unix:\" is invalid".to_string(),
+            "http.tls_cert_path and http.tls_key_path should be specified together".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn config_tokens_test() {
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[[http.tokens]]
+name = "ci"
+token = "ci-secret"
+collections = { "logs_*" = "read", "logs_ci" = "write" }
+
+[[http.tokens]]
+name = "admin"
+token = "admin-secret"
+collections = { "*" = "admin" }
+"#,
+    )
+    .unwrap();
+
+    let config = Config::from_value(value, vars(&[])).unwrap();
+
+    assert_eq!(
+        config.http.tokens,
+        vec![
+            Arc::new(ApiToken {
+                name: "ci".to_string(),
+                token: "ci-secret".to_string(),
+                grants: vec![
+                    AccessGrant {
+                        pattern: "logs_*".to_string(),
+                        role: AccessRole::Read,
+                    },
+                    AccessGrant {
+                        pattern: "logs_ci".to_string(),
+                        role: AccessRole::Write,
+                    },
+                ],
+            }),
+            Arc::new(ApiToken {
+                name: "admin".to_string(),
+                token: "admin-secret".to_string(),
+                grants: vec![AccessGrant {
+                    pattern: "*".to_string(),
+                    role: AccessRole::Admin,
+                }],
+            }),
+        ]
+    );
+
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[[http.tokens]]
+name = "empty"
+token = ""
+collections = {}
+
+[[http.tokens]]
+name = "first"
+token = "secret"
+collections = { "*" = "owner" }
+
+[[http.tokens]]
+name = "second"
+token = "secret"
+collections = {}
+"#,
+    )
+    .unwrap();
+
+    let Err(ReadConfigError::Invalid(errors)) = Config::from_value(value, vars(&[])) else {
+        panic!("config should be invalid");
+    };
+
+    assert_eq!(
+        errors,
+        vec![
+            "http.tokens \"empty\" token should not be empty".to_string(),
+            "http.tokens \"first\" role of \"*\" should be \"read\", \"write\" or \"admin\""
+                .to_string(),
+            "http.tokens \"second\" token is not unique".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn config_log_test() {
+    let value = toml::from_str("data_path = \"/var/lib/diffbelt\"").unwrap();
+
+    let config = Config::from_value(value, vars(&[])).unwrap();
+    assert_eq!(config.log, LogConfig::default());
+
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[log]
+filter = "info,diffbelt::raw_db=debug"
+format = "text"
+"#,
+    )
+    .unwrap();
+
+    let config = Config::from_value(value, vars(&[("DIFFBELT_LOG_FORMAT", "json")])).unwrap();
+
+    assert_eq!(
+        config.log,
+        LogConfig {
+            filter: "info,diffbelt::raw_db=debug".to_string(),
+            format: LogFormat::Json,
+        }
+    );
+
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[log]
+filter = "diffbelt=loud"
+format = "xml"
+"#,
+    )
+    .unwrap();
+
+    let Err(ReadConfigError::Invalid(errors)) = Config::from_value(value, vars(&[])) else {
+        panic!("config should be invalid");
+    };
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("log.filter is invalid: "));
+    assert_eq!(errors[1], "log.format should be \"text\" or \"json\"");
+}
+
+#[test]
+fn config_validation_test() {
+    let value = toml::from_str(
+        r#"
+data_path = "/var/lib/diffbelt"
+
+[database]
+max_cursors_per_collection = 0
+deleted_collection_readers = "keep"
+"#,
+    )
+    .unwrap();
+
+    let result = Config::from_value(value, vars(&[("DIFFBELT_HTTP_GET_REQUEST_MAX_BYTES", "0")]));
+
+    let Err(ReadConfigError::Invalid(errors)) = result else {
+        panic!("config should be invalid");
+    };
+
+    assert_eq!(
+        errors,
+        vec![
+            "http.get_request_max_bytes should be > 0".to_string(),
+            "database.deleted_collection_readers should be \"remove\" or \"orphan\"".to_string(),
+            "database.max_cursors_per_collection should be > 0".to_string(),
+        ]
+    );
+
+    let value = toml::from_str("data_path = \"/var/lib/diffbelt\"").unwrap();
+
+    let config = Config::from_value(
+        value,
+        vars(&[
+            ("DIFFBELT_DATABASE_UNKNOWN", "1"),
+            ("DIFFBELT_UNKNOWN", "1"),
+            ("DIFFBELT_HTTP_TOKENS", "secret"),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.warnings,
+        vec![
+            "unknown env variable DIFFBELT_DATABASE_UNKNOWN is ignored".to_string(),
+            "unknown env variable DIFFBELT_UNKNOWN is ignored".to_string(),
+            "unknown env variable DIFFBELT_HTTP_TOKENS is ignored".to_string(),
+        ]
+    );
+
+    let value = toml::from_str("data_path = \"/var/lib/diffbelt\"").unwrap();
+
+    let result = Config::from_value(
+        value,
+        vars(&[("DIFFBELT_DATABASE_QUERY_PACK_LIMIT", "many")]),
+    );
+    assert!(matches!(result, Err(ReadConfigError::Toml(_))));
+}
+
+#[test]
+fn config_env_value_types_test() {
+    let value = toml::from_str("data_path = \"/var/lib/diffbelt\"").unwrap();
+
+    let config = Config::from_value(
+        value,
+        vars(&[
+            ("DIFFBELT_HTTP_TLS_CERT_PATH", "1"),
+            ("DIFFBELT_HTTP_TLS_KEY_PATH", "2"),
+            ("DIFFBELT_ROCKSDB_MAX_OPEN_FILES", "-1"),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.http.tls,
+        Some(TlsConfig {
+            cert_path: PathBuf::from("1"),
+            key_path: PathBuf::from("2"),
+        })
+    );
+    assert_eq!(config.database.raw_db.max_open_files, Some(-1));
+    assert!(config.warnings.is_empty());
+}
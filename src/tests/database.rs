@@ -42,12 +42,24 @@ async fn database_test_inner() {
     .expect("Cannot open database");
 
     let collection = database
-        .create_collection("test", CreateCollectionOptions { is_manual: false })
+        .create_collection(
+            "test",
+            CreateCollectionOptions {
+                is_manual: false,
+                ..Default::default()
+            },
+        )
         .await
         .expect("Collection create");
 
     let manual_collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .expect("Collection create");
 
@@ -72,6 +84,7 @@ async fn database_test_inner() {
                     .unwrap(),
                 value: Option::Some(OwnedCollectionValue::new(b"passed")),
                 if_not_present: true,
+                if_value_changed: None,
             }),
             generation_id: None,
             phantom_id: None,
@@ -120,12 +133,14 @@ async fn database_test_inner() {
                         .unwrap(),
                     value: Option::Some(OwnedCollectionValue::new(b"passed3")),
                     if_not_present: true,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice(b"test2".to_vec().into_boxed_slice())
                         .unwrap(),
                     value: Option::Some(OwnedCollectionValue::new(b"passed again")),
                     if_not_present: true,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: None,
@@ -164,6 +179,7 @@ async fn database_test_inner() {
                     .unwrap(),
                 value: Option::Some(OwnedCollectionValue::new(b"manual passed")),
                 if_not_present: true,
+                if_value_changed: None,
             }),
             generation_id: Some(
                 OwnedGenerationId::from_boxed_slice(b"first".to_vec().into_boxed_slice()).unwrap(),
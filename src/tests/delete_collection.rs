@@ -1,6 +1,15 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::update_reader::UpdateReaderOptions;
+use crate::collection::Collection;
+use crate::common::reader::ReaderRecord;
+use crate::common::OwnedGenerationId;
+use crate::database::config::{DatabaseConfig, DeletedCollectionReadersMode};
 use crate::database::create_collection::CreateCollectionOptions;
+use crate::database::Database;
 use crate::tests::temp_database::TempDatabase;
 use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::sync::Arc;
 
 #[test]
 fn delete_collection_test() {
@@ -14,7 +23,13 @@ async fn delete_collection_test_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -24,3 +39,111 @@ async fn delete_collection_test_inner() {
 
     fut.await.unwrap();
 }
+
+#[test]
+fn delete_collection_removes_readers_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(delete_collection_readers_test_inner(
+        DeletedCollectionReadersMode::Remove,
+    ));
+}
+
+#[test]
+fn delete_collection_orphans_readers_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(delete_collection_readers_test_inner(
+        DeletedCollectionReadersMode::Orphan,
+    ));
+}
+
+async fn delete_collection_readers_test_inner(mode: DeletedCollectionReadersMode) {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        deleted_collection_readers: mode,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let target = create_collection(database, "target").await;
+    let other = create_collection(database, "other").await;
+
+    create_reader(&target, "own", None).await;
+    create_reader(&other, "own", None).await;
+    create_reader(&other, "to_target", Some("target")).await;
+
+    let fut = target.delete_collection();
+
+    drop(target);
+
+    fut.await.unwrap();
+
+    let mut items = other.list_readers().await.unwrap().items;
+    items.sort_by(|a, b| a.reader_name.cmp(&b.reader_name));
+
+    let own_reader = ReaderRecord {
+        reader_name: "own".to_string(),
+        collection_name: None,
+        generation_id: None,
+        is_orphaned: false,
+    };
+
+    match mode {
+        DeletedCollectionReadersMode::Remove => {
+            assert_eq!(items, vec![own_reader]);
+        }
+        DeletedCollectionReadersMode::Orphan => {
+            assert_eq!(
+                items,
+                vec![
+                    own_reader,
+                    ReaderRecord {
+                        reader_name: "to_target".to_string(),
+                        collection_name: Some("target".to_string()),
+                        generation_id: None,
+                        is_orphaned: true,
+                    },
+                ]
+            );
+
+            let result = other
+                .update_reader(UpdateReaderOptions {
+                    reader_name: "to_target".to_string(),
+                    generation_id: Some(OwnedGenerationId::empty()),
+                })
+                .await;
+
+            assert!(matches!(result, Err(CollectionMethodError::OrphanedReader)));
+        }
+    }
+
+    // Collection with the same name can be created again and it has no readers
+    let target = create_collection(database, "target").await;
+
+    let items = target.list_readers().await.unwrap().items;
+    assert!(items.is_empty());
+}
+
+async fn create_collection(database: &Database, name: &str) -> Arc<Collection> {
+    database
+        .create_collection(
+            name,
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_reader(collection: &Collection, reader_name: &str, collection_name: Option<&str>) {
+    collection
+        .create_reader(CreateReaderOptions {
+            reader_name: reader_name.to_string(),
+            collection_name: collection_name.map(|name| name.to_string()),
+            generation_id: None,
+        })
+        .await
+        .unwrap();
+}
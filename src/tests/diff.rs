@@ -35,7 +35,13 @@ async fn diff_test_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -59,6 +65,7 @@ async fn diff_test_inner() {
                 key,
                 value: Some(value),
                 if_not_present: false,
+                if_value_changed: None,
             }));
         }
 
@@ -113,6 +120,7 @@ async fn diff_test_inner() {
                         key,
                         value: Some(value),
                         if_not_present: false,
+                        if_value_changed: None,
                     }));
                 }
 
@@ -133,12 +141,8 @@ async fn diff_test_inner() {
         OwnedGenerationId::from_boxed_slice(last_generation_id_bytes.into()).unwrap();
 
     let mut expected_pack_size_distribution = [20usize; 31];
-    expected_pack_size_distribution[0] = 2;
-    for i in 1..6 {
-        expected_pack_size_distribution[i] = 3;
-    }
-    expected_pack_size_distribution[6] = 4;
-    expected_pack_size_distribution[30] = 19;
+    expected_pack_size_distribution[..6].fill(3);
+    expected_pack_size_distribution[30] = 2;
 
     // should stuck on first generation, since it has 500 changes that is bigger than `diff_changes_limit`
     assert_diff(
@@ -156,7 +160,7 @@ async fn diff_test_inner() {
     let expected_diff = make_diff(&first_generation_items, &last_generation_items);
 
     let mut expected_pack_size_distribution = [3usize; 7];
-    expected_pack_size_distribution[0] = 2;
+    expected_pack_size_distribution[6] = 2;
 
     assert_diff(
         &collection,
@@ -242,6 +246,7 @@ async fn assert_diff(
                         from_generation_id.map(|id| id.to_owned()),
                     ),
                     to_generation_id_loose: to_generation_id_loose.map(|id| id.to_owned()),
+                    omit_intermediate_values: true,
                 })
                 .await
                 .unwrap(),
@@ -0,0 +1,259 @@
+use crate::collection::methods::diff::{DiffOk, DiffOptions, ReadDiffCursorOptions};
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::Collection;
+use crate::common::generation_id::GenerationIdSource;
+use crate::common::{
+    IntermediateValue, KeyValueDiff, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::database::config::DatabaseConfig;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::util::manual_generation::wrap_generation;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+
+#[test]
+fn diff_intermediate_values_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(diff_intermediate_values_test_inner());
+}
+
+async fn diff_intermediate_values_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        diff_pack_limit: 2,
+        // Small enough to make cursor continuation in the middle of key records
+        diff_pack_records_limit: 3,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let gen = |x: u8| OwnedGenerationId::from_boxed_slice(vec![x].into_boxed_slice()).unwrap();
+
+    put_generation(
+        &collection,
+        gen(1),
+        vec![(b"a", Some(b"1")), (b"b", Some(b"1"))],
+    )
+    .await;
+    put_generation(
+        &collection,
+        gen(2),
+        vec![(b"a", Some(b"2")), (b"c", Some(b"2"))],
+    )
+    .await;
+    put_generation(&collection, gen(3), vec![(b"a", None), (b"b", Some(b"3"))]).await;
+    put_generation(
+        &collection,
+        gen(4),
+        vec![(b"a", Some(b"4")), (b"c", Some(b"4"))],
+    )
+    .await;
+
+    let expected = vec![
+        KeyValueDiff {
+            key: key(b"a"),
+            from_value: Some(value(b"1")),
+            intermediate_values: vec![
+                IntermediateValue {
+                    generation_id: gen(2),
+                    value: Some(value(b"2")),
+                },
+                IntermediateValue {
+                    generation_id: gen(3),
+                    value: None,
+                },
+            ],
+            to_value: Some(value(b"4")),
+        },
+        KeyValueDiff {
+            key: key(b"b"),
+            from_value: Some(value(b"1")),
+            intermediate_values: vec![],
+            to_value: Some(value(b"3")),
+        },
+        KeyValueDiff {
+            key: key(b"c"),
+            from_value: None,
+            intermediate_values: vec![IntermediateValue {
+                generation_id: gen(2),
+                value: Some(value(b"2")),
+            }],
+            to_value: Some(value(b"4")),
+        },
+    ];
+
+    let items = diff_all(&collection, Some(gen(1)), false).await;
+    assert_eq!(items, expected);
+
+    // Same diff without intermediate values
+    let items = diff_all(&collection, Some(gen(1)), true).await;
+    let expected_without_intermediate: Vec<KeyValueDiff> = expected
+        .into_iter()
+        .map(|item| KeyValueDiff {
+            intermediate_values: vec![],
+            ..item
+        })
+        .collect();
+    assert_eq!(items, expected_without_intermediate);
+}
+
+#[test]
+fn diff_intermediate_values_single_generation_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(diff_intermediate_values_single_generation_test_inner());
+}
+
+async fn diff_intermediate_values_single_generation_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        // Every generation has more changes, diff will be in single generation mode
+        diff_changes_limit: 1,
+        diff_pack_limit: 2,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let gen = |x: u8| OwnedGenerationId::from_boxed_slice(vec![x].into_boxed_slice()).unwrap();
+
+    put_generation(
+        &collection,
+        gen(1),
+        vec![(b"a", Some(b"1")), (b"b", Some(b"1")), (b"c", Some(b"1"))],
+    )
+    .await;
+    put_generation(
+        &collection,
+        gen(2),
+        vec![(b"a", Some(b"2")), (b"b", None), (b"c", Some(b"2"))],
+    )
+    .await;
+
+    let items = diff_all(&collection, Some(gen(1)), false).await;
+
+    // Only one generation is diffed, so there is no intermediate values
+    assert_eq!(
+        items,
+        vec![
+            KeyValueDiff {
+                key: key(b"a"),
+                from_value: Some(value(b"1")),
+                intermediate_values: vec![],
+                to_value: Some(value(b"2")),
+            },
+            KeyValueDiff {
+                key: key(b"b"),
+                from_value: Some(value(b"1")),
+                intermediate_values: vec![],
+                to_value: None,
+            },
+            KeyValueDiff {
+                key: key(b"c"),
+                from_value: Some(value(b"1")),
+                intermediate_values: vec![],
+                to_value: Some(value(b"2")),
+            },
+        ]
+    );
+}
+
+fn key(bytes: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(bytes.into()).unwrap()
+}
+
+fn value(bytes: &[u8]) -> OwnedCollectionValue {
+    OwnedCollectionValue::new(bytes)
+}
+
+async fn put_generation(
+    collection: &Collection,
+    generation_id: OwnedGenerationId,
+    updates: Vec<(&[u8], Option<&[u8]>)>,
+) {
+    wrap_generation(collection, generation_id.as_ref(), async {
+        let items = updates
+            .into_iter()
+            .map(|(key_bytes, value_bytes)| {
+                KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                    key: key(key_bytes),
+                    value: value_bytes.map(value),
+                    if_not_present: false,
+                    if_value_changed: None,
+                })
+            })
+            .collect();
+
+        collection
+            .put_many(CollectionPutManyOptions {
+                items,
+                generation_id: Some(generation_id.clone()),
+                phantom_id: None,
+            })
+            .await
+            .unwrap();
+    })
+    .await;
+}
+
+async fn diff_all(
+    collection: &Collection,
+    from_generation_id: Option<OwnedGenerationId>,
+    omit_intermediate_values: bool,
+) -> Vec<KeyValueDiff> {
+    let mut result = Vec::new();
+
+    let DiffOk {
+        mut items,
+        mut cursor_id,
+        ..
+    } = collection
+        .diff(DiffOptions {
+            from_generation_id: GenerationIdSource::Value(from_generation_id),
+            to_generation_id_loose: None,
+            omit_intermediate_values,
+        })
+        .await
+        .unwrap();
+
+    loop {
+        result.append(&mut items);
+
+        let Some(id) = cursor_id else {
+            break;
+        };
+
+        let next = collection
+            .read_diff_cursor(ReadDiffCursorOptions { cursor_id: id })
+            .await
+            .unwrap();
+
+        items = next.items;
+        cursor_id = next.cursor_id;
+    }
+
+    result
+}
@@ -0,0 +1,279 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::common::reader::ReaderRecord;
+use crate::common::OwnedGenerationId;
+use crate::database::dump::export::ExportCollectionOptions;
+use crate::database::dump::import::{ImportCollectionOk, ImportCollectionOptions};
+use crate::database::dump::{DumpError, DumpLine};
+use crate::database::Database;
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::util::collection::{create_collection, generation_id, get_value, put_value};
+use crate::tests::util::manual_generation::wrap_generation;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use futures::TryStreamExt;
+
+#[test]
+fn dump_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(dump_test_inner());
+}
+
+async fn dump_test_inner() {
+    let temp_database = TempDatabase::new().await;
+    let database = temp_database.get_database();
+
+    let source = create_collection(database, "source").await;
+    create_collection(database, "other").await;
+
+    wrap_generation(&source, generation_id(b"1").as_ref(), async {
+        put_value(&source, b"a", Some(b"1"), b"1").await;
+        put_value(&source, b"b", Some(b"2"), b"1").await;
+    })
+    .await;
+
+    wrap_generation(&source, generation_id(b"2").as_ref(), async {
+        put_value(&source, b"a", Some(b"3"), b"2").await;
+        put_value(&source, b"b", None, b"2").await;
+        put_value(&source, b"c", Some(b"4"), b"2").await;
+    })
+    .await;
+
+    for (reader_name, collection_name) in [("self", None), ("other", Some("other"))] {
+        source
+            .create_reader(CreateReaderOptions {
+                reader_name: reader_name.to_string(),
+                collection_name: collection_name.map(str::to_string),
+                generation_id: Some(generation_id(b"1")),
+            })
+            .await
+            .unwrap();
+    }
+
+    let dump = export(database, None, false).await;
+
+    let types: Vec<String> = dump
+        .lines()
+        .map(|line| {
+            let line: serde_json::Value = serde_json::from_str(line).unwrap();
+            line["type"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            "header",
+            "generation",
+            "record",
+            "record",
+            "reader",
+            "reader",
+            "end"
+        ]
+    );
+
+    let history_dump = export(database, None, true).await;
+    let first_generation_dump = export(database, Some(generation_id(b"1")), false).await;
+
+    // Collection with the same name already exists
+    let result = import(database, &dump).await;
+    assert!(matches!(result, Err(DumpError::AlreadyExist)));
+
+    let target = TempDatabase::new().await;
+    let target_database = target.get_database();
+
+    let ImportCollectionOk {
+        collection,
+        records,
+    } = import(target_database, &dump).await.unwrap();
+    assert_eq!(records, 2);
+    assert!(collection.is_manual());
+    assert_eq!(
+        collection.generation_pair().generation_id,
+        generation_id(b"2")
+    );
+    assert_eq!(
+        get_value(&collection, b"a", None).await,
+        Some(b"3".to_vec())
+    );
+    assert_eq!(get_value(&collection, b"b", None).await, None);
+    assert_eq!(
+        get_value(&collection, b"c", None).await,
+        Some(b"4".to_vec())
+    );
+
+    let mut readers = collection.list_readers().await.unwrap().items;
+    readers.sort_by(|a, b| a.reader_name.cmp(&b.reader_name));
+    assert_eq!(
+        readers,
+        vec![
+            ReaderRecord {
+                reader_name: "other".to_string(),
+                collection_name: Some("other".to_string()),
+                generation_id: Some(generation_id(b"1")),
+                is_orphaned: false,
+            },
+            ReaderRecord {
+                reader_name: "self".to_string(),
+                collection_name: None,
+                generation_id: Some(generation_id(b"1")),
+                is_orphaned: false,
+            },
+        ]
+    );
+
+    // History is kept, so previous generations can be read
+    let target = TempDatabase::new().await;
+    let ImportCollectionOk {
+        collection,
+        records,
+    } = import(target.get_database(), &history_dump).await.unwrap();
+    assert_eq!(records, 5);
+    assert_eq!(
+        collection.generation_pair().generation_id,
+        generation_id(b"2")
+    );
+    assert_eq!(
+        get_value(&collection, b"b", Some(b"1")).await,
+        Some(b"2".to_vec())
+    );
+    assert_eq!(get_value(&collection, b"b", None).await, None);
+    assert_eq!(
+        get_value(&collection, b"a", None).await,
+        Some(b"3".to_vec())
+    );
+
+    let target = TempDatabase::new().await;
+    let ImportCollectionOk { collection, .. } =
+        import(target.get_database(), &first_generation_dump)
+            .await
+            .unwrap();
+    assert_eq!(
+        collection.generation_pair().generation_id,
+        generation_id(b"1")
+    );
+    assert_eq!(
+        get_value(&collection, b"a", None).await,
+        Some(b"1".to_vec())
+    );
+    assert_eq!(get_value(&collection, b"c", None).await, None);
+}
+
+#[test]
+fn invalid_dump_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(invalid_dump_test_inner());
+}
+
+async fn invalid_dump_test_inner() {
+    let temp_database = TempDatabase::new().await;
+    let database = temp_database.get_database();
+
+    let source = create_collection(database, "source").await;
+
+    wrap_generation(&source, generation_id(b"1").as_ref(), async {
+        put_value(&source, b"a", Some(b"1"), b"1").await;
+    })
+    .await;
+
+    let dump = export(database, None, false).await;
+    drop(source);
+
+    let target = TempDatabase::new().await;
+    let target_database = target.get_database();
+
+    let truncated: String = dump
+        .lines()
+        .take(3)
+        .map(|line| format!("{}\n", line))
+        .collect();
+    let result = import(target_database, &truncated).await;
+    assert!(matches!(result, Err(DumpError::InvalidDump(_))));
+
+    let next_version = dump.replacen("\"version\":1", "\"version\":2", 1);
+    let result = import(target_database, &next_version).await;
+    assert!(matches!(result, Err(DumpError::UnsupportedVersion(2))));
+
+    let too_long_generation_id = base64::encode([b'1'; 256]);
+    let invalid_generation = dump.replace("\"MQ==\"", &format!("\"{}\"", too_long_generation_id));
+    let result = import(target_database, &invalid_generation).await;
+    assert!(matches!(result, Err(DumpError::InvalidDump(_))));
+
+    // Request body is interrupted after the header
+    let header = format!("{}\n", dump.lines().next().unwrap());
+    let interrupted = futures::stream::iter(vec![
+        Ok(header.into_bytes()),
+        Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "interrupted",
+        )),
+    ])
+    .into_async_read();
+    let result = target_database
+        .import_collection(
+            interrupted,
+            ImportCollectionOptions {
+                max_line_bytes: 1024,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(DumpError::Read(_))));
+
+    // Failed imports leave nothing
+    assert!(target_database.get_collection("source").await.is_none());
+
+    // Directory is left from a collection that was not removed completely
+    let leftover_path = target.get_path_buf().join("source");
+    std::fs::create_dir(&leftover_path).unwrap();
+    std::fs::write(leftover_path.join("leftover"), b"").unwrap();
+
+    let result = import(target_database, &dump).await;
+    assert!(matches!(result, Err(DumpError::AlreadyExist)));
+    assert!(leftover_path.join("leftover").exists());
+
+    std::fs::remove_dir_all(&leftover_path).unwrap();
+
+    let ImportCollectionOk { records, .. } = import(target_database, &dump).await.unwrap();
+    assert_eq!(records, 1);
+}
+
+async fn export(
+    database: &Database,
+    generation_id: Option<OwnedGenerationId>,
+    history: bool,
+) -> String {
+    let mut export = database
+        .export_collection(
+            "source",
+            ExportCollectionOptions {
+                generation_id,
+                history,
+            },
+        )
+        .await
+        .unwrap();
+
+    let mut dump = String::new();
+
+    while let Some(lines) = export.next_pack().await.unwrap() {
+        for line in lines {
+            if let DumpLine::Header(header) = &line {
+                assert_eq!(header.history, history);
+            }
+
+            dump.push_str(&serde_json::to_string(&line).unwrap());
+            dump.push('\n');
+        }
+    }
+
+    dump
+}
+
+async fn import(database: &Database, dump: &str) -> Result<ImportCollectionOk, DumpError> {
+    database
+        .import_collection(
+            futures::io::Cursor::new(dump.as_bytes()),
+            ImportCollectionOptions {
+                max_line_bytes: 1024,
+            },
+        )
+        .await
+}
@@ -1,5 +1,6 @@
 use crate::collection::methods::commit_generation::CommitGenerationOptions;
 use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::errors::CollectionMethodError;
 use crate::collection::methods::get::{CollectionGetOk, CollectionGetOptions};
 use crate::collection::methods::put::CollectionPutManyOptions;
 use crate::collection::methods::start_generation::StartGenerationOptions;
@@ -27,7 +28,13 @@ async fn garbage_collector_test_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -61,6 +68,7 @@ async fn garbage_collector_test_inner() {
                         b"42".to_vec().into_boxed_slice(),
                     )),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice(b"3".to_vec().into_boxed_slice())
@@ -69,6 +77,7 @@ async fn garbage_collector_test_inner() {
                         b"42".to_vec().into_boxed_slice(),
                     )),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(first_generation_id.clone()),
@@ -123,6 +132,7 @@ async fn garbage_collector_test_inner() {
                         b"13".to_vec().into_boxed_slice(),
                     )),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice(b"2".to_vec().into_boxed_slice())
@@ -131,6 +141,7 @@ async fn garbage_collector_test_inner() {
                         b"42".to_vec().into_boxed_slice(),
                     )),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(second_generation_id.clone()),
@@ -204,31 +215,22 @@ async fn garbage_collector_test_inner() {
         })
     );
 
-    let CollectionGetOk { item, .. } = collection
-        .get(CollectionGetOptions {
-            key: OwnedCollectionKey::from_boxed_slice(b"1".to_vec().into_boxed_slice()).unwrap(),
-            generation_id: Some(first_generation_id.clone()),
-            phantom_id: None,
-        })
-        .await
-        .unwrap();
-
-    assert_eq!(item, None);
-
-    let CollectionGetOk { item, .. } = collection
+    // First generation is collected, it cannot be read anymore
+    let result = collection
         .get(CollectionGetOptions {
             key: OwnedCollectionKey::from_boxed_slice(b"3".to_vec().into_boxed_slice()).unwrap(),
             generation_id: Some(first_generation_id.clone()),
             phantom_id: None,
         })
-        .await
-        .unwrap();
+        .await;
 
-    assert_eq!(
-        item,
-        Some(KeyValue {
-            key: OwnedCollectionKey::from_boxed_slice(b"3".to_vec().into_boxed_slice()).unwrap(),
-            value: OwnedCollectionValue::from_boxed_slice(b"42".to_vec().into_boxed_slice(),),
-        })
-    );
+    let Err(CollectionMethodError::GenerationIdLessThanMinimum(minimum_generation_id)) = result
+    else {
+        panic!(
+            "expected GenerationIdLessThanMinimum error, got {:?}",
+            result
+        );
+    };
+
+    assert_eq!(minimum_generation_id, second_generation_id);
 }
@@ -0,0 +1,187 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::Collection;
+use crate::common::{
+    IsByteArray, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::http::routing::response::Response;
+use crate::http::routing::routes::generation::events::{
+    create_generation_events_response, GenerationEventJsonData,
+};
+use crate::tests::temp_database::TempDatabase;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use hyper::body::HttpBody;
+use hyper::Body;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+#[test]
+fn generation_events_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(generation_events_test_inner());
+}
+
+async fn generation_events_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let mut collections = Vec::new();
+
+    for name in ["first", "second"] {
+        let collection = database
+            .create_collection(
+                name,
+                CreateCollectionOptions {
+                    is_manual: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        collections.push((name.to_string(), collection));
+    }
+
+    let second = collections[1].1.clone();
+
+    let response = create_generation_events_response(collections);
+    let Response::Stream(response) = response else {
+        panic!("not a stream response");
+    };
+    let mut body = response.body;
+
+    // Current generations first
+    let mut events = read_events(&mut body).await;
+    if events.len() < 2 {
+        events.extend(read_events(&mut body).await);
+    }
+
+    let names: Vec<&str> = events
+        .iter()
+        .map(|event| event.collection_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["first", "second"]);
+
+    commit_generation(&second, b"1", 3).await;
+
+    let mut events = read_events(&mut body).await;
+    assert_eq!(events.len(), 1);
+
+    let event = events.pop().unwrap();
+    assert_eq!(event.collection_name, "second");
+    assert_eq!(
+        event
+            .generation_id
+            .into_generation_id()
+            .unwrap()
+            .get_byte_array(),
+        b"1"
+    );
+    assert!(event.next_generation_id.is_none());
+    assert_eq!(event.changed_keys, 3);
+
+    // Every commit is reported even if several of them were made before the events were sent.
+    // Unread event of the first collection fills the body channel, so the writer is blocked
+    // after the commit of "2" until the body is read
+    let first = database.get_collection("first").await.unwrap();
+    commit_generation(&first, b"1", 1).await;
+    sleep(Duration::from_millis(100)).await;
+
+    commit_generation(&second, b"2", 2).await;
+    sleep(Duration::from_millis(100)).await;
+
+    commit_generation(&second, b"3", 1).await;
+    commit_generation(&second, b"4", 1).await;
+
+    let mut events = Vec::new();
+    while events.len() < 4 {
+        events.extend(read_events(&mut body).await);
+    }
+
+    let generations: Vec<(String, Vec<u8>, u32)> = events
+        .into_iter()
+        .map(|event| {
+            let generation_id = event.generation_id.into_generation_id().unwrap();
+
+            (
+                event.collection_name,
+                generation_id.get_byte_array().to_vec(),
+                event.changed_keys,
+            )
+        })
+        .collect();
+    assert_eq!(
+        generations,
+        vec![
+            ("first".to_string(), b"1".to_vec(), 1),
+            ("second".to_string(), b"2".to_vec(), 2),
+            ("second".to_string(), b"3".to_vec(), 1),
+            ("second".to_string(), b"4".to_vec(), 1),
+        ]
+    );
+}
+
+async fn read_events(body: &mut Body) -> Vec<GenerationEventJsonData> {
+    let chunk = timeout(Duration::from_secs(5), body.data())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let text = std::str::from_utf8(&chunk).unwrap();
+
+    text.split("\n\n")
+        .filter(|event| !event.is_empty())
+        .map(|event| {
+            let mut lines = event.lines();
+            assert_eq!(lines.next(), Some("event: generation"));
+
+            let data = lines.next().unwrap().strip_prefix("data: ").unwrap();
+            serde_json::from_str(data).unwrap()
+        })
+        .collect()
+}
+
+async fn commit_generation(collection: &Collection, generation_id: &[u8], puts: u8) {
+    let generation_id =
+        OwnedGenerationId::from_boxed_slice(generation_id.to_vec().into_boxed_slice()).unwrap();
+
+    collection
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id.clone(),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+
+    let items = (0..puts)
+        .map(|i| {
+            KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: OwnedCollectionKey::from_boxed_slice(vec![i].into_boxed_slice()).unwrap(),
+                value: Some(OwnedCollectionValue::new(&[i])),
+                if_not_present: false,
+                if_value_changed: None,
+            })
+        })
+        .collect();
+
+    collection
+        .put_many(CollectionPutManyOptions {
+            items,
+            generation_id: Some(generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .commit_generation(CommitGenerationOptions {
+            generation_id,
+            update_readers: None,
+        })
+        .await
+        .unwrap();
+}
@@ -1,3 +1,4 @@
 mod basic;
+mod not_existing_key;
 mod phantom;
 mod phantom_start;
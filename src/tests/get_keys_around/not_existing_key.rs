@@ -0,0 +1,199 @@
+use crate::collection::methods::get_keys_around::{
+    CollectionGetKeysAroundOk, CollectionGetKeysAroundOptions,
+};
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::Collection;
+use crate::common::{
+    IsByteArray, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId, OwnedPhantomId,
+};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::util::manual_generation::wrap_generation;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+
+#[test]
+fn get_keys_around_not_existing_key_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(get_keys_around_not_existing_key_inner());
+}
+
+async fn get_keys_around_not_existing_key_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let first_generation_id = OwnedGenerationId::from_boxed_slice((b"1" as &[u8]).into()).unwrap();
+    let second_generation_id = OwnedGenerationId::from_boxed_slice((b"2" as &[u8]).into()).unwrap();
+
+    wrap_generation(&collection, first_generation_id.as_ref(), async {
+        put(
+            &collection,
+            &first_generation_id,
+            None,
+            &[(b"0", true), (b"2", true), (b"4", true), (b"6", true)],
+        )
+        .await;
+    })
+    .await;
+
+    let phantom_id = collection.start_phantom().await.unwrap();
+
+    wrap_generation(&collection, second_generation_id.as_ref(), async {
+        put(
+            &collection,
+            &second_generation_id,
+            None,
+            &[(b"4", false), (b"5", true)],
+        )
+        .await;
+
+        put(
+            &collection,
+            &second_generation_id,
+            Some(phantom_id.clone()),
+            &[(b"3", true)],
+        )
+        .await;
+    })
+    .await;
+
+    // Key between existing ones
+    let result = keys_around(&collection, b"3", Some(&second_generation_id), None, 10).await;
+    assert_keys_around(result, false, &[b"2", b"0"], false, &[b"5", b"6"], false);
+
+    // Older generation is respected
+    let result = keys_around(&collection, b"3", Some(&first_generation_id), None, 10).await;
+    assert_keys_around(result, false, &[b"2", b"0"], false, &[b"4", b"6"], false);
+
+    // Deleted key is not present
+    let result = keys_around(&collection, b"4", None, None, 10).await;
+    assert_keys_around(result, false, &[b"2", b"0"], false, &[b"5", b"6"], false);
+
+    // Existing key is found
+    let result = keys_around(&collection, b"2", None, None, 10).await;
+    assert_keys_around(result, true, &[b"0"], false, &[b"5", b"6"], false);
+
+    // Before the first key
+    let result = keys_around(&collection, b"/", None, None, 2).await;
+    assert_keys_around(result, false, &[], false, &[b"0", b"2"], true);
+
+    // After the last key
+    let result = keys_around(&collection, b"7", None, None, 2).await;
+    assert_keys_around(result, false, &[b"6", b"5"], true, &[], false);
+
+    // Phantom records are visible only with phantom
+    let result = keys_around(&collection, b"31", None, Some(&phantom_id), 10).await;
+    assert_keys_around(
+        result,
+        false,
+        &[b"3", b"2", b"0"],
+        false,
+        &[b"5", b"6"],
+        false,
+    );
+
+    let result = keys_around(&collection, b"3", None, Some(&phantom_id), 10).await;
+    assert_keys_around(result, true, &[b"2", b"0"], false, &[b"5", b"6"], false);
+
+    // Without `require_key_existance: false` absent key is an error
+    let result = collection
+        .get_keys_around(CollectionGetKeysAroundOptions {
+            key: key(b"3"),
+            generation_id: None,
+            phantom_id: None,
+            require_key_existance: true,
+            limit: 10,
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+fn key(bytes: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(bytes.into()).unwrap()
+}
+
+async fn put(
+    collection: &Collection,
+    generation_id: &OwnedGenerationId,
+    phantom_id: Option<OwnedPhantomId>,
+    items: &[(&[u8], bool)],
+) {
+    let items = items
+        .iter()
+        .map(|(key_bytes, is_present)| {
+            KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(key_bytes),
+                value: if *is_present {
+                    Some(OwnedCollectionValue::new(b"value"))
+                } else {
+                    None
+                },
+                if_not_present: false,
+                if_value_changed: None,
+            })
+        })
+        .collect();
+
+    collection
+        .put_many(CollectionPutManyOptions {
+            items,
+            generation_id: Some(generation_id.clone()),
+            phantom_id,
+        })
+        .await
+        .unwrap();
+}
+
+async fn keys_around(
+    collection: &Collection,
+    key_bytes: &[u8],
+    generation_id: Option<&OwnedGenerationId>,
+    phantom_id: Option<&OwnedPhantomId>,
+    limit: usize,
+) -> CollectionGetKeysAroundOk {
+    collection
+        .get_keys_around(CollectionGetKeysAroundOptions {
+            key: key(key_bytes),
+            generation_id: generation_id.cloned(),
+            phantom_id: phantom_id.cloned(),
+            require_key_existance: false,
+            limit,
+        })
+        .await
+        .unwrap()
+}
+
+fn assert_keys_around(
+    result: CollectionGetKeysAroundOk,
+    expected_found_key: bool,
+    expected_left: &[&[u8]],
+    expected_has_more_on_the_left: bool,
+    expected_right: &[&[u8]],
+    expected_has_more_on_the_right: bool,
+) {
+    let to_bytes = |keys: Vec<OwnedCollectionKey>| -> Vec<Vec<u8>> {
+        keys.into_iter()
+            .map(|key| key.get_byte_array().to_vec())
+            .collect()
+    };
+    let expected_to_bytes =
+        |keys: &[&[u8]]| -> Vec<Vec<u8>> { keys.iter().map(|key| key.to_vec()).collect() };
+
+    assert_eq!(result.found_key, expected_found_key);
+    assert_eq!(to_bytes(result.left), expected_to_bytes(expected_left));
+    assert_eq!(result.has_more_on_the_left, expected_has_more_on_the_left);
+    assert_eq!(to_bytes(result.right), expected_to_bytes(expected_right));
+    assert_eq!(result.has_more_on_the_right, expected_has_more_on_the_right);
+}
@@ -24,7 +24,13 @@ async fn get_keys_around_phantom_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -45,21 +51,25 @@ async fn get_keys_around_phantom_inner() {
                     key: OwnedCollectionKey::from_boxed_slice((b"0" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"2" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"3" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"6" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(first_generation_id.clone()),
@@ -77,21 +87,25 @@ async fn get_keys_around_phantom_inner() {
                     key: OwnedCollectionKey::from_boxed_slice((b"1" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"3" as &[u8]).into()).unwrap(),
                     value: None,
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"4" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"5" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(first_generation_id.clone()),
@@ -108,6 +122,7 @@ async fn get_keys_around_phantom_inner() {
                 key: OwnedCollectionKey::from_boxed_slice((b"6" as &[u8]).into()).unwrap(),
                 value: Some(OwnedCollectionValue::new(b"")),
                 if_not_present: false,
+                if_value_changed: None,
             })],
             generation_id: Some(first_generation_id.clone()),
             phantom_id: Some(phantom_id_second),
@@ -140,8 +155,11 @@ async fn get_keys_around_phantom_inner() {
         right,
         has_more_on_the_left,
         has_more_on_the_right,
+        found_key,
     } = result;
 
+    assert!(found_key);
+
     // Non-phantom get_keys_around should not see phantom records, but only own phantoms
     assert_eq!(&generation_id, &first_generation_id);
     assert_eq!(
@@ -176,8 +194,11 @@ async fn get_keys_around_phantom_inner() {
         right,
         has_more_on_the_left,
         has_more_on_the_right,
+        found_key,
     } = result;
 
+    assert!(found_key);
+
     assert_eq!(&generation_id, &first_generation_id);
     assert_eq!(
         left,
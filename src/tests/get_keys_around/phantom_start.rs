@@ -25,7 +25,13 @@ async fn get_keys_around_phantom_start_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -46,16 +52,19 @@ async fn get_keys_around_phantom_start_inner() {
                     key: OwnedCollectionKey::from_boxed_slice((b"1" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"2" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"3" as &[u8]).into()).unwrap(),
                     value: Some(OwnedCollectionValue::new(b"")),
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(first_generation_id.clone()),
@@ -89,11 +98,13 @@ async fn get_keys_around_phantom_start_inner() {
                     key: OwnedCollectionKey::from_boxed_slice((b"1" as &[u8]).into()).unwrap(),
                     value: None,
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice((b"3" as &[u8]).into()).unwrap(),
                     value: None,
                     if_not_present: false,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(second_generation_id.clone()),
@@ -129,8 +140,11 @@ async fn get_keys_around_phantom_start_inner() {
         right,
         has_more_on_the_left,
         has_more_on_the_right,
+        found_key,
     } = result;
 
+    assert!(found_key);
+
     // Non-phantom get_keys_around should not see phantom records, but only own phantoms
     assert_eq!(&generation_id, &first_generation_id);
     assert_eq!(left, vec![]);
@@ -161,8 +175,11 @@ async fn get_keys_around_phantom_start_inner() {
         right,
         has_more_on_the_left,
         has_more_on_the_right,
+        found_key,
     } = result;
 
+    assert!(found_key);
+
     // Non-phantom get_keys_around should not see phantom records, but only own phantoms
     assert_eq!(&generation_id, &first_generation_id);
     assert_eq!(
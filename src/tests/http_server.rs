@@ -0,0 +1,245 @@
+use crate::config::Config;
+use crate::context::Context;
+use crate::database::open::DatabaseOpenOptions;
+use crate::database::Database;
+use crate::http::config::{HttpConfig, ListenAddress, TlsConfig};
+use crate::http::routing::register_routes::register_routes;
+use crate::http::routing::Routing;
+use crate::http::server::{start_http_server, StartHttpServerError};
+use crate::http::tls::TlsLoadError;
+use crate::tests::temp_dir::TempDir;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpStream, UnixStream};
+use tokio::time::sleep;
+use tokio_rustls::rustls::{Certificate, ClientConfig, RootCertStore, ServerName};
+use tokio_rustls::TlsConnector;
+
+const REQUEST: &[u8] =
+    b"POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+#[test]
+fn tls_and_unix_socket_listeners_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(tls_and_unix_socket_listeners_test_inner());
+}
+
+async fn tls_and_unix_socket_listeners_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.get_path_buf();
+
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let cert_path = path.join("cert.pem");
+    let key_path = path.join("key.pem");
+    std::fs::write(&cert_path, cert.serialize_pem().unwrap()).unwrap();
+    std::fs::write(&key_path, cert.serialize_private_key_pem()).unwrap();
+
+    // Port is released right away, so there is a small chance that it will be taken
+    let tcp_address = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let socket_path = path.join("diffbelt.sock");
+
+    let context = create_context(
+        path.join("data"),
+        HttpConfig {
+            listen_addresses: vec![
+                ListenAddress::Tcp(tcp_address),
+                ListenAddress::Unix(socket_path.clone()),
+            ],
+            tls: Some(TlsConfig {
+                cert_path,
+                key_path,
+            }),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let server = tokio::spawn(start_http_server(context));
+
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(&Certificate(cert.serialize_der().unwrap()))
+        .unwrap();
+
+    let connector = TlsConnector::from(Arc::new(
+        ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth(),
+    ));
+
+    let tcp_stream = connect_tcp(tcp_address).await;
+    let tls_stream = connector
+        .connect(ServerName::try_from("localhost").unwrap(), tcp_stream)
+        .await
+        .unwrap();
+
+    let response = send_request(tls_stream).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with("Hello, World!"));
+
+    // TLS is not optional for TCP listeners
+    let response = send_request(connect_tcp(tcp_address).await).await;
+    assert!(!response.starts_with("HTTP/1.1 200"));
+
+    // Unix socket is served without TLS
+    let unix_stream = UnixStream::connect(&socket_path).await.unwrap();
+    let response = send_request(unix_stream).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with("Hello, World!"));
+
+    server.abort();
+}
+
+#[test]
+fn http_server_start_errors_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(http_server_start_errors_test_inner());
+}
+
+async fn http_server_start_errors_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.get_path_buf();
+
+    let key_path = path.join("key.pem");
+    std::fs::write(&key_path, "not a key").unwrap();
+
+    let context = create_context(
+        path.join("data"),
+        HttpConfig {
+            listen_addresses: vec![ListenAddress::Unix(path.join("diffbelt.sock"))],
+            tls: Some(TlsConfig {
+                cert_path: path.join("cert.pem"),
+                key_path,
+            }),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let result = start_http_server(context).await;
+    assert!(matches!(
+        result,
+        Err(StartHttpServerError::Tls(TlsLoadError::Io(_, _)))
+    ));
+
+    let taken = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = taken.local_addr().unwrap();
+
+    let context = create_context(
+        path.join("data2"),
+        HttpConfig {
+            listen_addresses: vec![ListenAddress::Tcp(address)],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let result = start_http_server(context).await;
+    assert!(matches!(
+        result,
+        Err(StartHttpServerError::Bind(ListenAddress::Tcp(err_address), _)) if err_address == address
+    ));
+}
+
+pub(super) async fn create_context(data_path: PathBuf, http: HttpConfig) -> Arc<Context> {
+    let config = Arc::new(Config {
+        data_path,
+        is_clear: false,
+        backup_path: None,
+        http,
+        database: Arc::new(Default::default()),
+        log: Default::default(),
+        warnings: Vec::new(),
+    });
+
+    let database = Database::open(DatabaseOpenOptions {
+        data_path: &config.data_path,
+        config: config.database.clone(),
+    })
+    .await
+    .expect("Cannot open database");
+
+    let mut context = Context {
+        config,
+        routing: Routing::new(),
+        database: Arc::new(database),
+        http_metrics: Default::default(),
+        logging: Default::default(),
+    };
+
+    register_routes(&mut context);
+
+    Arc::new(context)
+}
+
+async fn connect_tcp(address: SocketAddr) -> TcpStream {
+    for _ in 0..100 {
+        if let Ok(stream) = TcpStream::connect(address).await {
+            return stream;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    panic!("server has not started");
+}
+
+async fn send_request<S: AsyncRead + AsyncWrite + Unpin>(mut stream: S) -> String {
+    stream.write_all(REQUEST).await.unwrap();
+
+    let mut response = Vec::new();
+    // Plain HTTP request to the TLS listener is closed with an error
+    let _ = stream.read_to_end(&mut response).await;
+
+    String::from_utf8_lossy(&response).to_string()
+}
+
+pub(super) async fn send_unix_request(
+    socket_path: &Path,
+    method: &str,
+    path: &str,
+    token: Option<&str>,
+    body: &str,
+) -> String {
+    let mut stream = connect_unix(socket_path).await;
+
+    let authorization = token
+        .map(|token| format!("Authorization: Bearer {}\r\n", token))
+        .unwrap_or_default();
+
+    let request = format!(
+        "{} {} HTTP/1.1\r\nHost: localhost\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method,
+        path,
+        authorization,
+        body.len(),
+        body
+    );
+
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+
+    String::from_utf8(response).unwrap()
+}
+
+pub(super) async fn connect_unix(socket_path: &Path) -> UnixStream {
+    for _ in 0..100 {
+        if let Ok(stream) = UnixStream::connect(socket_path).await {
+            return stream;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    panic!("server has not started");
+}
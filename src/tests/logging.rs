@@ -0,0 +1,156 @@
+use crate::http::config::{HttpConfig, ListenAddress};
+use crate::http::request_id::{get_or_generate_request_id, is_valid_request_id, REQUEST_ID_HEADER};
+use crate::http::server::start_http_server;
+use crate::logging::config::LogConfig;
+use crate::logging::{Logging, LoggingError};
+use crate::tests::http_server::{connect_unix, create_context, send_unix_request};
+use crate::tests::temp_dir::TempDir;
+use crate::util::async_task_thread::{AsyncTaskThread, TaskPoller};
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use hyper::http::HeaderValue;
+use hyper::HeaderMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::oneshot;
+use tracing::span::Id;
+use tracing::{Instrument, Level};
+
+#[test]
+fn request_id_test() {
+    assert!(is_valid_request_id("abc-123_4.5:6"));
+    assert!(!is_valid_request_id(""));
+    assert!(!is_valid_request_id("with space"));
+    assert!(!is_valid_request_id(&"a".repeat(129)));
+
+    let mut headers = HeaderMap::new();
+
+    let generated = get_or_generate_request_id(&headers);
+    assert!(is_valid_request_id(&generated));
+    assert_ne!(generated, get_or_generate_request_id(&headers));
+
+    headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("from-proxy"));
+    assert_eq!(get_or_generate_request_id(&headers), "from-proxy");
+
+    headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("{bad}"));
+    assert_ne!(get_or_generate_request_id(&headers), "{bad}");
+}
+
+#[test]
+fn logging_filter_test() {
+    let (logging, subscriber) = Logging::new(&LogConfig::default()).unwrap();
+
+    tracing::subscriber::with_default(subscriber, || {
+        assert!(tracing::enabled!(Level::INFO));
+        assert!(!tracing::enabled!(Level::DEBUG));
+
+        logging.set_filter("debug").unwrap();
+
+        assert!(tracing::enabled!(Level::DEBUG));
+        assert_eq!(logging.filter(), "debug");
+
+        assert!(matches!(
+            logging.set_filter("diffbelt=loud"),
+            Err(LoggingError::InvalidFilter(_))
+        ));
+        assert!(matches!(
+            logging.set_filter(" "),
+            Err(LoggingError::InvalidFilter(_))
+        ));
+        assert_eq!(logging.filter(), "debug");
+    });
+}
+
+#[test]
+fn task_thread_span_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry());
+
+    runtime.block_on(async {
+        let (sender, receiver) = oneshot::channel::<Option<Id>>();
+
+        let thread = AsyncTaskThread::new(
+            |sender: oneshot::Sender<Option<Id>>, mut poller: TaskPoller<()>| async move {
+                let span_id = poller.poll().await.and_then(|((), span)| span.id());
+                sender.send(span_id).unwrap_or(());
+            },
+            sender,
+            "test",
+        )
+        .await;
+
+        let span = tracing::info_span!("request");
+
+        thread.add_task(()).instrument(span.clone()).await;
+
+        let span_id = receiver.await.unwrap();
+        assert!(span_id.is_some());
+        assert_eq!(span_id, span.id());
+    });
+}
+
+#[test]
+fn log_route_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(log_route_test_inner());
+}
+
+async fn log_route_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.get_path_buf();
+    let socket_path = path.join("diffbelt.sock");
+
+    let context = create_context(
+        path.join("data"),
+        HttpConfig {
+            listen_addresses: vec![ListenAddress::Unix(socket_path.clone())],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let server = tokio::spawn(start_http_server(context));
+
+    let response = send_unix_request(&socket_path, "GET", "/admin/log", None, "").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("\r\nx-request-id: "));
+    assert!(response.ends_with("{\"filter\":\"info\"}"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/admin/log",
+        None,
+        "{\"filter\":\"info,diffbelt::raw_db=debug\"}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/admin/log",
+        None,
+        "{\"filter\":\"diffbelt=loud\"}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+
+    let response = send_unix_request(&socket_path, "GET", "/admin/log", None, "").await;
+    assert!(response.ends_with("{\"filter\":\"info,diffbelt::raw_db=debug\"}"));
+
+    // Id from the client is returned back
+    let mut stream = connect_unix(&socket_path).await;
+    stream
+        .write_all(
+            b"GET /admin/log HTTP/1.1\r\nHost: localhost\r\nX-Request-Id: client-id-1\r\nConnection: close\r\n\r\n",
+        )
+        .await
+        .unwrap();
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+    let response = String::from_utf8_lossy(&response);
+
+    assert!(response.contains("\r\nx-request-id: client-id-1\r\n"));
+
+    server.abort();
+}
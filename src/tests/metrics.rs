@@ -0,0 +1,173 @@
+use crate::http::config::{HttpConfig, ListenAddress};
+use crate::http::server::start_http_server;
+use crate::tests::http_server::{create_context, send_unix_request};
+use crate::tests::temp_dir::TempDir;
+use crate::util::prometheus::PrometheusWriter;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::path::Path;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[test]
+fn prometheus_writer_test() {
+    let mut writer = PrometheusWriter::default();
+
+    writer.metric("requests_total", "counter", "Requests");
+    writer.sample("requests_total", &[], 3);
+    writer.sample(
+        "requests_total",
+        &[("route", "/a"), ("name", "quote\" slash\\ line\n")],
+        1.5,
+    );
+
+    assert_eq!(
+        writer.finish(),
+        "# HELP requests_total Requests\n\
+         # TYPE requests_total counter\n\
+         requests_total 3\n\
+         requests_total{route=\"/a\",name=\"quote\\\" slash\\\\ line\\n\"} 1.5\n"
+    );
+}
+
+#[test]
+fn metrics_route_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(metrics_route_test_inner());
+}
+
+async fn metrics_route_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.get_path_buf();
+    let socket_path = path.join("diffbelt.sock");
+
+    let context = create_context(
+        path.join("data"),
+        HttpConfig {
+            listen_addresses: vec![ListenAddress::Unix(socket_path.clone())],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let server = tokio::spawn(start_http_server(context));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/collections/",
+        None,
+        "{\"collectionName\":\"c\",\"isManual\":false}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/collections/c/readers/",
+        None,
+        "{\"readerName\":\"r\",\"generationId\":null}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+    let response = send_unix_request(
+        &socket_path,
+        "POST",
+        "/collections/c/put",
+        None,
+        "{\"item\":{\"key\":{\"value\":\"a\"},\"value\":{\"value\":\"b\"}}}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+    let response = send_unix_request(&socket_path, "GET", "/unknown", None, "").await;
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    // Generation is committed after `commitDelayMs`
+    let metrics = wait_for_metric(
+        &socket_path,
+        "diffbelt_collection_commits_total{collection=\"c\"} 1\n",
+    )
+    .await;
+
+    assert!(response_has_header(
+        &metrics,
+        "content-type: text/plain; version=0.0.4; charset=utf-8"
+    ));
+
+    for line in [
+        "diffbelt_http_requests_total{route=\"/collections/\",status=\"200\"} 1\n",
+        "diffbelt_http_requests_total{route=\"/collections/:id/put\",status=\"200\"} 1\n",
+        "diffbelt_http_requests_total{route=\"unknown\",status=\"404\"} 1\n",
+        "diffbelt_http_errors_total{route=\"unknown\",error=\"not_found\"} 1\n",
+        "diffbelt_http_request_duration_seconds_bucket{route=\"/collections/:id/put\",le=\"+Inf\"} 1\n",
+        "diffbelt_http_request_duration_seconds_count{route=\"/collections/:id/put\"} 1\n",
+        "diffbelt_collection_generation_id{collection=\"c\"} 1\n",
+        "diffbelt_collection_next_generation_size{collection=\"c\"} 0\n",
+        "diffbelt_collection_cursors{collection=\"c\",type=\"query\"} 0\n",
+        "diffbelt_collection_cursors{collection=\"c\",type=\"diff\"} 0\n",
+        "diffbelt_reader_lag_generations{collection=\"c\",reader=\"r\",target_collection=\"c\"} 1\n",
+        "diffbelt_gc_lag_generations{collection=\"c\"} 0\n",
+        "# TYPE diffbelt_rocksdb_estimate_num_keys gauge\n",
+    ] {
+        assert!(metrics.contains(line), "{} is missing in\n{}", line, metrics);
+    }
+
+    assert!(metrics.contains("diffbelt_rocksdb_sst_files_bytes{collection=\"c\"} "));
+
+    // Lag of manual collections is counted in generations, not in differences of ids
+    for (path, body) in [
+        (
+            "/collections/",
+            "{\"collectionName\":\"m\",\"isManual\":true,\"initialGenerationId\":{\"value\":\"\"}}",
+        ),
+        (
+            "/collections/m/readers/",
+            "{\"readerName\":\"r\",\"generationId\":null}",
+        ),
+        (
+            "/collections/m/generation/start",
+            "{\"generationId\":{\"value\":\"a\"}}",
+        ),
+        (
+            "/collections/m/put",
+            "{\"item\":{\"key\":{\"value\":\"a\"},\"value\":{\"value\":\"b\"}},\"generationId\":{\"value\":\"a\"}}",
+        ),
+        (
+            "/collections/m/generation/commit",
+            "{\"generationId\":{\"value\":\"a\"}}",
+        ),
+    ] {
+        let response = send_unix_request(&socket_path, "POST", path, None, body).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+    }
+
+    wait_for_metric(
+        &socket_path,
+        "diffbelt_reader_lag_generations{collection=\"m\",reader=\"r\",target_collection=\"m\"} 1\n",
+    )
+    .await;
+
+    server.abort();
+}
+
+async fn wait_for_metric(socket_path: &Path, line: &str) -> String {
+    for _ in 0..100 {
+        let response = send_unix_request(socket_path, "GET", "/metrics", None, "").await;
+
+        if response.contains(line) {
+            return response;
+        }
+
+        sleep(Duration::from_millis(20)).await;
+    }
+
+    panic!("{} is not present in metrics", line);
+}
+
+fn response_has_header(response: &str, header: &str) -> bool {
+    let head = response.split("\r\n\r\n").next().unwrap();
+
+    head.split("\r\n").any(|line| line == header)
+}
@@ -0,0 +1,239 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::diff::DiffOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::get::CollectionGetOptions;
+use crate::collection::methods::get_keys_around::CollectionGetKeysAroundOptions;
+use crate::collection::methods::put::CollectionPutOptions;
+use crate::collection::methods::query::QueryOptions;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::methods::update_reader::UpdateReaderOptions;
+use crate::collection::{Collection, CommitGenerationUpdateReader};
+use crate::common::generation_id::GenerationIdSource;
+use crate::common::reader::ReaderDef;
+use crate::common::{
+    IsByteArray, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::ops::Bound;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[test]
+fn minimum_generation_id_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(minimum_generation_id_test_inner());
+}
+
+async fn minimum_generation_id_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let _: () = collection
+        .create_reader(CreateReaderOptions {
+            reader_name: "reader".to_string(),
+            collection_name: None,
+            generation_id: Some(OwnedGenerationId::empty()),
+        })
+        .await
+        .unwrap();
+
+    let first_generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0001".to_vec().into_boxed_slice()).unwrap();
+    let second_generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0002".to_vec().into_boxed_slice()).unwrap();
+
+    put_in_generation(&collection, &first_generation_id, None).await;
+    put_in_generation(
+        &collection,
+        &second_generation_id,
+        Some(vec![CommitGenerationUpdateReader {
+            reader_name: Arc::from("reader"),
+            generation_id: second_generation_id.clone(),
+        }]),
+    )
+    .await;
+
+    // Minimum generation id is updated asynchronously after the reader update
+    let mut attempts = 0;
+    loop {
+        let result = collection
+            .get(CollectionGetOptions {
+                key: key(b"a"),
+                generation_id: Some(first_generation_id.clone()),
+                phantom_id: None,
+            })
+            .await;
+
+        if result.is_err() {
+            assert_less_than_minimum(result, &second_generation_id);
+            break;
+        }
+
+        attempts += 1;
+        assert!(attempts < 100, "minimum generation id was not updated");
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    let result = collection
+        .query(QueryOptions {
+            generation_id: Some(first_generation_id.clone()),
+            phantom_id: None,
+            from_key: Bound::Unbounded,
+            to_key: Bound::Unbounded,
+            prefix: None,
+            reverse: false,
+        })
+        .await;
+    assert_less_than_minimum(result, &second_generation_id);
+
+    let result = collection
+        .get_keys_around(CollectionGetKeysAroundOptions {
+            key: key(b"a"),
+            generation_id: Some(first_generation_id.clone()),
+            phantom_id: None,
+            require_key_existance: false,
+            limit: 10,
+        })
+        .await;
+    assert_less_than_minimum(result, &second_generation_id);
+
+    let result = collection
+        .diff(DiffOptions {
+            from_generation_id: GenerationIdSource::Value(Some(first_generation_id.clone())),
+            to_generation_id_loose: None,
+            omit_intermediate_values: true,
+        })
+        .await;
+    assert_less_than_minimum(result, &second_generation_id);
+
+    let result = collection
+        .update_reader(UpdateReaderOptions {
+            reader_name: "reader".to_string(),
+            generation_id: Some(first_generation_id.clone()),
+        })
+        .await;
+    assert_less_than_minimum(result, &second_generation_id);
+
+    let result = collection
+        .create_reader(CreateReaderOptions {
+            reader_name: "another".to_string(),
+            collection_name: None,
+            generation_id: Some(first_generation_id.clone()),
+        })
+        .await;
+    assert_less_than_minimum(result, &second_generation_id);
+
+    // Minimum generation itself and current generation are readable
+    collection
+        .get(CollectionGetOptions {
+            key: key(b"a"),
+            generation_id: Some(second_generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .get(CollectionGetOptions {
+            key: key(b"a"),
+            generation_id: None,
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    // Diff from scratch or from the reader is not affected
+    collection
+        .diff(DiffOptions {
+            from_generation_id: GenerationIdSource::Value(None),
+            to_generation_id_loose: None,
+            omit_intermediate_values: true,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .diff(DiffOptions {
+            from_generation_id: GenerationIdSource::Reader(ReaderDef {
+                collection_name: None,
+                reader_name: "reader".to_string(),
+            }),
+            to_generation_id_loose: None,
+            omit_intermediate_values: true,
+        })
+        .await
+        .unwrap();
+}
+
+fn key(bytes: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(bytes.into()).unwrap()
+}
+
+async fn put_in_generation(
+    collection: &Collection,
+    generation_id: &OwnedGenerationId,
+    update_readers: Option<Vec<CommitGenerationUpdateReader>>,
+) {
+    collection
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id.clone(),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .put(CollectionPutOptions {
+            update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(b"a"),
+                value: Some(OwnedCollectionValue::new(generation_id.get_byte_array())),
+                if_not_present: false,
+                if_value_changed: None,
+            }),
+            generation_id: Some(generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .commit_generation(CommitGenerationOptions {
+            generation_id: generation_id.clone(),
+            update_readers,
+        })
+        .await
+        .unwrap();
+}
+
+fn assert_less_than_minimum<T>(
+    result: Result<T, CollectionMethodError>,
+    expected_minimum_generation_id: &OwnedGenerationId,
+) {
+    match result {
+        Err(CollectionMethodError::GenerationIdLessThanMinimum(minimum_generation_id)) => {
+            assert_eq!(&minimum_generation_id, expected_minimum_generation_id);
+        }
+        result => panic!(
+            "expected GenerationIdLessThanMinimum error, got {:?}",
+            result.err()
+        ),
+    }
+}
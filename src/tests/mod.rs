@@ -1,11 +1,29 @@
+mod auth;
+mod backup;
+mod collection_settings;
+mod config;
 mod database;
 mod delete_collection;
 mod diff;
+mod diff_intermediate_values;
+mod dump;
 mod garbage_collector;
+mod generation_events;
 mod get_keys_around;
+mod http_server;
+mod logging;
+mod metrics;
+mod minimum_generation_id;
+mod protobuf;
 mod put_many;
 mod query;
 mod readers;
+mod readers_status;
+mod rename_clone;
 mod temp_database;
 mod temp_dir;
+mod transaction;
+mod transform;
+mod trash;
 mod util;
+mod watch;
@@ -0,0 +1,58 @@
+use crate::collection::methods::query::QueryOk;
+use crate::common::{
+    IsByteArray, KeyValue, OwnedCollectionKey, OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::http::data::protobuf::{decode_key_value_update, decode_phantom_id};
+use crate::protos::http_api;
+use protobuf::Message;
+
+#[test]
+fn decode_key_value_update_test() {
+    let mut update = http_api::KeyValueUpdate::new();
+    update.key = b"key".to_vec();
+    update.value = Some(vec![0, 1, 2]);
+    update.if_value_changed = Some(true);
+
+    let bytes = update.write_to_bytes().unwrap();
+    let update = http_api::KeyValueUpdate::parse_from_bytes(&bytes).unwrap();
+    let update = decode_key_value_update(update).unwrap();
+
+    assert_eq!(update.key.get_byte_array(), b"key");
+    assert_eq!(update.value.unwrap().get_value(), &[0, 1, 2]);
+    assert!(!update.if_not_present);
+    assert_eq!(update.if_value_changed, Some(true));
+
+    let mut delete = http_api::KeyValueUpdate::new();
+    delete.key = b"key".to_vec();
+
+    let delete = decode_key_value_update(delete).unwrap();
+
+    assert!(delete.value.is_none());
+    assert_eq!(delete.if_value_changed, None);
+
+    assert!(decode_phantom_id(Vec::new()).is_err());
+}
+
+#[test]
+fn encode_query_response_test() {
+    let result = QueryOk {
+        generation_id: OwnedGenerationId::from_boxed_slice(b"gen".to_vec().into_boxed_slice())
+            .unwrap(),
+        items: vec![KeyValue {
+            key: OwnedCollectionKey::from_boxed_slice(b"a".to_vec().into_boxed_slice()).unwrap(),
+            value: OwnedCollectionValue::new(&[255, 0]),
+        }],
+        cursor_id: Some("cursor".into()),
+    };
+
+    let bytes = http_api::QueryResponse::from(result)
+        .write_to_bytes()
+        .unwrap();
+    let response = http_api::QueryResponse::parse_from_bytes(&bytes).unwrap();
+
+    assert_eq!(response.generation_id, b"gen");
+    assert_eq!(response.items.len(), 1);
+    assert_eq!(response.items[0].key, b"a");
+    assert_eq!(response.items[0].value, vec![255, 0]);
+    assert_eq!(response.cursor_id.as_deref(), Some("cursor"));
+}
@@ -21,7 +21,13 @@ async fn if_not_present_same_key_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -46,6 +52,7 @@ async fn if_not_present_same_key_inner() {
                         b"42".to_vec().into_boxed_slice(),
                     )),
                     if_not_present: true,
+                    if_value_changed: None,
                 }),
                 KeyValueUpdate::new(KeyValueUpdateNewOptions {
                     key: OwnedCollectionKey::from_boxed_slice(b"1".to_vec().into_boxed_slice())
@@ -54,6 +61,7 @@ async fn if_not_present_same_key_inner() {
                         b"13".to_vec().into_boxed_slice(),
                     )),
                     if_not_present: true,
+                    if_value_changed: None,
                 }),
             ],
             generation_id: Some(first_generation_id.clone()),
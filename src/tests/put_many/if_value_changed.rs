@@ -0,0 +1,277 @@
+use crate::collection::methods::diff::{DiffOk, DiffOptions};
+use crate::collection::methods::put::{
+    CollectionPutManyOk, CollectionPutManyOptions, CollectionPutOk, CollectionPutOptions,
+};
+use crate::collection::methods::query::{QueryOk, QueryOptions};
+use crate::collection::Collection;
+use crate::common::generation_id::GenerationIdSource;
+use crate::common::{
+    KeyValue, KeyValueDiff, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId, OwnedPhantomId,
+};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::util::manual_generation::wrap_generation;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::ops::Bound;
+
+#[test]
+fn if_value_changed_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(if_value_changed_inner());
+}
+
+async fn if_value_changed_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                if_value_changed: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let first_generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0001".to_vec().into_boxed_slice()).unwrap();
+    let second_generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0002".to_vec().into_boxed_slice()).unwrap();
+
+    let was_put = put_many(
+        &collection,
+        &first_generation_id,
+        vec![
+            update(b"a", Some(b"1"), None),
+            update(b"b", Some(b"1"), None),
+        ],
+    )
+    .await;
+
+    assert_eq!(was_put, vec![true, true]);
+
+    wrap_generation(&collection, second_generation_id.as_ref(), async {
+        let result = collection
+            .put_many(CollectionPutManyOptions {
+                items: vec![
+                    // Collection default is overridden
+                    update(b"a", Some(b"1"), Some(false)),
+                    update(b"b", Some(b"2"), None),
+                    // Deletion of absent key
+                    update(b"c", None, None),
+                ],
+                generation_id: Some(second_generation_id.clone()),
+                phantom_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.was_put, vec![true, true, false]);
+
+        let CollectionPutOk {
+            generation_id,
+            was_put,
+        } = collection
+            .put(CollectionPutOptions {
+                update: update(b"a", Some(b"1"), None),
+                generation_id: Some(second_generation_id.clone()),
+                phantom_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(!was_put);
+        // Generation of the current value
+        assert_eq!(generation_id, second_generation_id);
+
+        let CollectionPutOk {
+            generation_id,
+            was_put,
+        } = collection
+            .put(CollectionPutOptions {
+                update: update(b"d", Some(b"1"), None),
+                generation_id: Some(second_generation_id.clone()),
+                phantom_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(was_put);
+        assert_eq!(generation_id, second_generation_id);
+    })
+    .await;
+
+    let DiffOk { items, .. } = collection
+        .diff(DiffOptions {
+            from_generation_id: GenerationIdSource::Value(Some(first_generation_id.clone())),
+            to_generation_id_loose: None,
+            omit_intermediate_values: true,
+        })
+        .await
+        .unwrap();
+
+    // `c` was not written, `a` was written only because of explicit `if_value_changed: false`
+    assert_eq!(
+        items,
+        vec![
+            KeyValueDiff {
+                key: key(b"a"),
+                from_value: Some(OwnedCollectionValue::new(b"1")),
+                intermediate_values: vec![],
+                to_value: Some(OwnedCollectionValue::new(b"1")),
+            },
+            KeyValueDiff {
+                key: key(b"b"),
+                from_value: Some(OwnedCollectionValue::new(b"1")),
+                intermediate_values: vec![],
+                to_value: Some(OwnedCollectionValue::new(b"2")),
+            },
+            KeyValueDiff {
+                key: key(b"d"),
+                from_value: None,
+                intermediate_values: vec![],
+                to_value: Some(OwnedCollectionValue::new(b"1")),
+            },
+        ]
+    );
+}
+
+#[test]
+fn if_value_changed_phantom_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(if_value_changed_phantom_inner());
+}
+
+async fn if_value_changed_phantom_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                if_value_changed: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0001".to_vec().into_boxed_slice()).unwrap();
+
+    let was_put = put_many(
+        &collection,
+        &generation_id,
+        vec![
+            update(b"a", Some(b"1"), None),
+            update(b"b", Some(b"1"), None),
+        ],
+    )
+    .await;
+
+    assert_eq!(was_put, vec![true, true]);
+
+    let phantom_id = collection.start_phantom().await.unwrap();
+
+    let put_phantom = |update: KeyValueUpdate| {
+        collection.put(CollectionPutOptions {
+            update,
+            generation_id: Some(generation_id.clone()),
+            phantom_id: Some(phantom_id.clone()),
+        })
+    };
+
+    // Existing key is visible for the phantom
+    let CollectionPutOk { was_put, .. } = put_phantom(update(b"a", None, None)).await.unwrap();
+    assert!(was_put);
+
+    // Now the phantom sees its own deletion
+    let CollectionPutOk { was_put, .. } = put_phantom(update(b"a", None, None)).await.unwrap();
+    assert!(!was_put);
+
+    let CollectionPutOk { was_put, .. } =
+        put_phantom(update(b"b", Some(b"1"), None)).await.unwrap();
+    assert!(!was_put);
+
+    let items = query_phantom(&collection, &generation_id, &phantom_id).await;
+    assert_eq!(
+        items,
+        vec![KeyValue {
+            key: key(b"b"),
+            value: OwnedCollectionValue::new(b"1"),
+        }]
+    );
+
+    // Real records are not changed by the phantom
+    let items = query_phantom(&collection, &generation_id, &OwnedPhantomId::zero_64bits()).await;
+    assert_eq!(items.len(), 2);
+}
+
+async fn query_phantom(
+    collection: &Collection,
+    generation_id: &OwnedGenerationId,
+    phantom_id: &OwnedPhantomId,
+) -> Vec<KeyValue> {
+    let QueryOk { items, .. } = collection
+        .query(QueryOptions {
+            generation_id: Some(generation_id.clone()),
+            phantom_id: Some(phantom_id.clone()),
+            from_key: Bound::Unbounded,
+            to_key: Bound::Unbounded,
+            prefix: None,
+            reverse: false,
+        })
+        .await
+        .unwrap();
+
+    items
+}
+
+fn key(bytes: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(bytes.into()).unwrap()
+}
+
+fn update(
+    key_bytes: &[u8],
+    value: Option<&[u8]>,
+    if_value_changed: Option<bool>,
+) -> KeyValueUpdate {
+    KeyValueUpdate::new(KeyValueUpdateNewOptions {
+        key: key(key_bytes),
+        value: value.map(OwnedCollectionValue::new),
+        if_not_present: false,
+        if_value_changed,
+    })
+}
+
+async fn put_many(
+    collection: &Collection,
+    generation_id: &OwnedGenerationId,
+    items: Vec<KeyValueUpdate>,
+) -> Vec<bool> {
+    let mut result = Vec::new();
+
+    wrap_generation(collection, generation_id.as_ref(), async {
+        let CollectionPutManyOk { was_put, .. } = collection
+            .put_many(CollectionPutManyOptions {
+                items,
+                generation_id: Some(generation_id.clone()),
+                phantom_id: None,
+            })
+            .await
+            .unwrap();
+
+        result = was_put;
+    })
+    .await;
+
+    result
+}
@@ -1 +1,2 @@
 mod if_not_present_same_key;
+mod if_value_changed;
@@ -11,6 +11,7 @@ use crate::database::create_collection::CreateCollectionOptions;
 use crate::tests::temp_database::TempDatabase;
 use crate::util::tokio_runtime::create_main_tokio_runtime;
 use std::collections::BTreeMap;
+use std::ops::Bound;
 
 use futures::future::BoxFuture;
 
@@ -26,7 +27,13 @@ async fn query_test_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -51,6 +58,7 @@ async fn query_test_inner() {
             key: OwnedCollectionKey::from_boxed_slice((&key as &[u8]).into()).unwrap(),
             value: Some(OwnedCollectionValue::new(&value)),
             if_not_present: false,
+            if_value_changed: None,
         }));
     }
 
@@ -108,6 +116,7 @@ async fn query_test_inner() {
             key: OwnedCollectionKey::from_boxed_slice((&key as &[u8]).into()).unwrap(),
             value: Some(OwnedCollectionValue::new(&value)),
             if_not_present: false,
+            if_value_changed: None,
         }));
     }
 
@@ -119,6 +128,7 @@ async fn query_test_inner() {
             key: OwnedCollectionKey::from_boxed_slice((&key as &[u8]).into()).unwrap(),
             value: None,
             if_not_present: false,
+            if_value_changed: None,
         }));
     }
 
@@ -131,6 +141,7 @@ async fn query_test_inner() {
             key: OwnedCollectionKey::from_boxed_slice((&key as &[u8]).into()).unwrap(),
             value: Some(OwnedCollectionValue::new(&value)),
             if_not_present: false,
+            if_value_changed: None,
         }));
     }
 
@@ -252,6 +263,10 @@ fn assert_query_inner<'a>(
                 .query(QueryOptions {
                     generation_id: quering_generation_id.clone(),
                     phantom_id: None,
+                    from_key: Bound::Unbounded,
+                    to_key: Bound::Unbounded,
+                    prefix: None,
+                    reverse: false,
                 })
                 .await
                 .unwrap(),
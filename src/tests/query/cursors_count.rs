@@ -11,6 +11,7 @@ use crate::database::create_collection::CreateCollectionOptions;
 use crate::tests::temp_database::TempDatabase;
 use crate::util::tokio_runtime::create_main_tokio_runtime;
 use std::num::NonZeroUsize;
+use std::ops::Bound;
 
 use crate::database::config::DatabaseConfig;
 
@@ -31,7 +32,13 @@ async fn cursors_count_test_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("manual", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .unwrap();
 
@@ -45,6 +52,10 @@ async fn cursors_count_test_inner() {
             .query(QueryOptions {
                 generation_id: None,
                 phantom_id: None,
+                from_key: Bound::Unbounded,
+                to_key: Bound::Unbounded,
+                prefix: None,
+                reverse: false,
             })
             .await
             .unwrap();
@@ -61,6 +72,10 @@ async fn cursors_count_test_inner() {
             .query(QueryOptions {
                 generation_id: None,
                 phantom_id: None,
+                from_key: Bound::Unbounded,
+                to_key: Bound::Unbounded,
+                prefix: None,
+                reverse: false,
             })
             .await
             .unwrap();
@@ -95,6 +110,7 @@ async fn initialize(collection: &Collection) {
             key: OwnedCollectionKey::from_boxed_slice((&key as &[u8]).into()).unwrap(),
             value: Some(OwnedCollectionValue::new(&value)),
             if_not_present: false,
+            if_value_changed: None,
         }));
     }
 
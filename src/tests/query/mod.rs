@@ -1,2 +1,4 @@
 mod base;
 mod cursors_count;
+mod range;
+mod stream;
@@ -0,0 +1,240 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::methods::query::{QueryOk, QueryOptions, ReadQueryCursorOptions};
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::Collection;
+use crate::common::{
+    IsByteArray, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::database::config::DatabaseConfig;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+type RangeCase<'a> = (Bound<&'a [u8]>, Bound<&'a [u8]>, Option<&'a [u8]>);
+
+#[test]
+fn query_range_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(query_range_test_inner());
+}
+
+async fn query_range_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        // To check that continuations are respecting range
+        query_pack_limit: 3,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let mut state = BTreeMap::new();
+
+    let first_generation_id = OwnedGenerationId::from_boxed_slice(b"01".to_vec().into()).unwrap();
+
+    let mut updates = Vec::new();
+    for prefix in [b'a', b'b', b'c'] {
+        for i in 0..10u8 {
+            updates.push((vec![prefix, b'0' + i], Some(vec![i])));
+        }
+    }
+    // Key that is equal to the prefix itself
+    updates.push((vec![b'b'], Some(vec![42])));
+
+    put_generation(&collection, &first_generation_id, updates, &mut state).await;
+    let first_generation_state = state.clone();
+
+    let second_generation_id = OwnedGenerationId::from_boxed_slice(b"02".to_vec().into()).unwrap();
+
+    let updates = vec![
+        (b"a7".to_vec(), Some(vec![100])),
+        (b"b2".to_vec(), None),
+        (b"b3".to_vec(), Some(vec![101])),
+        (b"b5".to_vec(), None),
+        (b"c0".to_vec(), None),
+    ];
+
+    put_generation(&collection, &second_generation_id, updates, &mut state).await;
+
+    let cases: Vec<RangeCase<'_>> = vec![
+        (Bound::Unbounded, Bound::Unbounded, None),
+        (Bound::Included(b"a5"), Bound::Excluded(b"b3"), None),
+        (Bound::Excluded(b"a5"), Bound::Included(b"b3"), None),
+        (Bound::Included(b"a55"), Bound::Included(b"c"), None),
+        (Bound::Unbounded, Bound::Unbounded, Some(b"b")),
+        (Bound::Excluded(b"b"), Bound::Unbounded, Some(b"b")),
+        (Bound::Included(b"a"), Bound::Excluded(b"b4"), Some(b"b")),
+        (Bound::Unbounded, Bound::Unbounded, Some(b"d")),
+        (Bound::Included(b"c"), Bound::Excluded(b"b"), None),
+    ];
+
+    for (from, to, prefix) in cases {
+        for reverse in [false, true] {
+            for (generation_id, state) in [
+                (&first_generation_id, &first_generation_state),
+                (&second_generation_id, &state),
+            ] {
+                let expected = expected_items(state, from, to, prefix, reverse);
+
+                let actual = query_all(
+                    &collection,
+                    QueryOptions {
+                        generation_id: Some(generation_id.clone()),
+                        phantom_id: None,
+                        from_key: from.map(to_key),
+                        to_key: to.map(to_key),
+                        prefix: prefix.map(to_key),
+                        reverse,
+                    },
+                )
+                .await;
+
+                assert_eq!(
+                    actual, expected,
+                    "from: {:?}, to: {:?}, prefix: {:?}, reverse: {}",
+                    from, to, prefix, reverse
+                );
+            }
+        }
+    }
+}
+
+fn to_key(bytes: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(bytes.into()).unwrap()
+}
+
+async fn put_generation(
+    collection: &Collection,
+    generation_id: &OwnedGenerationId,
+    updates: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+    state: &mut BTreeMap<Vec<u8>, Vec<u8>>,
+) {
+    collection
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id.clone(),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+
+    let mut items = Vec::with_capacity(updates.len());
+
+    for (key, value) in updates {
+        items.push(KeyValueUpdate::new(KeyValueUpdateNewOptions {
+            key: to_key(&key),
+            value: value.as_ref().map(|value| OwnedCollectionValue::new(value)),
+            if_not_present: false,
+            if_value_changed: None,
+        }));
+
+        match value {
+            Some(value) => {
+                state.insert(key, value);
+            }
+            None => {
+                state.remove(&key);
+            }
+        }
+    }
+
+    collection
+        .put_many(CollectionPutManyOptions {
+            items,
+            generation_id: Some(generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .commit_generation(CommitGenerationOptions {
+            generation_id: generation_id.clone(),
+            update_readers: None,
+        })
+        .await
+        .unwrap();
+}
+
+fn expected_items(
+    state: &BTreeMap<Vec<u8>, Vec<u8>>,
+    from: Bound<&[u8]>,
+    to: Bound<&[u8]>,
+    prefix: Option<&[u8]>,
+    reverse: bool,
+) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut items: Vec<(Vec<u8>, Vec<u8>)> = state
+        .iter()
+        .filter(|(key, _)| {
+            let key = key.as_slice();
+
+            let is_after_from = match from {
+                Bound::Included(from) => key >= from,
+                Bound::Excluded(from) => key > from,
+                Bound::Unbounded => true,
+            };
+            let is_before_to = match to {
+                Bound::Included(to) => key <= to,
+                Bound::Excluded(to) => key < to,
+                Bound::Unbounded => true,
+            };
+            let is_prefixed = prefix.map(|prefix| key.starts_with(prefix)).unwrap_or(true);
+
+            is_after_from && is_before_to && is_prefixed
+        })
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+
+    if reverse {
+        items.reverse();
+    }
+
+    items
+}
+
+async fn query_all(collection: &Collection, options: QueryOptions) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut result = Vec::new();
+
+    let QueryOk {
+        generation_id: _,
+        mut items,
+        mut cursor_id,
+    } = collection.query(options).await.unwrap();
+
+    loop {
+        for item in items {
+            result.push((
+                item.key.as_ref().get_byte_array().to_vec(),
+                item.value.get_value().to_vec(),
+            ));
+        }
+
+        let Some(id) = cursor_id else {
+            break;
+        };
+
+        let next = collection
+            .read_query_cursor(ReadQueryCursorOptions { cursor_id: id })
+            .await
+            .unwrap();
+
+        items = next.items;
+        cursor_id = next.cursor_id;
+    }
+
+    result
+}
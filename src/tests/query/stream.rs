@@ -0,0 +1,250 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::diff::DiffOptions;
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::methods::query::{QueryOk, QueryOptions};
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::Collection;
+use crate::common::generation_id::GenerationIdSource;
+use crate::common::{
+    IsByteArray, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::database::config::DatabaseConfig;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::http::data::diff_response::DiffResponseJsonData;
+use crate::http::data::key_value::KeyValueJsonData;
+use crate::http::data::key_value_diff::KeyValueDiffJsonData;
+use crate::http::data::query_response::QueryResponseJsonData;
+use crate::http::routing::response::Response;
+use crate::http::routing::routes::diff::stream::create_diff_stream_response;
+use crate::http::routing::routes::query::stream::create_query_stream_response;
+use crate::http::validation::BodyFormat;
+use crate::protos::http_api;
+use crate::tests::temp_database::TempDatabase;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use hyper::Body;
+use protobuf::CodedInputStream;
+use std::ops::Bound;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[test]
+fn query_stream_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(query_stream_test_inner());
+}
+
+async fn query_stream_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        query_pack_limit: 10,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    initialize(&collection).await;
+
+    // NDJSON: header and then all 25 records, not only the first pack
+    let result = query(&collection).await;
+    let body = stream_body(&collection, result, BodyFormat::Json);
+    let bytes = hyper::body::to_bytes(body).await.unwrap();
+    let text = std::str::from_utf8(&bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 26);
+
+    let header: QueryResponseJsonData = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(
+        header
+            .generation_id
+            .into_generation_id()
+            .unwrap()
+            .get_byte_array(),
+        b"0"
+    );
+    assert!(header.items.is_empty());
+    assert!(header.cursor_id.is_none());
+
+    for line in &lines[1..] {
+        let _: KeyValueJsonData = serde_json::from_str(line).unwrap();
+    }
+
+    // Protobuf: length-delimited messages
+    let result = query(&collection).await;
+    let body = stream_body(&collection, result, BodyFormat::Protobuf);
+    let bytes = hyper::body::to_bytes(body).await.unwrap();
+    let mut input = CodedInputStream::from_bytes(&bytes);
+
+    let header: http_api::QueryResponse = input.read_message().unwrap();
+    assert_eq!(header.generation_id, b"0");
+
+    let mut keys = Vec::new();
+    while !input.eof().unwrap() {
+        let item: http_api::KeyValue = input.read_message().unwrap();
+        keys.push(item.key[0]);
+    }
+
+    assert_eq!(keys, (0..25).collect::<Vec<u8>>());
+
+    // Cursor is aborted when the client goes away
+    let result = query(&collection).await;
+    let cursors_count = collection.query_cursors_count().await;
+
+    let body = stream_body(&collection, result, BodyFormat::Json);
+    drop(body);
+
+    for _ in 0..100 {
+        if collection.query_cursors_count().await < cursors_count {
+            break;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(collection.query_cursors_count().await, cursors_count - 1);
+}
+
+#[test]
+fn diff_stream_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(diff_stream_test_inner());
+}
+
+async fn diff_stream_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        diff_pack_limit: 10,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    initialize(&collection).await;
+
+    // Header and then all 25 changes, not only the first pack
+    let result = collection
+        .diff(DiffOptions {
+            from_generation_id: GenerationIdSource::Value(None),
+            to_generation_id_loose: None,
+            omit_intermediate_values: true,
+        })
+        .await
+        .unwrap();
+    assert!(result.cursor_id.is_some());
+
+    let response = create_diff_stream_response(collection.clone(), result, BodyFormat::Json);
+    let Response::Stream(response) = response else {
+        panic!("not a stream response");
+    };
+
+    let bytes = hyper::body::to_bytes(response.body).await.unwrap();
+    let text = std::str::from_utf8(&bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 26);
+
+    let header: DiffResponseJsonData = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(
+        header
+            .to_generation_id
+            .into_generation_id()
+            .unwrap()
+            .get_byte_array(),
+        b"0"
+    );
+    assert!(header.items.is_empty());
+    assert!(header.cursor_id.is_none());
+
+    for line in &lines[1..] {
+        let _: KeyValueDiffJsonData = serde_json::from_str(line).unwrap();
+    }
+}
+
+async fn query(collection: &Collection) -> QueryOk {
+    collection
+        .query(QueryOptions {
+            generation_id: None,
+            phantom_id: None,
+            from_key: Bound::Unbounded,
+            to_key: Bound::Unbounded,
+            prefix: None,
+            reverse: false,
+        })
+        .await
+        .unwrap()
+}
+
+fn stream_body(collection: &Arc<Collection>, result: QueryOk, format: BodyFormat) -> Body {
+    let response = create_query_stream_response(collection.clone(), result, format);
+
+    let Response::Stream(response) = response else {
+        panic!("not a stream response");
+    };
+
+    response.body
+}
+
+async fn initialize(collection: &Collection) {
+    let generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0".to_vec().into_boxed_slice()).unwrap();
+
+    collection
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id.clone(),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+
+    let items = (0..25u8)
+        .map(|i| {
+            KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: OwnedCollectionKey::from_boxed_slice(vec![i].into_boxed_slice()).unwrap(),
+                value: Some(OwnedCollectionValue::new(&[i])),
+                if_not_present: false,
+                if_value_changed: None,
+            })
+        })
+        .collect();
+
+    collection
+        .put_many(CollectionPutManyOptions {
+            items,
+            generation_id: Some(generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .commit_generation(CommitGenerationOptions {
+            generation_id,
+            update_readers: None,
+        })
+        .await
+        .unwrap();
+}
@@ -22,7 +22,13 @@ async fn readers_test_inner() {
     let database = temp_database.get_database();
 
     let collection = database
-        .create_collection("test", CreateCollectionOptions { is_manual: true })
+        .create_collection(
+            "test",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
         .await
         .expect("Collection create");
 
@@ -72,11 +78,13 @@ async fn readers_test_inner() {
             reader_name: "first".to_string(),
             collection_name: Some("other_collection".to_string()),
             generation_id: None,
+            is_orphaned: false,
         },
         ReaderRecord {
             reader_name: "second".to_string(),
             collection_name: None,
             generation_id: None,
+            is_orphaned: false,
         },
     ];
 
@@ -118,6 +126,7 @@ async fn readers_test_inner() {
                 OwnedGenerationId::from_boxed_slice(b"some_gen".to_vec().into_boxed_slice())
                     .unwrap(),
             ),
+            is_orphaned: false,
         },
         ReaderRecord {
             reader_name: "second".to_string(),
@@ -126,6 +135,7 @@ async fn readers_test_inner() {
                 OwnedGenerationId::from_boxed_slice(b"another_gen".to_vec().into_boxed_slice())
                     .unwrap(),
             ),
+            is_orphaned: false,
         },
     ];
 
@@ -0,0 +1,227 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::Collection;
+use crate::common::{
+    KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey, OwnedCollectionValue,
+    OwnedGenerationId,
+};
+use crate::database::config::{DatabaseConfig, DeletedCollectionReadersMode};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::database::Database;
+use crate::http::config::{HttpConfig, ListenAddress};
+use crate::http::server::start_http_server;
+use crate::tests::http_server::{create_context, send_unix_request};
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::temp_dir::TempDir;
+use crate::tests::util::manual_generation::wrap_generation;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::sync::Arc;
+
+#[test]
+fn readers_status_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(readers_status_test_inner());
+}
+
+async fn readers_status_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        deleted_collection_readers: DeletedCollectionReadersMode::Orphan,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let source = create_manual_collection(database, "source").await;
+    let sink = create_manual_collection(database, "sink").await;
+
+    let first_generation_id = generation_id(b"0001");
+    let second_generation_id = generation_id(b"0002");
+
+    create_reader(&source, "own", Some(first_generation_id.clone()), None).await;
+    create_reader(&sink, "from_source", None, Some("source")).await;
+
+    wrap_generation(&source, first_generation_id.as_ref(), async {
+        put_keys(&source, &first_generation_id, &[b"1", b"3"]).await;
+    })
+    .await;
+    wrap_generation(&source, second_generation_id.as_ref(), async {
+        put_keys(&source, &second_generation_id, &[b"1", b"2"]).await;
+    })
+    .await;
+
+    // Generation in progress is not counted
+    let third_generation_id = generation_id(b"0003");
+    source
+        .start_generation(StartGenerationOptions {
+            generation_id: third_generation_id.clone(),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+    put_keys(&source, &third_generation_id, &[b"4"]).await;
+
+    let status = database.reader_status(&sink, "from_source").await.unwrap();
+    assert_eq!(status.target_collection_name, "source");
+    let target = status.target.unwrap();
+    assert_eq!(target.generation_id, second_generation_id);
+    assert_eq!(target.lag.generations_count, 2);
+    // Key `1` is counted in both generations
+    assert_eq!(target.lag.changed_keys_count, 4);
+
+    let status = database.reader_status(&source, "own").await.unwrap();
+    assert_eq!(status.target_collection_name, "source");
+    let target = status.target.unwrap();
+    assert_eq!(target.lag.generations_count, 1);
+    assert_eq!(target.lag.changed_keys_count, 2);
+
+    let result = database.reader_status(&source, "not_exists").await;
+    assert!(matches!(result, Err(CollectionMethodError::NoSuchReader)));
+
+    let fut = source.delete_collection();
+    drop(source);
+    fut.await.unwrap();
+
+    let statuses = database.readers_status(&sink).await.unwrap();
+    assert_eq!(statuses.len(), 1);
+    assert!(statuses[0].reader.is_orphaned);
+    assert!(statuses[0].target.is_none());
+}
+
+#[test]
+fn readers_status_route_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(readers_status_route_test_inner());
+}
+
+async fn readers_status_route_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.get_path_buf();
+    let socket_path = path.join("diffbelt.sock");
+
+    let context = create_context(
+        path.join("data"),
+        HttpConfig {
+            listen_addresses: vec![ListenAddress::Unix(socket_path.clone())],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let server = tokio::spawn(start_http_server(context));
+
+    for (path, body) in [
+        (
+            "/collections/",
+            "{\"collectionName\":\"c\",\"isManual\":true,\"initialGenerationId\":{\"value\":\"0000\"}}",
+        ),
+        (
+            "/collections/c/readers/",
+            "{\"readerName\":\"r\",\"generationId\":null}",
+        ),
+        (
+            "/collections/c/generation/start",
+            "{\"generationId\":{\"value\":\"0001\"}}",
+        ),
+        (
+            "/collections/c/put",
+            "{\"generationId\":{\"value\":\"0001\"},\"item\":{\"key\":{\"value\":\"a\"},\"value\":{\"value\":\"b\"}}}",
+        ),
+        (
+            "/collections/c/generation/commit",
+            "{\"generationId\":{\"value\":\"0001\"}}",
+        ),
+    ] {
+        let response = send_unix_request(&socket_path, "POST", path, None, body).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+    }
+
+    let expected = "{\"collectionName\":\"c\",\"readerName\":\"r\",\"targetCollectionName\":\"c\",\
+                    \"generationId\":{\"value\":\"\"},\"targetGenerationId\":{\"value\":\"0001\"},\
+                    \"generationsBehind\":1,\"changedKeysCount\":1}";
+
+    let response =
+        send_unix_request(&socket_path, "GET", "/collections/c/readers/r", None, "").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with(expected), "{}", response);
+
+    let response = send_unix_request(&socket_path, "GET", "/readers/", None, "").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(
+        response.ends_with(&format!("{{\"items\":[{}]}}", expected)),
+        "{}",
+        response
+    );
+
+    let response = send_unix_request(
+        &socket_path,
+        "GET",
+        "/collections/c/readers/missing",
+        None,
+        "",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+
+    server.abort();
+}
+
+async fn create_manual_collection(database: &Database, name: &str) -> Arc<Collection> {
+    database
+        .create_collection(
+            name,
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_reader(
+    collection: &Collection,
+    reader_name: &str,
+    generation_id: Option<OwnedGenerationId>,
+    collection_name: Option<&str>,
+) {
+    collection
+        .create_reader(CreateReaderOptions {
+            reader_name: reader_name.to_string(),
+            collection_name: collection_name.map(|name| name.to_string()),
+            generation_id,
+        })
+        .await
+        .unwrap();
+}
+
+async fn put_keys(collection: &Collection, generation_id: &OwnedGenerationId, keys: &[&[u8]]) {
+    let items = keys
+        .iter()
+        .map(|key| {
+            KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: OwnedCollectionKey::from_boxed_slice(key.to_vec().into_boxed_slice()).unwrap(),
+                value: Some(OwnedCollectionValue::from_boxed_slice(
+                    b"42".to_vec().into_boxed_slice(),
+                )),
+                if_not_present: false,
+                if_value_changed: None,
+            })
+        })
+        .collect();
+
+    collection
+        .put_many(CollectionPutManyOptions {
+            items,
+            generation_id: Some(generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+}
+
+fn generation_id(bytes: &[u8]) -> OwnedGenerationId {
+    OwnedGenerationId::from_boxed_slice(bytes.to_vec().into_boxed_slice()).unwrap()
+}
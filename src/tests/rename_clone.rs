@@ -0,0 +1,224 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::get::CollectionGetOptions;
+use crate::collection::methods::put::CollectionPutOptions;
+use crate::collection::Collection;
+use crate::common::reader::ReaderRecord;
+use crate::common::{
+    KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionValue, OwnedPhantomId,
+};
+use crate::database::clone_collection::{CloneCollectionError, CloneCollectionOptions};
+use crate::database::rename_collection::RenameCollectionError;
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::util::collection::{
+    commit_value, create_collection, generation_id, get_value, key,
+};
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+
+#[test]
+fn rename_collection_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(rename_collection_test_inner());
+}
+
+async fn rename_collection_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = create_collection(database, "old").await;
+    let other = create_collection(database, "other").await;
+
+    commit_value(&collection, b"key", b"first", b"0001").await;
+
+    create_reader(&collection, "own", None).await;
+    create_reader(&other, "to_old", Some("old")).await;
+
+    drop(collection);
+
+    let result = database.rename_collection("old", "other").await;
+    assert!(matches!(result, Err(RenameCollectionError::AlreadyExist)));
+
+    let collection = database.rename_collection("old", "new").await.unwrap();
+
+    assert!(database.get_collection("old").await.is_none());
+    assert!(database.get_collection("new").await.is_some());
+
+    assert_eq!(
+        get_value(&collection, b"key", None).await,
+        Some(b"first".to_vec())
+    );
+
+    let readers = collection.list_readers().await.unwrap().items;
+    assert_eq!(readers.len(), 1);
+    assert_eq!(readers[0].reader_name, "own");
+
+    let readers = other.list_readers().await.unwrap().items;
+    assert_eq!(
+        readers,
+        vec![ReaderRecord {
+            reader_name: "to_old".to_string(),
+            collection_name: Some("new".to_string()),
+            generation_id: None,
+            is_orphaned: false,
+        }]
+    );
+
+    let result = database.rename_collection("old", "another").await;
+    assert!(matches!(
+        result,
+        Err(RenameCollectionError::NoSuchCollection)
+    ));
+}
+
+#[test]
+fn clone_collection_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(clone_collection_test_inner());
+}
+
+async fn clone_collection_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = create_collection(database, "source").await;
+
+    commit_value(&collection, b"key", b"first", b"0001").await;
+    commit_value(&collection, b"key", b"second", b"0002").await;
+
+    let phantom_id = collection.start_phantom().await.unwrap();
+    put_phantom_value(&collection, b"0002", &phantom_id, b"phantom").await;
+    assert_eq!(
+        get_phantom_value(&collection, &phantom_id).await,
+        Some(b"phantom".to_vec())
+    );
+
+    let current = database
+        .clone_collection(
+            "source",
+            "current",
+            CloneCollectionOptions {
+                generation_id: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        current.generation_pair().generation_id,
+        generation_id(b"0002")
+    );
+    assert_eq!(
+        get_value(&current, b"key", None).await,
+        Some(b"second".to_vec())
+    );
+
+    let snapshot = database
+        .clone_collection(
+            "source",
+            "snapshot",
+            CloneCollectionOptions {
+                generation_id: Some(generation_id(b"0001")),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        snapshot.generation_pair().generation_id,
+        generation_id(b"0001")
+    );
+    assert_eq!(
+        get_value(&snapshot, b"key", None).await,
+        Some(b"first".to_vec())
+    );
+
+    // Clone is independent from the source
+    commit_value(&snapshot, b"key", b"snapshot", b"0003").await;
+
+    assert_eq!(
+        get_value(&collection, b"key", None).await,
+        Some(b"second".to_vec())
+    );
+    assert_eq!(
+        get_value(&snapshot, b"key", None).await,
+        Some(b"snapshot".to_vec())
+    );
+
+    // Phantom record of the generation after the snapshot is removed too
+    assert_eq!(get_phantom_value(&snapshot, &phantom_id).await, None);
+
+    let result = database
+        .clone_collection(
+            "source",
+            "future",
+            CloneCollectionOptions {
+                generation_id: Some(generation_id(b"0005")),
+            },
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(CloneCollectionError::CollectionMethod(_))
+    ));
+    assert!(database.get_collection("future").await.is_none());
+
+    let result = database
+        .clone_collection(
+            "source",
+            "current",
+            CloneCollectionOptions {
+                generation_id: None,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(CloneCollectionError::AlreadyExist)));
+}
+
+async fn create_reader(collection: &Collection, reader_name: &str, collection_name: Option<&str>) {
+    collection
+        .create_reader(CreateReaderOptions {
+            reader_name: reader_name.to_string(),
+            collection_name: collection_name.map(|name| name.to_string()),
+            generation_id: None,
+        })
+        .await
+        .unwrap();
+}
+
+async fn get_phantom_value(
+    collection: &Collection,
+    phantom_id: &OwnedPhantomId,
+) -> Option<Vec<u8>> {
+    let result = collection
+        .get(CollectionGetOptions {
+            key: key(b"key"),
+            generation_id: None,
+            phantom_id: Some(phantom_id.clone()),
+        })
+        .await
+        .unwrap();
+
+    result.item.map(|item| item.value.get_value().to_vec())
+}
+
+async fn put_phantom_value(
+    collection: &Collection,
+    id: &[u8],
+    phantom_id: &OwnedPhantomId,
+    value: &[u8],
+) {
+    collection
+        .put(CollectionPutOptions {
+            update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(b"key"),
+                value: Some(OwnedCollectionValue::new(value)),
+                if_not_present: false,
+                if_value_changed: None,
+            }),
+            generation_id: Some(generation_id(id)),
+            phantom_id: Some(phantom_id.clone()),
+        })
+        .await
+        .unwrap();
+}
@@ -2,6 +2,7 @@ use crate::database::config::DatabaseConfig;
 use crate::database::open::DatabaseOpenOptions;
 use crate::database::Database;
 use crate::tests::temp_dir::TempDir;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 pub struct TempDatabase {
@@ -35,6 +36,10 @@ impl TempDatabase {
     pub fn get_database(&self) -> &Database {
         self.database.as_ref().unwrap()
     }
+
+    pub fn get_path_buf(&self) -> &PathBuf {
+        self.temp_dir.as_ref().unwrap().get_path_buf()
+    }
 }
 
 impl Drop for TempDatabase {
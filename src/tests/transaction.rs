@@ -0,0 +1,379 @@
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::errors::CollectionMethodError;
+use crate::collection::CommitGenerationUpdateReader;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::database::open::DatabaseOpenOptions;
+use crate::database::transaction::{
+    CommitTransactionCollection, TransactionCollection, TransactionError,
+};
+use crate::database::Database;
+use crate::protos::database_meta::{
+    TransactionCollectionRecord, TransactionReaderUpdate, TransactionRecord,
+};
+use crate::raw_db::{RawDb, RawDbOptions};
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::temp_dir::TempDir;
+use crate::tests::util::collection::{create_collection, generation_id, get_value, put_value};
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use protobuf::Message;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+#[test]
+fn transaction_commit_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(transaction_commit_test_inner());
+}
+
+async fn transaction_commit_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let first = create_collection(database, "first").await;
+    let second = create_collection(database, "second").await;
+
+    first
+        .create_reader(CreateReaderOptions {
+            reader_name: "reader".to_string(),
+            collection_name: None,
+            generation_id: None,
+        })
+        .await
+        .unwrap();
+
+    database
+        .start_transaction(transaction_collections(b"0001"), false)
+        .await
+        .unwrap();
+
+    put_value(&first, b"key", Some(b"first"), b"0001").await;
+    put_value(&second, b"key", Some(b"second"), b"0001").await;
+
+    database
+        .commit_transaction(vec![
+            CommitTransactionCollection {
+                collection_name: "second".to_string(),
+                generation_id: generation_id(b"0001"),
+                update_readers: None,
+            },
+            CommitTransactionCollection {
+                collection_name: "first".to_string(),
+                generation_id: generation_id(b"0001"),
+                update_readers: Some(vec![CommitGenerationUpdateReader {
+                    reader_name: Arc::from("reader"),
+                    generation_id: generation_id(b"0001"),
+                }]),
+            },
+        ])
+        .await
+        .unwrap();
+
+    for (collection, value) in [(&first, "first"), (&second, "second")] {
+        let pair = collection.generation_pair();
+        assert_eq!(pair.generation_id, generation_id(b"0001"));
+        assert_eq!(pair.next_generation_id, None);
+
+        assert_eq!(
+            get_value(collection, b"key", None).await,
+            Some(value.as_bytes().to_vec())
+        );
+    }
+
+    let readers = first.list_readers().await.unwrap().items;
+    assert_eq!(readers[0].generation_id, Some(generation_id(b"0001")));
+
+    // Nothing is committed if one of generations is outdated
+    database
+        .start_transaction(
+            vec![TransactionCollection {
+                collection_name: "first".to_string(),
+                generation_id: generation_id(b"0002"),
+            }],
+            false,
+        )
+        .await
+        .unwrap();
+
+    put_value(&first, b"key", Some(b"updated"), b"0002").await;
+
+    let result = database
+        .commit_transaction(commit_collections(b"0002"))
+        .await;
+    assert!(matches!(
+        result,
+        Err(TransactionError::CollectionMethod(
+            CollectionMethodError::OutdatedGeneration
+        ))
+    ));
+
+    assert_eq!(
+        first.generation_pair().generation_id,
+        generation_id(b"0001")
+    );
+    assert_eq!(
+        get_value(&first, b"key", None).await,
+        Some(b"first".to_vec())
+    );
+
+    database
+        .abort_transaction(vec![TransactionCollection {
+            collection_name: "first".to_string(),
+            generation_id: generation_id(b"0002"),
+        }])
+        .await
+        .unwrap();
+
+    let result = database
+        .commit_transaction(vec![CommitTransactionCollection {
+            collection_name: "first".to_string(),
+            generation_id: generation_id(b"0002"),
+            update_readers: None,
+        }])
+        .await;
+    assert!(matches!(
+        result,
+        Err(TransactionError::CollectionMethod(
+            CollectionMethodError::OutdatedGeneration
+        ))
+    ));
+    assert_eq!(
+        get_value(&first, b"key", None).await,
+        Some(b"first".to_vec())
+    );
+}
+
+#[test]
+fn transaction_validation_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(transaction_validation_test_inner());
+}
+
+async fn transaction_validation_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    create_collection(database, "first").await;
+
+    database
+        .create_collection(
+            "non_manual",
+            CreateCollectionOptions {
+                is_manual: false,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = database
+        .start_transaction(
+            vec![
+                TransactionCollection {
+                    collection_name: "first".to_string(),
+                    generation_id: generation_id(b"0001"),
+                },
+                TransactionCollection {
+                    collection_name: "non_manual".to_string(),
+                    generation_id: generation_id(b"0001"),
+                },
+            ],
+            false,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(TransactionError::NotManualCollection(name)) if name == "non_manual"
+    ));
+
+    // Generations are started only after validation of all collections
+    let result = database
+        .commit_transaction(vec![CommitTransactionCollection {
+            collection_name: "first".to_string(),
+            generation_id: generation_id(b"0001"),
+            update_readers: None,
+        }])
+        .await;
+    assert!(matches!(
+        result,
+        Err(TransactionError::CollectionMethod(
+            CollectionMethodError::OutdatedGeneration
+        ))
+    ));
+
+    let result = database
+        .commit_transaction(vec![
+            CommitTransactionCollection {
+                collection_name: "first".to_string(),
+                generation_id: generation_id(b"0001"),
+                update_readers: None,
+            },
+            CommitTransactionCollection {
+                collection_name: "first".to_string(),
+                generation_id: generation_id(b"0001"),
+                update_readers: None,
+            },
+        ])
+        .await;
+    assert!(matches!(
+        result,
+        Err(TransactionError::DuplicateCollection(name)) if name == "first"
+    ));
+
+    let result = database
+        .abort_transaction(vec![TransactionCollection {
+            collection_name: "unknown".to_string(),
+            generation_id: generation_id(b"0001"),
+        }])
+        .await;
+    assert!(matches!(
+        result,
+        Err(TransactionError::NoSuchCollection(name)) if name == "unknown"
+    ));
+}
+
+#[test]
+fn interrupted_transaction_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(interrupted_transaction_test_inner());
+}
+
+async fn interrupted_transaction_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let data_path = temp_dir.get_path_buf().clone();
+
+    {
+        let database = open_database(&data_path).await;
+
+        let first = create_collection(&database, "first").await;
+        let second = create_collection(&database, "second").await;
+
+        first
+            .create_reader(CreateReaderOptions {
+                reader_name: "reader".to_string(),
+                collection_name: None,
+                generation_id: None,
+            })
+            .await
+            .unwrap();
+
+        database
+            .start_transaction(transaction_collections(b"0001"), false)
+            .await
+            .unwrap();
+
+        put_value(&first, b"key", Some(b"first"), b"0001").await;
+        put_value(&second, b"key", Some(b"second"), b"0001").await;
+    }
+
+    // Process crashed after the record was written, but before any generation was committed
+    let mut record = TransactionRecord::new();
+    for name in ["first", "second"] {
+        let mut collection_record = TransactionCollectionRecord::new();
+        collection_record.collection_name = name.to_string();
+        collection_record.generation_id = b"0001".to_vec();
+
+        if name == "first" {
+            let mut reader_update = TransactionReaderUpdate::new();
+            reader_update.reader_name = "reader".to_string();
+            reader_update.generation_id = b"0001".to_vec();
+            collection_record.update_readers.push(reader_update);
+        }
+
+        record.collections.push(collection_record);
+    }
+
+    let meta_raw_db = open_meta_raw_db(&data_path).await;
+    meta_raw_db
+        .put_cf_sync(
+            DATABASE_RAW_DB_CF,
+            b"transaction:interrupted",
+            &record.write_to_bytes().unwrap(),
+        )
+        .unwrap();
+    drop(meta_raw_db);
+
+    {
+        let database = open_database(&data_path).await;
+
+        let first = database.get_collection("first").await.unwrap();
+        let second = database.get_collection("second").await.unwrap();
+
+        for (collection, value) in [(&first, "first"), (&second, "second")] {
+            let pair = collection.generation_pair();
+            assert_eq!(pair.generation_id, generation_id(b"0001"));
+            assert_eq!(pair.next_generation_id, None);
+
+            assert_eq!(
+                get_value(collection, b"key", None).await,
+                Some(value.as_bytes().to_vec())
+            );
+        }
+
+        let readers = first.list_readers().await.unwrap().items;
+        assert_eq!(readers[0].generation_id, Some(generation_id(b"0001")));
+    }
+
+    let meta_raw_db = open_meta_raw_db(&data_path).await;
+    assert_eq!(
+        meta_raw_db
+            .get_cf_sync(DATABASE_RAW_DB_CF, b"transaction:interrupted")
+            .unwrap(),
+        None
+    );
+}
+
+async fn open_database(data_path: &PathBuf) -> Database {
+    Database::open(DatabaseOpenOptions {
+        data_path,
+        config: Arc::new(Default::default()),
+    })
+    .await
+    .unwrap()
+}
+
+// Threads of the dropped database are releasing its files in the background
+async fn open_meta_raw_db(data_path: &PathBuf) -> RawDb {
+    let path = data_path.join("_meta");
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    loop {
+        let result = RawDb::open_raw_db(RawDbOptions {
+            path: path.to_str().unwrap(),
+            comparator: None,
+            column_families: vec![],
+            config: &Default::default(),
+        });
+
+        match result {
+            Ok(raw_db) => return raw_db,
+            Err(err) if Instant::now() > deadline => panic!("{:?}", err),
+            Err(_) => sleep(Duration::from_millis(10)).await,
+        }
+    }
+}
+
+fn transaction_collections(id: &[u8]) -> Vec<TransactionCollection> {
+    ["first", "second"]
+        .into_iter()
+        .map(|name| TransactionCollection {
+            collection_name: name.to_string(),
+            generation_id: generation_id(id),
+        })
+        .collect()
+}
+
+fn commit_collections(id: &[u8]) -> Vec<CommitTransactionCollection> {
+    ["first", "second"]
+        .into_iter()
+        .map(|name| CommitTransactionCollection {
+            collection_name: name.to_string(),
+            generation_id: generation_id(id),
+            update_readers: None,
+        })
+        .collect()
+}
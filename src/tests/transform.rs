@@ -0,0 +1,348 @@
+use crate::collection::methods::get::CollectionGetOptions;
+use crate::collection::methods::put::CollectionPutOptions;
+use crate::collection::Collection;
+use crate::common::{
+    KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey, OwnedCollectionValue,
+};
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::database::open::DatabaseOpenOptions;
+use crate::database::transforms::{
+    CreateTransformOptions, TransformError, TransformSource, TransformStep,
+};
+use crate::database::Database;
+use crate::protos::database_meta::{TransformRecord, TransformStepRecord};
+use crate::raw_db::{RawDb, RawDbOptions};
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::temp_dir::TempDir;
+use crate::transform::{Transform, TransformItem};
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use protobuf::Message;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+
+#[test]
+fn transform_steps_test() {
+    let step = TransformStep::KeyRemap {
+        from_prefix: Box::from(&b"user:"[..]),
+        to_prefix: Box::from(&b"u:"[..]),
+    }
+    .to_transform();
+
+    let result = step.apply("source", item("user:1", Some("{}"))).unwrap();
+    assert_eq!(result.key, key("u:1"));
+
+    let result = step.apply("source", item("other", Some("{}"))).unwrap();
+    assert_eq!(result.key, key("other"));
+
+    let step = TransformStep::KeyPrefixFilter {
+        prefix: Box::from(&b"user:"[..]),
+    }
+    .to_transform();
+
+    assert!(step.apply("source", item("user:1", None)).is_some());
+    assert!(step.apply("source", item("other", None)).is_none());
+
+    let step = TransformStep::ValueProjection {
+        fields: vec!["name".to_string(), "missing".to_string()],
+    }
+    .to_transform();
+
+    let result = step
+        .apply(
+            "source",
+            item("user:1", Some(r#"{"name":"Alice","age":30}"#)),
+        )
+        .unwrap();
+    assert_eq!(result.value, Some(value(r#"{"name":"Alice"}"#)));
+
+    // Deletions are passed, values that are not objects are filtered out
+    assert!(step.apply("source", item("user:1", None)).is_some());
+    assert!(step.apply("source", item("user:1", Some("[1]"))).is_none());
+}
+
+#[test]
+fn transform_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(transform_test_inner());
+}
+
+async fn transform_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let source = create_collection(database, "source", false).await;
+    let target = create_collection(database, "target", true).await;
+
+    put_value(&source, "user:1", Some(r#"{"name":"Alice","age":30}"#)).await;
+    put_value(&source, "other:1", Some(r#"{"name":"Bob"}"#)).await;
+
+    database
+        .create_transform(
+            "users",
+            transform_options(&["source"]),
+            vec![
+                TransformStep::KeyPrefixFilter {
+                    prefix: Box::from(&b"user:"[..]),
+                },
+                TransformStep::KeyRemap {
+                    from_prefix: Box::from(&b"user:"[..]),
+                    to_prefix: Box::from(&b"u:"[..]),
+                },
+                TransformStep::ValueProjection {
+                    fields: vec!["name".to_string()],
+                },
+            ],
+        )
+        .await
+        .unwrap();
+
+    wait_for_value(&target, "u:1", Some(r#"{"name":"Alice"}"#)).await;
+    assert_eq!(get_value(&target, "other:1").await, None);
+
+    // Deletions are transformed too
+    put_value(&source, "user:2", Some(r#"{"name":"Carol"}"#)).await;
+    put_value(&source, "user:1", None).await;
+
+    wait_for_value(&target, "u:2", Some(r#"{"name":"Carol"}"#)).await;
+    wait_for_value(&target, "u:1", None).await;
+
+    // Reader of the source is created in the target
+    let readers = target.list_readers().await.unwrap().items;
+    assert_eq!(readers.len(), 1);
+    assert_eq!(readers[0].reader_name, "source_reader");
+
+    let transforms = database.list_transforms().await;
+    assert_eq!(transforms.len(), 1);
+    assert_eq!(transforms[0].name, "users");
+    assert_eq!(
+        transforms[0].steps.as_ref().map(|steps| steps.len()),
+        Some(3)
+    );
+
+    let result = database
+        .create_transform("users", transform_options(&["source"]), vec![])
+        .await;
+    assert!(matches!(result, Err(TransformError::AlreadyExist)));
+
+    database.delete_transform("users").await.unwrap();
+    assert!(database.list_transforms().await.is_empty());
+
+    let result = database.delete_transform("users").await;
+    assert!(matches!(result, Err(TransformError::NoSuchTransform)));
+}
+
+struct SourceNameTransform;
+
+impl Transform for SourceNameTransform {
+    fn apply(&self, source_collection_name: &str, item: TransformItem) -> Option<TransformItem> {
+        Some(TransformItem {
+            key: item.key,
+            value: Some(value(source_collection_name)),
+        })
+    }
+}
+
+#[test]
+fn custom_transform_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(custom_transform_test_inner());
+}
+
+async fn custom_transform_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let first = create_collection(database, "first", false).await;
+    let second = create_collection(database, "second", false).await;
+    let target = create_collection(database, "target", true).await;
+
+    database
+        .start_custom_transform(
+            "custom",
+            transform_options(&["first", "second"]),
+            Arc::new(SourceNameTransform),
+        )
+        .await
+        .unwrap();
+
+    put_value(&first, "a", Some("1")).await;
+    put_value(&second, "b", Some("2")).await;
+
+    wait_for_value(&target, "a", Some("first")).await;
+    wait_for_value(&target, "b", Some("second")).await;
+
+    let transforms = database.list_transforms().await;
+    assert_eq!(transforms[0].steps, None);
+
+    let result = database
+        .start_custom_transform(
+            "invalid",
+            transform_options(&["first", "target"]),
+            Arc::new(SourceNameTransform),
+        )
+        .await;
+    assert!(matches!(result, Err(TransformError::TargetIsSource)));
+
+    let result = database
+        .start_custom_transform(
+            "invalid",
+            CreateTransformOptions {
+                sources: vec![],
+                target_collection_name: "first".to_string(),
+            },
+            Arc::new(SourceNameTransform),
+        )
+        .await;
+    assert!(matches!(result, Err(TransformError::NoSources)));
+
+    let result = database
+        .start_custom_transform(
+            "invalid",
+            CreateTransformOptions {
+                sources: vec![TransformSource {
+                    collection_name: "second".to_string(),
+                    reader_name: "reader".to_string(),
+                }],
+                target_collection_name: "first".to_string(),
+            },
+            Arc::new(SourceNameTransform),
+        )
+        .await;
+    assert!(matches!(result, Err(TransformError::TargetIsNotManual)));
+}
+
+#[test]
+fn unknown_transform_step_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(unknown_transform_step_test_inner());
+}
+
+async fn unknown_transform_step_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let data_path = temp_dir.get_path_buf();
+    let meta_path = data_path.join("_meta");
+
+    let meta_raw_db = RawDb::open_raw_db(RawDbOptions {
+        path: meta_path.to_str().unwrap(),
+        comparator: None,
+        column_families: vec![],
+        config: &Default::default(),
+    })
+    .unwrap();
+
+    // Step without a known variant, as if it was created by a newer version
+    let mut record = TransformRecord::new();
+    record.name = "unknown".to_string();
+    record.target_collection_name = "target".to_string();
+    record.steps.push(TransformStepRecord::new());
+
+    meta_raw_db
+        .put_cf_sync(
+            DATABASE_RAW_DB_CF,
+            b"transform:unknown",
+            &record.write_to_bytes().unwrap(),
+        )
+        .unwrap();
+    drop(meta_raw_db);
+
+    let database = Database::open(DatabaseOpenOptions {
+        data_path,
+        config: Arc::new(Default::default()),
+    })
+    .await
+    .unwrap();
+
+    assert!(database.list_transforms().await.is_empty());
+}
+
+fn key(key: &str) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(key.as_bytes().into()).unwrap()
+}
+
+fn value(value: &str) -> OwnedCollectionValue {
+    OwnedCollectionValue::new(value.as_bytes())
+}
+
+fn item(item_key: &str, item_value: Option<&str>) -> TransformItem {
+    TransformItem {
+        key: key(item_key),
+        value: item_value.map(value),
+    }
+}
+
+fn transform_options(sources: &[&str]) -> CreateTransformOptions {
+    CreateTransformOptions {
+        sources: sources
+            .iter()
+            .map(|name| TransformSource {
+                collection_name: name.to_string(),
+                reader_name: format!("{}_reader", name),
+            })
+            .collect(),
+        target_collection_name: "target".to_string(),
+    }
+}
+
+async fn create_collection(database: &Database, name: &str, is_manual: bool) -> Arc<Collection> {
+    database
+        .create_collection(
+            name,
+            CreateCollectionOptions {
+                is_manual,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+}
+
+async fn get_value(collection: &Collection, item_key: &str) -> Option<Vec<u8>> {
+    let result = collection
+        .get(CollectionGetOptions {
+            key: key(item_key),
+            generation_id: None,
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    result.item.map(|item| item.value.get_value().to_vec())
+}
+
+async fn wait_for_value(collection: &Collection, item_key: &str, expected: Option<&str>) {
+    let expected = expected.map(|value| value.as_bytes().to_vec());
+    let mut generation_pair_receiver = collection.generation_pair_receiver.clone();
+
+    loop {
+        generation_pair_receiver.borrow_and_update();
+
+        if get_value(collection, item_key).await == expected {
+            break;
+        }
+
+        timeout(Duration::from_secs(5), generation_pair_receiver.changed())
+            .await
+            .expect("transform has not committed the value")
+            .unwrap();
+    }
+}
+
+async fn put_value(collection: &Collection, item_key: &str, item_value: Option<&str>) {
+    collection
+        .put(CollectionPutOptions {
+            update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(item_key),
+                value: item_value.map(value),
+                if_not_present: false,
+                if_value_changed: None,
+            }),
+            generation_id: None,
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,210 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::create_reader::CreateReaderOptions;
+use crate::collection::methods::get::CollectionGetOptions;
+use crate::collection::methods::put::CollectionPutOptions;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::Collection;
+use crate::common::{
+    KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey, OwnedCollectionValue,
+    OwnedGenerationId,
+};
+use crate::database::config::DatabaseConfig;
+use crate::database::constants::DATABASE_RAW_DB_CF;
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::database::open::{DatabaseOpenError, DatabaseOpenOptions};
+use crate::database::trash::TrashError;
+use crate::database::Database;
+use crate::raw_db::{RawDb, RawDbOptions};
+use crate::tests::temp_database::TempDatabase;
+use crate::tests::temp_dir::TempDir;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn trash_restore_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(trash_restore_test_inner());
+}
+
+async fn trash_restore_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    // Empty collection with the same name, that was deleted earlier
+    let collection = create_collection(database).await;
+    delete_collection(collection).await;
+
+    let collection = create_collection(database).await;
+    put_value(&collection).await;
+
+    let _: () = collection
+        .create_reader(CreateReaderOptions {
+            reader_name: "reader".to_string(),
+            collection_name: None,
+            generation_id: None,
+        })
+        .await
+        .unwrap();
+
+    delete_collection(collection).await;
+
+    assert!(database.get_collection("manual").await.is_none());
+
+    let items = database.trash_list().await.unwrap();
+    assert_eq!(items.len(), 2);
+    assert!(items
+        .iter()
+        .all(|item| item.collection_name == "manual" && item.is_manual));
+    assert!(items[0].deleted_at <= items[1].deleted_at);
+
+    // Most recently deleted collection is restored
+    let collection = database.restore_collection("manual").await.unwrap();
+
+    let result = collection
+        .get(CollectionGetOptions {
+            key: key(),
+            generation_id: None,
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.item.unwrap().value.get_value(), b"value");
+
+    let readers = collection.list_readers().await.unwrap().items;
+    assert_eq!(readers.len(), 1);
+    assert_eq!(readers[0].reader_name, "reader");
+
+    let restored_items = database.trash_list().await.unwrap();
+    assert_eq!(restored_items, vec![items[0].clone()]);
+
+    // Restore is not possible while the name is taken
+    let result = database.restore_collection("manual").await;
+    assert!(matches!(result, Err(TrashError::AlreadyExist)));
+}
+
+#[test]
+fn trash_purge_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(trash_purge_test_inner());
+}
+
+async fn trash_purge_test_inner() {
+    let temp_database = TempDatabase::new_with_config(DatabaseConfig {
+        trash_retention: Duration::ZERO,
+        ..Default::default()
+    })
+    .await;
+
+    let database = temp_database.get_database();
+
+    let collection = create_collection(database).await;
+    put_value(&collection).await;
+    delete_collection(collection).await;
+
+    database.purge_trash().await.unwrap();
+
+    let items = database.trash_list().await.unwrap();
+    assert!(items.is_empty());
+
+    let result = database.restore_collection("manual").await;
+    assert!(matches!(result, Err(TrashError::NoSuchCollection)));
+}
+
+#[test]
+fn trash_invalid_record_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(trash_invalid_record_test_inner());
+}
+
+async fn trash_invalid_record_test_inner() {
+    let temp_dir = TempDir::new().unwrap();
+    let data_path = temp_dir.get_path_buf();
+    let meta_path = data_path.join("_meta");
+
+    let meta_raw_db = RawDb::open_raw_db(RawDbOptions {
+        path: meta_path.to_str().unwrap(),
+        comparator: None,
+        column_families: vec![],
+        config: &Default::default(),
+    })
+    .unwrap();
+
+    // Trash record without collection record
+    meta_raw_db
+        .put_cf_sync(DATABASE_RAW_DB_CF, b"trash:bad", b"")
+        .unwrap();
+    drop(meta_raw_db);
+
+    let result = Database::open(DatabaseOpenOptions {
+        data_path,
+        config: Arc::new(Default::default()),
+    })
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(DatabaseOpenError::Trash(TrashError::InvalidRecord(trash_id))) if trash_id == "bad"
+    ));
+}
+
+fn key() -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(b"key".to_vec().into_boxed_slice()).unwrap()
+}
+
+async fn create_collection(database: &Database) -> Arc<Collection> {
+    database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+}
+
+async fn delete_collection(collection: Arc<Collection>) {
+    let fut = collection.delete_collection();
+
+    drop(collection);
+
+    fut.await.unwrap();
+}
+
+async fn put_value(collection: &Collection) {
+    let generation_id =
+        OwnedGenerationId::from_boxed_slice(b"0001".to_vec().into_boxed_slice()).unwrap();
+
+    collection
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id.clone(),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .put(CollectionPutOptions {
+            update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(),
+                value: Some(OwnedCollectionValue::new(b"value")),
+                if_not_present: false,
+                if_value_changed: None,
+            }),
+            generation_id: Some(generation_id.clone()),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .commit_generation(CommitGenerationOptions {
+            generation_id,
+            update_readers: None,
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,76 @@
+use crate::collection::methods::get::CollectionGetOptions;
+use crate::collection::methods::put::CollectionPutOptions;
+use crate::collection::Collection;
+use crate::common::{
+    KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey, OwnedCollectionValue,
+    OwnedGenerationId,
+};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::database::Database;
+use crate::tests::util::manual_generation::wrap_generation;
+use std::sync::Arc;
+
+pub fn key(key: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(key.to_vec().into_boxed_slice()).unwrap()
+}
+
+pub fn generation_id(id: &[u8]) -> OwnedGenerationId {
+    OwnedGenerationId::from_boxed_slice(id.to_vec().into_boxed_slice()).unwrap()
+}
+
+pub async fn create_collection(database: &Database, name: &str) -> Arc<Collection> {
+    database
+        .create_collection(
+            name,
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+}
+
+pub async fn get_value(
+    collection: &Collection,
+    key_bytes: &[u8],
+    id: Option<&[u8]>,
+) -> Option<Vec<u8>> {
+    let result = collection
+        .get(CollectionGetOptions {
+            key: key(key_bytes),
+            generation_id: id.map(generation_id),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    result.item.map(|item| item.value.get_value().to_vec())
+}
+
+// Puts into the already started generation `id`
+pub async fn put_value(collection: &Collection, key_bytes: &[u8], value: Option<&[u8]>, id: &[u8]) {
+    collection
+        .put(CollectionPutOptions {
+            update: KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(key_bytes),
+                value: value.map(OwnedCollectionValue::new),
+                if_not_present: false,
+                if_value_changed: None,
+            }),
+            generation_id: Some(generation_id(id)),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+}
+
+// Starts generation `id`, puts the value and commits it
+pub async fn commit_value(collection: &Collection, key_bytes: &[u8], value: &[u8], id: &[u8]) {
+    wrap_generation(
+        collection,
+        generation_id(id).as_ref(),
+        put_value(collection, key_bytes, Some(value), id),
+    )
+    .await;
+}
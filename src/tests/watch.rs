@@ -0,0 +1,166 @@
+use crate::collection::methods::commit_generation::CommitGenerationOptions;
+use crate::collection::methods::put::CollectionPutManyOptions;
+use crate::collection::methods::start_generation::StartGenerationOptions;
+use crate::collection::methods::watch::WatchOptions;
+use crate::collection::Collection;
+use crate::common::{
+    IsByteArray, KeyValueUpdate, KeyValueUpdateNewOptions, OwnedCollectionKey,
+    OwnedCollectionValue, OwnedGenerationId,
+};
+use crate::database::create_collection::CreateCollectionOptions;
+use crate::tests::temp_database::TempDatabase;
+use crate::util::tokio_runtime::create_main_tokio_runtime;
+use std::ops::Bound;
+use std::time::Duration;
+
+#[test]
+fn watch_test() {
+    let runtime = create_main_tokio_runtime().unwrap();
+    runtime.block_on(watch_test_inner());
+}
+
+async fn watch_test_inner() {
+    let temp_database = TempDatabase::new().await;
+
+    let database = temp_database.get_database();
+
+    let collection = database
+        .create_collection(
+            "manual",
+            CreateCollectionOptions {
+                is_manual: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    commit_generation(&collection, b"1", &[b"a", b"b"]).await;
+
+    // Waits for a generation that touches the prefix
+    let watch = {
+        let collection = collection.clone();
+
+        tokio::spawn(async move {
+            collection
+                .watch(WatchOptions {
+                    from_generation_id: Some(generation_id(b"1")),
+                    from_key: Bound::Unbounded,
+                    to_key: Bound::Unbounded,
+                    prefix: Some(key(b"x")),
+                    timeout: Duration::from_secs(5),
+                })
+                .await
+                .unwrap()
+        })
+    };
+
+    commit_generation(&collection, b"2", &[b"a"]).await;
+    commit_generation(&collection, b"3", &[b"x2", b"x1", b"y"]).await;
+
+    let result = watch.await.unwrap();
+
+    assert_eq!(result.generation_id, generation_id(b"3"));
+    assert_eq!(result.keys, vec![key(b"x1"), key(b"x2")]);
+    assert!(!result.has_more);
+
+    // Already committed changes are returned right away
+    let result = collection
+        .watch(WatchOptions {
+            from_generation_id: Some(generation_id(b"1")),
+            from_key: Bound::Included(key(b"a")),
+            to_key: Bound::Included(key(b"a")),
+            prefix: None,
+            timeout: Duration::from_secs(5),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.generation_id, generation_id(b"3"));
+    assert_eq!(result.keys, vec![key(b"a")]);
+
+    // Timeout
+    let result = collection
+        .watch(WatchOptions {
+            from_generation_id: Some(generation_id(b"2")),
+            from_key: Bound::Included(key(b"b")),
+            to_key: Bound::Included(key(b"b")),
+            prefix: None,
+            timeout: Duration::from_millis(50),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.generation_id, generation_id(b"3"));
+    assert!(result.keys.is_empty());
+
+    commit_generation(&collection, b"4", &[b"b", b"c", b"z"]).await;
+
+    // Keys outside of the range are skipped in every generation
+    let result = collection
+        .watch(WatchOptions {
+            from_generation_id: Some(generation_id(b"1")),
+            from_key: Bound::Excluded(key(b"b")),
+            to_key: Bound::Included(key(b"x1")),
+            prefix: None,
+            timeout: Duration::from_secs(5),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.generation_id, generation_id(b"4"));
+    assert_eq!(result.keys, vec![key(b"c"), key(b"x1")]);
+    assert!(!result.has_more);
+}
+
+fn generation_id(bytes: &[u8]) -> OwnedGenerationId {
+    OwnedGenerationId::from_boxed_slice(bytes.to_vec().into_boxed_slice()).unwrap()
+}
+
+fn key(bytes: &[u8]) -> OwnedCollectionKey {
+    OwnedCollectionKey::from_boxed_slice(bytes.to_vec().into_boxed_slice()).unwrap()
+}
+
+async fn commit_generation(collection: &Collection, id: &[u8], keys: &[&[u8]]) {
+    collection
+        .start_generation(StartGenerationOptions {
+            generation_id: generation_id(id),
+            abort_outdated: false,
+        })
+        .await
+        .unwrap();
+
+    let items = keys
+        .iter()
+        .map(|bytes| {
+            KeyValueUpdate::new(KeyValueUpdateNewOptions {
+                key: key(bytes),
+                value: Some(OwnedCollectionValue::new(id)),
+                if_not_present: false,
+                if_value_changed: None,
+            })
+        })
+        .collect();
+
+    collection
+        .put_many(CollectionPutManyOptions {
+            items,
+            generation_id: Some(generation_id(id)),
+            phantom_id: None,
+        })
+        .await
+        .unwrap();
+
+    collection
+        .commit_generation(CommitGenerationOptions {
+            generation_id: generation_id(id),
+            update_readers: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        collection.generation_pair().generation_id.get_byte_array(),
+        id
+    );
+}
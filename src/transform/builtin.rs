@@ -0,0 +1,80 @@
+use crate::common::{IsByteArray, OwnedCollectionKey, OwnedCollectionValue};
+use crate::transform::{Transform, TransformItem};
+
+// Passes only records with keys starting with `prefix`
+pub struct KeyPrefixFilter {
+    pub prefix: Box<[u8]>,
+}
+
+impl Transform for KeyPrefixFilter {
+    fn apply(&self, _: &str, item: TransformItem) -> Option<TransformItem> {
+        if item.key.get_byte_array().starts_with(&self.prefix) {
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+// Replaces `from_prefix` of keys with `to_prefix`, other keys are not changed
+pub struct KeyRemap {
+    pub from_prefix: Box<[u8]>,
+    pub to_prefix: Box<[u8]>,
+}
+
+impl Transform for KeyRemap {
+    fn apply(&self, _: &str, item: TransformItem) -> Option<TransformItem> {
+        let Some(rest) = item
+            .key
+            .get_byte_array()
+            .strip_prefix(self.from_prefix.as_ref())
+        else {
+            return Some(item);
+        };
+
+        let mut key = Vec::with_capacity(self.to_prefix.len() + rest.len());
+        key.extend_from_slice(&self.to_prefix);
+        key.extend_from_slice(rest);
+
+        // Too long keys are filtered out
+        let key = OwnedCollectionKey::from_boxed_slice(key.into_boxed_slice()).ok()?;
+
+        Some(TransformItem {
+            key,
+            value: item.value,
+        })
+    }
+}
+
+// Values are JSON objects, only `fields` of them are kept,
+// records with values that are not JSON objects are filtered out
+pub struct ValueProjection {
+    pub fields: Vec<String>,
+}
+
+impl Transform for ValueProjection {
+    fn apply(&self, _: &str, item: TransformItem) -> Option<TransformItem> {
+        let Some(value) = item.value else {
+            return Some(item);
+        };
+
+        let serde_json::Value::Object(mut object) =
+            serde_json::from_slice(value.get_value()).ok()?
+        else {
+            return None;
+        };
+
+        let projection: serde_json::Map<String, serde_json::Value> = self
+            .fields
+            .iter()
+            .filter_map(|field| object.remove_entry(field))
+            .collect();
+
+        let bytes = serde_json::to_vec(&projection).ok()?;
+
+        Some(TransformItem {
+            key: item.key,
+            value: Some(OwnedCollectionValue::new(&bytes)),
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use crate::common::{OwnedCollectionKey, OwnedCollectionValue};
+
+pub mod builtin;
+
+pub struct TransformItem {
+    pub key: OwnedCollectionKey,
+    // `None` if record was deleted, then it will be deleted from the target too
+    pub value: Option<OwnedCollectionValue>,
+}
+
+// Applied to every changed record of source collections, results are put to the target
+pub trait Transform: Send + Sync {
+    // `None` filters the item out
+    fn apply(&self, source_collection_name: &str, item: TransformItem) -> Option<TransformItem>;
+}
+
+// Steps are applied in order, item is filtered out if any of them has filtered it
+pub struct TransformPipeline {
+    steps: Vec<Box<dyn Transform>>,
+}
+
+impl TransformPipeline {
+    pub fn new(steps: Vec<Box<dyn Transform>>) -> Self {
+        Self { steps }
+    }
+}
+
+impl Transform for TransformPipeline {
+    fn apply(&self, source_collection_name: &str, item: TransformItem) -> Option<TransformItem> {
+        self.steps
+            .iter()
+            .try_fold(item, |item, step| step.apply(source_collection_name, item))
+    }
+}
@@ -3,21 +3,24 @@ use crate::util::tokio::spawn_async_thread_local;
 use std::future::Future;
 use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinHandle;
+use tracing::Span;
 
 pub struct AsyncTaskThread<T: Send + 'static> {
-    task_sender: mpsc::Sender<T>,
+    task_sender: mpsc::Sender<(T, Span)>,
     stop_sender: AtomicCleanup<oneshot::Sender<()>>,
     join_handle: AtomicCleanup<JoinHandle<Option<()>>>,
 }
 
 pub struct TaskPoller<T> {
-    pub task_sender: mpsc::Sender<T>,
-    task_receiver: mpsc::Receiver<T>,
+    pub task_sender: mpsc::Sender<(T, Span)>,
+    task_receiver: mpsc::Receiver<(T, Span)>,
     stop_receiver: oneshot::Receiver<()>,
 }
 
 impl<T> TaskPoller<T> {
-    pub async fn poll(&mut self) -> Option<T> {
+    // Span is the one that was current when the task was added, threads are entering it
+    // while handling the task, so logs are attributed to the request that caused them
+    pub async fn poll(&mut self) -> Option<(T, Span)> {
         tokio::select! {
             result = self.task_receiver.recv() => {
                 match result {
@@ -42,7 +45,7 @@ impl<Task: Send + 'static> AsyncTaskThread<Task> {
     >(
         run: F,
         data: Data,
-        #[cfg(feature = "debug_prints")] name: &str,
+        name: &str,
     ) -> Self {
         let (task_sender, task_receiver) = mpsc::channel(1000);
         let (stop_sender, stop_receiver) = oneshot::channel();
@@ -59,7 +62,6 @@ impl<Task: Send + 'static> AsyncTaskThread<Task> {
                     },
                 )
             },
-            #[cfg(feature = "debug_prints")]
             name,
         )
         .await;
@@ -72,7 +74,10 @@ impl<Task: Send + 'static> AsyncTaskThread<Task> {
     }
 
     pub async fn add_task(&self, task: Task) {
-        self.task_sender.send(task).await.unwrap_or(());
+        self.task_sender
+            .send((task, Span::current()))
+            .await
+            .unwrap_or(());
     }
 
     #[allow(dead_code)]
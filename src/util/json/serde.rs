@@ -9,9 +9,19 @@ where
     Ok(opt.unwrap_or_default())
 }
 
+// Use with `#[serde(default)]` on `Option<Option<T>>` fields to tell absent field (`None`)
+// from the explicit `null` (`Some(None)`)
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::util::json::serde::deserialize_strict_null;
+    use crate::util::json::serde::{deserialize_nullable, deserialize_strict_null};
     use serde::{Deserialize, Serialize};
     use serde_with::skip_serializing_none;
 
@@ -90,4 +100,22 @@ mod tests {
         let result: Result<WithCustomNull, _> = serde_json::from_str(r#"{}"#);
         assert!(result.is_err());
     }
+
+    #[derive(Deserialize, Debug)]
+    struct WithNullable {
+        #[serde(default, deserialize_with = "deserialize_nullable")]
+        value: Option<Option<u32>>,
+    }
+
+    #[test]
+    fn test_nullable() {
+        let data: WithNullable = serde_json::from_str(r#"{"value":1}"#).unwrap();
+        assert_eq!(data.value, Some(Some(1)));
+
+        let data: WithNullable = serde_json::from_str(r#"{"value":null}"#).unwrap();
+        assert_eq!(data.value, Some(None));
+
+        let data: WithNullable = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(data.value, None);
+    }
 }
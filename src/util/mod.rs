@@ -7,12 +7,12 @@ pub mod auto_sender_on_drop;
 pub mod base62;
 pub mod bytes;
 pub mod bytes_constants;
-pub mod debug_print;
 pub mod hashmap;
 pub mod indexed_container;
 pub mod json;
 pub mod option;
 pub mod owned_peek;
+pub mod prometheus;
 pub mod str_serialization;
 pub mod tokio;
 pub mod tokio_runtime;
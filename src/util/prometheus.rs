@@ -0,0 +1,53 @@
+use std::fmt::{Display, Write};
+
+// Prometheus text exposition format, samples of a metric should follow its `metric` call
+#[derive(Default)]
+pub struct PrometheusWriter {
+    out: String,
+}
+
+impl PrometheusWriter {
+    // `kind` is "counter", "gauge" or "histogram"
+    pub fn metric(&mut self, name: &str, kind: &str, help: &str) {
+        writeln!(self.out, "# HELP {} {}", name, help).unwrap();
+        writeln!(self.out, "# TYPE {} {}", name, kind).unwrap();
+    }
+
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.out.push_str(name);
+
+        if !labels.is_empty() {
+            self.out.push('{');
+
+            for (i, (label, label_value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+
+                self.out.push_str(label);
+                self.out.push_str("=\"");
+                push_escaped_label_value(&mut self.out, label_value);
+                self.out.push('"');
+            }
+
+            self.out.push('}');
+        }
+
+        writeln!(self.out, " {}", value).unwrap();
+    }
+
+    pub fn finish(self) -> String {
+        self.out
+    }
+}
+
+fn push_escaped_label_value(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+}
@@ -1,18 +1,29 @@
 use crate::util::tokio_runtime::create_single_thread_tokio_runtime;
 use std::future::Future;
 
-#[cfg(feature = "debug_prints")]
-use crate::util::debug_print::debug_print;
 use std::thread;
-use tokio::task::{JoinError, LocalSet};
+use tokio::task::{JoinError, JoinHandle, LocalSet};
+use tracing::{Instrument, Span};
+
+// All spawns are keeping the current span, so logs of spawned tasks have the request id
 
 pub fn spawn(f: impl Future<Output = ()> + Send + 'static) {
-    tokio::spawn(f);
+    tokio::spawn(f.in_current_span());
+}
+
+pub fn spawn_blocking<T: Send + 'static, F: (FnOnce() -> T) + Send + 'static>(
+    f: F,
+) -> JoinHandle<T> {
+    let span = Span::current();
+
+    tokio::task::spawn_blocking(move || span.in_scope(f))
 }
 
 pub async fn spawn_blocking_async<T: Send + 'static>(
     f: impl Future<Output = T> + Send + 'static,
 ) -> Result<T, JoinError> {
+    let f = f.in_current_span();
+
     let result = tokio::task::spawn_blocking(move || {
         let runtime = create_single_thread_tokio_runtime().expect("Cannot create tokio runtime");
 
@@ -29,32 +40,30 @@ pub async fn spawn_async_thread_local<
     F: (FnOnce() -> Fut) + Send + 'static,
 >(
     f: F,
-    #[cfg(feature = "debug_prints")] name: &str,
+    name: &str,
 ) -> tokio::task::JoinHandle<Option<T>> {
-    #[cfg(feature = "debug_prints")]
-    let name = {
-        debug_print(format!("Run: {}", name).as_str());
+    tracing::debug!(thread = name, "thread is started");
 
-        Box::from(name) as Box<str>
-    };
+    let name = Box::from(name) as Box<str>;
 
-    let join_handle = thread::spawn(move || {
-        let runtime = create_single_thread_tokio_runtime().expect("Cannot create tokio runtime");
+    let join_handle = thread::Builder::new()
+        .name(name.to_string())
+        .spawn(move || {
+            let runtime =
+                create_single_thread_tokio_runtime().expect("Cannot create tokio runtime");
 
-        runtime.block_on(async move {
-            let local = LocalSet::new();
+            runtime.block_on(async move {
+                let local = LocalSet::new();
 
-            local.run_until(f()).await
+                local.run_until(f()).await
+            })
         })
-    });
+        .expect("Cannot spawn thread");
 
     tokio::spawn(async move {
         let result = tokio::task::spawn_blocking(move || join_handle.join()).await;
 
-        #[cfg(feature = "debug_prints")]
-        {
-            debug_print(format!("Finish: {}\n", name).as_str());
-        }
+        tracing::debug!(thread = &*name, "thread is finished");
 
         match result {
             Ok(Ok(result)) => Some(result),
@@ -88,7 +97,6 @@ mod tests {
                     let (answer, sender) = receiver.await.unwrap();
                     sender.send(answer).unwrap_or(());
                 },
-                #[cfg(feature = "debug_prints")]
                 "a",
             )
             .await;
@@ -103,7 +111,6 @@ mod tests {
 
                     result_sender.send(format!("Answer is {}", answer)).unwrap();
                 },
-                #[cfg(feature = "debug_prints")]
                 "b",
             )
             .await;
@@ -143,7 +150,6 @@ mod tests {
                         }
                     }
                 },
-                #[cfg(feature = "debug_prints")]
                 "a",
             )
             .await;